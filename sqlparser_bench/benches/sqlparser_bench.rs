@@ -152,5 +152,59 @@ fn parse_many_identifiers(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, basic_queries, word_to_ident, parse_many_identifiers);
+/// Benchmark parsing statement-heavy corpora, which exercise keyword dispatch
+fn parse_corpora(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_corpora");
+    let dialect = GenericDialect {};
+
+    let alter_statements = [
+        "ALTER TABLE t ADD COLUMN c INT NOT NULL DEFAULT 0",
+        "ALTER TABLE t DROP COLUMN IF EXISTS c CASCADE",
+        "ALTER TABLE t RENAME COLUMN a TO b",
+        "ALTER TABLE t RENAME TO u",
+        "ALTER TABLE t ALTER COLUMN c SET DATA TYPE BIGINT",
+        "ALTER TABLE t ALTER COLUMN c DROP NOT NULL",
+        "ALTER TABLE t ADD CONSTRAINT pk PRIMARY KEY (a, b)",
+        "ALTER TABLE t DROP CONSTRAINT IF EXISTS fk",
+        "ALTER TABLE t OWNER TO admin",
+        "ALTER TABLE t ENABLE ROW LEVEL SECURITY",
+    ];
+    let alter_corpus = (0..100)
+        .map(|n| alter_statements[n % alter_statements.len()])
+        .collect::<Vec<_>>()
+        .join(";\n");
+
+    group.bench_function("alter_table_100", |b| {
+        b.iter(|| Parser::parse_sql(&dialect, std::hint::black_box(&alter_corpus)));
+    });
+
+    let select_statements = [
+        "SELECT a, b FROM t WHERE a > 1 ORDER BY b DESC LIMIT 10",
+        "SELECT DISTINCT a FROM t GROUP BY a HAVING COUNT(*) > 1",
+        "SELECT * FROM t1 LEFT JOIN t2 ON t1.id = t2.id WHERE t2.id IS NULL",
+        "SELECT CAST(a AS VARCHAR(10)), CASE WHEN b THEN 1 ELSE 0 END FROM t",
+        "SELECT a FROM t UNION ALL SELECT b FROM u",
+        "WITH c AS (SELECT 1 AS x) SELECT x FROM c",
+        "SELECT ROW_NUMBER() OVER (PARTITION BY a ORDER BY b) FROM t",
+        "SELECT a FROM t WHERE b IN (SELECT b FROM u) AND c BETWEEN 1 AND 2",
+    ];
+    let select_corpus = (0..100)
+        .map(|n| select_statements[n % select_statements.len()])
+        .collect::<Vec<_>>()
+        .join(";\n");
+
+    group.bench_function("select_100", |b| {
+        b.iter(|| Parser::parse_sql(&dialect, std::hint::black_box(&select_corpus)));
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    basic_queries,
+    word_to_ident,
+    parse_many_identifiers,
+    parse_corpora
+);
 criterion_main!(benches);
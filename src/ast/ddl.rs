@@ -41,16 +41,17 @@ use crate::ast::{
         CheckConstraint, ForeignKeyConstraint, PrimaryKeyConstraint, TableConstraint,
         UniqueConstraint,
     },
-    ArgMode, AttachedToken, CommentDef, ConditionalStatements, CreateFunctionBody,
-    CreateFunctionUsing, CreateTableLikeKind, CreateTableOptions, CreateViewParams, DataType, Expr,
-    FileFormat, FunctionBehavior, FunctionCalledOnNull, FunctionDefinitionSetParam, FunctionDesc,
-    FunctionDeterminismSpecifier, FunctionParallel, FunctionSecurity, HiveDistributionStyle,
-    HiveFormat, HiveIOFormat, HiveRowFormat, HiveSetLocation, Ident, InitializeKind,
-    MySQLColumnPosition, ObjectName, OnCommit, OneOrManyWithParens, OperateFunctionArg,
-    OrderByExpr, ProjectionSelect, Query, RefreshModeKind, ResetConfig, RowAccessPolicy,
-    SequenceOptions, Spanned, SqlOption, StorageLifecyclePolicy, StorageSerializationPolicy,
-    TableVersion, Tag, TriggerEvent, TriggerExecBody, TriggerObject, TriggerPeriod,
-    TriggerReferencing, Value, ValueWithSpan, WrappedCollection,
+    ArgMode, Assignment, AttachedToken, CommentDef, ConditionalStatements, CreateFunctionBody,
+    CreateFunctionUsing, CreateTableLikeKind, CreateTableOptions, CreateViewParams, DataType,
+    DateTimeField, Expr, FileFormat, FunctionBehavior, FunctionCalledOnNull,
+    FunctionDefinitionSetParam, FunctionDesc, FunctionDeterminismSpecifier, FunctionParallel,
+    FunctionSecurity, GranteeName, HiveDistributionStyle, HiveFormat, HiveIOFormat, HiveRowFormat,
+    HiveSetLocation, Ident, InitializeKind, MySQLColumnPosition, ObjectName, ObjectNamePart,
+    OnCommit, OneOrManyWithParens, OperateFunctionArg, OrderByExpr, ProjectionSelect, Query,
+    RefreshModeKind, ResetConfig, RowAccessPolicy, SequenceOptions, Spanned, SqlOption, Statement,
+    StorageLifecyclePolicy, StorageSerializationPolicy, StructField, TableVersion, Tag,
+    TriggerEvent, TriggerExecBody, TriggerObject, TriggerPeriod, TriggerReferencing, Value,
+    ValueWithSpan, ViewCheckOption, WrappedCollection,
 };
 use crate::display_utils::{DisplayCommaSeparated, Indent, NewLine, SpaceOrNewline};
 use crate::keywords::Keyword;
@@ -143,6 +144,23 @@ pub enum AlterTableOperation {
         /// MySQL `ALTER TABLE` only  [FIRST | AFTER column_name]
         column_position: Option<MySQLColumnPosition>,
     },
+    /// `ADD [COLUMN] [IF NOT EXISTS] (<column_def> [, ...])`
+    /// or `ADD [COLUMN] [IF NOT EXISTS] <column_def>, <column_def> [, ...]`
+    ///
+    /// Adds several columns at once. A single unparenthesized column is
+    /// parsed as [AlterTableOperation::AddColumn] instead.
+    ///
+    /// Note: this is a MySQL- and Snowflake-specific operation.
+    AddColumns {
+        /// `[COLUMN]`.
+        column_keyword: bool,
+        /// `[IF NOT EXISTS]`
+        if_not_exists: bool,
+        /// The definitions of the added columns.
+        column_defs: Vec<ColumnDef>,
+        /// Whether the column definitions were wrapped in parentheses.
+        parenthesized: bool,
+    },
     /// `ADD PROJECTION [IF NOT EXISTS] name ( SELECT <COLUMN LIST EXPR> [GROUP BY] [ORDER BY])`
     ///
     /// Note: this is a ClickHouse-specific operation.
@@ -263,6 +281,66 @@ pub enum AlterTableOperation {
         /// Optional name associated with the unfreeze operation.
         with_name: Option<Ident>,
     },
+    /// `UPDATE <column> = <expr> [, ...] [IN PARTITION <partition_expr>] WHERE <filter_expr>`
+    /// Note: this is a ClickHouse-specific operation, please refer to
+    /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/alter/update)
+    Update {
+        /// Column assignments to apply.
+        assignments: Vec<Assignment>,
+        /// Optional partition the mutation is restricted to.
+        in_partition: Option<Partition>,
+        /// Filter selecting the rows to update.
+        selection: Expr,
+    },
+    /// `DELETE [IN PARTITION <partition_expr>] WHERE <filter_expr>`
+    /// Note: this is a ClickHouse-specific operation, please refer to
+    /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/alter/delete)
+    Delete {
+        /// Optional partition the mutation is restricted to.
+        in_partition: Option<Partition>,
+        /// Filter selecting the rows to delete.
+        selection: Expr,
+    },
+    /// `MODIFY TTL <ttl_clause> [, ...]`
+    /// Note: this is a ClickHouse-specific operation, please refer to
+    /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/alter/ttl)
+    ModifyTtl {
+        /// The new TTL clauses.
+        clauses: Vec<TtlClause>,
+    },
+    /// `REMOVE TTL`
+    /// Note: this is a ClickHouse-specific operation, please refer to
+    /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/alter/ttl)
+    RemoveTtl,
+    /// `MODIFY QUERY <select>`
+    /// Note: this is a ClickHouse-specific operation, please refer to
+    /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/alter/view#alter-table--modify-query-statement)
+    ModifyQuery {
+        /// The new query behind the materialized view.
+        query: Box<Query>,
+    },
+    /// `COMMENT [=] '<comment>'`
+    ///
+    /// Note: this is a MySQL-specific operation, please refer to
+    /// [MySQL](https://dev.mysql.com/doc/refman/8.4/en/alter-table.html)
+    SetComment {
+        /// The new table comment.
+        comment: String,
+        /// Whether `=` was present between `COMMENT` and the comment.
+        has_eq: bool,
+    },
+    /// `COMMENT COLUMN [IF EXISTS] <name> '<comment>'`
+    ///
+    /// Note: this is a ClickHouse-specific operation, please refer to
+    /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/alter/column#comment-column)
+    CommentColumn {
+        /// The column to comment on.
+        column: Ident,
+        /// The new column comment.
+        comment: String,
+        /// Whether `IF EXISTS` was specified.
+        if_exists: bool,
+    },
     /// `DROP PRIMARY KEY`
     ///
     /// [MySQL](https://dev.mysql.com/doc/refman/8.4/en/alter-table.html)
@@ -430,6 +508,18 @@ pub enum AlterTableOperation {
         /// Operation to apply to the column.
         op: AlterColumnOperation,
     },
+    /// `MODIFY [ COLUMN ] <column_name> <operation>`
+    ///
+    /// Note: this is Snowflake specific, where `MODIFY` is a synonym of `ALTER`
+    /// for column tag operations <https://docs.snowflake.com/en/sql-reference/sql/alter-table-column>
+    ModifyColumnOperation {
+        /// Whether the optional `COLUMN` keyword was present.
+        has_column_keyword: bool,
+        /// The column to alter.
+        column_name: Ident,
+        /// Operation to apply to the column.
+        op: AlterColumnOperation,
+    },
     /// 'SWAP WITH <table_name>'
     ///
     /// Note: this is Snowflake specific <https://docs.snowflake.com/en/sql-reference/sql/alter-table>
@@ -449,6 +539,38 @@ pub enum AlterTableOperation {
         /// The new owner to assign to the table.
         new_owner: Owner,
     },
+    /// `SET SCHEMA <schema_name>`
+    ///
+    /// Note: this is PostgreSQL-specific <https://www.postgresql.org/docs/current/sql-altertable.html>
+    SetSchema {
+        /// The schema the table is moved into.
+        schema_name: ObjectName,
+    },
+    /// `INHERIT <parent_table>`
+    ///
+    /// Note: this is PostgreSQL-specific <https://www.postgresql.org/docs/current/sql-altertable.html>
+    Inherit {
+        /// The table to add as a parent.
+        parent: ObjectName,
+    },
+    /// `NO INHERIT <parent_table>`
+    ///
+    /// Note: this is PostgreSQL-specific <https://www.postgresql.org/docs/current/sql-altertable.html>
+    NoInherit {
+        /// The parent table to remove.
+        parent: ObjectName,
+    },
+    /// `OF <type_name>`
+    ///
+    /// Note: this is PostgreSQL-specific <https://www.postgresql.org/docs/current/sql-altertable.html>
+    Of {
+        /// The composite type the table is linked to.
+        type_name: ObjectName,
+    },
+    /// `NOT OF`
+    ///
+    /// Note: this is PostgreSQL-specific <https://www.postgresql.org/docs/current/sql-altertable.html>
+    NotOf,
     /// Snowflake table clustering options
     /// <https://docs.snowflake.com/en/sql-reference/sql/alter-table#clustering-actions-clusteringaction>
     ClusterBy {
@@ -467,6 +589,42 @@ pub enum AlterTableOperation {
     SuspendRecluster,
     /// Resume background reclustering operations.
     ResumeRecluster,
+    /// `SET TAG <tag_name> = '<tag_value>' [ , ... ]`
+    ///
+    /// Note: this is Snowflake specific <https://docs.snowflake.com/en/sql-reference/sql/alter-table>
+    SetTag {
+        /// Tags to set on the table.
+        tag_list: Vec<Tag>,
+    },
+    /// `UNSET TAG <tag_name> [ , ... ]`
+    ///
+    /// Note: this is Snowflake specific <https://docs.snowflake.com/en/sql-reference/sql/alter-table>
+    UnsetTag {
+        /// Names of the tags to remove from the table.
+        tag_names: Vec<ObjectName>,
+    },
+    /// `ADD SEARCH OPTIMIZATION [ ON <search_method_with_target> [ , ... ] ]`
+    ///
+    /// Note: this is Snowflake specific <https://docs.snowflake.com/en/sql-reference/sql/alter-table>
+    AddSearchOptimization {
+        /// Search methods and targets, e.g. `EQUALITY(col1)`.
+        on: Option<Vec<Expr>>,
+    },
+    /// `DROP SEARCH OPTIMIZATION [ ON <search_method_with_target> [ , ... ] ]`
+    ///
+    /// Note: this is Snowflake specific <https://docs.snowflake.com/en/sql-reference/sql/alter-table>
+    DropSearchOptimization {
+        /// Search methods and targets, e.g. `EQUALITY(col1)`.
+        on: Option<Vec<Expr>>,
+    },
+    /// `ADD SYSTEM VERSIONING`
+    ///
+    /// Note: this is MariaDB specific <https://mariadb.com/kb/en/system-versioned-tables/>
+    AddSystemVersioning,
+    /// `DROP SYSTEM VERSIONING`
+    ///
+    /// Note: this is MariaDB specific <https://mariadb.com/kb/en/system-versioned-tables/>
+    DropSystemVersioning,
     /// `REFRESH [ '<subpath>' ]`
     ///
     /// Note: this is Snowflake specific for dynamic/external tables
@@ -484,6 +642,12 @@ pub enum AlterTableOperation {
     ///
     /// Note: this is Snowflake specific for dynamic tables <https://docs.snowflake.com/en/sql-reference/sql/alter-table>
     Resume,
+    /// `FORCE`
+    ///
+    /// [MySQL]-specific operation that rebuilds the table.
+    ///
+    /// [MySQL]: https://dev.mysql.com/doc/refman/8.4/en/alter-table.html
+    Force,
     /// `ALGORITHM [=] { DEFAULT | INSTANT | INPLACE | COPY }`
     ///
     /// [MySQL]-specific table alter algorithm.
@@ -518,6 +682,18 @@ pub enum AlterTableOperation {
         /// Value to set for the auto-increment counter.
         value: ValueWithSpan,
     },
+    /// `CONVERT TO CHARACTER SET <charset> [COLLATE <collation>]`
+    ///
+    /// [MySQL]-specific operation converting the table and all of its
+    /// character columns to the given character set.
+    ///
+    /// [MySQL]: https://dev.mysql.com/doc/refman/8.4/en/alter-table.html
+    ConvertToCharacterSet {
+        /// Target character set.
+        charset: Ident,
+        /// Optional collation for the character set.
+        collation: Option<Ident>,
+    },
     /// `VALIDATE CONSTRAINT <name>`
     ValidateConstraint {
         /// Name of the constraint to validate.
@@ -534,6 +710,17 @@ pub enum AlterTableOperation {
         /// Parenthesized options supplied to `SET (...)`.
         options: Vec<SqlOption>,
     },
+    /// Parenthesized `RESET` options, restoring them to their defaults.
+    ///
+    /// Example:
+    /// ```sql
+    /// RESET (fillfactor, autovacuum_enabled)
+    /// ```
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-altertable.html)
+    ResetOptionsParens {
+        /// Names of the options supplied to `RESET (...)`.
+        options: Vec<ObjectName>,
+    },
 }
 
 /// An `ALTER Policy` (`Statement::AlterPolicy`) operation
@@ -656,6 +843,10 @@ pub enum Owner {
     CurrentUser,
     /// `SESSION_USER` keyword.
     SessionUser,
+    /// `USER` keyword, as in `CREATE USER MAPPING FOR USER ...`.
+    User,
+    /// `PUBLIC` keyword, as in `CREATE USER MAPPING FOR PUBLIC ...`.
+    Public,
 }
 
 impl fmt::Display for Owner {
@@ -665,6 +856,8 @@ impl fmt::Display for Owner {
             Owner::CurrentRole => write!(f, "CURRENT_ROLE"),
             Owner::CurrentUser => write!(f, "CURRENT_USER"),
             Owner::SessionUser => write!(f, "SESSION_USER"),
+            Owner::User => write!(f, "USER"),
+            Owner::Public => write!(f, "PUBLIC"),
         }
     }
 }
@@ -689,6 +882,32 @@ impl fmt::Display for AlterConnectorOwner {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+/// An operation in an `ALTER [ <kind> ] INTEGRATION` statement.
+///
+/// See <https://docs.snowflake.com/en/sql-reference/sql/alter-integration>
+pub enum AlterIntegrationOperation {
+    /// `SET <property> = <value> [ ... ]`
+    Set(Vec<SqlOption>),
+    /// `UNSET <property> [ , ... ]`
+    Unset(Vec<Ident>),
+}
+
+impl fmt::Display for AlterIntegrationOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlterIntegrationOperation::Set(options) => {
+                write!(f, "SET {}", display_separated(options, " "))
+            }
+            AlterIntegrationOperation::Unset(properties) => {
+                write!(f, "UNSET {}", display_comma_separated(properties))
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
@@ -701,6 +920,74 @@ pub enum AlterIndexOperation {
     },
 }
 
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+/// Alterations that can be applied to a view.
+pub enum AlterViewOperation {
+    /// `[ WITH ( options ) ] [ ( columns ) ] AS <query>`
+    ///
+    /// Replaces the view definition.
+    As {
+        /// Optional new column list for the view.
+        columns: Vec<Ident>,
+        /// Replacement query for the view definition.
+        query: Box<Query>,
+        /// Additional WITH options for the view.
+        with_options: Vec<SqlOption>,
+    },
+    /// `RENAME TO <new_name>`
+    RenameTo {
+        /// The new name for the view.
+        new_name: ObjectName,
+    },
+    /// `SET SCHEMA <schema_name>`
+    ///
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-altermaterializedview.html)
+    SetSchema {
+        /// The new schema for the view.
+        schema_name: ObjectName,
+    },
+    /// `SET ( <view_option> [= <value>] [, ...] )`
+    ///
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-alterview.html)
+    SetOptions {
+        /// The options to set.
+        options: Vec<SqlOption>,
+    },
+    /// `RESET ( <view_option> [, ...] )`
+    ///
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-alterview.html)
+    ResetOptions {
+        /// The names of the options to reset.
+        names: Vec<Ident>,
+    },
+    /// `OWNER TO { <new_owner> | CURRENT_ROLE | CURRENT_USER | SESSION_USER }`
+    ///
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-alterview.html)
+    OwnerTo {
+        /// The new owner of the view.
+        new_owner: Owner,
+    },
+    /// `SET SECURE`
+    ///
+    /// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/alter-view)
+    SetSecure,
+    /// `UNSET SECURE`
+    ///
+    /// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/alter-view)
+    UnsetSecure,
+    /// `ALTER [ COLUMN ] <column> SET DEFAULT <expr>`
+    ///
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-alterview.html)
+    AlterColumnSetDefault {
+        /// The column to alter.
+        column: Ident,
+        /// The new default value.
+        default: Expr,
+    },
+}
+
 impl fmt::Display for AlterTableOperation {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -744,6 +1031,25 @@ impl fmt::Display for AlterTableOperation {
 
                 Ok(())
             }
+            AlterTableOperation::AddColumns {
+                column_keyword,
+                if_not_exists,
+                column_defs,
+                parenthesized,
+            } => {
+                write!(f, "ADD")?;
+                if *column_keyword {
+                    write!(f, " COLUMN")?;
+                }
+                if *if_not_exists {
+                    write!(f, " IF NOT EXISTS")?;
+                }
+                if *parenthesized {
+                    write!(f, " ({})", display_comma_separated(column_defs))
+                } else {
+                    write!(f, " {}", display_comma_separated(column_defs))
+                }
+            }
             AlterTableOperation::AddProjection {
                 if_not_exists,
                 name,
@@ -803,6 +1109,17 @@ impl fmt::Display for AlterTableOperation {
             AlterTableOperation::AlterColumn { column_name, op } => {
                 write!(f, "ALTER COLUMN {column_name} {op}")
             }
+            AlterTableOperation::ModifyColumnOperation {
+                has_column_keyword,
+                column_name,
+                op,
+            } => {
+                write!(
+                    f,
+                    "MODIFY {}{column_name} {op}",
+                    if *has_column_keyword { "COLUMN " } else { "" }
+                )
+            }
             AlterTableOperation::DisableRowLevelSecurity => {
                 write!(f, "DISABLE ROW LEVEL SECURITY")
             }
@@ -964,6 +1281,13 @@ impl fmt::Display for AlterTableOperation {
             AlterTableOperation::OwnerTo { new_owner } => {
                 write!(f, "OWNER TO {new_owner}")
             }
+            AlterTableOperation::SetSchema { schema_name } => {
+                write!(f, "SET SCHEMA {schema_name}")
+            }
+            AlterTableOperation::Inherit { parent } => write!(f, "INHERIT {parent}"),
+            AlterTableOperation::NoInherit { parent } => write!(f, "NO INHERIT {parent}"),
+            AlterTableOperation::Of { type_name } => write!(f, "OF {type_name}"),
+            AlterTableOperation::NotOf => write!(f, "NOT OF"),
             AlterTableOperation::SetTblProperties { table_properties } => {
                 write!(
                     f,
@@ -991,6 +1315,52 @@ impl fmt::Display for AlterTableOperation {
                 }
                 Ok(())
             }
+            AlterTableOperation::Update {
+                assignments,
+                in_partition,
+                selection,
+            } => {
+                write!(f, "UPDATE {}", display_comma_separated(assignments))?;
+                if let Some(partition) = in_partition {
+                    write!(f, " IN {partition}")?;
+                }
+                write!(f, " WHERE {selection}")
+            }
+            AlterTableOperation::Delete {
+                in_partition,
+                selection,
+            } => {
+                write!(f, "DELETE")?;
+                if let Some(partition) = in_partition {
+                    write!(f, " IN {partition}")?;
+                }
+                write!(f, " WHERE {selection}")
+            }
+            AlterTableOperation::ModifyTtl { clauses } => {
+                write!(f, "MODIFY TTL {}", display_comma_separated(clauses))
+            }
+            AlterTableOperation::RemoveTtl => write!(f, "REMOVE TTL"),
+            AlterTableOperation::ModifyQuery { query } => write!(f, "MODIFY QUERY {query}"),
+            AlterTableOperation::SetComment { comment, has_eq } => {
+                write!(
+                    f,
+                    "COMMENT {eq}'{comment}'",
+                    eq = if *has_eq { "= " } else { "" },
+                    comment = escape_single_quote_string(comment)
+                )
+            }
+            AlterTableOperation::CommentColumn {
+                column,
+                comment,
+                if_exists,
+            } => {
+                write!(
+                    f,
+                    "COMMENT COLUMN {if_exists}{column} '{comment}'",
+                    if_exists = if *if_exists { "IF EXISTS " } else { "" },
+                    comment = escape_single_quote_string(comment)
+                )
+            }
             AlterTableOperation::ClusterBy { exprs } => {
                 write!(f, "CLUSTER BY ({})", display_comma_separated(exprs))?;
                 Ok(())
@@ -1011,6 +1381,28 @@ impl fmt::Display for AlterTableOperation {
                 write!(f, "RESUME RECLUSTER")?;
                 Ok(())
             }
+            AlterTableOperation::SetTag { tag_list } => {
+                write!(f, "SET TAG {}", display_comma_separated(tag_list))
+            }
+            AlterTableOperation::UnsetTag { tag_names } => {
+                write!(f, "UNSET TAG {}", display_comma_separated(tag_names))
+            }
+            AlterTableOperation::AddSearchOptimization { on } => {
+                write!(f, "ADD SEARCH OPTIMIZATION")?;
+                if let Some(on) = on {
+                    write!(f, " ON {}", display_comma_separated(on))?;
+                }
+                Ok(())
+            }
+            AlterTableOperation::DropSearchOptimization { on } => {
+                write!(f, "DROP SEARCH OPTIMIZATION")?;
+                if let Some(on) = on {
+                    write!(f, " ON {}", display_comma_separated(on))?;
+                }
+                Ok(())
+            }
+            AlterTableOperation::AddSystemVersioning => write!(f, "ADD SYSTEM VERSIONING"),
+            AlterTableOperation::DropSystemVersioning => write!(f, "DROP SYSTEM VERSIONING"),
             AlterTableOperation::Refresh { subpath } => {
                 write!(f, "REFRESH")?;
                 if let Some(path) = subpath {
@@ -1024,6 +1416,9 @@ impl fmt::Display for AlterTableOperation {
             AlterTableOperation::Resume => {
                 write!(f, "RESUME")
             }
+            AlterTableOperation::Force => {
+                write!(f, "FORCE")
+            }
             AlterTableOperation::AutoIncrement { equals, value } => {
                 write!(
                     f,
@@ -1032,6 +1427,13 @@ impl fmt::Display for AlterTableOperation {
                     value
                 )
             }
+            AlterTableOperation::ConvertToCharacterSet { charset, collation } => {
+                write!(f, "CONVERT TO CHARACTER SET {charset}")?;
+                if let Some(collation) = collation {
+                    write!(f, " COLLATE {collation}")?;
+                }
+                Ok(())
+            }
             AlterTableOperation::Lock { equals, lock } => {
                 write!(f, "LOCK {}{}", if *equals { "= " } else { "" }, lock)
             }
@@ -1044,6 +1446,9 @@ impl fmt::Display for AlterTableOperation {
             AlterTableOperation::SetOptionsParens { options } => {
                 write!(f, "SET ({})", display_comma_separated(options))
             }
+            AlterTableOperation::ResetOptionsParens { options } => {
+                write!(f, "RESET ({})", display_comma_separated(options))
+            }
         }
     }
 }
@@ -1058,6 +1463,40 @@ impl fmt::Display for AlterIndexOperation {
     }
 }
 
+impl fmt::Display for AlterViewOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlterViewOperation::As {
+                columns,
+                query,
+                with_options,
+            } => {
+                if !with_options.is_empty() {
+                    write!(f, "WITH ({}) ", display_comma_separated(with_options))?;
+                }
+                if !columns.is_empty() {
+                    write!(f, "({}) ", display_comma_separated(columns))?;
+                }
+                write!(f, "AS {query}")
+            }
+            AlterViewOperation::RenameTo { new_name } => write!(f, "RENAME TO {new_name}"),
+            AlterViewOperation::SetSchema { schema_name } => write!(f, "SET SCHEMA {schema_name}"),
+            AlterViewOperation::SetOptions { options } => {
+                write!(f, "SET ({})", display_comma_separated(options))
+            }
+            AlterViewOperation::ResetOptions { names } => {
+                write!(f, "RESET ({})", display_comma_separated(names))
+            }
+            AlterViewOperation::OwnerTo { new_owner } => write!(f, "OWNER TO {new_owner}"),
+            AlterViewOperation::SetSecure => write!(f, "SET SECURE"),
+            AlterViewOperation::UnsetSecure => write!(f, "UNSET SECURE"),
+            AlterViewOperation::AlterColumnSetDefault { column, default } => {
+                write!(f, "ALTER COLUMN {column} SET DEFAULT {default}")
+            }
+        }
+    }
+}
+
 /// An `ALTER TYPE` statement (`Statement::AlterType`)
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -1297,6 +1736,50 @@ pub enum AlterColumnOperation {
         /// Optional sequence options for identity generation.
         sequence_options: Option<Vec<SequenceOptions>>,
     },
+    /// `SET GENERATED { ALWAYS | BY DEFAULT } [ SET sequence_option | RESTART [ [ WITH ] restart ] ] [...]`
+    ///
+    /// Note: this is a PostgreSQL-specific operation.
+    SetGenerated {
+        /// The new `GENERATED` specifier of the identity column.
+        generated_as: GeneratedAs,
+        /// Optional sequence options changed along with the specifier.
+        sequence_options: Option<Vec<SequenceOptions>>,
+    },
+    /// `DROP IDENTITY [ IF EXISTS ]`
+    ///
+    /// Note: this is a PostgreSQL-specific operation.
+    DropIdentity {
+        /// `IF EXISTS` flag.
+        if_exists: bool,
+    },
+    /// `SET TAG <tag_name> = '<tag_value>' [ , ... ]`
+    ///
+    /// Note: this is Snowflake specific <https://docs.snowflake.com/en/sql-reference/sql/alter-table-column>
+    SetTag {
+        /// Tags to set on the column.
+        tag_list: Vec<Tag>,
+    },
+    /// `UNSET TAG <tag_name> [ , ... ]`
+    ///
+    /// Note: this is Snowflake specific <https://docs.snowflake.com/en/sql-reference/sql/alter-table-column>
+    UnsetTag {
+        /// Names of the tags to remove from the column.
+        tag_names: Vec<ObjectName>,
+    },
+    /// `SET STATISTICS <n>`
+    ///
+    /// Note: this is PostgreSQL-specific <https://www.postgresql.org/docs/current/sql-altertable.html>
+    SetStatistics {
+        /// The per-column statistics target; `-1` reverts to the system default.
+        value: Value,
+    },
+    /// `SET STORAGE { PLAIN | EXTERNAL | EXTENDED | MAIN }`
+    ///
+    /// Note: this is PostgreSQL-specific <https://www.postgresql.org/docs/current/sql-altertable.html>
+    SetStorage {
+        /// The storage mode of the column.
+        storage: ColumnStorage,
+    },
 }
 
 impl fmt::Display for AlterColumnOperation {
@@ -1336,16 +1819,76 @@ impl fmt::Display for AlterColumnOperation {
 
                 write!(f, "ADD GENERATED{generated_as} AS IDENTITY",)?;
                 if let Some(options) = sequence_options {
-                    write!(f, " (")?;
-
-                    for sequence_option in options {
-                        write!(f, "{sequence_option}")?;
+                    write!(f, " ({})", display_separated(options, " "))?;
+                }
+                Ok(())
+            }
+            AlterColumnOperation::SetGenerated {
+                generated_as,
+                sequence_options,
+            } => {
+                write!(f, "SET GENERATED")?;
+                match generated_as {
+                    GeneratedAs::Always => write!(f, " ALWAYS")?,
+                    GeneratedAs::ByDefault => write!(f, " BY DEFAULT")?,
+                    GeneratedAs::ExpStored => {}
+                }
+                for sequence_option in sequence_options.iter().flatten() {
+                    if let SequenceOptions::Restart(..) = sequence_option {
+                        write!(f, " {sequence_option}")?;
+                    } else {
+                        write!(f, " SET {sequence_option}")?;
                     }
-
-                    write!(f, " )")?;
                 }
                 Ok(())
             }
+            AlterColumnOperation::DropIdentity { if_exists } => {
+                write!(f, "DROP IDENTITY")?;
+                if *if_exists {
+                    write!(f, " IF EXISTS")?;
+                }
+                Ok(())
+            }
+            AlterColumnOperation::SetTag { tag_list } => {
+                write!(f, "SET TAG {}", display_comma_separated(tag_list))
+            }
+            AlterColumnOperation::UnsetTag { tag_names } => {
+                write!(f, "UNSET TAG {}", display_comma_separated(tag_names))
+            }
+            AlterColumnOperation::SetStatistics { value } => {
+                write!(f, "SET STATISTICS {value}")
+            }
+            AlterColumnOperation::SetStorage { storage } => {
+                write!(f, "SET STORAGE {storage}")
+            }
+        }
+    }
+}
+
+/// The storage mode of a column, as set by `ALTER COLUMN ... SET STORAGE`.
+///
+/// [PostgreSQL](https://www.postgresql.org/docs/current/sql-altertable.html)
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum ColumnStorage {
+    /// Inline, uncompressed storage: `PLAIN`
+    Plain,
+    /// Out-of-line, uncompressed storage: `EXTERNAL`
+    External,
+    /// Out-of-line, compressed storage: `EXTENDED`
+    Extended,
+    /// Inline, compressed storage: `MAIN`
+    Main,
+}
+
+impl fmt::Display for ColumnStorage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ColumnStorage::Plain => write!(f, "PLAIN"),
+            ColumnStorage::External => write!(f, "EXTERNAL"),
+            ColumnStorage::Extended => write!(f, "EXTENDED"),
+            ColumnStorage::Main => write!(f, "MAIN"),
         }
     }
 }
@@ -1495,27 +2038,80 @@ impl fmt::Display for NullsDistinctOption {
     }
 }
 
+/// The body of a `CREATE PROCEDURE` statement.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
-/// A parameter of a stored procedure or function declaration.
-pub struct ProcedureParam {
-    /// Parameter name.
-    pub name: Ident,
-    /// Parameter data type.
-    pub data_type: DataType,
-    /// Optional mode (`IN`, `OUT`, `INOUT`, etc.).
-    pub mode: Option<ArgMode>,
-    /// Optional default expression for the parameter.
-    pub default: Option<Expr>,
+pub enum CreateProcedureBody {
+    /// Statements following `AS`, e.g. `AS BEGIN SELECT 1; END`
+    AsStatements(ConditionalStatements),
+    /// A string literal definition following `AS`, e.g. `AS $$ ... $$`
+    ///
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-createprocedure.html)
+    /// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/create-procedure)
+    AsDefinition(Expr),
+    /// A `BEGIN ... END` block without a preceding `AS`, e.g. `BEGIN SELECT 1; END`
+    ///
+    /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#create_procedure)
+    BeginEnd(ConditionalStatements),
 }
 
-impl fmt::Display for ProcedureParam {
+impl fmt::Display for CreateProcedureBody {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if let Some(mode) = &self.mode {
-            if let Some(default) = &self.default {
-                write!(f, "{mode} {} {} = {}", self.name, self.data_type, default)
-            } else {
+        match self {
+            CreateProcedureBody::AsStatements(statements) => write!(f, "AS {statements}"),
+            CreateProcedureBody::AsDefinition(definition) => write!(f, "AS {definition}"),
+            CreateProcedureBody::BeginEnd(statements) => write!(f, "{statements}"),
+        }
+    }
+}
+
+/// The `EXECUTE AS` clause of a `CREATE PROCEDURE` statement.
+///
+/// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/create-procedure)
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum ProcedureExecuteAs {
+    /// `EXECUTE AS CALLER`
+    Caller,
+    /// `EXECUTE AS RESTRICTED CALLER`
+    RestrictedCaller,
+    /// `EXECUTE AS OWNER`
+    Owner,
+}
+
+impl fmt::Display for ProcedureExecuteAs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProcedureExecuteAs::Caller => write!(f, "EXECUTE AS CALLER"),
+            ProcedureExecuteAs::RestrictedCaller => write!(f, "EXECUTE AS RESTRICTED CALLER"),
+            ProcedureExecuteAs::Owner => write!(f, "EXECUTE AS OWNER"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+/// A parameter of a stored procedure or function declaration.
+pub struct ProcedureParam {
+    /// Parameter name.
+    pub name: Ident,
+    /// Parameter data type.
+    pub data_type: DataType,
+    /// Optional mode (`IN`, `OUT`, `INOUT`, etc.).
+    pub mode: Option<ArgMode>,
+    /// Optional default expression for the parameter.
+    pub default: Option<Expr>,
+}
+
+impl fmt::Display for ProcedureParam {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(mode) = &self.mode {
+            if let Some(default) = &self.default {
+                write!(f, "{mode} {} {} = {}", self.name, self.data_type, default)
+            } else {
                 write!(f, "{mode} {} {}", self.name, self.data_type)
             }
         } else if let Some(default) = &self.default {
@@ -1701,6 +2297,9 @@ impl fmt::Display for IdentityPropertyKind {
         if let Some(order) = &property.order {
             write!(f, "{order}")?;
         }
+        if property.not_for_replication {
+            write!(f, " NOT FOR REPLICATION")?;
+        }
         Ok(())
     }
 }
@@ -1714,6 +2313,9 @@ pub struct IdentityProperty {
     pub parameters: Option<IdentityPropertyFormatKind>,
     /// Optional ordering specifier (`ORDER` / `NOORDER`).
     pub order: Option<IdentityPropertyOrder>,
+    /// MSSQL-specific `NOT FOR REPLICATION` flag.
+    /// <https://learn.microsoft.com/en-us/sql/t-sql/statements/create-table-transact-sql-identity-property>
+    pub not_for_replication: bool,
 }
 
 /// A format of parameters of identity column.
@@ -2001,6 +2603,19 @@ pub enum ColumnOption {
     /// ```
     /// [MySQL]: https://dev.mysql.com/doc/refman/8.4/en/invisible-columns.html
     Invisible,
+    /// `GENERATED ALWAYS AS ROW { START | END } [ HIDDEN ]` column of a system-versioned table
+    /// Syntax:
+    /// ```sql
+    /// CREATE TABLE t (x INT, s TIMESTAMP(6) GENERATED ALWAYS AS ROW START);
+    /// ```
+    /// [MariaDB]: https://mariadb.com/kb/en/system-versioned-tables/
+    /// [MSSQL]: https://learn.microsoft.com/en-us/sql/relational-databases/tables/creating-a-system-versioned-temporal-table
+    GeneratedAsRow {
+        /// Whether the column stores the start or the end of the row period.
+        boundary: GeneratedRowBoundary,
+        /// MSSQL `HIDDEN` flag, excluding the column from `SELECT *`.
+        hidden: bool,
+    },
 }
 
 impl From<UniqueConstraint> for ColumnOption {
@@ -2097,6 +2712,7 @@ impl fmt::Display for ColumnOption {
                         None => "",
                         Some(GeneratedExpressionMode::Virtual) => " VIRTUAL",
                         Some(GeneratedExpressionMode::Stored) => " STORED",
+                        Some(GeneratedExpressionMode::Persisted) => " PERSISTED",
                     };
                     if *generated_keyword {
                         write!(f, "GENERATED ALWAYS AS ({expr}){modifier}")?;
@@ -2113,16 +2729,9 @@ impl fmt::Display for ColumnOption {
                         GeneratedAs::ExpStored => "",
                     };
                     write!(f, "GENERATED {when} AS IDENTITY")?;
-                    if sequence_options.is_some() {
-                        let so = sequence_options.as_ref().unwrap();
-                        if !so.is_empty() {
-                            write!(f, " (")?;
-                        }
-                        for sequence_option in so {
-                            write!(f, "{sequence_option}")?;
-                        }
+                    if let Some(so) = sequence_options {
                         if !so.is_empty() {
-                            write!(f, " )")?;
+                            write!(f, " ({})", display_separated(so, " "))?;
                         }
                     }
                     Ok(())
@@ -2150,10 +2759,63 @@ impl fmt::Display for ColumnOption {
             Invisible => {
                 write!(f, "INVISIBLE")
             }
+            GeneratedAsRow { boundary, hidden } => {
+                write!(f, "GENERATED ALWAYS AS ROW {boundary}")?;
+                if *hidden {
+                    write!(f, " HIDDEN")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The row period boundary stored by a `GENERATED ALWAYS AS ROW` column.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum GeneratedRowBoundary {
+    /// `ROW START`
+    Start,
+    /// `ROW END`
+    End,
+}
+
+impl fmt::Display for GeneratedRowBoundary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeneratedRowBoundary::Start => write!(f, "START"),
+            GeneratedRowBoundary::End => write!(f, "END"),
         }
     }
 }
 
+/// A `PERIOD FOR <name> (<start_column>, <end_column>)` table element.
+///
+/// [MariaDB](https://mariadb.com/kb/en/system-versioned-tables/)
+/// [MSSQL](https://learn.microsoft.com/en-us/sql/relational-databases/tables/creating-a-system-versioned-temporal-table)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct PeriodDefinition {
+    /// Name of the period, e.g. `SYSTEM_TIME`.
+    pub name: Ident,
+    /// Column holding the start of the period.
+    pub start_column: Ident,
+    /// Column holding the end of the period.
+    pub end_column: Ident,
+}
+
+impl fmt::Display for PeriodDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "PERIOD FOR {} ({}, {})",
+            self.name, self.start_column, self.end_column
+        )
+    }
+}
+
 /// `GeneratedAs`s are modifiers that follow a column option in a `generated`.
 /// 'ExpStored' is used for a column generated from an expression and stored.
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
@@ -2178,6 +2840,10 @@ pub enum GeneratedExpressionMode {
     Virtual,
     /// `STORED` generated expression
     Stored,
+    /// `PERSISTED` computed column
+    ///
+    /// [MsSql](https://learn.microsoft.com/en-us/sql/relational-databases/tables/specify-computed-columns-in-a-table)
+    Persisted,
 }
 
 #[must_use]
@@ -2237,6 +2903,9 @@ pub struct ConstraintCharacteristics {
     pub initially: Option<DeferrableInitial>,
     /// `[ ENFORCED | NOT ENFORCED ]`
     pub enforced: Option<bool>,
+    /// MSSQL-specific `NOT FOR REPLICATION`
+    /// <https://learn.microsoft.com/en-us/sql/t-sql/statements/create-table-transact-sql>
+    pub not_for_replication: bool,
 }
 
 /// Initial setting for deferrable constraints (`INITIALLY IMMEDIATE` or `INITIALLY DEFERRED`).
@@ -2284,30 +2953,24 @@ impl ConstraintCharacteristics {
 
 impl fmt::Display for ConstraintCharacteristics {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let deferrable = self.deferrable_text();
-        let initially_immediate = self.initially_immediate_text();
-        let enforced = self.enforced_text();
-
-        match (deferrable, initially_immediate, enforced) {
-            (None, None, None) => Ok(()),
-            (None, None, Some(enforced)) => write!(f, "{enforced}"),
-            (None, Some(initial), None) => write!(f, "{initial}"),
-            (None, Some(initial), Some(enforced)) => write!(f, "{initial} {enforced}"),
-            (Some(deferrable), None, None) => write!(f, "{deferrable}"),
-            (Some(deferrable), None, Some(enforced)) => write!(f, "{deferrable} {enforced}"),
-            (Some(deferrable), Some(initial), None) => write!(f, "{deferrable} {initial}"),
-            (Some(deferrable), Some(initial), Some(enforced)) => {
-                write!(f, "{deferrable} {initial} {enforced}")
-            }
-        }
+        let texts: Vec<&str> = [
+            self.deferrable_text(),
+            self.initially_immediate_text(),
+            self.enforced_text(),
+            self.not_for_replication.then_some("NOT FOR REPLICATION"),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        write!(f, "{}", display_separated(&texts, " "))
     }
 }
 
 /// `<referential_action> =
-/// { RESTRICT | CASCADE | SET NULL | NO ACTION | SET DEFAULT }`
+/// { RESTRICT | CASCADE | SET NULL [ (<columns>) ] | NO ACTION | SET DEFAULT [ (<columns>) ] }`
 ///
 /// Used in foreign key constraints in `ON UPDATE` and `ON DELETE` options.
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ReferentialAction {
@@ -2315,23 +2978,38 @@ pub enum ReferentialAction {
     Restrict,
     /// `CASCADE` - propagate the action to referencing rows.
     Cascade,
-    /// `SET NULL` - set referencing columns to NULL.
-    SetNull,
+    /// `SET NULL [ (<columns>) ]` - set referencing columns to NULL.
+    ///
+    /// The optional column list restricts which of the referencing columns
+    /// are set, see [PostgreSQL](https://www.postgresql.org/docs/current/sql-createtable.html).
+    SetNull(Vec<Ident>),
     /// `NO ACTION` - no action at the time; may be deferred.
     NoAction,
-    /// `SET DEFAULT` - set referencing columns to their default values.
-    SetDefault,
+    /// `SET DEFAULT [ (<columns>) ]` - set referencing columns to their default values.
+    SetDefault(Vec<Ident>),
 }
 
 impl fmt::Display for ReferentialAction {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_str(match self {
-            ReferentialAction::Restrict => "RESTRICT",
-            ReferentialAction::Cascade => "CASCADE",
-            ReferentialAction::SetNull => "SET NULL",
-            ReferentialAction::NoAction => "NO ACTION",
-            ReferentialAction::SetDefault => "SET DEFAULT",
-        })
+        match self {
+            ReferentialAction::Restrict => f.write_str("RESTRICT"),
+            ReferentialAction::Cascade => f.write_str("CASCADE"),
+            ReferentialAction::SetNull(columns) => {
+                f.write_str("SET NULL")?;
+                if !columns.is_empty() {
+                    write!(f, " ({})", display_comma_separated(columns))?;
+                }
+                Ok(())
+            }
+            ReferentialAction::NoAction => f.write_str("NO ACTION"),
+            ReferentialAction::SetDefault(columns) => {
+                f.write_str("SET DEFAULT")?;
+                if !columns.is_empty() {
+                    write!(f, " ({})", display_comma_separated(columns))?;
+                }
+                Ok(())
+            }
+        }
     }
 }
 
@@ -2748,6 +3426,54 @@ impl fmt::Display for Partition {
     }
 }
 
+/// A single ClickHouse TTL clause: `expr [DELETE | TO DISK 'name' | TO VOLUME 'name']`.
+///
+/// [ClickHouse](https://clickhouse.com/docs/en/engines/table-engines/mergetree-family/mergetree#table_engine-mergetree-ttl)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct TtlClause {
+    /// The expression determining when the rows expire.
+    pub expr: Expr,
+    /// The action to take on expired rows, if specified.
+    pub action: Option<TtlAction>,
+}
+
+impl fmt::Display for TtlClause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.expr)?;
+        if let Some(action) = &self.action {
+            write!(f, " {action}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The action of a ClickHouse [TtlClause].
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum TtlAction {
+    /// `DELETE`
+    Delete,
+    /// `TO DISK 'name'`
+    ToDisk(String),
+    /// `TO VOLUME 'name'`
+    ToVolume(String),
+}
+
+impl fmt::Display for TtlAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TtlAction::Delete => write!(f, "DELETE"),
+            TtlAction::ToDisk(disk) => write!(f, "TO DISK '{}'", escape_single_quote_string(disk)),
+            TtlAction::ToVolume(volume) => {
+                write!(f, "TO VOLUME '{}'", escape_single_quote_string(volume))
+            }
+        }
+    }
+}
+
 /// DEDUPLICATE statement used in OPTIMIZE TABLE et al. such as in ClickHouse SQL
 /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/optimize)
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
@@ -2756,15 +3482,17 @@ impl fmt::Display for Partition {
 pub enum Deduplicate {
     /// DEDUPLICATE ALL
     All,
-    /// DEDUPLICATE BY expr
-    ByExpression(Expr),
+    /// DEDUPLICATE BY expr [, ...]
+    ByExpressions(Vec<Expr>),
 }
 
 impl fmt::Display for Deduplicate {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Deduplicate::All => write!(f, "DEDUPLICATE"),
-            Deduplicate::ByExpression(expr) => write!(f, "DEDUPLICATE BY {expr}"),
+            Deduplicate::ByExpressions(exprs) => {
+                write!(f, "DEDUPLICATE BY {}", display_comma_separated(exprs))
+            }
         }
     }
 }
@@ -2799,6 +3527,59 @@ impl fmt::Display for ClusteredBy {
     }
 }
 
+/// Hive supports `SKEWED BY` statement in `CREATE TABLE`.
+/// Syntax: `SKEWED BY (col_name, ...) ON ((col_value, ...), ...) [STORED AS DIRECTORIES]`
+///
+/// [Hive](https://cwiki.apache.org/confluence/display/Hive/LanguageManual+DDL#LanguageManualDDL-SkewedTables)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct SkewedBy {
+    /// skewed columns
+    pub columns: Vec<Ident>,
+    /// skewed values, one tuple per skewed row
+    pub on: Vec<Vec<Expr>>,
+    /// whether the values were listed without their own parentheses,
+    /// e.g. `ON (1, 5, 6)` for a single skewed column
+    pub bare_values: bool,
+    /// whether `STORED AS DIRECTORIES` (list bucketing) was specified
+    pub stored_as_directories: bool,
+}
+
+impl fmt::Display for SkewedBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SKEWED BY ({}) ON (",
+            display_comma_separated(&self.columns)
+        )?;
+        if self.bare_values {
+            write!(
+                f,
+                "{}",
+                display_comma_separated(&self.on.iter().flatten().collect::<Vec<_>>())
+            )?;
+        } else {
+            write!(
+                f,
+                "{}",
+                display_comma_separated(
+                    &self
+                        .on
+                        .iter()
+                        .map(|values| format!("({})", display_comma_separated(values)))
+                        .collect::<Vec<_>>()
+                )
+            )?;
+        }
+        write!(f, ")")?;
+        if self.stored_as_directories {
+            write!(f, " STORED AS DIRECTORIES")?;
+        }
+        Ok(())
+    }
+}
+
 /// CREATE INDEX statement.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -2923,6 +3704,9 @@ pub struct CreateTable {
     pub columns: Vec<ColumnDef>,
     /// Table constraints
     pub constraints: Vec<TableConstraint>,
+    /// `PERIOD FOR` definitions in the column list
+    /// <https://mariadb.com/kb/en/system-versioned-tables/>
+    pub periods: Vec<PeriodDefinition>,
     /// Hive-specific distribution style
     pub hive_distribution: HiveDistributionStyle,
     /// Hive-specific formats like `ROW FORMAT DELIMITED` or `ROW FORMAT SERDE 'serde_class' WITH SERDEPROPERTIES (...)`
@@ -2943,6 +3727,12 @@ pub struct CreateTable {
     pub clone: Option<ObjectName>,
     /// Table version (for systems that support versioned tables)
     pub version: Option<TableVersion>,
+    /// BigQuery `COPY` clause
+    /// <https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#create_table_copy>
+    pub copy: Option<ObjectName>,
+    /// BigQuery `WITH CONNECTION` clause of external tables
+    /// <https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#create_external_table_statement>
+    pub with_connection: Option<ObjectName>,
     /// For Hive dialect, the table comment is after the column definitions without `=`,
     /// so the `comment` field is optional and different than the comment field in the general options list.
     /// [Hive](https://cwiki.apache.org/confluence/display/Hive/LanguageManual+DDL#LanguageManualDDL-CreateTable)
@@ -2952,7 +3742,7 @@ pub struct CreateTable {
     pub on_commit: Option<OnCommit>,
     /// ClickHouse "ON CLUSTER" clause:
     /// <https://clickhouse.com/docs/en/sql-reference/distributed-ddl/>
-    pub on_cluster: Option<Ident>,
+    pub on_cluster: Option<ObjectNamePart>,
     /// ClickHouse "PRIMARY KEY " clause.
     /// <https://clickhouse.com/docs/en/sql-reference/statements/create/table/>
     pub primary_key: Option<Box<Expr>>,
@@ -2971,6 +3761,9 @@ pub struct CreateTable {
     /// Hive: Table clustering column list.
     /// <https://cwiki.apache.org/confluence/display/Hive/LanguageManual+DDL#LanguageManualDDL-CreateTable>
     pub clustered_by: Option<ClusteredBy>,
+    /// Hive: Skewed columns and values.
+    /// <https://cwiki.apache.org/confluence/display/Hive/LanguageManual+DDL#LanguageManualDDL-SkewedTables>
+    pub skewed_by: Option<SkewedBy>,
     /// Postgres `INHERITs` clause, which contains the list of tables from which
     /// the new table inherits.
     /// <https://www.postgresql.org/docs/current/ddl-inherit.html>
@@ -3060,6 +3853,9 @@ pub struct CreateTable {
     /// Redshift `BACKUP` option: `BACKUP { YES | NO }`
     /// <https://docs.aws.amazon.com/redshift/latest/dg/r_CREATE_TABLE_NEW.html>
     pub backup: Option<bool>,
+    /// MariaDB `WITH SYSTEM VERSIONING` clause
+    /// <https://mariadb.com/kb/en/system-versioned-tables/>
+    pub with_system_versioning: bool,
 }
 
 impl fmt::Display for CreateTable {
@@ -3101,7 +3897,7 @@ impl fmt::Display for CreateTable {
         if let Some(on_cluster) = &self.on_cluster {
             write!(f, " ON CLUSTER {on_cluster}")?;
         }
-        if !self.columns.is_empty() || !self.constraints.is_empty() {
+        if !self.columns.is_empty() || !self.constraints.is_empty() || !self.periods.is_empty() {
             f.write_str(" (")?;
             NewLine.fmt(f)?;
             Indent(DisplayCommaSeparated(&self.columns)).fmt(f)?;
@@ -3110,11 +3906,20 @@ impl fmt::Display for CreateTable {
                 SpaceOrNewline.fmt(f)?;
             }
             Indent(DisplayCommaSeparated(&self.constraints)).fmt(f)?;
+            if !self.periods.is_empty() {
+                if !self.columns.is_empty() || !self.constraints.is_empty() {
+                    f.write_str(",")?;
+                    SpaceOrNewline.fmt(f)?;
+                }
+                Indent(DisplayCommaSeparated(&self.periods)).fmt(f)?;
+            }
             NewLine.fmt(f)?;
             f.write_str(")")?;
         } else if self.query.is_none()
             && self.like.is_none()
             && self.clone.is_none()
+            && self.copy.is_none()
+            && self.with_connection.is_none()
             && self.partition_of.is_none()
         {
             // PostgreSQL allows `CREATE TABLE t ();`, but requires empty parens
@@ -3149,6 +3954,22 @@ impl fmt::Display for CreateTable {
             write!(f, " {version}")?;
         }
 
+        if let Some(copy) = &self.copy {
+            write!(f, " COPY {copy}")?;
+        }
+
+        if let Some(connection) = &self.with_connection {
+            write!(f, " WITH CONNECTION {connection}")?;
+        }
+
+        if let Some(HiveFormat {
+            storage: Some(HiveIOFormat::Using { format }),
+            ..
+        }) = &self.hive_formats
+        {
+            write!(f, " USING {format}")?;
+        }
+
         match &self.hive_distribution {
             HiveDistributionStyle::PARTITIONED { columns } => {
                 write!(f, " PARTITIONED BY ({})", display_comma_separated(columns))?;
@@ -3175,6 +3996,10 @@ impl fmt::Display for CreateTable {
             write!(f, " {clustered_by}")?;
         }
 
+        if let Some(skewed_by) = &self.skewed_by {
+            write!(f, " {skewed_by}")?;
+        }
+
         if let Some(HiveFormat {
             row_format,
             serde_properties,
@@ -3203,7 +4028,6 @@ impl fmt::Display for CreateTable {
                 Some(HiveIOFormat::FileFormat { format }) if !self.external => {
                     write!(f, " STORED AS {format}")?
                 }
-                Some(HiveIOFormat::Using { format }) => write!(f, " USING {format}")?,
                 _ => (),
             }
             if let Some(serde_properties) = serde_properties.as_ref() {
@@ -3235,6 +4059,10 @@ impl fmt::Display for CreateTable {
             _ => (),
         }
 
+        if self.with_system_versioning {
+            write!(f, " WITH SYSTEM VERSIONING")?;
+        }
+
         if let Some(primary_key) = &self.primary_key {
             write!(f, " PRIMARY KEY {primary_key}")?;
         }
@@ -3724,6 +4552,57 @@ impl fmt::Display for CreateFunction {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+/// ```sql
+/// CREATE [ OR REPLACE ] TABLE FUNCTION [ IF NOT EXISTS ] name ( [ param [, ...] ] )
+///     [ RETURNS TABLE < column_name column_type [, ...] > ]
+///     [ OPTIONS ( option [, ...] ) ]
+///     AS query
+/// ```
+/// See [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#create_table_function_statement)
+pub struct CreateTableFunction {
+    /// True if this is a `CREATE OR REPLACE TABLE FUNCTION` statement
+    pub or_replace: bool,
+    /// True if `IF NOT EXISTS` was specified
+    pub if_not_exists: bool,
+    /// Name of the table function to be created.
+    pub name: ObjectName,
+    /// List of parameters for the table function.
+    pub args: Vec<OperateFunctionArg>,
+    /// Columns of the `RETURNS TABLE<...>` clause, if present.
+    pub returns: Option<Vec<StructField>>,
+    /// List of options for creating the table function.
+    pub options: Option<Vec<SqlOption>>,
+    /// The query that defines the table function.
+    pub query: Box<Query>,
+}
+
+impl fmt::Display for CreateTableFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CREATE {or_replace}TABLE FUNCTION {if_not_exists}{name}({args})",
+            or_replace = if self.or_replace { "OR REPLACE " } else { "" },
+            if_not_exists = if self.if_not_exists {
+                "IF NOT EXISTS "
+            } else {
+                ""
+            },
+            name = self.name,
+            args = display_comma_separated(&self.args),
+        )?;
+        if let Some(returns) = &self.returns {
+            write!(f, " RETURNS TABLE<{}>", display_comma_separated(returns))?;
+        }
+        if let Some(options) = &self.options {
+            write!(f, " OPTIONS({})", display_comma_separated(options))?;
+        }
+        write!(f, " AS {}", self.query)
+    }
+}
+
 /// ```sql
 /// CREATE CONNECTOR [IF NOT EXISTS] connector_name
 /// [TYPE datasource_type]
@@ -3981,6 +4860,10 @@ pub struct CreateTrigger {
     /// EXECUTE FUNCTION trigger_function();
     /// ```
     pub or_replace: bool,
+    /// The `IF NOT EXISTS` clause.
+    ///
+    /// [SQLite](https://sqlite.org/lang_createtrigger.html)
+    pub if_not_exists: bool,
     /// The `CONSTRAINT` keyword is used to create a trigger as a constraint.
     pub is_constraint: bool,
     /// The name of the trigger to be created.
@@ -4057,6 +4940,7 @@ impl Display for CreateTrigger {
             or_alter,
             temporary,
             or_replace,
+            if_not_exists,
             is_constraint,
             name,
             period_before_table,
@@ -4074,11 +4958,12 @@ impl Display for CreateTrigger {
         } = self;
         write!(
             f,
-            "CREATE {temporary}{or_alter}{or_replace}{is_constraint}TRIGGER {name} ",
+            "CREATE {temporary}{or_alter}{or_replace}{is_constraint}TRIGGER {if_not_exists}{name} ",
             temporary = if *temporary { "TEMPORARY " } else { "" },
             or_alter = if *or_alter { "OR ALTER " } else { "" },
             or_replace = if *or_replace { "OR REPLACE " } else { "" },
             is_constraint = if *is_constraint { "CONSTRAINT " } else { "" },
+            if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
         )?;
 
         if *period_before_table {
@@ -4196,7 +5081,7 @@ pub struct Truncate {
     pub cascade: Option<super::CascadeOption>,
     /// ClickHouse-specific option: [ ON CLUSTER cluster_name ]
     /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/truncate/)
-    pub on_cluster: Option<Ident>,
+    pub on_cluster: Option<ObjectNamePart>,
 }
 
 impl fmt::Display for Truncate {
@@ -4329,6 +5214,12 @@ pub struct CreateView {
     pub comment: Option<String>,
     /// if true, has RedShift [`WITH NO SCHEMA BINDING`] clause <https://docs.aws.amazon.com/redshift/latest/dg/r_CREATE_VIEW.html>
     pub with_no_schema_binding: bool,
+    /// Optional `WITH [ CASCADED | LOCAL ] CHECK OPTION` clause
+    pub with_check_option: Option<ViewCheckOption>,
+    /// PostgreSQL materialized views: `WITH DATA` (`Some(true)`) or
+    /// `WITH NO DATA` (`Some(false)`)
+    /// <https://www.postgresql.org/docs/current/sql-creatematerializedview.html>
+    pub with_data: Option<bool>,
     /// if true, has SQLite `IF NOT EXISTS` clause <https://www.sqlite.org/lang_createview.html>
     pub if_not_exists: bool,
     /// if true, has SQLite `TEMP` or `TEMPORARY` clause <https://www.sqlite.org/lang_createview.html>
@@ -4407,6 +5298,14 @@ impl fmt::Display for CreateView {
         if self.with_no_schema_binding {
             write!(f, " WITH NO SCHEMA BINDING")?;
         }
+        if let Some(check_option) = &self.with_check_option {
+            write!(f, " {check_option}")?;
+        }
+        match self.with_data {
+            Some(true) => write!(f, " WITH DATA")?,
+            Some(false) => write!(f, " WITH NO DATA")?,
+            None => {}
+        }
         Ok(())
     }
 }
@@ -4504,75 +5403,808 @@ impl Spanned for DropExtension {
     }
 }
 
-/// CREATE COLLATION statement.
-/// Note: this is a PostgreSQL-specific statement.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
-pub struct CreateCollation {
-    /// Whether `IF NOT EXISTS` was specified.
-    pub if_not_exists: bool,
-    /// Name of the collation being created.
-    pub name: ObjectName,
-    /// Source definition for the collation.
-    pub definition: CreateCollationDefinition,
-}
-
-/// Definition forms supported by `CREATE COLLATION`.
+/// CREATE LANGUAGE statement
+/// Note: this is a PostgreSQL-specific statement
+///
+/// ```sql
+/// CREATE [ OR REPLACE ] [ TRUSTED ] [ PROCEDURAL ] LANGUAGE name
+///     [ HANDLER call_handler [ INLINE inline_handler ] [ VALIDATOR valfunction ] ]
+/// ```
+///
+/// <https://www.postgresql.org/docs/current/sql-createlanguage.html>
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
-pub enum CreateCollationDefinition {
-    /// Create from an existing collation.
-    ///
-    /// ```sql
-    /// CREATE COLLATION name FROM existing_collation
-    /// ```
-    From(ObjectName),
-    /// Create with an option list.
-    ///
-    /// ```sql
-    /// CREATE COLLATION name (key = value, ...)
-    /// ```
-    Options(Vec<SqlOption>),
+pub struct CreateLanguage {
+    /// Whether `OR REPLACE` was specified.
+    pub or_replace: bool,
+    /// Whether `TRUSTED` was specified.
+    pub trusted: bool,
+    /// Whether the optional `PROCEDURAL` keyword was present.
+    pub procedural: bool,
+    /// Name of the language.
+    pub name: Ident,
+    /// Optional `HANDLER` function.
+    pub handler: Option<ObjectName>,
+    /// Optional `INLINE` handler function.
+    pub inline_handler: Option<ObjectName>,
+    /// Optional `VALIDATOR` function.
+    pub validator: Option<ObjectName>,
 }
 
-impl fmt::Display for CreateCollation {
+impl fmt::Display for CreateLanguage {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "CREATE COLLATION {if_not_exists}{name}",
-            if_not_exists = if self.if_not_exists {
-                "IF NOT EXISTS "
-            } else {
-                ""
-            },
-            name = self.name
+            "CREATE {or_replace}{trusted}{procedural}LANGUAGE {name}",
+            or_replace = if self.or_replace { "OR REPLACE " } else { "" },
+            trusted = if self.trusted { "TRUSTED " } else { "" },
+            procedural = if self.procedural { "PROCEDURAL " } else { "" },
+            name = self.name,
         )?;
-        match &self.definition {
-            CreateCollationDefinition::From(existing_collation) => {
-                write!(f, " FROM {existing_collation}")
-            }
-            CreateCollationDefinition::Options(options) => {
-                write!(f, " ({})", display_comma_separated(options))
-            }
+        if let Some(handler) = &self.handler {
+            write!(f, " HANDLER {handler}")?;
+        }
+        if let Some(inline_handler) = &self.inline_handler {
+            write!(f, " INLINE {inline_handler}")?;
         }
+        if let Some(validator) = &self.validator {
+            write!(f, " VALIDATOR {validator}")?;
+        }
+        Ok(())
     }
 }
 
-impl Spanned for CreateCollation {
+impl Spanned for CreateLanguage {
     fn span(&self) -> Span {
         Span::empty()
     }
 }
 
-/// ALTER COLLATION statement.
-/// Note: this is a PostgreSQL-specific statement.
+/// DROP LANGUAGE statement
+/// Note: this is a PostgreSQL-specific statement
+///
+/// ```sql
+/// DROP [ PROCEDURAL ] LANGUAGE [ IF EXISTS ] name [ CASCADE | RESTRICT ]
+/// ```
+///
+/// <https://www.postgresql.org/docs/current/sql-droplanguage.html>
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
-pub struct AlterCollation {
-    /// Name of the collation being altered.
+pub struct DropLanguage {
+    /// Whether the optional `PROCEDURAL` keyword was present.
+    pub procedural: bool,
+    /// Whether `IF EXISTS` was specified.
+    pub if_exists: bool,
+    /// Name of the language to drop.
+    pub name: Ident,
+    /// `CASCADE` or `RESTRICT` behaviour for the drop.
+    pub drop_behavior: Option<DropBehavior>,
+}
+
+impl fmt::Display for DropLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "DROP {procedural}LANGUAGE {if_exists}{name}",
+            procedural = if self.procedural { "PROCEDURAL " } else { "" },
+            if_exists = if self.if_exists { "IF EXISTS " } else { "" },
+            name = self.name,
+        )?;
+        if let Some(drop_behavior) = &self.drop_behavior {
+            write!(f, " {drop_behavior}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Spanned for DropLanguage {
+    fn span(&self) -> Span {
+        Span::empty()
+    }
+}
+
+/// CREATE TRANSFORM statement
+/// Note: this is a PostgreSQL-specific statement
+///
+/// ```sql
+/// CREATE [ OR REPLACE ] TRANSFORM FOR type_name LANGUAGE lang_name (
+///     FROM SQL WITH FUNCTION from_sql_function_name [ (argument_type [, ...]) ],
+///     TO SQL WITH FUNCTION to_sql_function_name [ (argument_type [, ...]) ]
+/// )
+/// ```
+///
+/// <https://www.postgresql.org/docs/current/sql-createtransform.html>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CreateTransform {
+    /// Whether `OR REPLACE` was specified.
+    pub or_replace: bool,
+    /// The data type the transform applies to.
+    pub type_name: DataType,
+    /// The procedural language the transform applies to.
+    pub language: Ident,
+    /// Optional `FROM SQL WITH FUNCTION` function.
+    pub from_sql: Option<FunctionDesc>,
+    /// Optional `TO SQL WITH FUNCTION` function.
+    pub to_sql: Option<FunctionDesc>,
+}
+
+impl fmt::Display for CreateTransform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CREATE {or_replace}TRANSFORM FOR {type_name} LANGUAGE {language} (",
+            or_replace = if self.or_replace { "OR REPLACE " } else { "" },
+            type_name = self.type_name,
+            language = self.language,
+        )?;
+        if let Some(from_sql) = &self.from_sql {
+            write!(f, "FROM SQL WITH FUNCTION {from_sql}")?;
+            if self.to_sql.is_some() {
+                write!(f, ", ")?;
+            }
+        }
+        if let Some(to_sql) = &self.to_sql {
+            write!(f, "TO SQL WITH FUNCTION {to_sql}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl Spanned for CreateTransform {
+    fn span(&self) -> Span {
+        Span::empty()
+    }
+}
+
+/// DROP TRANSFORM statement
+/// Note: this is a PostgreSQL-specific statement
+///
+/// ```sql
+/// DROP TRANSFORM [ IF EXISTS ] FOR type_name LANGUAGE lang_name [ CASCADE | RESTRICT ]
+/// ```
+///
+/// <https://www.postgresql.org/docs/current/sql-droptransform.html>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct DropTransform {
+    /// Whether `IF EXISTS` was specified.
+    pub if_exists: bool,
+    /// The data type the transform applies to.
+    pub type_name: DataType,
+    /// The procedural language the transform applies to.
+    pub language: Ident,
+    /// `CASCADE` or `RESTRICT` behaviour for the drop.
+    pub drop_behavior: Option<DropBehavior>,
+}
+
+impl fmt::Display for DropTransform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "DROP TRANSFORM {if_exists}FOR {type_name} LANGUAGE {language}",
+            if_exists = if self.if_exists { "IF EXISTS " } else { "" },
+            type_name = self.type_name,
+            language = self.language,
+        )?;
+        if let Some(drop_behavior) = &self.drop_behavior {
+            write!(f, " {drop_behavior}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Spanned for DropTransform {
+    fn span(&self) -> Span {
+        Span::empty()
+    }
+}
+
+/// CREATE CONVERSION statement
+/// Note: this is a PostgreSQL-specific statement
+///
+/// ```sql
+/// CREATE [ DEFAULT ] CONVERSION name
+///     FOR source_encoding TO dest_encoding FROM function_name
+/// ```
+///
+/// <https://www.postgresql.org/docs/current/sql-createconversion.html>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CreateConversion {
+    /// Whether `DEFAULT` was specified.
+    pub default: bool,
+    /// Name of the conversion.
+    pub name: ObjectName,
+    /// The source encoding name.
+    pub source_encoding: String,
+    /// The destination encoding name.
+    pub destination_encoding: String,
+    /// The function performing the conversion.
+    pub function: ObjectName,
+}
+
+impl fmt::Display for CreateConversion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CREATE {default}CONVERSION {name} FOR '{source}' TO '{destination}' FROM {function}",
+            default = if self.default { "DEFAULT " } else { "" },
+            name = self.name,
+            source = escape_single_quote_string(&self.source_encoding),
+            destination = escape_single_quote_string(&self.destination_encoding),
+            function = self.function,
+        )
+    }
+}
+
+impl Spanned for CreateConversion {
+    fn span(&self) -> Span {
+        Span::empty()
+    }
+}
+
+/// DROP CONVERSION statement
+/// Note: this is a PostgreSQL-specific statement
+///
+/// ```sql
+/// DROP CONVERSION [ IF EXISTS ] name [ CASCADE | RESTRICT ]
+/// ```
+///
+/// <https://www.postgresql.org/docs/current/sql-dropconversion.html>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct DropConversion {
+    /// Whether `IF EXISTS` was specified.
+    pub if_exists: bool,
+    /// Name of the conversion to drop.
+    pub name: ObjectName,
+    /// `CASCADE` or `RESTRICT` behaviour for the drop.
+    pub drop_behavior: Option<DropBehavior>,
+}
+
+impl fmt::Display for DropConversion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "DROP CONVERSION {if_exists}{name}",
+            if_exists = if self.if_exists { "IF EXISTS " } else { "" },
+            name = self.name,
+        )?;
+        if let Some(drop_behavior) = &self.drop_behavior {
+            write!(f, " {drop_behavior}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Spanned for DropConversion {
+    fn span(&self) -> Span {
+        Span::empty()
+    }
+}
+
+/// The kind of object created by a `CREATE TEXT SEARCH` statement.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum TextSearchObjectType {
+    /// `CONFIGURATION`
+    Configuration,
+    /// `DICTIONARY`
+    Dictionary,
+    /// `PARSER`
+    Parser,
+    /// `TEMPLATE`
+    Template,
+}
+
+impl fmt::Display for TextSearchObjectType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TextSearchObjectType::Configuration => write!(f, "CONFIGURATION"),
+            TextSearchObjectType::Dictionary => write!(f, "DICTIONARY"),
+            TextSearchObjectType::Parser => write!(f, "PARSER"),
+            TextSearchObjectType::Template => write!(f, "TEMPLATE"),
+        }
+    }
+}
+
+/// CREATE TEXT SEARCH statement
+/// Note: this is a PostgreSQL-specific statement
+///
+/// ```sql
+/// CREATE TEXT SEARCH { CONFIGURATION | DICTIONARY | PARSER | TEMPLATE } name ( option = value [, ... ] )
+/// ```
+///
+/// <https://www.postgresql.org/docs/current/sql-createtsconfig.html>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CreateTextSearch {
+    /// The kind of text search object being created.
+    pub object_type: TextSearchObjectType,
+    /// Name of the text search object.
+    pub name: ObjectName,
+    /// The parenthesized option list, e.g. `COPY = french`.
+    pub options: Vec<SqlOption>,
+}
+
+impl fmt::Display for CreateTextSearch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CREATE TEXT SEARCH {object_type} {name} ({options})",
+            object_type = self.object_type,
+            name = self.name,
+            options = display_comma_separated(&self.options),
+        )
+    }
+}
+
+impl Spanned for CreateTextSearch {
+    fn span(&self) -> Span {
+        Span::empty()
+    }
+}
+
+/// An operation of an `ALTER TEXT SEARCH CONFIGURATION` statement.
+///
+/// <https://www.postgresql.org/docs/current/sql-altertsconfig.html>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum AlterTextSearchConfigurationOperation {
+    /// `ADD MAPPING FOR token_type [, ...] WITH dictionary_name [, ...]`
+    AddMapping {
+        /// The token types to map.
+        token_types: Vec<Ident>,
+        /// The dictionaries to consult, in order.
+        dictionaries: Vec<ObjectName>,
+    },
+    /// `ALTER MAPPING FOR token_type [, ...] WITH dictionary_name [, ...]`
+    AlterMapping {
+        /// The token types whose mapping is changed.
+        token_types: Vec<Ident>,
+        /// The dictionaries to consult, in order.
+        dictionaries: Vec<ObjectName>,
+    },
+    /// `ALTER MAPPING [ FOR token_type [, ...] ] REPLACE old_dictionary WITH new_dictionary`
+    ReplaceMapping {
+        /// The token types to restrict the replacement to; empty for all.
+        token_types: Vec<Ident>,
+        /// The dictionary being replaced.
+        old_dictionary: ObjectName,
+        /// The replacement dictionary.
+        new_dictionary: ObjectName,
+    },
+    /// `DROP MAPPING [ IF EXISTS ] FOR token_type [, ...]`
+    DropMapping {
+        /// Whether `IF EXISTS` was specified.
+        if_exists: bool,
+        /// The token types whose mapping is removed.
+        token_types: Vec<Ident>,
+    },
+}
+
+impl fmt::Display for AlterTextSearchConfigurationOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlterTextSearchConfigurationOperation::AddMapping {
+                token_types,
+                dictionaries,
+            } => write!(
+                f,
+                "ADD MAPPING FOR {} WITH {}",
+                display_comma_separated(token_types),
+                display_comma_separated(dictionaries)
+            ),
+            AlterTextSearchConfigurationOperation::AlterMapping {
+                token_types,
+                dictionaries,
+            } => write!(
+                f,
+                "ALTER MAPPING FOR {} WITH {}",
+                display_comma_separated(token_types),
+                display_comma_separated(dictionaries)
+            ),
+            AlterTextSearchConfigurationOperation::ReplaceMapping {
+                token_types,
+                old_dictionary,
+                new_dictionary,
+            } => {
+                write!(f, "ALTER MAPPING")?;
+                if !token_types.is_empty() {
+                    write!(f, " FOR {}", display_comma_separated(token_types))?;
+                }
+                write!(f, " REPLACE {old_dictionary} WITH {new_dictionary}")
+            }
+            AlterTextSearchConfigurationOperation::DropMapping {
+                if_exists,
+                token_types,
+            } => write!(
+                f,
+                "DROP MAPPING {}FOR {}",
+                if *if_exists { "IF EXISTS " } else { "" },
+                display_comma_separated(token_types)
+            ),
+        }
+    }
+}
+
+/// ALTER TEXT SEARCH CONFIGURATION statement
+/// Note: this is a PostgreSQL-specific statement
+///
+/// <https://www.postgresql.org/docs/current/sql-altertsconfig.html>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct AlterTextSearchConfiguration {
+    /// Name of the text search configuration.
+    pub name: ObjectName,
+    /// The mapping operation to apply.
+    pub operation: AlterTextSearchConfigurationOperation,
+}
+
+impl fmt::Display for AlterTextSearchConfiguration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ALTER TEXT SEARCH CONFIGURATION {} {}",
+            self.name, self.operation
+        )
+    }
+}
+
+impl Spanned for AlterTextSearchConfiguration {
+    fn span(&self) -> Span {
+        Span::empty()
+    }
+}
+
+/// CREATE STATISTICS statement
+/// Note: this is a PostgreSQL-specific statement
+///
+/// ```sql
+/// CREATE STATISTICS [ IF NOT EXISTS ] statistics_name
+///     [ ( statistics_kind [, ... ] ) ]
+///     ON { column_name | ( expression ) } [, ...]
+///     FROM table_name
+/// ```
+///
+/// <https://www.postgresql.org/docs/current/sql-createstatistics.html>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CreateStatistics {
+    /// Whether `IF NOT EXISTS` was specified.
+    pub if_not_exists: bool,
+    /// Name of the statistics object.
+    pub name: ObjectName,
+    /// The statistics kinds to compute, e.g. `ndistinct`, `dependencies` or `mcv`.
+    pub kinds: Vec<Ident>,
+    /// The columns or parenthesized expressions the statistics cover.
+    pub expressions: Vec<Expr>,
+    /// The table the statistics are defined on.
+    pub table_name: ObjectName,
+}
+
+impl fmt::Display for CreateStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CREATE STATISTICS {if_not_exists}{name}",
+            if_not_exists = if self.if_not_exists {
+                "IF NOT EXISTS "
+            } else {
+                ""
+            },
+            name = self.name,
+        )?;
+        if !self.kinds.is_empty() {
+            write!(f, " ({})", display_comma_separated(&self.kinds))?;
+        }
+        write!(
+            f,
+            " ON {} FROM {}",
+            display_comma_separated(&self.expressions),
+            self.table_name
+        )
+    }
+}
+
+impl Spanned for CreateStatistics {
+    fn span(&self) -> Span {
+        Span::empty()
+    }
+}
+
+/// ALTER STATISTICS statement
+/// Note: this is a PostgreSQL-specific statement
+///
+/// <https://www.postgresql.org/docs/current/sql-alterstatistics.html>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct AlterStatistics {
+    /// Name of the statistics object.
+    pub name: ObjectName,
+    /// The operation to apply.
+    pub operation: AlterStatisticsOperation,
+}
+
+impl fmt::Display for AlterStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ALTER STATISTICS {} {}", self.name, self.operation)
+    }
+}
+
+impl Spanned for AlterStatistics {
+    fn span(&self) -> Span {
+        Span::empty()
+    }
+}
+
+/// An [AlterStatistics] operation
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum AlterStatisticsOperation {
+    /// `RENAME TO new_name`
+    RenameTo {
+        /// The new name of the statistics object.
+        new_name: Ident,
+    },
+    /// `OWNER TO { new_owner | CURRENT_ROLE | CURRENT_USER | SESSION_USER }`
+    OwnerTo(Owner),
+    /// `SET SCHEMA new_schema`
+    SetSchema {
+        /// The schema the statistics object is moved to.
+        schema_name: ObjectName,
+    },
+    /// `SET STATISTICS new_target`
+    SetStatistics {
+        /// The new statistics target.
+        value: Value,
+    },
+}
+
+impl fmt::Display for AlterStatisticsOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlterStatisticsOperation::RenameTo { new_name } => write!(f, "RENAME TO {new_name}"),
+            AlterStatisticsOperation::OwnerTo(owner) => write!(f, "OWNER TO {owner}"),
+            AlterStatisticsOperation::SetSchema { schema_name } => {
+                write!(f, "SET SCHEMA {schema_name}")
+            }
+            AlterStatisticsOperation::SetStatistics { value } => {
+                write!(f, "SET STATISTICS {value}")
+            }
+        }
+    }
+}
+
+/// ```sql
+/// CREATE CATALOG [ IF NOT EXISTS ] catalog_name
+///     [ MANAGED LOCATION 'location_path' ]
+///     [ COMMENT 'comment' ]
+/// ```
+///
+/// <https://docs.databricks.com/aws/en/sql/language-manual/sql-ref-syntax-ddl-create-catalog>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CreateCatalog {
+    /// Whether `IF NOT EXISTS` was specified.
+    pub if_not_exists: bool,
+    /// Name of the catalog.
+    pub name: Ident,
+    /// Optional `MANAGED LOCATION` path.
+    pub managed_location: Option<String>,
+    /// Optional `COMMENT`.
+    pub comment: Option<String>,
+}
+
+impl fmt::Display for CreateCatalog {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CREATE CATALOG {if_not_exists}{name}",
+            if_not_exists = if self.if_not_exists {
+                "IF NOT EXISTS "
+            } else {
+                ""
+            },
+            name = self.name,
+        )?;
+        if let Some(location) = &self.managed_location {
+            write!(
+                f,
+                " MANAGED LOCATION '{}'",
+                escape_single_quote_string(location)
+            )?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT '{}'", escape_single_quote_string(comment))?;
+        }
+        Ok(())
+    }
+}
+
+impl Spanned for CreateCatalog {
+    fn span(&self) -> Span {
+        Span::empty()
+    }
+}
+
+/// ```sql
+/// CREATE [ OR REPLACE ] { SYNONYM | ALIAS } name FOR object_name
+/// ```
+///
+/// [MSSQL](https://learn.microsoft.com/en-us/sql/t-sql/statements/create-synonym-transact-sql)
+/// [Db2](https://www.ibm.com/docs/en/db2/11.5?topic=statements-create-alias)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CreateSynonym {
+    /// Whether `OR REPLACE` was specified.
+    pub or_replace: bool,
+    /// Whether the `ALIAS` spelling was used instead of `SYNONYM`.
+    pub alias_keyword: bool,
+    /// Name of the synonym.
+    pub name: ObjectName,
+    /// The object the synonym refers to, possibly a four-part
+    /// `server.database.schema.object` name.
+    pub for_object: ObjectName,
+}
+
+impl fmt::Display for CreateSynonym {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CREATE {or_replace}{keyword} {name} FOR {for_object}",
+            or_replace = if self.or_replace { "OR REPLACE " } else { "" },
+            keyword = if self.alias_keyword {
+                "ALIAS"
+            } else {
+                "SYNONYM"
+            },
+            name = self.name,
+            for_object = self.for_object,
+        )
+    }
+}
+
+impl Spanned for CreateSynonym {
+    fn span(&self) -> Span {
+        self.name.span().union(&self.for_object.span())
+    }
+}
+
+/// ALTER CATALOG statement
+/// Note: this is a Databricks-specific statement
+///
+/// <https://docs.databricks.com/aws/en/sql/language-manual/sql-ref-syntax-ddl-alter-catalog>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct AlterCatalog {
+    /// Name of the catalog.
+    pub name: Ident,
+    /// The operation to apply.
+    pub operation: AlterCatalogOperation,
+}
+
+impl fmt::Display for AlterCatalog {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ALTER CATALOG {} {}", self.name, self.operation)
+    }
+}
+
+impl Spanned for AlterCatalog {
+    fn span(&self) -> Span {
+        Span::empty()
+    }
+}
+
+/// An [AlterCatalog] operation
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum AlterCatalogOperation {
+    /// `OWNER TO principal`
+    OwnerTo(Owner),
+    /// `RENAME TO new_name`
+    RenameTo {
+        /// The new name of the catalog.
+        new_name: Ident,
+    },
+}
+
+impl fmt::Display for AlterCatalogOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlterCatalogOperation::OwnerTo(owner) => write!(f, "OWNER TO {owner}"),
+            AlterCatalogOperation::RenameTo { new_name } => write!(f, "RENAME TO {new_name}"),
+        }
+    }
+}
+
+/// CREATE COLLATION statement.
+/// Note: this is a PostgreSQL-specific statement.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CreateCollation {
+    /// Whether `IF NOT EXISTS` was specified.
+    pub if_not_exists: bool,
+    /// Name of the collation being created.
+    pub name: ObjectName,
+    /// Source definition for the collation.
+    pub definition: CreateCollationDefinition,
+}
+
+/// Definition forms supported by `CREATE COLLATION`.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum CreateCollationDefinition {
+    /// Create from an existing collation.
+    ///
+    /// ```sql
+    /// CREATE COLLATION name FROM existing_collation
+    /// ```
+    From(ObjectName),
+    /// Create with an option list.
+    ///
+    /// ```sql
+    /// CREATE COLLATION name (key = value, ...)
+    /// ```
+    Options(Vec<SqlOption>),
+}
+
+impl fmt::Display for CreateCollation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CREATE COLLATION {if_not_exists}{name}",
+            if_not_exists = if self.if_not_exists {
+                "IF NOT EXISTS "
+            } else {
+                ""
+            },
+            name = self.name
+        )?;
+        match &self.definition {
+            CreateCollationDefinition::From(existing_collation) => {
+                write!(f, " FROM {existing_collation}")
+            }
+            CreateCollationDefinition::Options(options) => {
+                write!(f, " ({})", display_comma_separated(options))
+            }
+        }
+    }
+}
+
+impl Spanned for CreateCollation {
+    fn span(&self) -> Span {
+        Span::empty()
+    }
+}
+
+/// ALTER COLLATION statement.
+/// Note: this is a PostgreSQL-specific statement.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct AlterCollation {
+    /// Name of the collation being altered.
     pub name: ObjectName,
     /// The operation to perform on the collation.
     pub operation: AlterCollationOperation,
@@ -4655,6 +6287,12 @@ pub enum AlterTableType {
     /// External table type
     /// <https://docs.snowflake.com/en/sql-reference/sql/alter-external-table>
     External,
+    /// View, for operations shared with `ALTER TABLE`
+    /// <https://www.postgresql.org/docs/current/sql-alterview.html>
+    View,
+    /// Sequence, for operations shared with `ALTER TABLE`
+    /// <https://www.postgresql.org/docs/current/sql-altersequence.html>
+    Sequence,
 }
 
 /// ALTER TABLE statement
@@ -4676,7 +6314,7 @@ pub struct AlterTable {
     /// ClickHouse dialect supports `ON CLUSTER` clause for ALTER TABLE
     /// For example: `ALTER TABLE table_name ON CLUSTER cluster_name ADD COLUMN c UInt32`
     /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/alter/update)
-    pub on_cluster: Option<Ident>,
+    pub on_cluster: Option<ObjectNamePart>,
     /// Table type: None for regular tables, Some(AlterTableType) for Iceberg or Dynamic tables
     pub table_type: Option<AlterTableType>,
     /// Token that represents the end of the statement (semicolon or EOF)
@@ -4689,6 +6327,8 @@ impl fmt::Display for AlterTable {
             Some(AlterTableType::Iceberg) => write!(f, "ALTER ICEBERG TABLE ")?,
             Some(AlterTableType::Dynamic) => write!(f, "ALTER DYNAMIC TABLE ")?,
             Some(AlterTableType::External) => write!(f, "ALTER EXTERNAL TABLE ")?,
+            Some(AlterTableType::View) => write!(f, "ALTER VIEW ")?,
+            Some(AlterTableType::Sequence) => write!(f, "ALTER SEQUENCE ")?,
             None => write!(f, "ALTER TABLE ")?,
         }
 
@@ -5359,7 +6999,7 @@ impl Spanned for AlterOperatorClass {
     }
 }
 
-/// `ALTER FUNCTION` / `ALTER AGGREGATE` statement.
+/// `ALTER FUNCTION` / `ALTER AGGREGATE` / `ALTER PROCEDURE` statement.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
@@ -5389,6 +7029,8 @@ pub enum AlterFunctionKind {
     Function,
     /// `AGGREGATE`
     Aggregate,
+    /// `PROCEDURE`
+    Procedure,
 }
 
 impl fmt::Display for AlterFunctionKind {
@@ -5396,11 +7038,12 @@ impl fmt::Display for AlterFunctionKind {
         match self {
             Self::Function => write!(f, "FUNCTION"),
             Self::Aggregate => write!(f, "AGGREGATE"),
+            Self::Procedure => write!(f, "PROCEDURE"),
         }
     }
 }
 
-/// Operation for `ALTER FUNCTION` / `ALTER AGGREGATE`.
+/// Operation for `ALTER FUNCTION` / `ALTER AGGREGATE` / `ALTER PROCEDURE`.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
@@ -5470,7 +7113,7 @@ impl fmt::Display for AlterFunction {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "ALTER {} ", self.kind)?;
         match self.kind {
-            AlterFunctionKind::Function => {
+            AlterFunctionKind::Function | AlterFunctionKind::Procedure => {
                 write!(f, "{} ", self.function)?;
             }
             AlterFunctionKind::Aggregate => {
@@ -5757,3 +7400,234 @@ impl From<AlterPolicy> for crate::ast::Statement {
         crate::ast::Statement::AlterPolicy(v)
     }
 }
+
+/// The schedule of a MySQL event, as given in its `ON SCHEDULE` clause.
+///
+/// [MySQL](https://dev.mysql.com/doc/refman/8.4/en/create-event.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum EventSchedule {
+    /// `AT timestamp [+ INTERVAL interval] ...`
+    At(Expr),
+    /// `EVERY interval [STARTS timestamp] [ENDS timestamp]`
+    Every {
+        /// The quantity of the recurrence interval, e.g. `1` in `EVERY 1 DAY`.
+        quantity: Expr,
+        /// The unit of the recurrence interval, e.g. `DAY` in `EVERY 1 DAY`.
+        unit: DateTimeField,
+        /// Optional `STARTS timestamp` expression.
+        starts: Option<Expr>,
+        /// Optional `ENDS timestamp` expression.
+        ends: Option<Expr>,
+    },
+}
+
+impl fmt::Display for EventSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EventSchedule::At(timestamp) => write!(f, "AT {timestamp}"),
+            EventSchedule::Every {
+                quantity,
+                unit,
+                starts,
+                ends,
+            } => {
+                write!(f, "EVERY {quantity} {unit}")?;
+                if let Some(starts) = starts {
+                    write!(f, " STARTS {starts}")?;
+                }
+                if let Some(ends) = ends {
+                    write!(f, " ENDS {ends}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The `ON COMPLETION [NOT] PRESERVE` clause of a MySQL event.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum EventOnCompletion {
+    /// `ON COMPLETION PRESERVE`
+    Preserve,
+    /// `ON COMPLETION NOT PRESERVE`
+    NotPreserve,
+}
+
+impl fmt::Display for EventOnCompletion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EventOnCompletion::Preserve => write!(f, "ON COMPLETION PRESERVE"),
+            EventOnCompletion::NotPreserve => write!(f, "ON COMPLETION NOT PRESERVE"),
+        }
+    }
+}
+
+/// Whether a MySQL event is enabled.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum EventStatus {
+    /// `ENABLE`
+    Enable,
+    /// `DISABLE`
+    Disable,
+    /// `DISABLE ON REPLICA`
+    DisableOnReplica,
+    /// `DISABLE ON SLAVE`
+    DisableOnSlave,
+}
+
+impl fmt::Display for EventStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EventStatus::Enable => write!(f, "ENABLE"),
+            EventStatus::Disable => write!(f, "DISABLE"),
+            EventStatus::DisableOnReplica => write!(f, "DISABLE ON REPLICA"),
+            EventStatus::DisableOnSlave => write!(f, "DISABLE ON SLAVE"),
+        }
+    }
+}
+
+/// CREATE EVENT statement.
+///
+/// ```sql
+/// CREATE [DEFINER = user] EVENT [IF NOT EXISTS] event_name
+///     ON SCHEDULE schedule
+///     [ON COMPLETION [NOT] PRESERVE]
+///     [ENABLE | DISABLE | DISABLE ON {REPLICA | SLAVE}]
+///     [COMMENT 'string']
+///     DO event_body
+/// ```
+///
+/// [MySQL](https://dev.mysql.com/doc/refman/8.4/en/create-event.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CreateEvent {
+    /// Optional `DEFINER = user` clause.
+    pub definer: Option<GranteeName>,
+    /// `IF NOT EXISTS` flag.
+    pub if_not_exists: bool,
+    /// Name of the event.
+    pub name: ObjectName,
+    /// The `ON SCHEDULE` clause.
+    pub schedule: EventSchedule,
+    /// Optional `ON COMPLETION [NOT] PRESERVE` clause.
+    pub on_completion: Option<EventOnCompletion>,
+    /// Optional `ENABLE` / `DISABLE` status.
+    pub status: Option<EventStatus>,
+    /// Optional `COMMENT 'string'` clause.
+    pub comment: Option<String>,
+    /// The statement executed by the event.
+    pub body: Box<Statement>,
+}
+
+impl fmt::Display for CreateEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CREATE ")?;
+        if let Some(definer) = &self.definer {
+            write!(f, "DEFINER = {definer} ")?;
+        }
+        write!(
+            f,
+            "EVENT {if_not_exists}{name} ON SCHEDULE {schedule}",
+            if_not_exists = if self.if_not_exists {
+                "IF NOT EXISTS "
+            } else {
+                ""
+            },
+            name = self.name,
+            schedule = self.schedule,
+        )?;
+        if let Some(on_completion) = &self.on_completion {
+            write!(f, " {on_completion}")?;
+        }
+        if let Some(status) = &self.status {
+            write!(f, " {status}")?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT '{}'", escape_single_quote_string(comment))?;
+        }
+        write!(f, " DO {}", self.body)
+    }
+}
+
+impl From<CreateEvent> for crate::ast::Statement {
+    fn from(v: CreateEvent) -> Self {
+        crate::ast::Statement::CreateEvent(v)
+    }
+}
+
+/// ALTER EVENT statement.
+///
+/// ```sql
+/// ALTER [DEFINER = user] EVENT event_name
+///     [ON SCHEDULE schedule]
+///     [ON COMPLETION [NOT] PRESERVE]
+///     [RENAME TO new_event_name]
+///     [ENABLE | DISABLE | DISABLE ON {REPLICA | SLAVE}]
+///     [COMMENT 'string']
+///     [DO event_body]
+/// ```
+///
+/// [MySQL](https://dev.mysql.com/doc/refman/8.4/en/alter-event.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct AlterEvent {
+    /// Optional `DEFINER = user` clause.
+    pub definer: Option<GranteeName>,
+    /// Name of the event.
+    pub name: ObjectName,
+    /// Optional new `ON SCHEDULE` clause.
+    pub schedule: Option<EventSchedule>,
+    /// Optional `ON COMPLETION [NOT] PRESERVE` clause.
+    pub on_completion: Option<EventOnCompletion>,
+    /// Optional `RENAME TO new_event_name` clause.
+    pub rename_to: Option<ObjectName>,
+    /// Optional `ENABLE` / `DISABLE` status.
+    pub status: Option<EventStatus>,
+    /// Optional `COMMENT 'string'` clause.
+    pub comment: Option<String>,
+    /// Optional new statement executed by the event.
+    pub body: Option<Box<Statement>>,
+}
+
+impl fmt::Display for AlterEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ALTER ")?;
+        if let Some(definer) = &self.definer {
+            write!(f, "DEFINER = {definer} ")?;
+        }
+        write!(f, "EVENT {}", self.name)?;
+        if let Some(schedule) = &self.schedule {
+            write!(f, " ON SCHEDULE {schedule}")?;
+        }
+        if let Some(on_completion) = &self.on_completion {
+            write!(f, " {on_completion}")?;
+        }
+        if let Some(rename_to) = &self.rename_to {
+            write!(f, " RENAME TO {rename_to}")?;
+        }
+        if let Some(status) = &self.status {
+            write!(f, " {status}")?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT '{}'", escape_single_quote_string(comment))?;
+        }
+        if let Some(body) = &self.body {
+            write!(f, " DO {body}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<AlterEvent> for crate::ast::Statement {
+    fn from(v: AlterEvent) -> Self {
+        crate::ast::Statement::AlterEvent(v)
+    }
+}
@@ -16,7 +16,12 @@
 // under the License.
 
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, format, string::ToString, vec::Vec};
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use core::fmt::{self, Display};
 #[cfg(feature = "serde")]
@@ -25,16 +30,16 @@ use serde::{Deserialize, Serialize};
 use sqlparser_derive::{Visit, VisitMut};
 
 use crate::{
-    ast::display_separated,
+    ast::{display_separated, value::escape_single_quote_string},
     display_utils::{indented_list, Indent, SpaceOrNewline},
 };
 
 use super::{
     display_comma_separated, helpers::attached_token::AttachedToken, query::InputFormatClause,
     Assignment, Expr, FromTable, Ident, InsertAliases, MysqlInsertPriority, ObjectName, OnInsert,
-    OptimizerHint, OrderByExpr, Query, SelectInto, SelectItem, Setting, SqliteOnConflict,
-    TableAliasWithoutColumns, TableFactor, TableObject, TableWithJoins, UpdateTableFromKind,
-    Values,
+    OptimizerHint, OrderByExpr, Query, QueryHint, SelectInto, SelectItem, Setting, SqlOption,
+    SqliteOnConflict, TableAliasWithoutColumns, TableFactor, TableObject, TableWithJoins, Top,
+    UpdateTableFromKind, Values,
 };
 
 /// INSERT statement.
@@ -57,11 +62,19 @@ pub struct Insert {
     pub into: bool,
     /// TABLE
     pub table: TableObject,
+    /// MSSQL table hints: `INSERT INTO t WITH (TABLOCK) ...`
+    /// See <https://learn.microsoft.com/en-us/sql/t-sql/statements/insert-transact-sql>
+    pub with_hints: Vec<Expr>,
     /// `table_name as foo` (for PostgreSQL)
     /// `table_name foo` (for Oracle)
     pub table_alias: Option<TableAliasWithoutColumns>,
     /// COLUMNS
     pub columns: Vec<ObjectName>,
+    /// DuckDB `INSERT INTO t BY NAME ...`, matching source columns to
+    /// target columns by name rather than by position.
+    ///
+    /// See <https://duckdb.org/docs/stable/sql/statements/insert#insert-into--by-name>
+    pub by_name: bool,
     /// Overwrite (Hive)
     pub overwrite: bool,
     /// A SQL query that specifies what to insert
@@ -130,15 +143,23 @@ pub struct Insert {
 impl Display for Insert {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // SQLite OR conflict has a special format: INSERT OR ... INTO table_name
+        let table = if self.with_hints.is_empty() {
+            self.table.to_string()
+        } else {
+            format!(
+                "{} WITH ({})",
+                self.table,
+                display_comma_separated(&self.with_hints)
+            )
+        };
         let table_name = if let Some(table_alias) = &self.table_alias {
             format!(
                 "{table} {as_keyword}{alias}",
-                table = self.table,
                 as_keyword = if table_alias.explicit { "AS " } else { "" },
                 alias = table_alias.alias
             )
         } else {
-            self.table.to_string()
+            table
         };
 
         if let Some(on_conflict) = self.or {
@@ -189,6 +210,11 @@ impl Display for Insert {
             }
         }
 
+        if self.by_name {
+            write!(f, "BY NAME")?;
+            SpaceOrNewline.fmt(f)?;
+        }
+
         if !self.columns.is_empty() {
             write!(f, "({})", display_comma_separated(&self.columns))?;
             SpaceOrNewline.fmt(f)?;
@@ -287,6 +313,9 @@ pub struct Delete {
     /// [MySQL](https://dev.mysql.com/doc/refman/8.4/en/optimizer-hints.html)
     /// [Oracle](https://docs.oracle.com/en/database/oracle/oracle-database/21/sqlrf/Comments.html#GUID-D316D545-89E2-4D54-977F-FC97815CD62E)
     pub optimizer_hints: Vec<OptimizerHint>,
+    /// `TOP (expression) [PERCENT]` (MSSQL)
+    /// See <https://learn.microsoft.com/en-us/sql/t-sql/statements/delete-transact-sql>
+    pub top: Option<Top>,
     /// Multi tables delete are supported in mysql
     pub tables: Vec<ObjectName>,
     /// FROM
@@ -304,6 +333,9 @@ pub struct Delete {
     pub order_by: Vec<OrderByExpr>,
     /// LIMIT (MySQL)
     pub limit: Option<Expr>,
+    /// `OPTION ( <query_hint> [ ,...n ] )` (MSSQL)
+    /// See <https://learn.microsoft.com/en-us/sql/t-sql/queries/option-clause-transact-sql>
+    pub options: Vec<QueryHint>,
 }
 
 impl Display for Delete {
@@ -313,6 +345,9 @@ impl Display for Delete {
             f.write_str(" ")?;
             hint.fmt(f)?;
         }
+        if let Some(top) = &self.top {
+            write!(f, " {top}")?;
+        }
         if !self.tables.is_empty() {
             indented_list(f, &self.tables)?;
         }
@@ -356,6 +391,10 @@ impl Display for Delete {
             SpaceOrNewline.fmt(f)?;
             Indent(limit).fmt(f)?;
         }
+        if !self.options.is_empty() {
+            SpaceOrNewline.fmt(f)?;
+            write!(f, "OPTION ({})", display_comma_separated(&self.options))?;
+        }
         Ok(())
     }
 }
@@ -372,6 +411,9 @@ pub struct Update {
     /// [MySQL](https://dev.mysql.com/doc/refman/8.4/en/optimizer-hints.html)
     /// [Oracle](https://docs.oracle.com/en/database/oracle/oracle-database/21/sqlrf/Comments.html#GUID-D316D545-89E2-4D54-977F-FC97815CD62E)
     pub optimizer_hints: Vec<OptimizerHint>,
+    /// `TOP (expression) [PERCENT]` (MSSQL)
+    /// See <https://learn.microsoft.com/en-us/sql/t-sql/queries/update-transact-sql>
+    pub top: Option<Top>,
     /// TABLE
     pub table: TableWithJoins,
     /// Column assignments
@@ -392,6 +434,9 @@ pub struct Update {
     pub order_by: Vec<OrderByExpr>,
     /// LIMIT
     pub limit: Option<Expr>,
+    /// `OPTION ( <query_hint> [ ,...n ] )` (MSSQL)
+    /// See <https://learn.microsoft.com/en-us/sql/t-sql/queries/option-clause-transact-sql>
+    pub options: Vec<QueryHint>,
 }
 
 impl Display for Update {
@@ -401,6 +446,9 @@ impl Display for Update {
             f.write_str(" ")?;
             hint.fmt(f)?;
         }
+        if let Some(top) = &self.top {
+            write!(f, " {top}")?;
+        }
         f.write_str(" ")?;
         if let Some(or) = &self.or {
             or.fmt(f)?;
@@ -446,6 +494,10 @@ impl Display for Update {
             SpaceOrNewline.fmt(f)?;
             write!(f, "LIMIT {limit}")?;
         }
+        if !self.options.is_empty() {
+            SpaceOrNewline.fmt(f)?;
+            write!(f, "OPTION ({})", display_comma_separated(&self.options))?;
+        }
         Ok(())
     }
 }
@@ -473,6 +525,9 @@ pub struct Merge {
     pub clauses: Vec<MergeClause>,
     /// Specifies the output to save changes in MSSQL
     pub output: Option<OutputClause>,
+    /// `OPTION ( <query_hint> [ ,...n ] )` (MSSQL)
+    /// See <https://learn.microsoft.com/en-us/sql/t-sql/statements/merge-transact-sql>
+    pub options: Vec<QueryHint>,
 }
 
 impl Display for Merge {
@@ -484,17 +539,31 @@ impl Display for Merge {
         if self.into {
             write!(f, " INTO")?;
         }
-        write!(
-            f,
-            " {table} USING {source} ",
-            table = self.table,
-            source = self.source
-        )?;
+        match &self.table {
+            // MSSQL places the target table hints before its alias:
+            // `MERGE t WITH (HOLDLOCK) AS tgt USING ...`
+            TableFactor::Table {
+                alias: Some(alias),
+                with_hints,
+                ..
+            } if !with_hints.is_empty() => {
+                let mut table = self.table.clone();
+                if let TableFactor::Table { alias, .. } = &mut table {
+                    *alias = None;
+                }
+                write!(f, " {table} {alias}")?;
+            }
+            table => write!(f, " {table}")?,
+        }
+        write!(f, " USING {source} ", source = self.source)?;
         write!(f, "ON {on} ", on = self.on)?;
         write!(f, "{}", display_separated(&self.clauses, " "))?;
         if let Some(ref output) = self.output {
             write!(f, " {output}")?;
         }
+        if !self.options.is_empty() {
+            write!(f, " OPTION ({})", display_comma_separated(&self.options))?;
+        }
         Ok(())
     }
 }
@@ -911,3 +980,111 @@ impl Display for MultiTableInsertType {
         }
     }
 }
+
+/// Databricks `COPY INTO` statement, loading files into a Delta table.
+///
+/// ```sql
+/// COPY INTO target_table [ ( col_name [, ...] ) ]
+///   FROM 'source_location'
+///   FILEFORMAT = data_source
+///   [ VALIDATE [ ALL | num_rows ROWS ] ]
+///   [ FILES = ( 'file_name' [, ...] ) | PATTERN = 'glob_pattern' ]
+///   [ FORMAT_OPTIONS ( key = value [, ...] ) ]
+///   [ COPY_OPTIONS ( key = value [, ...] ) ]
+/// ```
+///
+/// See: <https://docs.databricks.com/aws/en/sql/language-manual/delta-copy-into>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CopyIntoDatabricks {
+    /// The target table.
+    pub into: ObjectName,
+    /// Optional list of target columns.
+    pub into_columns: Option<Vec<Ident>>,
+    /// The source location.
+    pub from: String,
+    /// The source file format, e.g. `CSV` or `PARQUET`.
+    pub file_format: Ident,
+    /// Optional `VALIDATE` clause.
+    pub validate: Option<CopyIntoDatabricksValidate>,
+    /// Optional list of specific file names to load.
+    pub files: Option<Vec<String>>,
+    /// Optional glob pattern selecting the files to load.
+    pub pattern: Option<String>,
+    /// Options passed to the data source reader.
+    pub format_options: Vec<SqlOption>,
+    /// Options controlling the copy operation.
+    pub copy_options: Vec<SqlOption>,
+}
+
+impl Display for CopyIntoDatabricks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "COPY INTO {}", self.into)?;
+        if let Some(columns) = &self.into_columns {
+            write!(f, " ({})", display_comma_separated(columns))?;
+        }
+        write!(
+            f,
+            " FROM '{}' FILEFORMAT = {}",
+            escape_single_quote_string(&self.from),
+            self.file_format
+        )?;
+        if let Some(validate) = &self.validate {
+            write!(f, " {validate}")?;
+        }
+        if let Some(files) = &self.files {
+            write!(
+                f,
+                " FILES = ({})",
+                display_comma_separated(
+                    &files
+                        .iter()
+                        .map(|file| format!("'{}'", escape_single_quote_string(file)))
+                        .collect::<Vec<_>>()
+                )
+            )?;
+        }
+        if let Some(pattern) = &self.pattern {
+            write!(f, " PATTERN = '{}'", escape_single_quote_string(pattern))?;
+        }
+        if !self.format_options.is_empty() {
+            write!(
+                f,
+                " FORMAT_OPTIONS ({})",
+                display_comma_separated(&self.format_options)
+            )?;
+        }
+        if !self.copy_options.is_empty() {
+            write!(
+                f,
+                " COPY_OPTIONS ({})",
+                display_comma_separated(&self.copy_options)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The `VALIDATE` clause of a Databricks [CopyIntoDatabricks] statement.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum CopyIntoDatabricksValidate {
+    /// `VALIDATE`
+    Default,
+    /// `VALIDATE ALL`
+    All,
+    /// `VALIDATE <num_rows> ROWS`
+    Rows(u64),
+}
+
+impl Display for CopyIntoDatabricksValidate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CopyIntoDatabricksValidate::Default => write!(f, "VALIDATE"),
+            CopyIntoDatabricksValidate::All => write!(f, "VALIDATE ALL"),
+            CopyIntoDatabricksValidate::Rows(rows) => write!(f, "VALIDATE {rows} ROWS"),
+        }
+    }
+}
@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 use core::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
 use core::fmt::{self, Debug, Formatter};
 use core::hash::{Hash, Hasher};
@@ -134,3 +136,87 @@ impl From<AttachedToken> for TokenWithSpan {
         value.0
     }
 }
+
+/// A list of [`TokenWithSpan`]s that compares, orders and hashes only the
+/// tokens, ignoring their source locations.
+///
+/// Unlike [`AttachedToken`], the tokens themselves are significant, e.g. in
+/// [`Statement::Unparsed`](crate::ast::Statement::Unparsed).
+///
+/// # Examples
+///
+/// Same tokens, different locations are equal
+/// ```
+/// # use sqlparser::ast::helpers::attached_token::AttachedTokens;
+/// # use sqlparser::tokenizer::{Location, Span, Token, TokenWithSpan};
+/// let tok1 = TokenWithSpan::new(
+///   Token::Comma,
+///   Span::new(Location::new(1, 10), Location::new(1, 11)),
+/// );
+/// let tok2 = TokenWithSpan::new(
+///   Token::Comma,
+///   Span::new(Location::new(2, 20), Location::new(2, 21)),
+/// );
+/// let tok3 = TokenWithSpan::new(
+///   Token::Period,
+///   Span::new(Location::new(1, 10), Location::new(1, 11)),
+/// );
+///
+/// assert_eq!(AttachedTokens(vec![tok1.clone()]), AttachedTokens(vec![tok2]));
+/// assert_ne!(AttachedTokens(vec![tok1]), AttachedTokens(vec![tok3]));
+/// ```
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct AttachedTokens(pub Vec<TokenWithSpan>);
+
+impl Debug for AttachedTokens {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl PartialEq for AttachedTokens {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len()
+            && self.0.iter().zip(&other.0).all(|(a, b)| a.token == b.token)
+    }
+}
+
+impl Eq for AttachedTokens {}
+
+impl PartialOrd for AttachedTokens {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AttachedTokens {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .iter()
+            .map(|t| &t.token)
+            .cmp(other.0.iter().map(|t| &t.token))
+    }
+}
+
+impl Hash for AttachedTokens {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.len().hash(state);
+        for token in &self.0 {
+            token.token.hash(state);
+        }
+    }
+}
+
+impl From<Vec<TokenWithSpan>> for AttachedTokens {
+    fn from(value: Vec<TokenWithSpan>) -> Self {
+        AttachedTokens(value)
+    }
+}
+
+impl From<AttachedTokens> for Vec<TokenWithSpan> {
+    fn from(value: AttachedTokens) -> Self {
+        value.0
+    }
+}
@@ -27,9 +27,9 @@ use sqlparser_derive::{Visit, VisitMut};
 use crate::ast::{
     ClusteredBy, ColumnDef, CommentDef, CreateTable, CreateTableLikeKind, CreateTableOptions,
     DistStyle, Expr, FileFormat, ForValues, HiveDistributionStyle, HiveFormat, Ident,
-    InitializeKind, ObjectName, OnCommit, OneOrManyWithParens, Query, RefreshModeKind,
-    RowAccessPolicy, Statement, StorageLifecyclePolicy, StorageSerializationPolicy,
-    TableConstraint, TableVersion, Tag, WrappedCollection,
+    InitializeKind, ObjectName, ObjectNamePart, OnCommit, OneOrManyWithParens, PeriodDefinition,
+    Query, RefreshModeKind, RowAccessPolicy, SkewedBy, Statement, StorageLifecyclePolicy,
+    StorageSerializationPolicy, TableConstraint, TableVersion, Tag, WrappedCollection,
 };
 
 use crate::parser::ParserError;
@@ -91,6 +91,8 @@ pub struct CreateTableBuilder {
     pub columns: Vec<ColumnDef>,
     /// Table-level constraints.
     pub constraints: Vec<TableConstraint>,
+    /// `PERIOD FOR` definitions.
+    pub periods: Vec<PeriodDefinition>,
     /// Hive distribution style.
     pub hive_distribution: HiveDistributionStyle,
     /// Optional Hive format settings.
@@ -109,12 +111,16 @@ pub struct CreateTableBuilder {
     pub clone: Option<ObjectName>,
     /// Optional table version.
     pub version: Option<TableVersion>,
+    /// Optional `COPY` source object name.
+    pub copy: Option<ObjectName>,
+    /// Optional `WITH CONNECTION` name for external tables.
+    pub with_connection: Option<ObjectName>,
     /// Optional table comment.
     pub comment: Option<CommentDef>,
     /// Optional `ON COMMIT` behavior.
     pub on_commit: Option<OnCommit>,
     /// Optional cluster identifier.
-    pub on_cluster: Option<Ident>,
+    pub on_cluster: Option<ObjectNamePart>,
     /// Optional primary key expression.
     pub primary_key: Option<Box<Expr>>,
     /// Optional `ORDER BY` for clustering/sorting.
@@ -125,6 +131,8 @@ pub struct CreateTableBuilder {
     pub cluster_by: Option<WrappedCollection<Vec<Expr>>>,
     /// Optional `CLUSTERED BY` clause.
     pub clustered_by: Option<ClusteredBy>,
+    /// Optional `SKEWED BY` clause.
+    pub skewed_by: Option<SkewedBy>,
     /// Optional parent tables (`INHERITS`).
     pub inherits: Option<Vec<ObjectName>>,
     /// Optional partitioned table (`PARTITION OF`)
@@ -183,6 +191,8 @@ pub struct CreateTableBuilder {
     pub sortkey: Option<Vec<Expr>>,
     /// Redshift `BACKUP` option.
     pub backup: Option<bool>,
+    /// MariaDB `WITH SYSTEM VERSIONING` clause.
+    pub with_system_versioning: bool,
 }
 
 impl CreateTableBuilder {
@@ -202,6 +212,7 @@ impl CreateTableBuilder {
             name,
             columns: vec![],
             constraints: vec![],
+            periods: vec![],
             hive_distribution: HiveDistributionStyle::NONE,
             hive_formats: None,
             file_format: None,
@@ -211,6 +222,8 @@ impl CreateTableBuilder {
             like: None,
             clone: None,
             version: None,
+            copy: None,
+            with_connection: None,
             comment: None,
             on_commit: None,
             on_cluster: None,
@@ -219,6 +232,7 @@ impl CreateTableBuilder {
             partition_by: None,
             cluster_by: None,
             clustered_by: None,
+            skewed_by: None,
             inherits: None,
             partition_of: None,
             for_values: None,
@@ -248,6 +262,7 @@ impl CreateTableBuilder {
             distkey: None,
             sortkey: None,
             backup: None,
+            with_system_versioning: false,
         }
     }
     /// Set `OR REPLACE` for the CREATE TABLE statement.
@@ -356,6 +371,18 @@ impl CreateTableBuilder {
         self.version = version;
         self
     }
+
+    /// Set the `COPY` source object name.
+    pub fn copy(mut self, copy: Option<ObjectName>) -> Self {
+        self.copy = copy;
+        self
+    }
+
+    /// Set the `WITH CONNECTION` name for external tables.
+    pub fn with_connection(mut self, with_connection: Option<ObjectName>) -> Self {
+        self.with_connection = with_connection;
+        self
+    }
     /// Set a comment for the table or following column definitions.
     pub fn comment_after_column_def(mut self, comment: Option<CommentDef>) -> Self {
         self.comment = comment;
@@ -367,7 +394,7 @@ impl CreateTableBuilder {
         self
     }
     /// Set cluster identifier for the table.
-    pub fn on_cluster(mut self, on_cluster: Option<Ident>) -> Self {
+    pub fn on_cluster(mut self, on_cluster: Option<ObjectNamePart>) -> Self {
         self.on_cluster = on_cluster;
         self
     }
@@ -396,6 +423,11 @@ impl CreateTableBuilder {
         self.clustered_by = clustered_by;
         self
     }
+    /// Set `SKEWED BY` clause.
+    pub fn skewed_by(mut self, skewed_by: Option<SkewedBy>) -> Self {
+        self.skewed_by = skewed_by;
+        self
+    }
     /// Set parent tables via `INHERITS`.
     pub fn inherits(mut self, inherits: Option<Vec<ObjectName>>) -> Self {
         self.inherits = inherits;
@@ -556,6 +588,16 @@ impl CreateTableBuilder {
         self.backup = backup;
         self
     }
+    /// Set the `PERIOD FOR` definitions.
+    pub fn periods(mut self, periods: Vec<PeriodDefinition>) -> Self {
+        self.periods = periods;
+        self
+    }
+    /// Set the MariaDB `WITH SYSTEM VERSIONING` clause.
+    pub fn with_system_versioning(mut self, with_system_versioning: bool) -> Self {
+        self.with_system_versioning = with_system_versioning;
+        self
+    }
     /// Consume the builder and produce a `CreateTable`.
     pub fn build(self) -> CreateTable {
         CreateTable {
@@ -572,6 +614,7 @@ impl CreateTableBuilder {
             name: self.name,
             columns: self.columns,
             constraints: self.constraints,
+            periods: self.periods,
             hive_distribution: self.hive_distribution,
             hive_formats: self.hive_formats,
             file_format: self.file_format,
@@ -581,6 +624,8 @@ impl CreateTableBuilder {
             like: self.like,
             clone: self.clone,
             version: self.version,
+            copy: self.copy,
+            with_connection: self.with_connection,
             comment: self.comment,
             on_commit: self.on_commit,
             on_cluster: self.on_cluster,
@@ -589,6 +634,7 @@ impl CreateTableBuilder {
             partition_by: self.partition_by,
             cluster_by: self.cluster_by,
             clustered_by: self.clustered_by,
+            skewed_by: self.skewed_by,
             inherits: self.inherits,
             partition_of: self.partition_of,
             for_values: self.for_values,
@@ -618,6 +664,7 @@ impl CreateTableBuilder {
             distkey: self.distkey,
             sortkey: self.sortkey,
             backup: self.backup,
+            with_system_versioning: self.with_system_versioning,
         }
     }
 }
@@ -653,6 +700,7 @@ impl From<CreateTable> for CreateTableBuilder {
             name: table.name,
             columns: table.columns,
             constraints: table.constraints,
+            periods: table.periods,
             hive_distribution: table.hive_distribution,
             hive_formats: table.hive_formats,
             file_format: table.file_format,
@@ -662,6 +710,8 @@ impl From<CreateTable> for CreateTableBuilder {
             like: table.like,
             clone: table.clone,
             version: table.version,
+            copy: table.copy,
+            with_connection: table.with_connection,
             comment: table.comment,
             on_commit: table.on_commit,
             on_cluster: table.on_cluster,
@@ -670,6 +720,7 @@ impl From<CreateTable> for CreateTableBuilder {
             partition_by: table.partition_by,
             cluster_by: table.cluster_by,
             clustered_by: table.clustered_by,
+            skewed_by: table.skewed_by,
             inherits: table.inherits,
             partition_of: table.partition_of,
             for_values: table.for_values,
@@ -699,6 +750,7 @@ impl From<CreateTable> for CreateTableBuilder {
             distkey: table.distkey,
             sortkey: table.sortkey,
             backup: table.backup,
+            with_system_versioning: table.with_system_versioning,
         }
     }
 }
@@ -25,7 +25,7 @@ use alloc::{
     vec::Vec,
 };
 use helpers::{
-    attached_token::AttachedToken,
+    attached_token::{AttachedToken, AttachedTokens},
     stmt_data_loading::{FileStagingCommand, StageLoadSelectItemKind},
 };
 
@@ -43,12 +43,14 @@ use serde::{Deserialize, Serialize};
 use sqlparser_derive::{Visit, VisitMut};
 
 use crate::{
-    display_utils::SpaceOrNewline,
-    tokenizer::{Span, Token},
-};
-use crate::{
+    dialect::Dialect,
     display_utils::{Indent, NewLine},
     keywords::Keyword,
+    parser::{Parser, ParserError},
+};
+use crate::{
+    display_utils::SpaceOrNewline,
+    tokenizer::{Span, Token},
 };
 
 pub use self::data_type::{
@@ -60,34 +62,42 @@ pub use self::dcl::{
     SetConfigValue, Use,
 };
 pub use self::ddl::{
-    Alignment, AlterCollation, AlterCollationOperation, AlterColumnOperation, AlterConnectorOwner,
-    AlterFunction, AlterFunctionAction, AlterFunctionKind, AlterFunctionOperation,
-    AlterIndexOperation, AlterOperator, AlterOperatorClass, AlterOperatorClassOperation,
-    AlterOperatorFamily, AlterOperatorFamilyOperation, AlterOperatorOperation, AlterPolicy,
-    AlterPolicyOperation, AlterSchema, AlterSchemaOperation, AlterTable, AlterTableAlgorithm,
-    AlterTableLock, AlterTableOperation, AlterTableType, AlterType, AlterTypeAddValue,
-    AlterTypeAddValuePosition, AlterTypeOperation, AlterTypeRename, AlterTypeRenameValue,
-    ClusteredBy, ColumnDef, ColumnOption, ColumnOptionDef, ColumnOptions, ColumnPolicy,
-    ColumnPolicyProperty, ConstraintCharacteristics, CreateCollation, CreateCollationDefinition,
-    CreateConnector, CreateDomain, CreateExtension, CreateFunction, CreateIndex, CreateOperator,
-    CreateOperatorClass, CreateOperatorFamily, CreatePolicy, CreatePolicyCommand, CreatePolicyType,
-    CreateTable, CreateTrigger, CreateView, Deduplicate, DeferrableInitial, DistStyle,
-    DropBehavior, DropExtension, DropFunction, DropOperator, DropOperatorClass, DropOperatorFamily,
-    DropOperatorSignature, DropPolicy, DropTrigger, ForValues, FunctionReturnType, GeneratedAs,
-    GeneratedExpressionMode, IdentityParameters, IdentityProperty, IdentityPropertyFormatKind,
-    IdentityPropertyKind, IdentityPropertyOrder, IndexColumn, IndexOption, IndexType,
-    KeyOrIndexDisplay, Msck, NullsDistinctOption, OperatorArgTypes, OperatorClassItem,
-    OperatorFamilyDropItem, OperatorFamilyItem, OperatorOption, OperatorPurpose, Owner, Partition,
-    PartitionBoundValue, ProcedureParam, ReferentialAction, RenameTableNameKind, ReplicaIdentity,
-    TagsColumnOption, TriggerObjectKind, Truncate, UserDefinedTypeCompositeAttributeDef,
-    UserDefinedTypeInternalLength, UserDefinedTypeRangeOption, UserDefinedTypeRepresentation,
-    UserDefinedTypeSqlDefinitionOption, UserDefinedTypeStorage, ViewColumnDef,
+    Alignment, AlterCatalog, AlterCatalogOperation, AlterCollation, AlterCollationOperation,
+    AlterColumnOperation, AlterConnectorOwner, AlterEvent, AlterFunction, AlterFunctionAction,
+    AlterFunctionKind, AlterFunctionOperation, AlterIndexOperation, AlterIntegrationOperation,
+    AlterOperator, AlterOperatorClass, AlterOperatorClassOperation, AlterOperatorFamily,
+    AlterOperatorFamilyOperation, AlterOperatorOperation, AlterPolicy, AlterPolicyOperation,
+    AlterSchema, AlterSchemaOperation, AlterStatistics, AlterStatisticsOperation, AlterTable,
+    AlterTableAlgorithm, AlterTableLock, AlterTableOperation, AlterTableType,
+    AlterTextSearchConfiguration, AlterTextSearchConfigurationOperation, AlterType,
+    AlterTypeAddValue, AlterTypeAddValuePosition, AlterTypeOperation, AlterTypeRename,
+    AlterTypeRenameValue, AlterViewOperation, ClusteredBy, ColumnDef, ColumnOption,
+    ColumnOptionDef, ColumnOptions, ColumnPolicy, ColumnPolicyProperty, ColumnStorage,
+    ConstraintCharacteristics, CreateCatalog, CreateCollation, CreateCollationDefinition,
+    CreateConnector, CreateConversion, CreateDomain, CreateEvent, CreateExtension, CreateFunction,
+    CreateIndex, CreateLanguage, CreateOperator, CreateOperatorClass, CreateOperatorFamily,
+    CreatePolicy, CreatePolicyCommand, CreatePolicyType, CreateProcedureBody, CreateStatistics,
+    CreateSynonym, CreateTable, CreateTableFunction, CreateTextSearch, CreateTransform,
+    CreateTrigger, CreateView, Deduplicate, DeferrableInitial, DistStyle, DropBehavior,
+    DropConversion, DropExtension, DropFunction, DropLanguage, DropOperator, DropOperatorClass,
+    DropOperatorFamily, DropOperatorSignature, DropPolicy, DropTransform, DropTrigger,
+    EventOnCompletion, EventSchedule, EventStatus, ForValues, FunctionReturnType, GeneratedAs,
+    GeneratedExpressionMode, GeneratedRowBoundary, IdentityParameters, IdentityProperty,
+    IdentityPropertyFormatKind, IdentityPropertyKind, IdentityPropertyOrder, IndexColumn,
+    IndexOption, IndexType, KeyOrIndexDisplay, Msck, NullsDistinctOption, OperatorArgTypes,
+    OperatorClassItem, OperatorFamilyDropItem, OperatorFamilyItem, OperatorOption, OperatorPurpose,
+    Owner, Partition, PartitionBoundValue, PeriodDefinition, ProcedureExecuteAs, ProcedureParam,
+    ReferentialAction, RenameTableNameKind, ReplicaIdentity, SkewedBy, TagsColumnOption,
+    TextSearchObjectType, TriggerObjectKind, Truncate, TtlAction, TtlClause,
+    UserDefinedTypeCompositeAttributeDef, UserDefinedTypeInternalLength,
+    UserDefinedTypeRangeOption, UserDefinedTypeRepresentation, UserDefinedTypeSqlDefinitionOption,
+    UserDefinedTypeStorage, ViewColumnDef,
 };
 pub use self::dml::{
-    Delete, Insert, Merge, MergeAction, MergeClause, MergeClauseKind, MergeInsertExpr,
-    MergeInsertKind, MergeUpdateExpr, MultiTableInsertIntoClause, MultiTableInsertType,
-    MultiTableInsertValue, MultiTableInsertValues, MultiTableInsertWhenClause, OutputClause,
-    Update,
+    CopyIntoDatabricks, CopyIntoDatabricksValidate, Delete, Insert, Merge, MergeAction,
+    MergeClause, MergeClauseKind, MergeInsertExpr, MergeInsertKind, MergeUpdateExpr,
+    MultiTableInsertIntoClause, MultiTableInsertType, MultiTableInsertValue,
+    MultiTableInsertValues, MultiTableInsertWhenClause, OutputClause, Update,
 };
 pub use self::operator::{BinaryOperator, UnaryOperator};
 pub use self::query::{
@@ -95,21 +105,21 @@ pub use self::query::{
     ExceptSelectItem, ExcludeSelectItem, ExprWithAlias, ExprWithAliasAndOrderBy, Fetch, ForClause,
     ForJson, ForXml, FormatClause, GroupByExpr, GroupByWithModifier, IdentWithAlias,
     IlikeSelectItem, InputFormatClause, Interpolate, InterpolateExpr, Join, JoinConstraint,
-    JoinOperator, JsonTableColumn, JsonTableColumnErrorHandling, JsonTableNamedColumn,
-    JsonTableNestedColumn, LateralView, LimitClause, LockClause, LockType, MatchRecognizePattern,
-    MatchRecognizeSymbol, Measure, NamedWindowDefinition, NamedWindowExpr, NonBlock, Offset,
-    OffsetRows, OpenJsonTableColumn, OrderBy, OrderByExpr, OrderByKind, OrderByOptions,
-    PipeOperator, PivotValueSource, ProjectionSelect, Query, RenameSelectItem,
-    RepetitionQuantifier, ReplaceSelectElement, ReplaceSelectItem, RowsPerMatch, Select,
-    SelectFlavor, SelectInto, SelectItem, SelectItemQualifiedWildcardKind, SelectModifiers,
-    SetExpr, SetOperator, SetQuantifier, Setting, SymbolDefinition, Table, TableAlias,
-    TableAliasColumnDef, TableFactor, TableFunctionArgs, TableIndexHintForClause,
-    TableIndexHintType, TableIndexHints, TableIndexType, TableSample, TableSampleBucket,
-    TableSampleKind, TableSampleMethod, TableSampleModifier, TableSampleQuantity, TableSampleSeed,
-    TableSampleSeedModifier, TableSampleUnit, TableVersion, TableWithJoins, Top, TopQuantity,
-    UpdateTableFromKind, ValueTableMode, Values, WildcardAdditionalOptions, With, WithFill,
-    XmlNamespaceDefinition, XmlPassingArgument, XmlPassingClause, XmlTableColumn,
-    XmlTableColumnOption,
+    JoinOperator, JoinStrictness, JsonTableColumn, JsonTableColumnErrorHandling,
+    JsonTableNamedColumn, JsonTableNestedColumn, LateralView, LimitBy, LimitClause, LockClause,
+    LockType, MatchRecognizePattern, MatchRecognizeSymbol, Measure, MeasureSemantics,
+    NamedWindowDefinition, NamedWindowExpr, NonBlock, Offset, OffsetRows, OpenJsonTableColumn,
+    OptimizeForVariable, OrderBy, OrderByExpr, OrderByKind, OrderByOptions, PipeOperator,
+    PivotValueSource, ProjectionSelect, Query, QueryHint, RenameSelectItem, RepetitionQuantifier,
+    ReplaceSelectElement, ReplaceSelectItem, RowsPerMatch, Select, SelectFlavor, SelectInto,
+    SelectItem, SelectItemQualifiedWildcardKind, SelectModifiers, SetExpr, SetOperator,
+    SetQuantifier, Setting, SymbolDefinition, Table, TableAlias, TableAliasColumnDef, TableFactor,
+    TableFunctionArgs, TableIndexHintForClause, TableIndexHintType, TableIndexHints,
+    TableIndexType, TableSample, TableSampleBucket, TableSampleKind, TableSampleMethod,
+    TableSampleModifier, TableSampleQuantity, TableSampleSeed, TableSampleSeedModifier,
+    TableSampleUnit, TableVersion, TableWithJoins, Top, TopQuantity, UpdateTableFromKind,
+    ValueTableMode, Values, WildcardAdditionalOptions, With, WithFill, XmlNamespaceDefinition,
+    XmlPassingArgument, XmlPassingClause, XmlTableColumn, XmlTableColumnOption,
 };
 
 pub use self::trigger::{
@@ -138,8 +148,9 @@ mod dml;
 pub mod helpers;
 pub mod table_constraints;
 pub use table_constraints::{
-    CheckConstraint, ConstraintUsingIndex, ForeignKeyConstraint, FullTextOrSpatialConstraint,
-    IndexConstraint, PrimaryKeyConstraint, TableConstraint, UniqueConstraint,
+    CheckConstraint, ConstraintUsingIndex, ExcludeElement, ExclusionConstraint,
+    ForeignKeyConstraint, FullTextOrSpatialConstraint, IndexConstraint, PrimaryKeyConstraint,
+    TableConstraint, UniqueConstraint,
 };
 mod operator;
 mod query;
@@ -362,6 +373,28 @@ impl Ident {
             span,
         }
     }
+
+    /// Parse a single, possibly quoted, identifier using the quoting rules of
+    /// the given dialect.
+    ///
+    /// Keywords are accepted as identifiers. The whole input must be consumed,
+    /// so trailing tokens (including a `.` and further parts) are an error.
+    ///
+    /// Example
+    /// ```
+    /// # use sqlparser::{ast::Ident, dialect::MySqlDialect, parser::ParserError};
+    /// # fn main() -> Result<(), ParserError> {
+    /// let ident = Ident::parse(&MySqlDialect {}, "`my table`")?;
+    /// assert_eq!(ident, Ident::with_quote('`', "my table"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse(dialect: &dyn Dialect, ident: &str) -> Result<Self, ParserError> {
+        let mut parser = Parser::new(dialect).try_with_sql(ident)?;
+        let ident = parser.parse_identifier()?;
+        parser.expect_token(&Token::EOF)?;
+        Ok(ident)
+    }
 }
 
 impl From<&str> for Ident {
@@ -406,6 +439,38 @@ impl From<Ident> for ObjectName {
     }
 }
 
+impl ObjectName {
+    /// Parse a possibly qualified, possibly quoted object name such as
+    /// `"db"."schema"."table"`, `[db].[t]` or `` `db`.`t` `` using the quoting
+    /// rules of the given dialect.
+    ///
+    /// Keywords are accepted as name parts. The whole input must be consumed,
+    /// so trailing tokens are an error.
+    ///
+    /// Example
+    /// ```
+    /// # use sqlparser::{ast::{Ident, ObjectName}, dialect::PostgreSqlDialect, parser::ParserError};
+    /// # fn main() -> Result<(), ParserError> {
+    /// let name = ObjectName::parse(&PostgreSqlDialect {}, r#"db."my.schema".t"#)?;
+    /// assert_eq!(
+    ///     name,
+    ///     ObjectName::from(vec![
+    ///         Ident::new("db"),
+    ///         Ident::with_quote('"', "my.schema"),
+    ///         Ident::new("t"),
+    ///     ])
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse(dialect: &dyn Dialect, name: &str) -> Result<Self, ParserError> {
+        let mut parser = Parser::new(dialect).try_with_sql(name)?;
+        let name = parser.parse_object_name(false)?;
+        parser.expect_token(&Token::EOF)?;
+        Ok(name)
+    }
+}
+
 impl fmt::Display for ObjectName {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", display_separated(&self.0, "."))
@@ -421,6 +486,8 @@ pub enum ObjectNamePart {
     Identifier(Ident),
     /// A function that returns an identifier (dialect-specific).
     Function(ObjectNamePartFunction),
+    /// A name containing macros substituted by the server (ClickHouse).
+    Macro(ObjectNamePartMacro),
 }
 
 impl ObjectNamePart {
@@ -428,7 +495,7 @@ impl ObjectNamePart {
     pub fn as_ident(&self) -> Option<&Ident> {
         match self {
             ObjectNamePart::Identifier(ident) => Some(ident),
-            ObjectNamePart::Function(_) => None,
+            ObjectNamePart::Function(_) | ObjectNamePart::Macro(_) => None,
         }
     }
 }
@@ -438,6 +505,7 @@ impl fmt::Display for ObjectNamePart {
         match self {
             ObjectNamePart::Identifier(ident) => write!(f, "{ident}"),
             ObjectNamePart::Function(func) => write!(f, "{func}"),
+            ObjectNamePart::Macro(name) => write!(f, "{name}"),
         }
     }
 }
@@ -463,6 +531,48 @@ impl fmt::Display for ObjectNamePartFunction {
     }
 }
 
+/// An object name part made of text and `{macro}` references, which the
+/// server replaces with values from its configuration, e.g. `{cluster}` or
+/// `db_{shard}`.
+///
+/// - [ClickHouse](https://clickhouse.com/docs/en/operations/server-configuration-parameters/settings#macros)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct ObjectNamePartMacro {
+    /// The segments of the name, in order.
+    pub segments: Vec<MacroSegment>,
+}
+
+impl fmt::Display for ObjectNamePartMacro {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for segment in &self.segments {
+            write!(f, "{segment}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A segment of an [`ObjectNamePartMacro`].
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum MacroSegment {
+    /// Literal text, e.g. `db_` in `db_{shard}`.
+    Text(Ident),
+    /// A macro reference, e.g. `{shard}`.
+    Macro(Ident),
+}
+
+impl fmt::Display for MacroSegment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MacroSegment::Text(text) => write!(f, "{text}"),
+            MacroSegment::Macro(name) => write!(f, "{{{name}}}"),
+        }
+    }
+}
+
 /// Represents an Array Expression, either
 /// `ARRAY[..]`, or `[..]`
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
@@ -937,7 +1047,16 @@ pub enum Expr {
         /// `true` when `NOT` is present.
         negated: bool,
     },
-    /// `[ NOT ] IN (val1, val2, ...)`
+    /// `NORMALIZE(<expr> [, <form>])`
+    ///
+    /// See <https://www.postgresql.org/docs/current/functions-string.html>
+    Normalize {
+        /// Expression being normalized.
+        expr: Box<Expr>,
+        /// Optional normalization `form` (e.g., NFC, NFKC).
+        form: Option<NormalizationForm>,
+    },
+    /// `[ GLOBAL ] [ NOT ] IN (val1, val2, ...)`
     InList {
         /// Left-hand expression to test for membership.
         expr: Box<Expr>,
@@ -945,8 +1064,10 @@ pub enum Expr {
         list: Vec<Expr>,
         /// `true` when the `NOT` modifier is present.
         negated: bool,
+        /// `true` for ClickHouse's distributed `GLOBAL [NOT] IN`.
+        global: bool,
     },
-    /// `[ NOT ] IN (SELECT ...)`
+    /// `[ GLOBAL ] [ NOT ] IN (SELECT ...)`
     InSubquery {
         /// Left-hand expression to test for membership.
         expr: Box<Expr>,
@@ -954,6 +1075,8 @@ pub enum Expr {
         subquery: Box<Query>,
         /// `true` when the `NOT` modifier is present.
         negated: bool,
+        /// `true` for ClickHouse's distributed `GLOBAL [NOT] IN`.
+        global: bool,
     },
     /// `[ NOT ] IN UNNEST(array_expression)`
     InUnnest {
@@ -1100,6 +1223,10 @@ pub enum Expr {
         ///
         /// [BigQuery]: https://cloud.google.com/bigquery/docs/reference/standard-sql/format-elements#formatting_syntax
         format: Option<CastFormat>,
+        /// Optional `CHARACTER SET` of the target type, e.g. `CAST(x AS CHAR CHARACTER SET utf8mb4)` in [MySQL]
+        ///
+        /// [MySQL]: https://dev.mysql.com/doc/refman/8.4/en/cast-functions.html#function_cast
+        charset: Option<ObjectName>,
     },
     /// AT a timestamp to a different timezone e.g. `FROM_UNIXTIME(0) AT TIME ZONE 'UTC-06:00'`
     AtTimeZone {
@@ -1341,6 +1468,10 @@ pub enum Expr {
     /// Qualified wildcard, e.g. `alias.*` or `schema.table.*`.
     /// (Same caveats apply to `QualifiedWildcard` as to `Wildcard`.)
     QualifiedWildcard(ObjectName, AttachedToken),
+    /// The `DEFAULT` keyword used as a value in a `VALUES` list or an
+    /// `UPDATE` or `SET` assignment, e.g. `INSERT INTO t VALUES (DEFAULT)`
+    /// or `UPDATE t SET a = DEFAULT`.
+    Default,
     /// Some dialects support an older syntax for outer joins where columns are
     /// marked with the `(+)` operator in the WHERE clause, for example:
     ///
@@ -1721,6 +1852,7 @@ impl fmt::Display for Expr {
             Expr::Identifier(s) => write!(f, "{s}"),
             Expr::Wildcard(_) => f.write_str("*"),
             Expr::QualifiedWildcard(prefix, _) => write!(f, "{prefix}.*"),
+            Expr::Default => f.write_str("DEFAULT"),
             Expr::CompoundIdentifier(s) => write!(f, "{}", display_separated(s, ".")),
             Expr::CompoundFieldAccess { root, access_chain } => {
                 write!(f, "{root}")?;
@@ -1741,10 +1873,12 @@ impl fmt::Display for Expr {
                 expr,
                 list,
                 negated,
+                global,
             } => write!(
                 f,
-                "{} {}IN ({})",
+                "{} {}{}IN ({})",
                 expr,
+                if *global { "GLOBAL " } else { "" },
                 if *negated { "NOT " } else { "" },
                 display_comma_separated(list)
             ),
@@ -1752,10 +1886,12 @@ impl fmt::Display for Expr {
                 expr,
                 subquery,
                 negated,
+                global,
             } => write!(
                 f,
-                "{} {}IN ({})",
+                "{} {}{}IN ({})",
                 expr,
+                if *global { "GLOBAL " } else { "" },
                 if *negated { "NOT " } else { "" },
                 subquery
             ),
@@ -1847,6 +1983,13 @@ impl fmt::Display for Expr {
                 if *regexp { "REGEXP" } else { "RLIKE" },
                 pattern
             ),
+            Expr::Normalize { expr, form } => {
+                write!(f, "NORMALIZE({expr}")?;
+                if let Some(form) = form {
+                    write!(f, ", {form}")?;
+                }
+                write!(f, ")")
+            }
             Expr::IsNormalized {
                 expr,
                 form,
@@ -1965,35 +2108,26 @@ impl fmt::Display for Expr {
                 data_type,
                 array,
                 format,
-            } => match kind {
-                CastKind::Cast => {
-                    write!(f, "CAST({expr} AS {data_type}")?;
-                    if *array {
-                        write!(f, " ARRAY")?;
-                    }
-                    if let Some(format) = format {
-                        write!(f, " FORMAT {format}")?;
-                    }
-                    write!(f, ")")
-                }
-                CastKind::TryCast => {
-                    if let Some(format) = format {
-                        write!(f, "TRY_CAST({expr} AS {data_type} FORMAT {format})")
-                    } else {
-                        write!(f, "TRY_CAST({expr} AS {data_type})")
-                    }
+                charset,
+            } => {
+                let name = match kind {
+                    CastKind::Cast => "CAST",
+                    CastKind::TryCast => "TRY_CAST",
+                    CastKind::SafeCast => "SAFE_CAST",
+                    CastKind::DoubleColon => return write!(f, "{expr}::{data_type}"),
+                };
+                write!(f, "{name}({expr} AS {data_type}")?;
+                if let Some(charset) = charset {
+                    write!(f, " CHARACTER SET {charset}")?;
                 }
-                CastKind::SafeCast => {
-                    if let Some(format) = format {
-                        write!(f, "SAFE_CAST({expr} AS {data_type} FORMAT {format})")
-                    } else {
-                        write!(f, "SAFE_CAST({expr} AS {data_type})")
-                    }
+                if *array {
+                    write!(f, " ARRAY")?;
                 }
-                CastKind::DoubleColon => {
-                    write!(f, "{expr}::{data_type}")
+                if let Some(format) = format {
+                    write!(f, " FORMAT {format}")?;
                 }
-            },
+                write!(f, ")")
+            }
             Expr::Extract {
                 field,
                 syntax,
@@ -2457,16 +2591,22 @@ impl fmt::Display for AddDropSync {
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 /// Object kinds supported by `SHOW CREATE` statements.
 pub enum ShowCreateObject {
+    /// A database object for `SHOW CREATE DATABASE`.
+    Database,
     /// An event object for `SHOW CREATE EVENT`.
     Event,
     /// A function object for `SHOW CREATE FUNCTION`.
     Function,
     /// A procedure object for `SHOW CREATE PROCEDURE`.
     Procedure,
+    /// A schema object for `SHOW CREATE SCHEMA`.
+    Schema,
     /// A table object for `SHOW CREATE TABLE`.
     Table,
     /// A trigger object for `SHOW CREATE TRIGGER`.
     Trigger,
+    /// A user object for `SHOW CREATE USER`.
+    User,
     /// A view object for `SHOW CREATE VIEW`.
     View,
 }
@@ -2474,11 +2614,14 @@ pub enum ShowCreateObject {
 impl fmt::Display for ShowCreateObject {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            ShowCreateObject::Database => f.write_str("DATABASE"),
             ShowCreateObject::Event => f.write_str("EVENT"),
             ShowCreateObject::Function => f.write_str("FUNCTION"),
             ShowCreateObject::Procedure => f.write_str("PROCEDURE"),
+            ShowCreateObject::Schema => f.write_str("SCHEMA"),
             ShowCreateObject::Table => f.write_str("TABLE"),
             ShowCreateObject::Trigger => f.write_str("TRIGGER"),
+            ShowCreateObject::User => f.write_str("USER"),
             ShowCreateObject::View => f.write_str("VIEW"),
         }
     }
@@ -2866,6 +3009,134 @@ impl fmt::Display for BeginEndStatements {
     }
 }
 
+/// A `FOR ... IN ... DO ... END FOR` loop over the rows of a query.
+///
+/// ```sql
+/// FOR rec IN (SELECT * FROM t) DO
+///   INSERT INTO log VALUES (rec.id);
+/// END FOR;
+/// ```
+///
+/// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/procedural-language#for-in)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct ForStatement {
+    /// Loop variable bound to each row of the query.
+    pub variable: Ident,
+    /// Query producing the rows to iterate over.
+    pub query: Box<Query>,
+    /// Statements executed for each row.
+    pub body: Vec<Statement>,
+}
+
+impl fmt::Display for ForStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ForStatement {
+            variable,
+            query,
+            body,
+        } = self;
+
+        write!(f, "FOR {variable} IN ({query}) DO")?;
+        if !body.is_empty() {
+            write!(f, " ")?;
+            format_statement_list(f, body)?;
+        }
+        write!(f, " END FOR")
+    }
+}
+
+/// A `REPEAT ... UNTIL ... END REPEAT` loop.
+///
+/// ```sql
+/// REPEAT
+///   SET x = x + 1;
+/// UNTIL x >= 10 END REPEAT;
+/// ```
+///
+/// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/procedural-language#repeat)
+/// [Snowflake](https://docs.snowflake.com/en/sql-reference/snowflake-scripting/repeat)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct RepeatStatement {
+    /// Statements executed on each iteration.
+    pub body: Vec<Statement>,
+    /// Condition that ends the loop once it evaluates to true.
+    pub until: Expr,
+}
+
+impl fmt::Display for RepeatStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let RepeatStatement { body, until } = self;
+
+        write!(f, "REPEAT")?;
+        if !body.is_empty() {
+            write!(f, " ")?;
+            format_statement_list(f, body)?;
+        }
+        write!(f, " UNTIL {until} END REPEAT")
+    }
+}
+
+/// The keyword used by a [LoopControlStatement].
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum LoopControlKind {
+    /// `BREAK`
+    Break,
+    /// `LEAVE`, a synonym for `BREAK`
+    Leave,
+    /// `CONTINUE`
+    Continue,
+    /// `ITERATE`, a synonym for `CONTINUE`
+    Iterate,
+}
+
+impl fmt::Display for LoopControlKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LoopControlKind::Break => "BREAK",
+            LoopControlKind::Leave => "LEAVE",
+            LoopControlKind::Continue => "CONTINUE",
+            LoopControlKind::Iterate => "ITERATE",
+        })
+    }
+}
+
+/// A statement that exits or restarts the enclosing loop.
+///
+/// ```sql
+/// BREAK;
+/// CONTINUE outer_loop;
+/// ```
+///
+/// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/procedural-language#break)
+/// [Snowflake](https://docs.snowflake.com/en/sql-reference/snowflake-scripting/break)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct LoopControlStatement {
+    /// The keyword used.
+    pub kind: LoopControlKind,
+    /// Optional label of the loop to exit or restart.
+    pub label: Option<Ident>,
+}
+
+impl fmt::Display for LoopControlStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let LoopControlStatement { kind, label } = self;
+
+        write!(f, "{kind}")?;
+        if let Some(label) = label {
+            write!(f, " {label}")?;
+        }
+        Ok(())
+    }
+}
+
 /// A `RAISE` statement.
 ///
 /// Examples:
@@ -3253,6 +3524,8 @@ pub enum Set {
         hivevar: bool,
         /// Variable name to assign.
         variable: ObjectName,
+        /// `true` when the values were introduced with `TO` rather than `=`.
+        uses_to: bool,
         /// Values assigned to the variable.
         values: Vec<Expr>,
     },
@@ -3332,6 +3605,17 @@ pub enum Set {
     /// Note: this is a MySQL-specific statement.
     SetNamesDefault {},
     /// ```sql
+    /// SET CHARACTER SET charset_name
+    /// ```
+    ///
+    /// Note: this is a MySQL-specific statement.
+    ///
+    /// See <https://dev.mysql.com/doc/refman/8.4/en/set-character-set.html>
+    SetCharacterSet {
+        /// Character set name to set, or `DEFAULT`.
+        charset_name: Ident,
+    },
+    /// ```sql
     /// SET TRANSACTION ...
     /// ```
     SetTransaction {
@@ -3412,18 +3696,23 @@ impl Display for Set {
 
                 Ok(())
             }
+            Self::SetCharacterSet { charset_name } => {
+                write!(f, "SET CHARACTER SET {charset_name}")
+            }
             Set::SingleAssignment {
                 scope,
                 hivevar,
                 variable,
+                uses_to,
                 values,
             } => {
                 write!(
                     f,
-                    "SET {}{}{} = {}",
+                    "SET {}{}{} {} {}",
                     scope.map(|s| format!("{s}")).unwrap_or_default(),
                     if *hivevar { "HIVEVAR:" } else { "" },
                     variable,
+                    if *uses_to { "TO" } else { "=" },
                     display_comma_separated(values)
                 )
             }
@@ -3546,6 +3835,22 @@ pub enum Statement {
     /// `SET` statements (session, transaction, timezone, etc.).
     Set(Set),
     /// ```sql
+    /// SET NOCOUNT ON
+    /// SET ANSI_NULLS, QUOTED_IDENTIFIER OFF
+    /// SET IDENTITY_INSERT dbo.t ON
+    /// ```
+    /// Session option toggle (MSSQL)
+    ///
+    /// See <https://learn.microsoft.com/en-us/sql/t-sql/statements/set-statements-transact-sql>
+    SetSessionOption {
+        /// Names of the options being set.
+        names: Vec<Ident>,
+        /// Table targeted by `IDENTITY_INSERT`.
+        target_table: Option<ObjectName>,
+        /// Whether the options are turned `ON` or `OFF`.
+        value: SessionParamValue,
+    },
+    /// ```sql
     /// TRUNCATE
     /// ```
     /// Truncate (Hive)
@@ -3597,6 +3902,12 @@ pub enum Statement {
     If(IfStatement),
     /// A `WHILE` statement.
     While(WhileStatement),
+    /// A `FOR ... IN ... DO ... END FOR` statement.
+    For(ForStatement),
+    /// A `REPEAT ... UNTIL ... END REPEAT` statement.
+    Repeat(RepeatStatement),
+    /// A `BREAK`, `LEAVE`, `CONTINUE` or `ITERATE` statement.
+    LoopControl(LoopControlStatement),
     /// A `RAISE` statement.
     Raise(RaiseStatement),
     /// ```sql
@@ -3662,6 +3973,11 @@ pub enum Statement {
         partition: Option<Box<Expr>>,
     },
     /// ```sql
+    /// COPY INTO <table> FROM '<location>' FILEFORMAT = <format> ...
+    /// ```
+    /// See [Databricks](https://docs.databricks.com/aws/en/sql/language-manual/delta-copy-into)
+    CopyIntoDatabricks(CopyIntoDatabricks),
+    /// ```sql
     /// OPEN cursor_name
     /// ```
     /// Opens a cursor.
@@ -3702,8 +4018,9 @@ pub enum Statement {
         if_not_exists: bool,
         /// Module name used by the virtual table.
         module_name: Ident,
-        /// Arguments passed to the module.
-        module_args: Vec<Ident>,
+        /// Raw text of each argument passed to the module, or `None` if the
+        /// module name was not followed by a parenthesized argument list.
+        module_args: Option<Vec<String>>,
     },
     /// ```sql
     /// `CREATE INDEX`
@@ -3736,6 +4053,14 @@ pub enum Statement {
     },
     /// A `CREATE SERVER` statement.
     CreateServer(CreateServerStatement),
+    /// An `ALTER SERVER` statement.
+    AlterServer(AlterServer),
+    /// A `CREATE USER MAPPING` statement.
+    CreateUserMapping(CreateUserMapping),
+    /// An `ALTER USER MAPPING` statement.
+    AlterUserMapping(AlterUserMapping),
+    /// A `DROP USER MAPPING` statement.
+    DropUserMapping(DropUserMapping),
     /// ```sql
     /// CREATE POLICY
     /// ```
@@ -3780,18 +4105,29 @@ pub enum Statement {
         operation: AlterIndexOperation,
     },
     /// ```sql
-    /// ALTER VIEW
+    /// ALTER VIEW [ IF EXISTS ] <name> <operation>
     /// ```
     AlterView {
+        /// Whether `IF EXISTS` was specified.
+        if_exists: bool,
         /// View name being altered.
         #[cfg_attr(feature = "visitor", visit(with = "visit_relation"))]
         name: ObjectName,
-        /// Optional new column list for the view.
-        columns: Vec<Ident>,
-        /// Replacement query for the view definition.
-        query: Box<Query>,
-        /// Additional WITH options for the view.
-        with_options: Vec<SqlOption>,
+        /// The operation to perform on the view.
+        operation: AlterViewOperation,
+    },
+    /// ```sql
+    /// ALTER MATERIALIZED VIEW [ IF EXISTS ] <name> <operation> [, ...]
+    /// ```
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-altermaterializedview.html)
+    AlterMaterializedView {
+        /// Whether `IF EXISTS` was specified.
+        if_exists: bool,
+        /// Materialized view name being altered.
+        #[cfg_attr(feature = "visitor", visit(with = "visit_relation"))]
+        name: ObjectName,
+        /// The operations to perform on the materialized view.
+        operations: Vec<AlterViewOperation>,
     },
     /// ```sql
     /// ALTER FUNCTION
@@ -4019,6 +4355,83 @@ pub enum Statement {
     /// <https://www.postgresql.org/docs/current/sql-dropextension.html>
     DropExtension(DropExtension),
     /// ```sql
+    /// CREATE [ OR REPLACE ] [ TRUSTED ] [ PROCEDURAL ] LANGUAGE name [ HANDLER ... ]
+    /// ```
+    /// Note: this is a PostgreSQL-specific statement.
+    /// <https://www.postgresql.org/docs/current/sql-createlanguage.html>
+    CreateLanguage(CreateLanguage),
+    /// ```sql
+    /// DROP [ PROCEDURAL ] LANGUAGE [ IF EXISTS ] name [ CASCADE | RESTRICT ]
+    /// ```
+    /// Note: this is a PostgreSQL-specific statement.
+    /// <https://www.postgresql.org/docs/current/sql-droplanguage.html>
+    DropLanguage(DropLanguage),
+    /// ```sql
+    /// CREATE [ OR REPLACE ] TRANSFORM FOR type_name LANGUAGE lang_name ( ... )
+    /// ```
+    /// Note: this is a PostgreSQL-specific statement.
+    /// <https://www.postgresql.org/docs/current/sql-createtransform.html>
+    CreateTransform(CreateTransform),
+    /// ```sql
+    /// DROP TRANSFORM [ IF EXISTS ] FOR type_name LANGUAGE lang_name [ CASCADE | RESTRICT ]
+    /// ```
+    /// Note: this is a PostgreSQL-specific statement.
+    /// <https://www.postgresql.org/docs/current/sql-droptransform.html>
+    DropTransform(DropTransform),
+    /// ```sql
+    /// CREATE [ DEFAULT ] CONVERSION name FOR source_encoding TO dest_encoding FROM function_name
+    /// ```
+    /// Note: this is a PostgreSQL-specific statement.
+    /// <https://www.postgresql.org/docs/current/sql-createconversion.html>
+    CreateConversion(CreateConversion),
+    /// ```sql
+    /// DROP CONVERSION [ IF EXISTS ] name [ CASCADE | RESTRICT ]
+    /// ```
+    /// Note: this is a PostgreSQL-specific statement.
+    /// <https://www.postgresql.org/docs/current/sql-dropconversion.html>
+    DropConversion(DropConversion),
+    /// ```sql
+    /// CREATE TEXT SEARCH { CONFIGURATION | DICTIONARY | PARSER | TEMPLATE } name ( ... )
+    /// ```
+    /// Note: this is a PostgreSQL-specific statement.
+    /// <https://www.postgresql.org/docs/current/textsearch-configuration.html>
+    CreateTextSearch(CreateTextSearch),
+    /// ```sql
+    /// ALTER TEXT SEARCH CONFIGURATION name { ADD | ALTER | DROP } MAPPING ...
+    /// ```
+    /// Note: this is a PostgreSQL-specific statement.
+    /// <https://www.postgresql.org/docs/current/sql-altertsconfig.html>
+    AlterTextSearchConfiguration(AlterTextSearchConfiguration),
+    /// ```sql
+    /// CREATE STATISTICS [ IF NOT EXISTS ] name [ ( kind [, ...] ) ] ON ... FROM table_name
+    /// ```
+    /// Note: this is a PostgreSQL-specific statement.
+    /// <https://www.postgresql.org/docs/current/sql-createstatistics.html>
+    CreateStatistics(CreateStatistics),
+    /// ```sql
+    /// ALTER STATISTICS name { RENAME TO | OWNER TO | SET SCHEMA | SET STATISTICS } ...
+    /// ```
+    /// Note: this is a PostgreSQL-specific statement.
+    /// <https://www.postgresql.org/docs/current/sql-alterstatistics.html>
+    AlterStatistics(AlterStatistics),
+    /// ```sql
+    /// CREATE CATALOG [ IF NOT EXISTS ] name [ MANAGED LOCATION 'path' ] [ COMMENT 'comment' ]
+    /// ```
+    /// Note: this is a Databricks-specific statement.
+    /// <https://docs.databricks.com/aws/en/sql/language-manual/sql-ref-syntax-ddl-create-catalog>
+    CreateCatalog(CreateCatalog),
+    /// ```sql
+    /// CREATE [ OR REPLACE ] { SYNONYM | ALIAS } name FOR object_name
+    /// ```
+    /// See [CreateSynonym].
+    CreateSynonym(CreateSynonym),
+    /// ```sql
+    /// ALTER CATALOG name { OWNER TO principal | RENAME TO new_name }
+    /// ```
+    /// Note: this is a Databricks-specific statement.
+    /// <https://docs.databricks.com/aws/en/sql/language-manual/sql-ref-syntax-ddl-alter-catalog>
+    AlterCatalog(AlterCatalog),
+    /// ```sql
     /// DROP OPERATOR [ IF EXISTS ] name ( { left_type | NONE } , right_type ) [, ...] [ CASCADE | RESTRICT ]
     /// ```
     /// Note: this is a PostgreSQL-specific statement.
@@ -4129,12 +4542,14 @@ pub enum Statement {
     /// SHOW CREATE TABLE
     /// ```
     ///
-    /// Note: this is a MySQL-specific statement.
+    /// Note: this is a MySQL-specific statement, also supported by ClickHouse.
     ShowCreate {
         /// The kind of object being shown (TABLE, VIEW, etc.).
         obj_type: ShowCreateObject,
         /// The name of the object to show create statement for.
         obj_name: ObjectName,
+        /// ClickHouse-specific trailing `FORMAT` clause.
+        format: Option<FormatClause>,
     },
     /// ```sql
     /// SHOW COLUMNS
@@ -4431,24 +4846,47 @@ pub enum Statement {
     /// 3. [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#create_function_statement)
     /// 4. [MsSql](https://learn.microsoft.com/en-us/sql/t-sql/statements/create-function-transact-sql)
     CreateFunction(CreateFunction),
+    /// ```sql
+    /// CREATE TABLE FUNCTION
+    /// ```
+    /// See [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#create_table_function_statement)
+    CreateTableFunction(CreateTableFunction),
     /// CREATE TRIGGER statement. See struct [CreateTrigger] for details.
     CreateTrigger(CreateTrigger),
     /// DROP TRIGGER statement. See struct [DropTrigger] for details.
     DropTrigger(DropTrigger),
+    /// CREATE EVENT statement. See struct [CreateEvent] for details.
+    CreateEvent(CreateEvent),
+    /// ALTER EVENT statement. See struct [AlterEvent] for details.
+    AlterEvent(AlterEvent),
     /// ```sql
     /// CREATE PROCEDURE
     /// ```
     CreateProcedure {
         /// `OR ALTER` flag.
         or_alter: bool,
+        /// `OR REPLACE` flag.
+        or_replace: bool,
         /// Procedure name.
         name: ObjectName,
         /// Optional procedure parameters.
         params: Option<Vec<ProcedureParam>>,
+        /// Optional `RETURNS` data type.
+        ///
+        /// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/create-procedure)
+        return_type: Option<DataType>,
         /// Optional language identifier.
         language: Option<Ident>,
-        /// Procedure body statements.
-        body: ConditionalStatements,
+        /// Optional `EXECUTE AS` clause.
+        ///
+        /// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/create-procedure)
+        execute_as: Option<ProcedureExecuteAs>,
+        /// Optional `OPTIONS(...)` list.
+        ///
+        /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#create_procedure)
+        options: Option<Vec<SqlOption>>,
+        /// Procedure body.
+        body: CreateProcedureBody,
     },
     /// ```sql
     /// CREATE MACRO
@@ -4493,6 +4931,60 @@ pub enum Statement {
         comment: Option<String>,
     },
     /// ```sql
+    /// CREATE [ OR REPLACE ] <kind> INTEGRATION [ IF NOT EXISTS ] <name> <property> = <value> [ ... ]
+    /// ```
+    /// See <https://docs.snowflake.com/en/sql-reference/sql/create-integration>
+    CreateIntegration {
+        /// `OR REPLACE` flag.
+        or_replace: bool,
+        /// The kind of integration, e.g. `STORAGE`, `NOTIFICATION` or `SECURITY`.
+        kind: Ident,
+        /// `IF NOT EXISTS` flag.
+        if_not_exists: bool,
+        /// Integration name.
+        name: ObjectName,
+        /// Integration properties; values may be scalars or parenthesized lists.
+        options: Vec<SqlOption>,
+    },
+    /// ```sql
+    /// ALTER [ <kind> ] INTEGRATION [ IF EXISTS ] <name> { SET ... | UNSET ... }
+    /// ```
+    /// See <https://docs.snowflake.com/en/sql-reference/sql/alter-integration>
+    AlterIntegration {
+        /// The optional kind of integration.
+        kind: Option<Ident>,
+        /// `IF EXISTS` flag.
+        if_exists: bool,
+        /// Integration name.
+        name: ObjectName,
+        /// The operation to apply.
+        operation: AlterIntegrationOperation,
+    },
+    /// ```sql
+    /// DROP [ <kind> ] INTEGRATION [ IF EXISTS ] <name>
+    /// ```
+    /// See <https://docs.snowflake.com/en/sql-reference/sql/drop-integration>
+    DropIntegration {
+        /// The optional kind of integration.
+        kind: Option<Ident>,
+        /// `IF EXISTS` flag.
+        if_exists: bool,
+        /// Integration name.
+        name: ObjectName,
+    },
+    /// ```sql
+    /// DESC[RIBE] [ <kind> ] INTEGRATION <name>
+    /// ```
+    /// See <https://docs.snowflake.com/en/sql-reference/sql/desc-integration>
+    DescribeIntegration {
+        /// Whether `DESC` or `DESCRIBE` was used.
+        describe_alias: DescribeAlias,
+        /// The optional kind of integration.
+        kind: Option<Ident>,
+        /// Integration name.
+        name: ObjectName,
+    },
+    /// ```sql
     /// ASSERT <condition> [AS <message>]
     /// ```
     Assert {
@@ -4587,14 +5079,19 @@ pub enum Statement {
         describe_alias: DescribeAlias,
         /// Hive style `FORMATTED | EXTENDED`
         hive_format: Option<HiveDescribeFormat>,
-        /// Snowflake and ClickHouse support `DESC|DESCRIBE TABLE <table_name>` syntax
+        /// Snowflake and ClickHouse support `DESC|DESCRIBE TABLE <table_name>` syntax,
+        /// Snowflake additionally supports other object types such as `VIEW` or `STAGE`.
         ///
         /// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/desc-table.html)
         /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/describe-table)
-        has_table_keyword: bool,
+        object_type: Option<ObjectType>,
         /// Table name
         #[cfg_attr(feature = "visitor", visit(with = "visit_relation"))]
         table_name: ObjectName,
+        /// Hive style `PARTITION (<partition_spec>)`
+        ///
+        /// [Hive](https://cwiki.apache.org/confluence/display/Hive/LanguageManual+DDL#LanguageManualDDL-DescribePartition)
+        partition: Option<Vec<Expr>>,
     },
     /// ```sql
     /// [EXPLAIN | DESC | DESCRIBE]  <statement>
@@ -4777,7 +5274,7 @@ pub enum Statement {
         has_table_keyword: bool,
         /// Optional cluster identifier.
         /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/optimize)
-        on_cluster: Option<Ident>,
+        on_cluster: Option<ObjectNamePart>,
         /// Optional partition spec.
         /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/optimize)
         partition: Option<Partition>,
@@ -4805,14 +5302,14 @@ pub enum Statement {
         channel: Ident,
     },
     /// ```sql
-    /// UNLISTEN
+    /// UNLISTEN { channel | * }
     /// ```
     /// stop listening for a notification
     ///
     /// See Postgres <https://www.postgresql.org/docs/current/sql-unlisten.html>
     UNLISTEN {
-        /// Notification channel identifier.
-        channel: Ident,
+        /// Notification channel(s) to stop listening on.
+        channel: UnlistenChannel,
     },
     /// ```sql
     /// NOTIFY channel [ , payload ]
@@ -4931,6 +5428,34 @@ pub enum Statement {
     /// ```
     /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-reset.html)
     Reset(ResetStatement),
+    /// ```sql
+    /// REFRESH MATERIALIZED VIEW [ CONCURRENTLY ] name [ WITH [ NO ] DATA ]
+    /// ```
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-refreshmaterializedview.html)
+    RefreshMaterializedView {
+        /// Whether `CONCURRENTLY` was specified.
+        concurrently: bool,
+        /// Name of the materialized view.
+        #[cfg_attr(feature = "visitor", visit(with = "visit_relation"))]
+        name: ObjectName,
+        /// `WITH DATA` (`Some(true)`) or `WITH NO DATA` (`Some(false)`).
+        with_data: Option<bool>,
+    },
+    /// A statement the parser did not recognize or failed to parse, kept
+    /// verbatim instead of aborting the whole batch.
+    ///
+    /// Only produced when [`ParserOptions::unparsed_statements`] is enabled.
+    ///
+    /// [`ParserOptions::unparsed_statements`]: crate::parser::ParserOptions::unparsed_statements
+    Unparsed {
+        /// The statement's tokens, excluding whitespace and the trailing `;`.
+        tokens: AttachedTokens,
+        /// The original text of the statement, or text rebuilt from the tokens
+        /// if the parser was not given the SQL text, e.g. [`Parser::with_tokens`].
+        ///
+        /// [`Parser::with_tokens`]: crate::parser::Parser::with_tokens
+        sql: String,
+    },
 }
 
 impl From<Analyze> for Statement {
@@ -5076,19 +5601,25 @@ impl fmt::Display for Statement {
             Statement::ExplainTable {
                 describe_alias,
                 hive_format,
-                has_table_keyword,
+                object_type,
                 table_name,
+                partition,
             } => {
                 write!(f, "{describe_alias} ")?;
 
                 if let Some(format) = hive_format {
                     write!(f, "{format} ")?;
                 }
-                if *has_table_keyword {
-                    write!(f, "TABLE ")?;
+                if let Some(object_type) = object_type {
+                    write!(f, "{object_type} ")?;
                 }
 
-                write!(f, "{table_name}")
+                write!(f, "{table_name}")?;
+
+                if let Some(partition) = partition {
+                    write!(f, " PARTITION ({})", display_comma_separated(partition))?;
+                }
+                Ok(())
             }
             Statement::Explain {
                 describe_alias,
@@ -5175,6 +5706,15 @@ impl fmt::Display for Statement {
             Statement::While(stmt) => {
                 write!(f, "{stmt}")
             }
+            Statement::For(stmt) => {
+                write!(f, "{stmt}")
+            }
+            Statement::Repeat(stmt) => {
+                write!(f, "{stmt}")
+            }
+            Statement::LoopControl(stmt) => {
+                write!(f, "{stmt}")
+            }
             Statement::Raise(stmt) => {
                 write!(f, "{stmt}")
             }
@@ -5391,20 +5931,28 @@ impl fmt::Display for Statement {
                 Ok(())
             }
             Statement::CreateFunction(create_function) => create_function.fmt(f),
+            Statement::CreateTableFunction(create_table_function) => create_table_function.fmt(f),
             Statement::CreateDomain(create_domain) => create_domain.fmt(f),
             Statement::CreateTrigger(create_trigger) => create_trigger.fmt(f),
+            Statement::CreateEvent(create_event) => create_event.fmt(f),
+            Statement::AlterEvent(alter_event) => alter_event.fmt(f),
             Statement::DropTrigger(drop_trigger) => drop_trigger.fmt(f),
             Statement::CreateProcedure {
                 name,
                 or_alter,
+                or_replace,
                 params,
+                return_type,
                 language,
+                execute_as,
+                options,
                 body,
             } => {
                 write!(
                     f,
-                    "CREATE {or_alter}PROCEDURE {name}",
+                    "CREATE {or_alter}{or_replace}PROCEDURE {name}",
                     or_alter = if *or_alter { "OR ALTER " } else { "" },
+                    or_replace = if *or_replace { "OR REPLACE " } else { "" },
                     name = name
                 )?;
 
@@ -5414,11 +5962,23 @@ impl fmt::Display for Statement {
                     }
                 }
 
+                if let Some(return_type) = return_type {
+                    write!(f, " RETURNS {return_type}")?;
+                }
+
                 if let Some(language) = language {
                     write!(f, " LANGUAGE {language}")?;
                 }
 
-                write!(f, " AS {body}")
+                if let Some(execute_as) = execute_as {
+                    write!(f, " {execute_as}")?;
+                }
+
+                if let Some(options) = options {
+                    write!(f, " OPTIONS({})", display_comma_separated(options))?;
+                }
+
+                write!(f, " {body}")
             }
             Statement::CreateMacro {
                 or_replace,
@@ -5487,7 +6047,7 @@ impl fmt::Display for Statement {
                     name = name,
                     module_name = module_name
                 )?;
-                if !module_args.is_empty() {
+                if let Some(module_args) = module_args {
                     write!(f, " ({})", display_comma_separated(module_args))?;
                 }
                 Ok(())
@@ -5496,6 +6056,22 @@ impl fmt::Display for Statement {
             Statement::CreateExtension(create_extension) => write!(f, "{create_extension}"),
             Statement::CreateCollation(create_collation) => write!(f, "{create_collation}"),
             Statement::DropExtension(drop_extension) => write!(f, "{drop_extension}"),
+            Statement::CreateLanguage(create_language) => write!(f, "{create_language}"),
+            Statement::DropLanguage(drop_language) => write!(f, "{drop_language}"),
+            Statement::CreateTransform(create_transform) => write!(f, "{create_transform}"),
+            Statement::DropTransform(drop_transform) => write!(f, "{drop_transform}"),
+            Statement::CreateConversion(create_conversion) => write!(f, "{create_conversion}"),
+            Statement::DropConversion(drop_conversion) => write!(f, "{drop_conversion}"),
+            Statement::CreateTextSearch(create_text_search) => write!(f, "{create_text_search}"),
+            Statement::AlterTextSearchConfiguration(alter_text_search) => {
+                write!(f, "{alter_text_search}")
+            }
+            Statement::CreateStatistics(create_statistics) => write!(f, "{create_statistics}"),
+            Statement::CopyIntoDatabricks(copy_into) => write!(f, "{copy_into}"),
+            Statement::CreateCatalog(create_catalog) => write!(f, "{create_catalog}"),
+            Statement::CreateSynonym(create_synonym) => write!(f, "{create_synonym}"),
+            Statement::AlterCatalog(alter_catalog) => write!(f, "{alter_catalog}"),
+            Statement::AlterStatistics(alter_statistics) => write!(f, "{alter_statistics}"),
             Statement::DropOperator(drop_operator) => write!(f, "{drop_operator}"),
             Statement::DropOperatorFamily(drop_operator_family) => {
                 write!(f, "{drop_operator_family}")
@@ -5542,6 +6118,10 @@ impl fmt::Display for Statement {
             Statement::CreateServer(stmt) => {
                 write!(f, "{stmt}")
             }
+            Statement::AlterServer(alter_server) => write!(f, "{alter_server}"),
+            Statement::CreateUserMapping(create_user_mapping) => write!(f, "{create_user_mapping}"),
+            Statement::AlterUserMapping(alter_user_mapping) => write!(f, "{alter_user_mapping}"),
+            Statement::DropUserMapping(drop_user_mapping) => write!(f, "{drop_user_mapping}"),
             Statement::CreatePolicy(policy) => write!(f, "{policy}"),
             Statement::CreateConnector(create_connector) => create_connector.fmt(f),
             Statement::CreateOperator(create_operator) => create_operator.fmt(f),
@@ -5554,19 +6134,27 @@ impl fmt::Display for Statement {
                 write!(f, "ALTER INDEX {name} {operation}")
             }
             Statement::AlterView {
+                if_exists,
                 name,
-                columns,
-                query,
-                with_options,
+                operation,
             } => {
-                write!(f, "ALTER VIEW {name}")?;
-                if !with_options.is_empty() {
-                    write!(f, " WITH ({})", display_comma_separated(with_options))?;
-                }
-                if !columns.is_empty() {
-                    write!(f, " ({})", display_comma_separated(columns))?;
-                }
-                write!(f, " AS {query}")
+                write!(
+                    f,
+                    "ALTER VIEW {if_exists}{name} {operation}",
+                    if_exists = if *if_exists { "IF EXISTS " } else { "" }
+                )
+            }
+            Statement::AlterMaterializedView {
+                if_exists,
+                name,
+                operations,
+            } => {
+                write!(
+                    f,
+                    "ALTER MATERIALIZED VIEW {if_exists}{name} {operations}",
+                    if_exists = if *if_exists { "IF EXISTS " } else { "" },
+                    operations = display_comma_separated(operations)
+                )
             }
             Statement::AlterFunction(alter_function) => write!(f, "{alter_function}"),
             Statement::AlterType(AlterType { name, operation }) => {
@@ -5721,6 +6309,17 @@ impl fmt::Display for Statement {
                 Ok(())
             }
             Self::Set(set) => write!(f, "{set}"),
+            Statement::SetSessionOption {
+                names,
+                target_table,
+                value,
+            } => {
+                write!(f, "SET {}", display_comma_separated(names))?;
+                if let Some(target_table) = target_table {
+                    write!(f, " {target_table}")?;
+                }
+                write!(f, " {value}")
+            }
             Statement::ShowVariable { variable } => {
                 write!(f, "SHOW")?;
                 if !variable.is_empty() {
@@ -5764,8 +6363,15 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
-            Statement::ShowCreate { obj_type, obj_name } => {
+            Statement::ShowCreate {
+                obj_type,
+                obj_name,
+                format,
+            } => {
                 write!(f, "SHOW CREATE {obj_type} {obj_name}",)?;
+                if let Some(format) = format {
+                    write!(f, " {format}")?;
+                }
                 Ok(())
             }
             Statement::ShowColumns {
@@ -6137,7 +6743,7 @@ impl fmt::Display for Statement {
                     as_type = as_type
                 )?;
                 for sequence_option in sequence_options {
-                    write!(f, "{sequence_option}")?;
+                    write!(f, " {sequence_option}")?;
                 }
                 if let Some(ob) = owned_by.as_ref() {
                     write!(f, " OWNED BY {ob}")?;
@@ -6172,10 +6778,70 @@ impl fmt::Display for Statement {
                 if !copy_options.options.is_empty() {
                     write!(f, " COPY_OPTIONS=({copy_options})")?;
                 }
-                if comment.is_some() {
-                    write!(f, " COMMENT='{}'", comment.as_ref().unwrap())?;
+                if comment.is_some() {
+                    write!(f, " COMMENT='{}'", comment.as_ref().unwrap())?;
+                }
+                Ok(())
+            }
+            Statement::CreateIntegration {
+                or_replace,
+                kind,
+                if_not_exists,
+                name,
+                options,
+            } => {
+                write!(
+                    f,
+                    "CREATE {or_replace}{kind} INTEGRATION {if_not_exists}{name}",
+                    or_replace = if *or_replace { "OR REPLACE " } else { "" },
+                    if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                )?;
+                if !options.is_empty() {
+                    write!(f, " {}", display_separated(options, " "))?;
+                }
+                Ok(())
+            }
+            Statement::AlterIntegration {
+                kind,
+                if_exists,
+                name,
+                operation,
+            } => {
+                write!(f, "ALTER ")?;
+                if let Some(kind) = kind {
+                    write!(f, "{kind} ")?;
+                }
+                write!(
+                    f,
+                    "INTEGRATION {if_exists}{name} {operation}",
+                    if_exists = if *if_exists { "IF EXISTS " } else { "" },
+                )
+            }
+            Statement::DropIntegration {
+                kind,
+                if_exists,
+                name,
+            } => {
+                write!(f, "DROP ")?;
+                if let Some(kind) = kind {
+                    write!(f, "{kind} ")?;
+                }
+                write!(
+                    f,
+                    "INTEGRATION {if_exists}{name}",
+                    if_exists = if *if_exists { "IF EXISTS " } else { "" },
+                )
+            }
+            Statement::DescribeIntegration {
+                describe_alias,
+                kind,
+                name,
+            } => {
+                write!(f, "{describe_alias} ")?;
+                if let Some(kind) = kind {
+                    write!(f, "{kind} ")?;
                 }
-                Ok(())
+                write!(f, "INTEGRATION {name}")
             }
             Statement::CopyIntoSnowflake {
                 kind,
@@ -6386,6 +7052,23 @@ impl fmt::Display for Statement {
             Statement::Vacuum(s) => write!(f, "{s}"),
             Statement::AlterUser(s) => write!(f, "{s}"),
             Statement::Reset(s) => write!(f, "{s}"),
+            Statement::RefreshMaterializedView {
+                concurrently,
+                name,
+                with_data,
+            } => {
+                write!(
+                    f,
+                    "REFRESH MATERIALIZED VIEW {concurrently}{name}",
+                    concurrently = if *concurrently { "CONCURRENTLY " } else { "" }
+                )?;
+                match with_data {
+                    Some(true) => write!(f, " WITH DATA"),
+                    Some(false) => write!(f, " WITH NO DATA"),
+                    None => Ok(()),
+                }
+            }
+            Statement::Unparsed { sql, .. } => f.write_str(sql),
         }
     }
 }
@@ -6412,6 +7095,8 @@ pub enum SequenceOptions {
     Cache(Expr),
     /// `CYCLE` or `NO CYCLE` option.
     Cycle(bool),
+    /// `RESTART [ [ WITH ] <expr> ]`; second value indicates presence of `WITH`.
+    Restart(Option<Expr>, bool),
 }
 
 impl fmt::Display for SequenceOptions {
@@ -6420,36 +7105,43 @@ impl fmt::Display for SequenceOptions {
             SequenceOptions::IncrementBy(increment, by) => {
                 write!(
                     f,
-                    " INCREMENT{by} {increment}",
+                    "INCREMENT{by} {increment}",
                     by = if *by { " BY" } else { "" },
                     increment = increment
                 )
             }
             SequenceOptions::MinValue(Some(expr)) => {
-                write!(f, " MINVALUE {expr}")
+                write!(f, "MINVALUE {expr}")
             }
             SequenceOptions::MinValue(None) => {
-                write!(f, " NO MINVALUE")
+                write!(f, "NO MINVALUE")
             }
             SequenceOptions::MaxValue(Some(expr)) => {
-                write!(f, " MAXVALUE {expr}")
+                write!(f, "MAXVALUE {expr}")
             }
             SequenceOptions::MaxValue(None) => {
-                write!(f, " NO MAXVALUE")
+                write!(f, "NO MAXVALUE")
             }
             SequenceOptions::StartWith(start, with) => {
                 write!(
                     f,
-                    " START{with} {start}",
+                    "START{with} {start}",
                     with = if *with { " WITH" } else { "" },
                     start = start
                 )
             }
             SequenceOptions::Cache(cache) => {
-                write!(f, " CACHE {}", *cache)
+                write!(f, "CACHE {}", *cache)
             }
             SequenceOptions::Cycle(no) => {
-                write!(f, " {}CYCLE", if *no { "NO " } else { "" })
+                write!(f, "{}CYCLE", if *no { "NO " } else { "" })
+            }
+            SequenceOptions::Restart(restart, with) => {
+                write!(f, "RESTART")?;
+                if let Some(restart) = restart {
+                    write!(f, "{} {restart}", if *with { " WITH" } else { "" })?;
+                }
+                Ok(())
             }
         }
     }
@@ -7090,6 +7782,14 @@ pub enum Action {
     },
     /// Usage permission.
     Usage,
+    /// `USE CATALOG` permission.
+    ///
+    /// See <https://docs.databricks.com/en/data-governance/unity-catalog/manage-privileges/privileges.html>
+    UseCatalog,
+    /// `USE SCHEMA` permission.
+    ///
+    /// See <https://docs.databricks.com/en/data-governance/unity-catalog/manage-privileges/privileges.html>
+    UseSchema,
 }
 
 impl fmt::Display for Action {
@@ -7160,6 +7860,8 @@ impl fmt::Display for Action {
             Action::Truncate => f.write_str("TRUNCATE")?,
             Action::Update { .. } => f.write_str("UPDATE")?,
             Action::Usage => f.write_str("USAGE")?,
+            Action::UseCatalog => f.write_str("USE CATALOG")?,
+            Action::UseSchema => f.write_str("USE SCHEMA")?,
         };
         match self {
             Action::Insert { columns }
@@ -7188,6 +7890,8 @@ pub enum ActionCreateObjectType {
     Application,
     /// An application package object.
     ApplicationPackage,
+    /// A catalog object.
+    Catalog,
     /// A compute pool object.
     ComputePool,
     /// A data exchange listing.
@@ -7198,8 +7902,12 @@ pub enum ActionCreateObjectType {
     ExternalVolume,
     /// A failover group object.
     FailoverGroup,
+    /// A function object.
+    Function,
     /// An integration object.
     Integration,
+    /// A materialized view object.
+    MaterializedView,
     /// A network policy object.
     NetworkPolicy,
     /// An organization listing.
@@ -7212,8 +7920,12 @@ pub enum ActionCreateObjectType {
     Schema,
     /// A share object.
     Share,
+    /// A table object.
+    Table,
     /// A user object.
     User,
+    /// A view object.
+    View,
     /// A warehouse object.
     Warehouse,
 }
@@ -7224,19 +7936,24 @@ impl fmt::Display for ActionCreateObjectType {
             ActionCreateObjectType::Account => write!(f, "ACCOUNT"),
             ActionCreateObjectType::Application => write!(f, "APPLICATION"),
             ActionCreateObjectType::ApplicationPackage => write!(f, "APPLICATION PACKAGE"),
+            ActionCreateObjectType::Catalog => write!(f, "CATALOG"),
             ActionCreateObjectType::ComputePool => write!(f, "COMPUTE POOL"),
             ActionCreateObjectType::DataExchangeListing => write!(f, "DATA EXCHANGE LISTING"),
             ActionCreateObjectType::Database => write!(f, "DATABASE"),
             ActionCreateObjectType::ExternalVolume => write!(f, "EXTERNAL VOLUME"),
             ActionCreateObjectType::FailoverGroup => write!(f, "FAILOVER GROUP"),
+            ActionCreateObjectType::Function => write!(f, "FUNCTION"),
             ActionCreateObjectType::Integration => write!(f, "INTEGRATION"),
+            ActionCreateObjectType::MaterializedView => write!(f, "MATERIALIZED VIEW"),
             ActionCreateObjectType::NetworkPolicy => write!(f, "NETWORK POLICY"),
             ActionCreateObjectType::OrganiationListing => write!(f, "ORGANIZATION LISTING"),
             ActionCreateObjectType::ReplicationGroup => write!(f, "REPLICATION GROUP"),
             ActionCreateObjectType::Role => write!(f, "ROLE"),
             ActionCreateObjectType::Schema => write!(f, "SCHEMA"),
             ActionCreateObjectType::Share => write!(f, "SHARE"),
+            ActionCreateObjectType::Table => write!(f, "TABLE"),
             ActionCreateObjectType::User => write!(f, "USER"),
+            ActionCreateObjectType::View => write!(f, "VIEW"),
             ActionCreateObjectType::Warehouse => write!(f, "WAREHOUSE"),
         }
     }
@@ -7568,6 +8285,8 @@ pub enum GrantObjects {
         /// The target schema names.
         schemas: Vec<ObjectName>,
     },
+    /// Grant privileges on specific catalogs
+    Catalogs(Vec<ObjectName>),
     /// Grant privileges on specific databases
     Databases(Vec<ObjectName>),
     /// Grant privileges on specific schemas
@@ -7627,6 +8346,9 @@ impl fmt::Display for GrantObjects {
             GrantObjects::Sequences(sequences) => {
                 write!(f, "SEQUENCE {}", display_comma_separated(sequences))
             }
+            GrantObjects::Catalogs(catalogs) => {
+                write!(f, "CATALOG {}", display_comma_separated(catalogs))
+            }
             GrantObjects::Databases(databases) => {
                 write!(f, "DATABASE {}", display_comma_separated(databases))
             }
@@ -7858,6 +8580,11 @@ pub enum FunctionArgExpr {
     ///
     /// Used in Snowflake to support expressions like `HASH(* EXCLUDE(col))`.
     WildcardWithOptions(WildcardAdditionalOptions),
+    /// A bare date/time part argument, e.g. `HOUR` in BigQuery's
+    /// `TIMESTAMP_DIFF(t1, t2, HOUR)` or `WEEK(MONDAY)` in `LAST_DAY(d, WEEK(MONDAY))`.
+    ///
+    /// See [Dialect::date_part_argument_position].
+    DateTimeField(DateTimeField),
 }
 
 impl From<Expr> for FunctionArgExpr {
@@ -7877,6 +8604,7 @@ impl fmt::Display for FunctionArgExpr {
             FunctionArgExpr::QualifiedWildcard(prefix) => write!(f, "{prefix}.*"),
             FunctionArgExpr::Wildcard => f.write_str("*"),
             FunctionArgExpr::WildcardWithOptions(opts) => write!(f, "*{opts}"),
+            FunctionArgExpr::DateTimeField(field) => write!(f, "{field}"),
         }
     }
 }
@@ -7982,6 +8710,29 @@ impl fmt::Display for CloseCursor {
     }
 }
 
+/// Which notification channel(s) an `UNLISTEN` statement applies to.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum UnlistenChannel {
+    /// `UNLISTEN *`, stop listening on all channels.
+    All,
+    /// Stop listening on a specific channel.
+    Specific {
+        /// The name of the channel.
+        name: Ident,
+    },
+}
+
+impl fmt::Display for UnlistenChannel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UnlistenChannel::All => write!(f, "*"),
+            UnlistenChannel::Specific { name } => write!(f, "{name}"),
+        }
+    }
+}
+
 /// A Drop Domain statement
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -8485,6 +9236,16 @@ pub enum ObjectType {
     User,
     /// A stream.
     Stream,
+    /// An event.
+    Event,
+    /// An extended statistics object.
+    Statistics,
+    /// A foreign server.
+    Server,
+    /// A catalog.
+    Catalog,
+    /// A synonym.
+    Synonym,
 }
 
 impl fmt::Display for ObjectType {
@@ -8503,6 +9264,11 @@ impl fmt::Display for ObjectType {
             ObjectType::Type => "TYPE",
             ObjectType::User => "USER",
             ObjectType::Stream => "STREAM",
+            ObjectType::Event => "EVENT",
+            ObjectType::Statistics => "STATISTICS",
+            ObjectType::Server => "SERVER",
+            ObjectType::Catalog => "CATALOG",
+            ObjectType::Synonym => "SYNONYM",
         })
     }
 }
@@ -8811,6 +9577,19 @@ pub enum SqlOption {
         /// The expression value for the option.
         value: Expr,
     },
+    /// A key value pair followed by a parenthesized list of nested options, e.g.
+    ///
+    ///   WITH (SYSTEM_VERSIONING = ON (HISTORY_TABLE = dbo.history))
+    ///
+    /// <https://learn.microsoft.com/en-us/sql/t-sql/statements/create-table-transact-sql#system_versioning>
+    KeyValueWithOptions {
+        /// The option key identifier.
+        key: Ident,
+        /// The expression value for the option.
+        value: Expr,
+        /// The nested options.
+        options: Vec<SqlOption>,
+    },
     /// One or more table partitions and represents which partition the boundary values belong to,
     /// e.g.
     ///
@@ -8849,6 +9628,13 @@ impl fmt::Display for SqlOption {
             SqlOption::KeyValue { key: name, value } => {
                 write!(f, "{name} = {value}")
             }
+            SqlOption::KeyValueWithOptions {
+                key,
+                value,
+                options,
+            } => {
+                write!(f, "{key} = {value} ({})", display_comma_separated(options))
+            }
             SqlOption::Partition {
                 column_name,
                 range_direction,
@@ -9011,6 +9797,206 @@ impl fmt::Display for CreateServerOption {
     }
 }
 
+/// An `ALTER SERVER` statement.
+///
+/// [PostgreSQL Documentation](https://www.postgresql.org/docs/current/sql-alterserver.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct AlterServer {
+    /// The server name.
+    pub name: ObjectName,
+    /// The operation to apply.
+    pub operation: AlterServerOperation,
+}
+
+impl fmt::Display for AlterServer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ALTER SERVER {} {}", self.name, self.operation)
+    }
+}
+
+/// An operation of an `ALTER SERVER` statement.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum AlterServerOperation {
+    /// `[ VERSION 'new_version' ] [ OPTIONS ( [ ADD | SET | DROP ] option ['value'] [, ... ] ) ]`
+    Update {
+        /// Optional new server version.
+        version: Option<Ident>,
+        /// Optional list of option changes.
+        options: Option<Vec<AlterServerOption>>,
+    },
+    /// `OWNER TO new_owner`
+    OwnerTo(Owner),
+    /// `RENAME TO new_name`
+    RenameTo {
+        /// The new server name.
+        new_name: Ident,
+    },
+}
+
+impl fmt::Display for AlterServerOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlterServerOperation::Update { version, options } => {
+                let mut delim = "";
+                if let Some(version) = version {
+                    write!(f, "VERSION {version}")?;
+                    delim = " ";
+                }
+                if let Some(options) = options {
+                    write!(f, "{delim}OPTIONS ({})", display_comma_separated(options))?;
+                }
+                Ok(())
+            }
+            AlterServerOperation::OwnerTo(owner) => write!(f, "OWNER TO {owner}"),
+            AlterServerOperation::RenameTo { new_name } => write!(f, "RENAME TO {new_name}"),
+        }
+    }
+}
+
+/// An option change in `ALTER SERVER ... OPTIONS (...)`.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct AlterServerOption {
+    /// The explicit action, if any. Omitting it is equivalent to `ADD`.
+    pub action: Option<AlterServerOptionAction>,
+    /// Option key identifier.
+    pub key: Ident,
+    /// Option value, absent for `DROP`.
+    pub value: Option<Ident>,
+}
+
+impl fmt::Display for AlterServerOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(action) = &self.action {
+            write!(f, "{action} ")?;
+        }
+        write!(f, "{}", self.key)?;
+        if let Some(value) = &self.value {
+            write!(f, " {value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A `CREATE USER MAPPING` statement.
+///
+/// [PostgreSQL Documentation](https://www.postgresql.org/docs/current/sql-createusermapping.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CreateUserMapping {
+    /// Whether `IF NOT EXISTS` was specified.
+    pub if_not_exists: bool,
+    /// The user the mapping applies to.
+    pub user: Owner,
+    /// The foreign server name.
+    pub server_name: ObjectName,
+    /// Optional list of user mapping options.
+    pub options: Option<Vec<CreateServerOption>>,
+}
+
+impl fmt::Display for CreateUserMapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "CREATE USER MAPPING {if_not_exists}FOR {user} SERVER {server_name}",
+            if_not_exists = if self.if_not_exists {
+                "IF NOT EXISTS "
+            } else {
+                ""
+            },
+            user = self.user,
+            server_name = self.server_name,
+        )?;
+        if let Some(options) = &self.options {
+            write!(f, " OPTIONS ({})", display_comma_separated(options))?;
+        }
+        Ok(())
+    }
+}
+
+/// An `ALTER USER MAPPING` statement.
+///
+/// [PostgreSQL Documentation](https://www.postgresql.org/docs/current/sql-alterusermapping.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct AlterUserMapping {
+    /// The user the mapping applies to.
+    pub user: Owner,
+    /// The foreign server name.
+    pub server_name: ObjectName,
+    /// The option changes.
+    pub options: Vec<AlterServerOption>,
+}
+
+impl fmt::Display for AlterUserMapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ALTER USER MAPPING FOR {} SERVER {} OPTIONS ({})",
+            self.user,
+            self.server_name,
+            display_comma_separated(&self.options)
+        )
+    }
+}
+
+/// A `DROP USER MAPPING` statement.
+///
+/// [PostgreSQL Documentation](https://www.postgresql.org/docs/current/sql-dropusermapping.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct DropUserMapping {
+    /// Whether `IF EXISTS` was specified.
+    pub if_exists: bool,
+    /// The user the mapping applies to.
+    pub user: Owner,
+    /// The foreign server name.
+    pub server_name: ObjectName,
+}
+
+impl fmt::Display for DropUserMapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "DROP USER MAPPING {if_exists}FOR {user} SERVER {server_name}",
+            if_exists = if self.if_exists { "IF EXISTS " } else { "" },
+            user = self.user,
+            server_name = self.server_name,
+        )
+    }
+}
+
+/// The action of an `ALTER SERVER` option change.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum AlterServerOptionAction {
+    /// `ADD`
+    Add,
+    /// `SET`
+    Set,
+    /// `DROP`
+    Drop,
+}
+
+impl fmt::Display for AlterServerOptionAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            AlterServerOptionAction::Add => "ADD",
+            AlterServerOptionAction::Set => "SET",
+            AlterServerOptionAction::Drop => "DROP",
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
@@ -10545,6 +11531,32 @@ impl Display for CreateViewParams {
     }
 }
 
+/// `WITH [ CASCADED | LOCAL ] CHECK OPTION` clause of `CREATE VIEW`
+///
+/// [PostgreSQL](https://www.postgresql.org/docs/current/sql-createview.html)
+/// [MySQL](https://dev.mysql.com/doc/refman/9.1/en/view-check-option.html)
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum ViewCheckOption {
+    /// `WITH CHECK OPTION`
+    Plain,
+    /// `WITH LOCAL CHECK OPTION`
+    Local,
+    /// `WITH CASCADED CHECK OPTION`
+    Cascaded,
+}
+
+impl Display for ViewCheckOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ViewCheckOption::Plain => write!(f, "WITH CHECK OPTION"),
+            ViewCheckOption::Local => write!(f, "WITH LOCAL CHECK OPTION"),
+            ViewCheckOption::Cascaded => write!(f, "WITH CASCADED CHECK OPTION"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
@@ -11078,8 +12090,14 @@ impl fmt::Display for SetSessionAuthorizationParamKind {
 /// Kind of session parameter being set by `SET SESSION`.
 pub enum SetSessionParamKind {
     /// Generic session parameter (name/value pair).
+    ///
+    /// Dialects that support [`Statement::SetSessionOption`] (MSSQL) parse
+    /// `ON`/`OFF` options into that statement instead.
     Generic(SetSessionParamGeneric),
     /// Identity insert related parameter.
+    ///
+    /// Dialects that support [`Statement::SetSessionOption`] (MSSQL) parse
+    /// `IDENTITY_INSERT` into that statement instead.
     IdentityInsert(SetSessionParamIdentityInsert),
     /// Offsets-related parameter.
     Offsets(SetSessionParamOffsets),
@@ -12114,6 +13132,90 @@ impl From<DropExtension> for Statement {
     }
 }
 
+impl From<CreateLanguage> for Statement {
+    fn from(cl: CreateLanguage) -> Self {
+        Self::CreateLanguage(cl)
+    }
+}
+
+impl From<DropLanguage> for Statement {
+    fn from(dl: DropLanguage) -> Self {
+        Self::DropLanguage(dl)
+    }
+}
+
+impl From<CreateTransform> for Statement {
+    fn from(ct: CreateTransform) -> Self {
+        Self::CreateTransform(ct)
+    }
+}
+
+impl From<DropTransform> for Statement {
+    fn from(dt: DropTransform) -> Self {
+        Self::DropTransform(dt)
+    }
+}
+
+impl From<CreateConversion> for Statement {
+    fn from(cc: CreateConversion) -> Self {
+        Self::CreateConversion(cc)
+    }
+}
+
+impl From<DropConversion> for Statement {
+    fn from(dc: DropConversion) -> Self {
+        Self::DropConversion(dc)
+    }
+}
+
+impl From<CreateTextSearch> for Statement {
+    fn from(cts: CreateTextSearch) -> Self {
+        Self::CreateTextSearch(cts)
+    }
+}
+
+impl From<AlterTextSearchConfiguration> for Statement {
+    fn from(atsc: AlterTextSearchConfiguration) -> Self {
+        Self::AlterTextSearchConfiguration(atsc)
+    }
+}
+
+impl From<CreateStatistics> for Statement {
+    fn from(cs: CreateStatistics) -> Self {
+        Self::CreateStatistics(cs)
+    }
+}
+
+impl From<AlterStatistics> for Statement {
+    fn from(a: AlterStatistics) -> Self {
+        Self::AlterStatistics(a)
+    }
+}
+
+impl From<CopyIntoDatabricks> for Statement {
+    fn from(c: CopyIntoDatabricks) -> Self {
+        Self::CopyIntoDatabricks(c)
+    }
+}
+
+impl From<CreateCatalog> for Statement {
+    fn from(c: CreateCatalog) -> Self {
+        Self::CreateCatalog(c)
+    }
+}
+
+impl From<CreateSynonym> for Statement {
+    fn from(c: CreateSynonym) -> Self {
+        Self::CreateSynonym(c)
+    }
+}
+
+impl From<AlterCatalog> for Statement {
+    fn from(a: AlterCatalog) -> Self {
+        Self::AlterCatalog(a)
+    }
+}
+
 impl From<CaseStatement> for Statement {
     fn from(c: CaseStatement) -> Self {
         Self::Case(c)
@@ -12132,6 +13234,24 @@ impl From<WhileStatement> for Statement {
     }
 }
 
+impl From<ForStatement> for Statement {
+    fn from(f: ForStatement) -> Self {
+        Self::For(f)
+    }
+}
+
+impl From<RepeatStatement> for Statement {
+    fn from(r: RepeatStatement) -> Self {
+        Self::Repeat(r)
+    }
+}
+
+impl From<LoopControlStatement> for Statement {
+    fn from(l: LoopControlStatement) -> Self {
+        Self::LoopControl(l)
+    }
+}
+
 impl From<RaiseStatement> for Statement {
     fn from(r: RaiseStatement) -> Self {
         Self::Raise(r)
@@ -12180,6 +13300,30 @@ impl From<CreateServerStatement> for Statement {
     }
 }
 
+impl From<AlterServer> for Statement {
+    fn from(a: AlterServer) -> Self {
+        Self::AlterServer(a)
+    }
+}
+
+impl From<CreateUserMapping> for Statement {
+    fn from(c: CreateUserMapping) -> Self {
+        Self::CreateUserMapping(c)
+    }
+}
+
+impl From<AlterUserMapping> for Statement {
+    fn from(a: AlterUserMapping) -> Self {
+        Self::AlterUserMapping(a)
+    }
+}
+
+impl From<DropUserMapping> for Statement {
+    fn from(d: DropUserMapping) -> Self {
+        Self::DropUserMapping(d)
+    }
+}
+
 impl From<CreateConnector> for Statement {
     fn from(c: CreateConnector) -> Self {
         Self::CreateConnector(c)
@@ -12324,6 +13468,12 @@ impl From<DenyStatement> for Statement {
     }
 }
 
+impl From<CreateTableFunction> for Statement {
+    fn from(c: CreateTableFunction) -> Self {
+        Self::CreateTableFunction(c)
+    }
+}
+
 impl From<CreateDomain> for Statement {
     fn from(c: CreateDomain) -> Self {
         Self::CreateDomain(c)
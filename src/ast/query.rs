@@ -66,6 +66,11 @@ pub struct Query {
 
     /// Pipe operator
     pub pipe_operators: Vec<PipeOperator>,
+    /// `OPTION ( <query_hint> [ ,...n ] )`
+    /// (MSSQL-specific)
+    ///
+    /// [MSSQL](https://learn.microsoft.com/en-us/sql/t-sql/queries/option-clause-transact-sql)
+    pub options: Vec<QueryHint>,
 }
 
 impl fmt::Display for Query {
@@ -99,6 +104,9 @@ impl fmt::Display for Query {
             f.write_str(" ")?;
             for_clause.fmt(f)?;
         }
+        if !self.options.is_empty() {
+            write!(f, " OPTION ({})", display_comma_separated(&self.options))?;
+        }
         if let Some(ref format) = self.format_clause {
             f.write_str(" ")?;
             format.fmt(f)?;
@@ -330,7 +338,10 @@ pub enum SelectFlavor {
     Standard,
     /// `FROM ... SELECT *`
     FromFirst,
-    /// `FROM *`
+    /// `FROM ...` without a `SELECT`, equivalent to `SELECT * FROM ...`.
+    ///
+    /// The projection of such a select holds a single implicit wildcard
+    /// which is not displayed.
     FromFirstNoSelect,
 }
 
@@ -553,7 +564,7 @@ impl fmt::Display for Select {
             select_modifiers.fmt(f)?;
         }
 
-        if !self.projection.is_empty() {
+        if !self.projection.is_empty() && self.flavor != SelectFlavor::FromFirstNoSelect {
             indented_list(f, &self.projection)?;
         }
 
@@ -1495,6 +1506,9 @@ pub enum TableFactor {
         /// Optional index hints(mysql)
         /// See: <https://dev.mysql.com/doc/refman/8.4/en/index-hints.html>
         index_hints: Vec<TableIndexHints>,
+        /// Whether the `FINAL` modifier follows the table (ClickHouse)
+        /// See: <https://clickhouse.com/docs/en/sql-reference/statements/select/from#final-modifier>
+        final_modifier: bool,
     },
     /// A derived table (a parenthesized subquery), optionally `LATERAL`.
     Derived {
@@ -1544,8 +1558,9 @@ pub enum TableFactor {
         array_exprs: Vec<Expr>,
         /// Whether `WITH OFFSET` was specified to include element offsets.
         with_offset: bool,
-        /// Optional alias for the offset column when `WITH OFFSET` is used.
-        with_offset_alias: Option<Ident>,
+        /// Optional alias for the offset column when `WITH OFFSET` is used,
+        /// e.g. `WITH OFFSET AS pos`.
+        with_offset_alias: Option<TableAlias>,
         /// Whether `WITH ORDINALITY` was specified to include ordinality.
         with_ordinality: bool,
     },
@@ -1987,6 +2002,8 @@ impl fmt::Display for PivotValueSource {
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 /// An item in the `MEASURES` clause of `MATCH_RECOGNIZE`.
 pub struct Measure {
+    /// Optional `RUNNING` or `FINAL` semantics of the measure.
+    pub semantics: Option<MeasureSemantics>,
     /// Expression producing the measure value.
     pub expr: Expr,
     /// Alias for the measure column.
@@ -1995,10 +2012,35 @@ pub struct Measure {
 
 impl fmt::Display for Measure {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(semantics) = &self.semantics {
+            write!(f, "{semantics} ")?;
+        }
         write!(f, "{} AS {}", self.expr, self.alias)
     }
 }
 
+/// The semantics of a measure in the `MEASURES` clause of `MATCH_RECOGNIZE`.
+///
+/// See <https://docs.snowflake.com/en/sql-reference/constructs/match_recognize#measures-specifying-additional-output-columns>.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum MeasureSemantics {
+    /// `RUNNING`
+    Running,
+    /// `FINAL`
+    Final,
+}
+
+impl fmt::Display for MeasureSemantics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MeasureSemantics::Running => write!(f, "RUNNING"),
+            MeasureSemantics::Final => write!(f, "FINAL"),
+        }
+    }
+}
+
 /// The rows per match option in a `MATCH_RECOGNIZE` operation.
 ///
 /// See <https://docs.snowflake.com/en/sql-reference/constructs/match_recognize#row-s-per-match-specifying-the-rows-to-return>.
@@ -2042,6 +2084,8 @@ pub enum AfterMatchSkip {
     ToFirst(Ident),
     /// `TO LAST <symbol>`
     ToLast(Ident),
+    /// `TO <symbol>`
+    To(Ident),
 }
 
 impl fmt::Display for AfterMatchSkip {
@@ -2049,9 +2093,10 @@ impl fmt::Display for AfterMatchSkip {
         write!(f, "AFTER MATCH SKIP ")?;
         match self {
             AfterMatchSkip::PastLastRow => write!(f, "PAST LAST ROW"),
-            AfterMatchSkip::ToNextRow => write!(f, " TO NEXT ROW"),
+            AfterMatchSkip::ToNextRow => write!(f, "TO NEXT ROW"),
             AfterMatchSkip::ToFirst(symbol) => write!(f, "TO FIRST {symbol}"),
             AfterMatchSkip::ToLast(symbol) => write!(f, "TO LAST {symbol}"),
+            AfterMatchSkip::To(symbol) => write!(f, "TO {symbol}"),
         }
     }
 }
@@ -2211,6 +2256,7 @@ impl fmt::Display for TableFactor {
                 json_path,
                 sample,
                 index_hints,
+                final_modifier,
             } => {
                 name.fmt(f)?;
                 if let Some(json_path) = json_path {
@@ -2219,6 +2265,9 @@ impl fmt::Display for TableFactor {
                 if !partitions.is_empty() {
                     write!(f, "PARTITION ({})", display_comma_separated(partitions))?;
                 }
+                if let Some(version) = version {
+                    write!(f, " {version}")?;
+                }
                 if let Some(args) = args {
                     write!(f, "(")?;
                     write!(f, "{}", display_comma_separated(&args.args))?;
@@ -2245,8 +2294,8 @@ impl fmt::Display for TableFactor {
                 if !with_hints.is_empty() {
                     write!(f, " WITH ({})", display_comma_separated(with_hints))?;
                 }
-                if let Some(version) = version {
-                    write!(f, " {version}")?;
+                if *final_modifier {
+                    write!(f, " FINAL")?;
                 }
                 if let Some(TableSampleKind::AfterTableAlias(sample)) = sample {
                     write!(f, " {sample}")?;
@@ -2597,6 +2646,31 @@ pub enum TableVersion {
     /// When the table version is defined using `FOR SYSTEM_TIME AS OF`.
     /// For example: `SELECT * FROM tbl FOR SYSTEM_TIME AS OF TIMESTAMP_SUB(CURRENT_TIMESTAMP(), INTERVAL 1 HOUR)`
     ForSystemTimeAsOf(Expr),
+    /// MSSQL `FOR SYSTEM_TIME FROM <start> TO <end>`
+    ///
+    /// <https://learn.microsoft.com/en-us/sql/relational-databases/tables/querying-data-in-a-system-versioned-temporal-table>
+    ForSystemTimeFromTo {
+        /// Start of the time range.
+        from: Expr,
+        /// End of the time range.
+        to: Expr,
+    },
+    /// MSSQL `FOR SYSTEM_TIME BETWEEN <start> AND <end>`
+    ForSystemTimeBetween {
+        /// Start of the time range.
+        low: Expr,
+        /// End of the time range.
+        high: Expr,
+    },
+    /// MSSQL `FOR SYSTEM_TIME CONTAINED IN (<start>, <end>)`
+    ForSystemTimeContainedIn {
+        /// Start of the time range.
+        from: Expr,
+        /// End of the time range.
+        to: Expr,
+    },
+    /// MSSQL `FOR SYSTEM_TIME ALL`
+    ForSystemTimeAll,
     /// When the table version is defined using `TIMESTAMP AS OF`.
     /// Databricks supports this syntax.
     /// For example: `SELECT * FROM tbl TIMESTAMP AS OF CURRENT_TIMESTAMP() - INTERVAL 1 HOUR`
@@ -2631,6 +2705,16 @@ impl Display for TableVersion {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             TableVersion::ForSystemTimeAsOf(e) => write!(f, "FOR SYSTEM_TIME AS OF {e}")?,
+            TableVersion::ForSystemTimeFromTo { from, to } => {
+                write!(f, "FOR SYSTEM_TIME FROM {from} TO {to}")?
+            }
+            TableVersion::ForSystemTimeBetween { low, high } => {
+                write!(f, "FOR SYSTEM_TIME BETWEEN {low} AND {high}")?
+            }
+            TableVersion::ForSystemTimeContainedIn { from, to } => {
+                write!(f, "FOR SYSTEM_TIME CONTAINED IN ({from}, {to})")?
+            }
+            TableVersion::ForSystemTimeAll => write!(f, "FOR SYSTEM_TIME ALL")?,
             TableVersion::TimestampAsOf(e) => write!(f, "TIMESTAMP AS OF {e}")?,
             TableVersion::VersionAsOf(e) => write!(f, "VERSION AS OF {e}")?,
             TableVersion::Function(func) => write!(f, "{func}")?,
@@ -2655,6 +2739,9 @@ pub struct Join {
     /// ClickHouse supports the optional `GLOBAL` keyword before the join operator.
     /// See [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/select/join)
     pub global: bool,
+    /// ClickHouse join strictness (`ANY`, `ALL` or `ASOF`) preceding the join kind.
+    /// See [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/select/join#supported-types-of-join)
+    pub strictness: Option<JoinStrictness>,
     /// The join operator and its constraint (INNER/LEFT/RIGHT/CROSS/ASOF/etc.).
     pub join_operator: JoinOperator,
 }
@@ -2685,6 +2772,9 @@ impl fmt::Display for Join {
         if self.global {
             write!(f, "GLOBAL ")?;
         }
+        if let Some(strictness) = &self.strictness {
+            write!(f, "{strictness} ")?;
+        }
 
         match &self.join_operator {
             JoinOperator::Join(constraint) => f.write_fmt(format_args!(
@@ -2789,6 +2879,31 @@ impl fmt::Display for Join {
     }
 }
 
+/// ClickHouse join strictness, controlling how rows with multiple matches are joined.
+///
+/// See [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/select/join#supported-types-of-join)
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum JoinStrictness {
+    /// `ANY`: at most one matching row is joined.
+    Any,
+    /// `ALL`: every matching row is joined (the default).
+    All,
+    /// `ASOF`: joins the closest match according to the last `ON` condition.
+    Asof,
+}
+
+impl fmt::Display for JoinStrictness {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JoinStrictness::Any => write!(f, "ANY"),
+            JoinStrictness::All => write!(f, "ALL"),
+            JoinStrictness::Asof => write!(f, "ASOF"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
@@ -3038,16 +3153,16 @@ impl fmt::Display for OrderByOptions {
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 /// Represents the different syntactic forms of `LIMIT` clauses.
 pub enum LimitClause {
-    /// Standard SQL `LIMIT` syntax (optionally `BY` and `OFFSET`).
+    /// Standard SQL `LIMIT` syntax (optionally preceded by a `LIMIT ... BY` clause).
     ///
-    /// `LIMIT <limit> [BY <expr>,<expr>,...] [OFFSET <offset>]`
+    /// `[LIMIT <n> [OFFSET <m>] BY <expr>,<expr>,...] LIMIT <limit> [OFFSET <offset>]`
     LimitOffset {
         /// `LIMIT { <N> | ALL }` expression.
         limit: Option<Expr>,
         /// Optional `OFFSET` expression with optional `ROW(S)` keyword.
         offset: Option<Offset>,
-        /// Optional `BY { <expr>,... }` list used by some dialects (ClickHouse).
-        limit_by: Vec<Expr>,
+        /// Optional `LIMIT <n> [OFFSET <m>] BY <expr>,...` clause used by some dialects (ClickHouse).
+        limit_by: Option<LimitBy>,
     },
     /// MySQL-specific syntax: `LIMIT <offset>, <limit>` (order reversed).
     OffsetCommaLimit {
@@ -3066,16 +3181,15 @@ impl fmt::Display for LimitClause {
                 limit_by,
                 offset,
             } => {
+                if let Some(ref limit_by) = limit_by {
+                    write!(f, " {limit_by}")?;
+                }
                 if let Some(ref limit) = limit {
                     write!(f, " LIMIT {limit}")?;
                 }
                 if let Some(ref offset) = offset {
                     write!(f, " {offset}")?;
                 }
-                if !limit_by.is_empty() {
-                    debug_assert!(limit.is_some());
-                    write!(f, " BY {}", display_separated(limit_by, ", "))?;
-                }
                 Ok(())
             }
             LimitClause::OffsetCommaLimit { offset, limit } => {
@@ -3085,6 +3199,32 @@ impl fmt::Display for LimitClause {
     }
 }
 
+/// ClickHouse `LIMIT <n> [OFFSET <m>] BY <expr>, ...` clause, which keeps the
+/// first `n` rows for each distinct value of the `BY` expressions.
+///
+/// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/select/limit-by)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct LimitBy {
+    /// Number of rows kept per group.
+    pub limit: Expr,
+    /// Optional number of rows skipped per group.
+    pub offset: Option<Offset>,
+    /// The grouping expressions following `BY`.
+    pub exprs: Vec<Expr>,
+}
+
+impl fmt::Display for LimitBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LIMIT {}", self.limit)?;
+        if let Some(ref offset) = self.offset {
+            write!(f, " {offset}")?;
+        }
+        write!(f, " BY {}", display_comma_separated(&self.exprs))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
@@ -3813,6 +3953,75 @@ impl fmt::Display for InputFormatClause {
     }
 }
 
+/// A query hint in an MSSQL `OPTION` clause.
+///
+/// See <https://learn.microsoft.com/en-us/sql/t-sql/queries/hints-transact-sql-query>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum QueryHint {
+    /// One or more keywords, optionally followed by an argument, e.g.
+    /// `RECOMPILE`, `HASH JOIN`, `MAXDOP 1` or `MAX_GRANT_PERCENT = 25`.
+    Keyword {
+        /// The hint keywords.
+        keywords: Vec<Ident>,
+        /// Whether the argument was introduced by `=`.
+        has_eq: bool,
+        /// The optional argument.
+        value: Option<Expr>,
+    },
+    /// `OPTIMIZE FOR ( @variable_name { UNKNOWN | = literal_constant } [ , ...n ] )`
+    OptimizeFor(Vec<OptimizeForVariable>),
+    /// `OPTIMIZE FOR UNKNOWN`
+    OptimizeForUnknown,
+    /// `USE HINT ( 'hint_name' [ , ...n ] )`
+    UseHint(Vec<Value>),
+}
+
+impl fmt::Display for QueryHint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            QueryHint::Keyword {
+                keywords,
+                has_eq,
+                value,
+            } => {
+                write!(f, "{}", display_separated(keywords, " "))?;
+                if let Some(value) = value {
+                    let eq = if *has_eq { " =" } else { "" };
+                    write!(f, "{eq} {value}")?;
+                }
+                Ok(())
+            }
+            QueryHint::OptimizeFor(variables) => {
+                write!(f, "OPTIMIZE FOR ({})", display_comma_separated(variables))
+            }
+            QueryHint::OptimizeForUnknown => write!(f, "OPTIMIZE FOR UNKNOWN"),
+            QueryHint::UseHint(hints) => write!(f, "USE HINT ({})", display_comma_separated(hints)),
+        }
+    }
+}
+
+/// A variable binding in an MSSQL `OPTIMIZE FOR` query hint.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct OptimizeForVariable {
+    /// The variable name, e.g. `@p`.
+    pub name: Ident,
+    /// The value to optimize for, or `None` for `UNKNOWN`.
+    pub value: Option<Expr>,
+}
+
+impl fmt::Display for OptimizeForVariable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.value {
+            Some(value) => write!(f, "{} = {value}", self.name),
+            None => write!(f, "{} UNKNOWN", self.name),
+        }
+    }
+}
+
 /// `FOR XML` or `FOR JSON` clause (MSSQL): formats the output of a query as XML or JSON.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -29,25 +29,26 @@ use crate::tokenizer::Span;
 
 use super::{
     comments, dcl::SecondaryRoles, value::ValueWithSpan, AccessExpr, AlterColumnOperation,
-    AlterIndexOperation, AlterTableOperation, Analyze, Array, Assignment, AssignmentTarget,
-    AttachedToken, BeginEndStatements, CaseStatement, CloseCursor, ClusteredIndex, ColumnDef,
-    ColumnOption, ColumnOptionDef, ConditionalStatementBlock, ConditionalStatements,
-    ConflictTarget, ConnectByKind, ConstraintCharacteristics, CopySource, CreateIndex, CreateTable,
-    CreateTableOptions, Cte, Delete, DoUpdate, ExceptSelectItem, ExcludeSelectItem, Expr,
-    ExprWithAlias, Fetch, ForValues, FromTable, Function, FunctionArg, FunctionArgExpr,
-    FunctionArgumentClause, FunctionArgumentList, FunctionArguments, GroupByExpr, HavingBound,
-    IfStatement, IlikeSelectItem, IndexColumn, Insert, Interpolate, InterpolateExpr, Join,
-    JoinConstraint, JoinOperator, JsonPath, JsonPathElem, LateralView, LimitClause,
-    MatchRecognizePattern, Measure, Merge, MergeAction, MergeClause, MergeInsertExpr,
-    MergeInsertKind, MergeUpdateExpr, NamedParenthesizedList, NamedWindowDefinition, ObjectName,
-    ObjectNamePart, Offset, OnConflict, OnConflictAction, OnInsert, OpenStatement, OrderBy,
-    OrderByExpr, OrderByKind, OutputClause, Parens, Partition, PartitionBoundValue,
-    PivotValueSource, ProjectionSelect, Query, RaiseStatement, RaiseStatementValue,
-    ReferentialAction, RenameSelectItem, ReplaceSelectElement, ReplaceSelectItem, Select,
-    SelectInto, SelectItem, SetExpr, SqlOption, Statement, Subscript, SymbolDefinition, TableAlias,
-    TableAliasColumnDef, TableConstraint, TableFactor, TableObject, TableOptionsClustered,
-    TableWithJoins, Update, UpdateTableFromKind, Use, Values, ViewColumnDef, WhileStatement,
-    WildcardAdditionalOptions, With, WithFill,
+    AlterIndexOperation, AlterTableOperation, AlterViewOperation, Analyze, Array, Assignment,
+    AssignmentTarget, AttachedToken, BeginEndStatements, CaseStatement, CloseCursor,
+    ClusteredIndex, ColumnDef, ColumnOption, ColumnOptionDef, ConditionalStatementBlock,
+    ConditionalStatements, ConflictTarget, ConnectByKind, ConstraintCharacteristics, CopySource,
+    CreateIndex, CreateTable, CreateTableOptions, Cte, Delete, DoUpdate, ExceptSelectItem,
+    ExcludeSelectItem, Expr, ExprWithAlias, Fetch, ForStatement, ForValues, FromTable, Function,
+    FunctionArg, FunctionArgExpr, FunctionArgumentClause, FunctionArgumentList, FunctionArguments,
+    GroupByExpr, HavingBound, IfStatement, IlikeSelectItem, IndexColumn, Insert, Interpolate,
+    InterpolateExpr, Join, JoinConstraint, JoinOperator, JsonPath, JsonPathElem, LateralView,
+    LimitBy, LimitClause, LoopControlStatement, MacroSegment, MatchRecognizePattern, Measure,
+    Merge, MergeAction, MergeClause, MergeInsertExpr, MergeInsertKind, MergeUpdateExpr,
+    NamedParenthesizedList, NamedWindowDefinition, ObjectName, ObjectNamePart, Offset, OnConflict,
+    OnConflictAction, OnInsert, OpenStatement, OrderBy, OrderByExpr, OrderByKind, OutputClause,
+    Parens, Partition, PartitionBoundValue, PeriodDefinition, PivotValueSource, ProjectionSelect,
+    Query, RaiseStatement, RaiseStatementValue, ReferentialAction, RenameSelectItem,
+    RepeatStatement, ReplaceSelectElement, ReplaceSelectItem, Select, SelectInto, SelectItem,
+    SetExpr, SqlOption, Statement, Subscript, SymbolDefinition, TableAlias, TableAliasColumnDef,
+    TableConstraint, TableFactor, TableObject, TableOptionsClustered, TableWithJoins, Update,
+    UpdateTableFromKind, Use, Values, ViewColumnDef, WhileStatement, WildcardAdditionalOptions,
+    With, WithFill,
 };
 
 /// Given an iterator of spans, return the [Span::union] of all spans.
@@ -125,6 +126,7 @@ impl Spanned for Query {
             settings: _,       // todo, clickhouse specific
             format_clause: _,  // todo, clickhouse specific
             pipe_operators: _, // todo bigquery specific
+            options: _,        // todo, mssql specific
         } = self;
 
         union_spans(
@@ -150,13 +152,29 @@ impl Spanned for LimitClause {
                     .iter()
                     .map(|i| i.span())
                     .chain(offset.as_ref().map(|i| i.span()))
-                    .chain(limit_by.iter().map(|i| i.span())),
+                    .chain(limit_by.as_ref().map(|i| i.span())),
             ),
             LimitClause::OffsetCommaLimit { offset, limit } => offset.span().union(&limit.span()),
         }
     }
 }
 
+impl Spanned for LimitBy {
+    fn span(&self) -> Span {
+        let LimitBy {
+            limit,
+            offset,
+            exprs,
+        } = self;
+
+        union_spans(
+            core::iter::once(limit.span())
+                .chain(offset.as_ref().map(|i| i.span()))
+                .chain(exprs.iter().map(|i| i.span())),
+        )
+    }
+}
+
 impl Spanned for Offset {
     fn span(&self) -> Span {
         let Offset {
@@ -257,6 +275,7 @@ impl Spanned for Values {
 ///
 /// Missing spans:
 /// - [Statement::CopyIntoSnowflake]
+/// - [Statement::CopyIntoDatabricks]
 /// - [Statement::CreateSecret]
 /// - [Statement::CreateRole]
 /// - [Statement::AlterType]
@@ -271,6 +290,22 @@ impl Spanned for Values {
 /// - [Statement::DropSecret]
 /// - [Statement::Declare]
 /// - [Statement::CreateExtension]
+/// - [Statement::CreateLanguage]
+/// - [Statement::DropLanguage]
+/// - [Statement::CreateTransform]
+/// - [Statement::DropTransform]
+/// - [Statement::CreateConversion]
+/// - [Statement::DropConversion]
+/// - [Statement::CreateTextSearch]
+/// - [Statement::AlterTextSearchConfiguration]
+/// - [Statement::CreateStatistics]
+/// - [Statement::AlterStatistics]
+/// - [Statement::CreateCatalog]
+/// - [Statement::AlterCatalog]
+/// - [Statement::AlterServer]
+/// - [Statement::CreateUserMapping]
+/// - [Statement::AlterUserMapping]
+/// - [Statement::DropUserMapping]
 /// - [Statement::CreateCollation]
 /// - [Statement::AlterCollation]
 /// - [Statement::Fetch]
@@ -294,9 +329,15 @@ impl Spanned for Values {
 /// - [Statement::CreateFunction]
 /// - [Statement::CreateTrigger]
 /// - [Statement::DropTrigger]
+/// - [Statement::CreateEvent]
+/// - [Statement::AlterEvent]
 /// - [Statement::CreateProcedure]
 /// - [Statement::CreateMacro]
 /// - [Statement::CreateStage]
+/// - [Statement::CreateIntegration]
+/// - [Statement::AlterIntegration]
+/// - [Statement::DropIntegration]
+/// - [Statement::DescribeIntegration]
 /// - [Statement::Assert]
 /// - [Statement::Grant]
 /// - [Statement::Revoke]
@@ -338,6 +379,9 @@ impl Spanned for Statement {
             Statement::Case(stmt) => stmt.span(),
             Statement::If(stmt) => stmt.span(),
             Statement::While(stmt) => stmt.span(),
+            Statement::For(stmt) => stmt.span(),
+            Statement::Repeat(stmt) => stmt.span(),
+            Statement::LoopControl(stmt) => stmt.span(),
             Statement::Raise(stmt) => stmt.span(),
             Statement::Call(function) => function.span(),
             Statement::Copy {
@@ -364,6 +408,7 @@ impl Spanned for Statement {
                 from_query: _,
                 partition: _,
             } => Span::empty(),
+            Statement::CopyIntoDatabricks(_) => Span::empty(),
             Statement::Open(open) => open.span(),
             Statement::Close { cursor } => match cursor {
                 CloseCursor::All => Span::empty(),
@@ -377,22 +422,37 @@ impl Spanned for Statement {
                 name,
                 if_not_exists: _,
                 module_name,
-                module_args,
-            } => union_spans(
-                core::iter::once(name.span())
-                    .chain(core::iter::once(module_name.span))
-                    .chain(module_args.iter().map(|i| i.span)),
-            ),
+                module_args: _,
+            } => {
+                union_spans(core::iter::once(name.span()).chain(core::iter::once(module_name.span)))
+            }
             Statement::CreateIndex(create_index) => create_index.span(),
             Statement::CreateRole(create_role) => create_role.span(),
             Statement::CreateExtension(create_extension) => create_extension.span(),
             Statement::CreateCollation(create_collation) => create_collation.span(),
             Statement::DropExtension(drop_extension) => drop_extension.span(),
+            Statement::CreateLanguage(create_language) => create_language.span(),
+            Statement::DropLanguage(drop_language) => drop_language.span(),
+            Statement::CreateTransform(create_transform) => create_transform.span(),
+            Statement::DropTransform(drop_transform) => drop_transform.span(),
+            Statement::CreateConversion(create_conversion) => create_conversion.span(),
+            Statement::DropConversion(drop_conversion) => drop_conversion.span(),
+            Statement::CreateTextSearch(create_text_search) => create_text_search.span(),
+            Statement::AlterTextSearchConfiguration(alter_text_search) => alter_text_search.span(),
+            Statement::CreateStatistics(create_statistics) => create_statistics.span(),
+            Statement::AlterStatistics(alter_statistics) => alter_statistics.span(),
+            Statement::CreateCatalog(create_catalog) => create_catalog.span(),
+            Statement::CreateSynonym(create_synonym) => create_synonym.span(),
+            Statement::AlterCatalog(alter_catalog) => alter_catalog.span(),
             Statement::DropOperator(drop_operator) => drop_operator.span(),
             Statement::DropOperatorFamily(drop_operator_family) => drop_operator_family.span(),
             Statement::DropOperatorClass(drop_operator_class) => drop_operator_class.span(),
             Statement::CreateSecret { .. } => Span::empty(),
             Statement::CreateServer { .. } => Span::empty(),
+            Statement::AlterServer { .. } => Span::empty(),
+            Statement::CreateUserMapping { .. } => Span::empty(),
+            Statement::AlterUserMapping { .. } => Span::empty(),
+            Statement::DropUserMapping { .. } => Span::empty(),
             Statement::CreateConnector { .. } => Span::empty(),
             Statement::CreateOperator(create_operator) => create_operator.span(),
             Statement::CreateOperatorFamily(create_operator_family) => {
@@ -402,15 +462,16 @@ impl Spanned for Statement {
             Statement::AlterTable(alter_table) => alter_table.span(),
             Statement::AlterIndex { name, operation } => name.span().union(&operation.span()),
             Statement::AlterView {
+                if_exists: _,
                 name,
-                columns,
-                query,
-                with_options,
+                operation,
+            } => name.span().union(&operation.span()),
+            Statement::AlterMaterializedView {
+                if_exists: _,
+                name,
+                operations,
             } => union_spans(
-                core::iter::once(name.span())
-                    .chain(columns.iter().map(|i| i.span))
-                    .chain(core::iter::once(query.span()))
-                    .chain(with_options.iter().map(|i| i.span())),
+                core::iter::once(name.span()).chain(operations.iter().map(|op| op.span())),
             ),
             // These statements need to be implemented
             Statement::AlterFunction { .. } => Span::empty(),
@@ -434,6 +495,16 @@ impl Spanned for Statement {
             Statement::Flush { .. } => Span::empty(),
             Statement::Discard { .. } => Span::empty(),
             Statement::Set(_) => Span::empty(),
+            Statement::SetSessionOption {
+                names,
+                target_table,
+                value: _,
+            } => union_spans(
+                names
+                    .iter()
+                    .map(|i| i.span)
+                    .chain(target_table.iter().map(|t| t.span())),
+            ),
             Statement::ShowFunctions { .. } => Span::empty(),
             Statement::ShowVariable { .. } => Span::empty(),
             Statement::ShowStatus { .. } => Span::empty(),
@@ -451,12 +522,19 @@ impl Spanned for Statement {
             Statement::CreateSchema { .. } => Span::empty(),
             Statement::CreateDatabase { .. } => Span::empty(),
             Statement::CreateFunction { .. } => Span::empty(),
+            Statement::CreateTableFunction { .. } => Span::empty(),
             Statement::CreateDomain { .. } => Span::empty(),
             Statement::CreateTrigger { .. } => Span::empty(),
+            Statement::CreateEvent { .. } => Span::empty(),
+            Statement::AlterEvent { .. } => Span::empty(),
             Statement::DropTrigger { .. } => Span::empty(),
             Statement::CreateProcedure { .. } => Span::empty(),
             Statement::CreateMacro { .. } => Span::empty(),
             Statement::CreateStage { .. } => Span::empty(),
+            Statement::CreateIntegration { .. } => Span::empty(),
+            Statement::AlterIntegration { .. } => Span::empty(),
+            Statement::DropIntegration { .. } => Span::empty(),
+            Statement::DescribeIntegration { .. } => Span::empty(),
             Statement::Assert { .. } => Span::empty(),
             Statement::Grant { .. } => Span::empty(),
             Statement::Deny { .. } => Span::empty(),
@@ -518,6 +596,8 @@ impl Spanned for Statement {
             Statement::Vacuum(..) => Span::empty(),
             Statement::AlterUser(..) => Span::empty(),
             Statement::Reset(..) => Span::empty(),
+            Statement::RefreshMaterializedView { name, .. } => name.span(),
+            Statement::Unparsed { tokens, .. } => union_spans(tokens.0.iter().map(|t| t.span)),
         }
     }
 }
@@ -558,6 +638,7 @@ impl Spanned for CreateTable {
             name,
             columns,
             constraints,
+            periods,
             hive_distribution: _, // hive specific
             hive_formats: _,      // hive specific
             file_format: _,       // enum
@@ -566,6 +647,8 @@ impl Spanned for CreateTable {
             without_rowid: _, // bool
             like: _,
             clone,
+            copy,
+            with_connection,
             comment: _, // todo, no span
             on_commit: _,
             on_cluster: _,   // todo, clickhouse specific
@@ -574,6 +657,7 @@ impl Spanned for CreateTable {
             partition_by: _, // todo, BigQuery specific
             cluster_by: _,   // todo, BigQuery specific
             clustered_by: _, // todo, Hive specific
+            skewed_by: _,    // todo, Hive specific
             inherits: _,     // todo, PostgreSQL specific
             partition_of,
             for_values,
@@ -604,6 +688,7 @@ impl Spanned for CreateTable {
             distkey: _,
             sortkey: _,
             backup: _,
+            with_system_versioning: _, // bool
         } = self;
 
         union_spans(
@@ -611,8 +696,11 @@ impl Spanned for CreateTable {
                 .chain(core::iter::once(table_options.span()))
                 .chain(columns.iter().map(|i| i.span()))
                 .chain(constraints.iter().map(|i| i.span()))
+                .chain(periods.iter().map(|i| i.span()))
                 .chain(query.iter().map(|i| i.span()))
                 .chain(clone.iter().map(|i| i.span()))
+                .chain(copy.iter().map(|i| i.span()))
+                .chain(with_connection.iter().map(|i| i.span()))
                 .chain(partition_of.iter().map(|i| i.span()))
                 .chain(for_values.iter().map(|i| i.span())),
         )
@@ -650,6 +738,7 @@ impl Spanned for TableConstraint {
             TableConstraint::FulltextOrSpatial(constraint) => constraint.span(),
             TableConstraint::PrimaryKeyUsingIndex(constraint)
             | TableConstraint::UniqueUsingIndex(constraint) => constraint.span(),
+            TableConstraint::Exclude(constraint) => constraint.span(),
         }
     }
 }
@@ -758,6 +847,42 @@ impl Spanned for WhileStatement {
     }
 }
 
+impl Spanned for ForStatement {
+    fn span(&self) -> Span {
+        let ForStatement {
+            variable,
+            query,
+            body,
+        } = self;
+
+        union_spans(
+            iter::once(variable.span)
+                .chain(iter::once(query.span()))
+                .chain(body.iter().map(|s| s.span())),
+        )
+    }
+}
+
+impl Spanned for RepeatStatement {
+    fn span(&self) -> Span {
+        let RepeatStatement { body, until } = self;
+
+        union_spans(
+            body.iter()
+                .map(|s| s.span())
+                .chain(iter::once(until.span())),
+        )
+    }
+}
+
+impl Spanned for LoopControlStatement {
+    fn span(&self) -> Span {
+        let LoopControlStatement { kind: _, label } = self;
+
+        label.as_ref().map_or(Span::empty(), |l| l.span)
+    }
+}
+
 impl Spanned for ConditionalStatements {
     fn span(&self) -> Span {
         match self {
@@ -840,6 +965,7 @@ impl Spanned for ColumnOption {
             ColumnOption::Tags(..) => Span::empty(),
             ColumnOption::Srid(..) => Span::empty(),
             ColumnOption::Invisible => Span::empty(),
+            ColumnOption::GeneratedAsRow { .. } => Span::empty(),
         }
     }
 }
@@ -855,9 +981,10 @@ impl Spanned for ReferentialAction {
 impl Spanned for ConstraintCharacteristics {
     fn span(&self) -> Span {
         let ConstraintCharacteristics {
-            deferrable: _, // bool
-            initially: _,  // enum
-            enforced: _,   // bool
+            deferrable: _,          // bool
+            initially: _,           // enum
+            enforced: _,            // bool
+            not_for_replication: _, // bool
         } = self;
 
         Span::empty()
@@ -887,6 +1014,10 @@ impl Spanned for Analyze {
 /// - [AlterColumnOperation::DropNotNull]
 /// - [AlterColumnOperation::DropDefault]
 /// - [AlterColumnOperation::AddGenerated]
+/// - [AlterColumnOperation::SetGenerated]
+/// - [AlterColumnOperation::DropIdentity]
+/// - [AlterColumnOperation::SetStatistics]
+/// - [AlterColumnOperation::SetStorage]
 impl Spanned for AlterColumnOperation {
     fn span(&self) -> Span {
         match self {
@@ -900,6 +1031,16 @@ impl Spanned for AlterColumnOperation {
                 had_set: _,
             } => using.as_ref().map_or(Span::empty(), |u| u.span()),
             AlterColumnOperation::AddGenerated { .. } => Span::empty(),
+            AlterColumnOperation::SetGenerated { .. } => Span::empty(),
+            AlterColumnOperation::DropIdentity { .. } => Span::empty(),
+            AlterColumnOperation::SetTag { tag_list } => {
+                union_spans(tag_list.iter().map(|t| t.key.span()))
+            }
+            AlterColumnOperation::SetStatistics { .. } => Span::empty(),
+            AlterColumnOperation::SetStorage { .. } => Span::empty(),
+            AlterColumnOperation::UnsetTag { tag_names } => {
+                union_spans(tag_names.iter().map(|n| n.span()))
+            }
         }
     }
 }
@@ -923,6 +1064,7 @@ impl Spanned for Delete {
         let Delete {
             delete_token,
             optimizer_hints: _,
+            top: _,
             tables,
             from,
             using,
@@ -931,6 +1073,7 @@ impl Spanned for Delete {
             output,
             order_by,
             limit,
+            options: _,
         } = self;
 
         union_spans(
@@ -959,6 +1102,7 @@ impl Spanned for Update {
         let Update {
             update_token,
             optimizer_hints: _,
+            top: _,
             table,
             assignments,
             from,
@@ -968,6 +1112,7 @@ impl Spanned for Update {
             or: _,
             order_by,
             limit,
+            options: _,
         } = self;
 
         union_spans(
@@ -1028,6 +1173,15 @@ impl Spanned for SqlOption {
             SqlOption::Clustered(table_options_clustered) => table_options_clustered.span(),
             SqlOption::Ident(ident) => ident.span,
             SqlOption::KeyValue { key, value } => key.span.union(&value.span()),
+            SqlOption::KeyValueWithOptions {
+                key,
+                value,
+                options,
+            } => union_spans(
+                core::iter::once(key.span)
+                    .chain(core::iter::once(value.span()))
+                    .chain(options.iter().map(|o| o.span())),
+            ),
             SqlOption::Partition {
                 column_name,
                 range_direction: _,
@@ -1105,6 +1259,9 @@ impl Spanned for AlterTableOperation {
                 column_def,
                 column_position: _,
             } => column_def.span(),
+            AlterTableOperation::AddColumns { column_defs, .. } => {
+                union_spans(column_defs.iter().map(|c| c.span()))
+            }
             AlterTableOperation::AddProjection {
                 if_not_exists: _,
                 name,
@@ -1149,6 +1306,30 @@ impl Spanned for AlterTableOperation {
             } => partition
                 .span()
                 .union_opt(&with_name.as_ref().map(|n| n.span)),
+            AlterTableOperation::Update {
+                assignments,
+                in_partition,
+                selection,
+            } => union_spans(
+                assignments
+                    .iter()
+                    .map(|a| a.span())
+                    .chain(in_partition.iter().map(|p| p.span()))
+                    .chain(core::iter::once(selection.span())),
+            ),
+            AlterTableOperation::Delete {
+                in_partition,
+                selection,
+            } => selection
+                .span()
+                .union_opt(&in_partition.as_ref().map(|p| p.span())),
+            AlterTableOperation::ModifyTtl { clauses } => {
+                union_spans(clauses.iter().map(|c| c.expr.span()))
+            }
+            AlterTableOperation::RemoveTtl => Span::empty(),
+            AlterTableOperation::ModifyQuery { query } => query.span(),
+            AlterTableOperation::SetComment { .. } => Span::empty(),
+            AlterTableOperation::CommentColumn { column, .. } => column.span,
             AlterTableOperation::DropPrimaryKey { .. } => Span::empty(),
             AlterTableOperation::DropForeignKey { name, .. } => name.span,
             AlterTableOperation::DropIndex { name } => name.span,
@@ -1208,27 +1389,56 @@ impl Spanned for AlterTableOperation {
             AlterTableOperation::AlterColumn { column_name, op } => {
                 column_name.span.union(&op.span())
             }
+            AlterTableOperation::ModifyColumnOperation {
+                has_column_keyword: _,
+                column_name,
+                op,
+            } => column_name.span.union(&op.span()),
             AlterTableOperation::SwapWith { table_name } => table_name.span(),
             AlterTableOperation::SetTblProperties { table_properties } => {
                 union_spans(table_properties.iter().map(|i| i.span()))
             }
             AlterTableOperation::OwnerTo { .. } => Span::empty(),
+            AlterTableOperation::SetSchema { schema_name } => schema_name.span(),
+            AlterTableOperation::Inherit { parent } => parent.span(),
+            AlterTableOperation::NoInherit { parent } => parent.span(),
+            AlterTableOperation::Of { type_name } => type_name.span(),
+            AlterTableOperation::NotOf => Span::empty(),
             AlterTableOperation::ClusterBy { exprs } => union_spans(exprs.iter().map(|e| e.span())),
             AlterTableOperation::DropClusteringKey => Span::empty(),
             AlterTableOperation::AlterSortKey { .. } => Span::empty(),
             AlterTableOperation::SuspendRecluster => Span::empty(),
             AlterTableOperation::ResumeRecluster => Span::empty(),
+            AlterTableOperation::SetTag { tag_list } => {
+                union_spans(tag_list.iter().map(|t| t.key.span()))
+            }
+            AlterTableOperation::UnsetTag { tag_names } => {
+                union_spans(tag_names.iter().map(|n| n.span()))
+            }
+            AlterTableOperation::AddSearchOptimization { on }
+            | AlterTableOperation::DropSearchOptimization { on } => {
+                union_spans(on.iter().flatten().map(|e| e.span()))
+            }
+            AlterTableOperation::AddSystemVersioning => Span::empty(),
+            AlterTableOperation::DropSystemVersioning => Span::empty(),
             AlterTableOperation::Refresh { .. } => Span::empty(),
             AlterTableOperation::Suspend => Span::empty(),
             AlterTableOperation::Resume => Span::empty(),
+            AlterTableOperation::Force => Span::empty(),
             AlterTableOperation::Algorithm { .. } => Span::empty(),
             AlterTableOperation::AutoIncrement { value, .. } => value.span(),
+            AlterTableOperation::ConvertToCharacterSet { charset, collation } => {
+                union_spans(iter::once(charset.span).chain(collation.iter().map(|c| c.span)))
+            }
             AlterTableOperation::Lock { .. } => Span::empty(),
             AlterTableOperation::ReplicaIdentity { .. } => Span::empty(),
             AlterTableOperation::ValidateConstraint { name } => name.span,
             AlterTableOperation::SetOptionsParens { options } => {
                 union_spans(options.iter().map(|i| i.span()))
             }
+            AlterTableOperation::ResetOptionsParens { options } => {
+                union_spans(options.iter().map(|i| i.span()))
+            }
         }
     }
 }
@@ -1311,6 +1521,18 @@ impl Spanned for InterpolateExpr {
     }
 }
 
+impl Spanned for PeriodDefinition {
+    fn span(&self) -> Span {
+        let PeriodDefinition {
+            name,
+            start_column,
+            end_column,
+        } = self;
+
+        name.span.union(&start_column.span).union(&end_column.span)
+    }
+}
+
 impl Spanned for AlterIndexOperation {
     fn span(&self) -> Span {
         match self {
@@ -1319,6 +1541,42 @@ impl Spanned for AlterIndexOperation {
     }
 }
 
+/// # partial span
+///
+/// Missing spans:
+/// - [AlterViewOperation::OwnerTo]
+/// - [AlterViewOperation::SetSecure]
+/// - [AlterViewOperation::UnsetSecure]
+impl Spanned for AlterViewOperation {
+    fn span(&self) -> Span {
+        match self {
+            AlterViewOperation::As {
+                columns,
+                query,
+                with_options,
+            } => union_spans(
+                columns
+                    .iter()
+                    .map(|i| i.span)
+                    .chain(core::iter::once(query.span()))
+                    .chain(with_options.iter().map(|i| i.span())),
+            ),
+            AlterViewOperation::RenameTo { new_name } => new_name.span(),
+            AlterViewOperation::SetSchema { schema_name } => schema_name.span(),
+            AlterViewOperation::SetOptions { options } => {
+                union_spans(options.iter().map(|i| i.span()))
+            }
+            AlterViewOperation::ResetOptions { names } => union_spans(names.iter().map(|i| i.span)),
+            AlterViewOperation::OwnerTo { .. } => Span::empty(),
+            AlterViewOperation::SetSecure => Span::empty(),
+            AlterViewOperation::UnsetSecure => Span::empty(),
+            AlterViewOperation::AlterColumnSetDefault { column, default } => {
+                column.span.union(&default.span())
+            }
+        }
+    }
+}
+
 /// # partial span
 ///
 /// Missing spans:ever
@@ -1332,8 +1590,10 @@ impl Spanned for Insert {
             ignore: _, // bool
             into: _,   // bool
             table,
+            with_hints: _, // todo, mssql specific
             table_alias,
             columns,
+            by_name: _,   // bool
             overwrite: _, // bool
             source,
             partitioned,
@@ -1482,6 +1742,7 @@ impl Spanned for Expr {
                 expr,
                 list,
                 negated: _,
+                global: _,
             } => union_spans(
                 core::iter::once(expr.span()).chain(list.iter().map(|item| item.span())),
             ),
@@ -1489,6 +1750,7 @@ impl Spanned for Expr {
                 expr,
                 subquery,
                 negated: _,
+                global: _,
             } => expr.span().union(&subquery.span()),
             Expr::InUnnest {
                 expr,
@@ -1518,6 +1780,7 @@ impl Spanned for Expr {
                 any: _,
             } => expr.span().union(&pattern.span()),
             Expr::RLike { .. } => Span::empty(),
+            Expr::Normalize { expr, form: _ } => expr.span(),
             Expr::IsNormalized {
                 expr,
                 form: _,
@@ -1588,6 +1851,7 @@ impl Spanned for Expr {
                 data_type: _,
                 array: _,
                 format: _,
+                charset: _,
             } => expr.span(),
             Expr::AtTimeZone {
                 timestamp,
@@ -1652,6 +1916,7 @@ impl Spanned for Expr {
             Expr::Map(_) => Span::empty(),
             Expr::Interval(interval) => interval.value.span(),
             Expr::Wildcard(token) => token.0.span,
+            Expr::Default => Span::empty(),
             Expr::QualifiedWildcard(object_name, token) => union_spans(
                 object_name
                     .0
@@ -1713,6 +1978,11 @@ impl Spanned for ObjectNamePart {
                 .name
                 .span
                 .union(&union_spans(func.args.iter().map(|i| i.span()))),
+            ObjectNamePart::Macro(name) => {
+                union_spans(name.segments.iter().map(|segment| match segment {
+                    MacroSegment::Text(ident) | MacroSegment::Macro(ident) => ident.span,
+                }))
+            }
         }
     }
 }
@@ -1952,6 +2222,7 @@ impl Spanned for TableFactor {
                 json_path: _,
                 sample: _,
                 index_hints: _,
+                final_modifier: _,
             } => union_spans(
                 name.0
                     .iter()
@@ -1985,7 +2256,7 @@ impl Spanned for TableFactor {
                     .iter()
                     .map(|i| i.span())
                     .chain(array_exprs.iter().map(|i| i.span()))
-                    .chain(with_offset_alias.as_ref().map(|i| i.span)),
+                    .chain(with_offset_alias.as_ref().map(|i| i.span())),
             ),
             TableFactor::NestedJoin {
                 table_with_joins,
@@ -2113,7 +2384,11 @@ impl Spanned for SymbolDefinition {
 
 impl Spanned for Measure {
     fn span(&self) -> Span {
-        let Measure { expr, alias } = self;
+        let Measure {
+            semantics: _,
+            expr,
+            alias,
+        } = self;
 
         expr.span().union(&alias.span)
     }
@@ -2167,6 +2442,7 @@ impl Spanned for FunctionArg {
 /// Missing spans:
 /// - [FunctionArgExpr::Wildcard]
 /// - [FunctionArgExpr::WildcardWithOptions]
+/// - [FunctionArgExpr::DateTimeField]
 impl Spanned for FunctionArgExpr {
     fn span(&self) -> Span {
         match self {
@@ -2176,6 +2452,7 @@ impl Spanned for FunctionArgExpr {
             }
             FunctionArgExpr::Wildcard => Span::empty(),
             FunctionArgExpr::WildcardWithOptions(_) => Span::empty(),
+            FunctionArgExpr::DateTimeField(_) => Span::empty(),
         }
     }
 }
@@ -2214,7 +2491,8 @@ impl Spanned for Join {
     fn span(&self) -> Span {
         let Join {
             relation,
-            global: _, // bool
+            global: _,     // bool
+            strictness: _, // enum
             join_operator,
         } = self;
 
@@ -2483,7 +2761,7 @@ impl Spanned for AlterTable {
         union_spans(
             core::iter::once(self.name.span())
                 .chain(self.operations.iter().map(|i| i.span()))
-                .chain(self.on_cluster.iter().map(|i| i.span))
+                .chain(self.on_cluster.iter().map(|i| i.span()))
                 .chain(core::iter::once(self.end_token.0.span)),
         )
     }
@@ -2773,6 +3051,20 @@ ALTER TABLE users
         assert_eq!(stmt_span.end, (4, 11).into());
     }
 
+    #[test]
+    fn test_alter_table_set_tag_span() {
+        let sql = "ALTER TABLE t SET TAG a.b='x', c='y'";
+
+        let r = Parser::parse_sql(&SnowflakeDialect, sql).unwrap();
+        let Statement::AlterTable(alter_table) = &r[0] else {
+            panic!("expected ALTER TABLE");
+        };
+
+        let op_span = alter_table.operations[0].span();
+        assert_eq!(op_span.start, (1, 23).into());
+        assert_eq!(op_span.end, (1, 33).into());
+    }
+
     #[test]
     fn test_update_statement_span() {
         let sql = r#"-- foo
@@ -2887,6 +3179,7 @@ WHERE id = 1
             on: _,
             clauses,
             output,
+            options: _,
         }) = &r[0]
         else {
             panic!("not a MERGE statement");
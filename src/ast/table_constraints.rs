@@ -26,7 +26,7 @@ use crate::tokenizer::Span;
 use core::fmt;
 
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, string::String, vec::Vec};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -117,6 +117,14 @@ pub enum TableConstraint {
     ///
     /// [1]: https://www.postgresql.org/docs/current/sql-altertable.html
     UniqueUsingIndex(ConstraintUsingIndex),
+    /// PostgreSQL [definition][1] for exclusion constraints:
+    ///
+    /// `[ CONSTRAINT constraint_name ] EXCLUDE [ USING index_method ]
+    ///   ( exclude_element WITH operator [, ... ] ) [ WHERE ( predicate ) ]
+    ///   [ DEFERRABLE | NOT DEFERRABLE ] [ INITIALLY DEFERRED | INITIALLY IMMEDIATE ]`
+    ///
+    /// [1]: https://www.postgresql.org/docs/current/sql-createtable.html
+    Exclude(ExclusionConstraint),
 }
 
 impl From<UniqueConstraint> for TableConstraint {
@@ -155,6 +163,12 @@ impl From<FullTextOrSpatialConstraint> for TableConstraint {
     }
 }
 
+impl From<ExclusionConstraint> for TableConstraint {
+    fn from(constraint: ExclusionConstraint) -> Self {
+        TableConstraint::Exclude(constraint)
+    }
+}
+
 impl fmt::Display for TableConstraint {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -166,6 +180,7 @@ impl fmt::Display for TableConstraint {
             TableConstraint::FulltextOrSpatial(constraint) => constraint.fmt(f),
             TableConstraint::PrimaryKeyUsingIndex(c) => c.fmt_with_keyword(f, "PRIMARY KEY"),
             TableConstraint::UniqueUsingIndex(c) => c.fmt_with_keyword(f, "UNIQUE"),
+            TableConstraint::Exclude(constraint) => constraint.fmt(f),
         }
     }
 }
@@ -179,9 +194,15 @@ pub struct CheckConstraint {
     pub name: Option<Ident>,
     /// The boolean expression the CHECK constraint enforces.
     pub expr: Box<Expr>,
+    /// PostgreSQL-specific `NO INHERIT` flag.
+    /// <https://www.postgresql.org/docs/current/sql-createtable.html>
+    pub no_inherit: bool,
     /// MySQL-specific `ENFORCED` / `NOT ENFORCED` flag.
     /// <https://dev.mysql.com/doc/refman/8.4/en/create-table.html>
     pub enforced: Option<bool>,
+    /// MSSQL-specific `NOT FOR REPLICATION` flag, written before the expression.
+    /// <https://learn.microsoft.com/en-us/sql/t-sql/statements/create-table-transact-sql>
+    pub not_for_replication: bool,
 }
 
 impl fmt::Display for CheckConstraint {
@@ -189,10 +210,18 @@ impl fmt::Display for CheckConstraint {
         use crate::ast::ddl::display_constraint_name;
         write!(
             f,
-            "{}CHECK ({})",
+            "{}CHECK {}({})",
             display_constraint_name(&self.name),
+            if self.not_for_replication {
+                "NOT FOR REPLICATION "
+            } else {
+                ""
+            },
             self.expr
         )?;
+        if self.no_inherit {
+            write!(f, " NO INHERIT")?;
+        }
         if let Some(b) = self.enforced {
             write!(f, " {}", if b { "ENFORCED" } else { "NOT ENFORCED" })
         } else {
@@ -603,3 +632,77 @@ impl crate::ast::Spanned for ConstraintUsingIndex {
         start.union(&end)
     }
 }
+
+/// An element of an [ExclusionConstraint]: `exclude_element WITH operator`.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct ExcludeElement {
+    /// The column or parenthesized expression, with optional operator class and ordering.
+    pub expr: IndexColumn,
+    /// The operator compared with, e.g. `=` or `&&`.
+    pub operator: String,
+}
+
+impl fmt::Display for ExcludeElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} WITH {}", self.expr, self.operator)
+    }
+}
+
+/// A PostgreSQL exclusion constraint.
+///
+/// `[ CONSTRAINT <name> ] EXCLUDE [ USING <index_type> ] (<element> WITH <operator> [, ...])
+/// [ WHERE (<predicate>) ] [ <characteristics> ]`
+///
+/// See <https://www.postgresql.org/docs/current/sql-createtable.html#SQL-CREATETABLE-EXCLUDE>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct ExclusionConstraint {
+    /// Optional constraint name.
+    pub name: Option<Ident>,
+    /// Optional `USING` index method, e.g. `gist`.
+    pub index_type: Option<IndexType>,
+    /// The elements and operators of the constraint.
+    pub elements: Vec<ExcludeElement>,
+    /// Optional `WHERE (predicate)` making this a partial constraint.
+    pub where_clause: Option<Box<Expr>>,
+    /// Optional characteristics like `DEFERRABLE`.
+    pub characteristics: Option<ConstraintCharacteristics>,
+}
+
+impl fmt::Display for ExclusionConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use crate::ast::ddl::{display_constraint_name, display_option, display_option_spaced};
+        write!(
+            f,
+            "{}EXCLUDE{} ({})",
+            display_constraint_name(&self.name),
+            display_option(" USING ", "", &self.index_type),
+            display_comma_separated(&self.elements),
+        )?;
+        if let Some(predicate) = &self.where_clause {
+            write!(f, " WHERE ({predicate})")?;
+        }
+        write!(f, "{}", display_option_spaced(&self.characteristics))?;
+        Ok(())
+    }
+}
+
+impl crate::ast::Spanned for ExclusionConstraint {
+    fn span(&self) -> Span {
+        fn union_spans<I: Iterator<Item = Span>>(iter: I) -> Span {
+            Span::union_iter(iter)
+        }
+
+        union_spans(
+            self.name
+                .iter()
+                .map(|i| i.span)
+                .chain(self.elements.iter().map(|e| e.expr.span()))
+                .chain(self.where_clause.iter().map(|e| e.span()))
+                .chain(self.characteristics.iter().map(|c| c.span())),
+        )
+    }
+}
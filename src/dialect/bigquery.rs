@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::ast::Statement;
+use crate::ast::{ObjectName, Statement};
 use crate::dialect::Dialect;
 use crate::keywords::Keyword;
 use crate::parser::{Parser, ParserError};
@@ -166,4 +166,39 @@ impl Dialect for BigQueryDialect {
     fn supports_comma_separated_trim(&self) -> bool {
         true
     }
+
+    /// See <https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#create_table_copy>
+    fn supports_create_table_copy(&self) -> bool {
+        true
+    }
+
+    /// See <https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#create_table_function_statement>
+    fn supports_create_table_function(&self) -> bool {
+        true
+    }
+
+    /// See <https://cloud.google.com/bigquery/docs/reference/standard-sql/procedural-language#for-in>
+    fn supports_for_in_loop(&self) -> bool {
+        true
+    }
+
+    /// See <https://cloud.google.com/bigquery/docs/reference/standard-sql/procedural-language#repeat>
+    fn supports_repeat_loop(&self) -> bool {
+        true
+    }
+
+    /// See <https://cloud.google.com/bigquery/docs/reference/standard-sql/timestamp_functions>
+    fn date_part_argument_position(&self, function_name: &ObjectName) -> Option<usize> {
+        let name = function_name.0.last()?.as_ident()?;
+        if name.quote_style.is_some() {
+            return None;
+        }
+        match name.value.to_uppercase().as_str() {
+            "DATE_DIFF" | "DATETIME_DIFF" | "TIMESTAMP_DIFF" | "TIME_DIFF" => Some(2),
+            "DATE_TRUNC" | "DATETIME_TRUNC" | "TIMESTAMP_TRUNC" | "TIME_TRUNC" | "LAST_DAY" => {
+                Some(1)
+            }
+            _ => None,
+        }
+    }
 }
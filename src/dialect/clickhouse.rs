@@ -16,6 +16,8 @@
 // under the License.
 
 use crate::dialect::Dialect;
+use crate::keywords::Keyword;
+use crate::parser::Parser;
 
 /// A [`Dialect`] for [ClickHouse](https://clickhouse.com/).
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -112,6 +114,21 @@ impl Dialect for ClickHouseDialect {
         true
     }
 
+    /// See <https://clickhouse.com/docs/en/sql-reference/statements/select/from#final-modifier>
+    fn supports_table_final_modifier(&self) -> bool {
+        true
+    }
+
+    fn is_table_factor_alias(&self, explicit: bool, kw: &Keyword, parser: &mut Parser) -> bool {
+        // `FROM t FINAL` applies the FINAL modifier and `FROM t ANY JOIN ...`
+        // a join strictness rather than aliasing the table
+        explicit
+            || (!matches!(
+                kw,
+                Keyword::FINAL | Keyword::ANY | Keyword::ALL | Keyword::ASOF
+            ) && self.is_table_alias(kw, parser))
+    }
+
     /// See <https://clickhouse.com/docs/en/sql-reference/statements/select/order-by#order-by-expr-with-fill-modifier>
     fn supports_with_fill(&self) -> bool {
         true
@@ -122,6 +139,16 @@ impl Dialect for ClickHouseDialect {
         true
     }
 
+    /// See <https://clickhouse.com/docs/en/sql-reference/statements/select/join#supported-types-of-join>
+    fn supports_join_strictness(&self) -> bool {
+        true
+    }
+
+    /// See <https://clickhouse.com/docs/en/sql-reference/operators/in#distributed-subqueries>
+    fn supports_global_in(&self) -> bool {
+        true
+    }
+
     /// See <https://clickhouse.com/docs/en/sql-reference/statements/select/order-by#order-by-expr-with-fill-modifier>
     fn supports_interpolate(&self) -> bool {
         true
@@ -145,4 +172,14 @@ impl Dialect for ClickHouseDialect {
     fn supports_comma_separated_trim(&self) -> bool {
         true
     }
+
+    /// See <https://clickhouse.com/docs/sql-reference/statements/create/table#from-select-query>
+    fn supports_create_table_as_column_names(&self) -> bool {
+        true
+    }
+
+    /// See <https://clickhouse.com/docs/en/operations/server-configuration-parameters/settings#macros>
+    fn supports_object_name_macros(&self) -> bool {
+        true
+    }
 }
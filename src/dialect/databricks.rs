@@ -15,7 +15,11 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::ast::{CopyIntoDatabricks, CopyIntoDatabricksValidate, Statement};
 use crate::dialect::Dialect;
+use crate::keywords::Keyword;
+use crate::parser::{IsOptional, Parser, ParserError};
+use crate::tokenizer::Token;
 
 /// A [`Dialect`] for [Databricks SQL](https://www.databricks.com/)
 ///
@@ -95,6 +99,23 @@ impl Dialect for DatabricksDialect {
         true
     }
 
+    /// See <https://docs.databricks.com/aws/en/sql/language-manual/sql-ref-syntax-ddl-create-table-using>
+    fn supports_create_table_using(&self) -> bool {
+        true
+    }
+
+    fn parse_statement(&self, parser: &mut Parser) -> Option<Result<Statement, ParserError>> {
+        if parser.parse_keywords(&[Keyword::COPY, Keyword::INTO]) {
+            return Some(parse_copy_into(parser).map(Into::into));
+        }
+        None
+    }
+
+    /// See <https://docs.databricks.com/aws/en/sql/language-manual/sql-ref-syntax-ddl-create-catalog>
+    fn supports_catalog_ddl(&self) -> bool {
+        true
+    }
+
     /// See <https://docs.databricks.com/aws/en/sql/language-manual/functions/bangsign>
     fn supports_bang_not_operator(&self) -> bool {
         true
@@ -109,3 +130,63 @@ impl Dialect for DatabricksDialect {
         true
     }
 }
+
+/// Parses a Databricks `COPY INTO` statement, after the `COPY INTO` keywords.
+///
+/// See [CopyIntoDatabricks] for the supported syntax.
+pub fn parse_copy_into(parser: &mut Parser) -> Result<CopyIntoDatabricks, ParserError> {
+    let into = parser.parse_object_name(false)?;
+    let into_columns = if parser.peek_token_ref().token == Token::LParen {
+        Some(parser.parse_parenthesized_column_list(IsOptional::Mandatory, false)?)
+    } else {
+        None
+    };
+
+    parser.expect_keyword_is(Keyword::FROM)?;
+    let from = parser.parse_literal_string()?;
+
+    parser.expect_keyword_is(Keyword::FILEFORMAT)?;
+    parser.expect_token(&Token::Eq)?;
+    let file_format = parser.parse_identifier()?;
+
+    let validate = if parser.parse_keyword(Keyword::VALIDATE) {
+        if parser.parse_keyword(Keyword::ALL) {
+            Some(CopyIntoDatabricksValidate::All)
+        } else if let Token::Number(_, _) = parser.peek_token_ref().token {
+            let rows = parser.parse_literal_uint()?;
+            parser.expect_keyword_is(Keyword::ROWS)?;
+            Some(CopyIntoDatabricksValidate::Rows(rows))
+        } else {
+            Some(CopyIntoDatabricksValidate::Default)
+        }
+    } else {
+        None
+    };
+
+    let mut files = None;
+    let mut pattern = None;
+    if parser.parse_keyword(Keyword::FILES) {
+        parser.expect_token(&Token::Eq)?;
+        parser.expect_token(&Token::LParen)?;
+        files = Some(parser.parse_comma_separated(Parser::parse_literal_string)?);
+        parser.expect_token(&Token::RParen)?;
+    } else if parser.parse_keyword(Keyword::PATTERN) {
+        parser.expect_token(&Token::Eq)?;
+        pattern = Some(parser.parse_literal_string()?);
+    }
+
+    let format_options = parser.parse_options(Keyword::FORMAT_OPTIONS)?;
+    let copy_options = parser.parse_options(Keyword::COPY_OPTIONS)?;
+
+    Ok(CopyIntoDatabricks {
+        into,
+        into_columns,
+        from,
+        file_format,
+        validate,
+        files,
+        pattern,
+        format_options,
+        copy_options,
+    })
+}
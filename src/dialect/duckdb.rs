@@ -100,6 +100,11 @@ impl Dialect for DuckDbDialect {
         true
     }
 
+    /// See <https://duckdb.org/docs/stable/sql/statements/insert#insert-into--by-name>
+    fn supports_insert_by_name(&self) -> bool {
+        true
+    }
+
     /// See DuckDB <https://duckdb.org/docs/sql/query_syntax/orderby.html#order-by-all-examples>
     fn supports_order_by_all(&self) -> bool {
         true
@@ -133,4 +138,9 @@ impl Dialect for DuckDbDialect {
     fn supports_comma_separated_trim(&self) -> bool {
         true
     }
+
+    /// See <https://duckdb.org/docs/sql/statements/create_table#create-table--as-select-ctas>
+    fn supports_create_table_as_column_names(&self) -> bool {
+        true
+    }
 }
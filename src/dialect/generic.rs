@@ -57,6 +57,62 @@ impl Dialect for GenericDialect {
         true
     }
 
+    fn supports_create_table_copy(&self) -> bool {
+        true
+    }
+
+    fn supports_create_table_as_column_names(&self) -> bool {
+        true
+    }
+
+    fn supports_alter_table_inherit(&self) -> bool {
+        true
+    }
+
+    fn supports_exclusion_constraint(&self) -> bool {
+        true
+    }
+
+    fn supports_extended_statistics(&self) -> bool {
+        true
+    }
+
+    fn supports_catalog_ddl(&self) -> bool {
+        true
+    }
+
+    fn supports_alter_materialized_view(&self) -> bool {
+        true
+    }
+
+    fn supports_system_versioned_tables(&self) -> bool {
+        true
+    }
+
+    fn supports_create_table_period_definitions(&self) -> bool {
+        true
+    }
+
+    fn supports_synonyms(&self) -> bool {
+        true
+    }
+
+    fn supports_alter_table_force(&self) -> bool {
+        true
+    }
+
+    fn supports_alter_table_add_multiple_columns(&self) -> bool {
+        true
+    }
+
+    fn supports_for_in_loop(&self) -> bool {
+        true
+    }
+
+    fn supports_repeat_loop(&self) -> bool {
+        true
+    }
+
     fn supports_connect_by(&self) -> bool {
         true
     }
@@ -97,6 +153,10 @@ impl Dialect for GenericDialect {
         true
     }
 
+    fn supports_niladic_sysdate(&self) -> bool {
+        true
+    }
+
     fn allow_extract_custom(&self) -> bool {
         true
     }
@@ -137,10 +197,22 @@ impl Dialect for GenericDialect {
         true
     }
 
+    fn supports_dml_top(&self) -> bool {
+        true
+    }
+
     fn supports_from_first_select(&self) -> bool {
         true
     }
 
+    fn supports_normalize_function(&self) -> bool {
+        true
+    }
+
+    fn supports_insert_by_name(&self) -> bool {
+        true
+    }
+
     fn supports_projection_trailing_commas(&self) -> bool {
         true
     }
@@ -304,4 +376,16 @@ impl Dialect for GenericDialect {
     fn supports_xml_expressions(&self) -> bool {
         true
     }
+
+    fn supports_not_for_replication(&self) -> bool {
+        true
+    }
+
+    fn supports_skewed_by(&self) -> bool {
+        true
+    }
+
+    fn supports_object_name_macros(&self) -> bool {
+        true
+    }
 }
@@ -73,6 +73,11 @@ impl Dialect for HiveDialect {
         true
     }
 
+    /// See <https://cwiki.apache.org/confluence/display/Hive/LanguageManual+DDL#LanguageManualDDL-SkewedTables>
+    fn supports_skewed_by(&self) -> bool {
+        true
+    }
+
     // TODO: The parsing of the FROM keyword seems wrong, as it happens within the CTE.
     // See https://github.com/apache/datafusion-sqlparser-rs/issues/2236 for more details.
     /// See <https://hive.apache.org/docs/latest/language/common-table-expression/>
@@ -130,7 +130,7 @@ pub use self::sqlite::SQLiteDialect;
 #[cfg(feature = "derive-dialect")]
 pub use sqlparser_derive::derive_dialect;
 
-use crate::ast::{ColumnOption, Expr, GranteesType, Ident, ObjectNamePart, Statement};
+use crate::ast::{ColumnOption, Expr, GranteesType, Ident, ObjectName, ObjectNamePart, Statement};
 pub use crate::keywords;
 use crate::keywords::Keyword;
 use crate::parser::{Parser, ParserError};
@@ -481,6 +481,20 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns true if the dialect supports specifying the null treatment
+    /// after the function's argument list.
+    ///
+    /// i.e. the following syntax returns true
+    /// ```sql
+    /// LAG(a) IGNORE NULLS OVER (ORDER BY b)
+    /// ```
+    ///
+    /// Dialects returning false parse `IGNORE` / `RESPECT` in that
+    /// position as a regular alias, e.g. `SELECT max(a) ignore FROM t`.
+    fn supports_null_treatment_after_function_args(&self) -> bool {
+        true
+    }
+
     /// Returns true if the dialect supports defining structs or objects using a
     /// syntax like `{'x': 1, 'y': 2, 'z': 3}`.
     fn supports_dictionary_syntax(&self) -> bool {
@@ -502,6 +516,18 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns true if the dialect parses `NORMALIZE` calls into
+    /// [`Expr::Normalize`], for example:
+    ///
+    /// ```sql
+    /// SELECT NORMALIZE(s, NFKC)
+    /// ```
+    ///
+    /// [`Expr::Normalize`]: crate::ast::Expr::Normalize
+    fn supports_normalize_function(&self) -> bool {
+        false
+    }
+
     /// Returns true if the dialect supports multiple variable assignment
     /// using parentheses in a `SET` variable declaration.
     ///
@@ -532,6 +558,40 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns true if the dialect supports a trailing `OPTION (<query_hint>, ...)`
+    /// clause on queries and DML statements.
+    ///
+    /// ```sql
+    /// SELECT * FROM foo OPTION (MAXDOP 1, RECOMPILE);
+    /// ```
+    ///
+    /// See <https://learn.microsoft.com/en-us/sql/t-sql/queries/option-clause-transact-sql>
+    fn supports_option_clause(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the dialect supports a `TOP` clause in `UPDATE` and
+    /// `DELETE` statements.
+    ///
+    /// ```sql
+    /// DELETE TOP (1000) FROM foo WHERE bar = 1;
+    /// ```
+    fn supports_dml_top(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the dialect supports the `NOT FOR REPLICATION` marker on
+    /// identity columns and foreign key or check constraints.
+    ///
+    /// ```sql
+    /// CREATE TABLE t (id INT IDENTITY(1, 1) NOT FOR REPLICATION)
+    /// ```
+    ///
+    /// See <https://learn.microsoft.com/en-us/sql/relational-databases/replication/control-behavior-of-triggers-and-constraints-in-synchronization>
+    fn supports_not_for_replication(&self) -> bool {
+        false
+    }
+
     /// Returns true if the dialect supports an `EXCEPT` clause following a
     /// wildcard in a select list.
     ///
@@ -618,6 +678,17 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns true if the dialect supports `{macro}` references in object
+    /// names, which the server substitutes from its configuration.
+    ///
+    /// Example
+    /// ```sql
+    /// CREATE TABLE db_{shard}.t ON CLUSTER {cluster} (a Int32)
+    /// ```
+    fn supports_object_name_macros(&self) -> bool {
+        false
+    }
+
     /// Return true if the dialect supports the STRUCT literal
     ///
     /// Example
@@ -832,7 +903,21 @@ pub trait Dialect: Debug + Any {
                 Ok(p!(Is))
             }
             Token::Word(w) if w.keyword == Keyword::IS => Ok(p!(Is)),
-            Token::Word(w) if w.keyword == Keyword::IN => Ok(p!(Between)),
+            // `IN PARTITION` introduces a ClickHouse mutation partition
+            // clause, not an `IN` list.
+            Token::Word(w) if w.keyword == Keyword::IN => match &parser.peek_nth_token_ref(1).token
+            {
+                Token::Word(w) if w.keyword == Keyword::PARTITION => Ok(self.prec_unknown()),
+                _ => Ok(p!(Between)),
+            },
+            Token::Word(w) if w.keyword == Keyword::GLOBAL && self.supports_global_in() => {
+                match &parser.peek_nth_token_ref(1).token {
+                    Token::Word(w) if matches!(w.keyword, Keyword::IN | Keyword::NOT) => {
+                        Ok(p!(Between))
+                    }
+                    _ => Ok(self.prec_unknown()),
+                }
+            }
             Token::Word(w) if w.keyword == Keyword::BETWEEN => Ok(p!(Between)),
             Token::Word(w) if w.keyword == Keyword::OVERLAPS => Ok(p!(Between)),
             Token::Word(w) if w.keyword == Keyword::LIKE => Ok(p!(Like)),
@@ -977,6 +1062,29 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns true if the dialect supports object types other than `TABLE`
+    /// in `DESCRIBE` statements, e.g. `DESCRIBE STAGE my_stage`.
+    ///
+    /// Only relevant when [Self::describe_requires_table_keyword] is true.
+    fn supports_describe_object_types(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the dialect supports calling `SYSDATE` as a niladic
+    /// function, i.e. without parentheses.
+    ///
+    /// Example:
+    /// ```sql
+    /// SELECT SYSDATE FROM dual;
+    /// ```
+    ///
+    /// [Oracle](https://docs.oracle.com/en/database/oracle/oracle-database/21/sqlrf/SYSDATE.html)
+    /// [MySQL](https://dev.mysql.com/doc/refman/8.4/en/date-and-time-functions.html#function_sysdate)
+    /// [Redshift](https://docs.aws.amazon.com/redshift/latest/dg/r_SYSDATE.html)
+    fn supports_niladic_sysdate(&self) -> bool {
+        false
+    }
+
     /// Returns true if this dialect allows the `EXTRACT` function to words other than [`Keyword`].
     fn allow_extract_custom(&self) -> bool {
         false
@@ -1172,6 +1280,15 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns true if the dialect supports a `SKEWED BY` clause in `CREATE TABLE`
+    ///
+    /// ```sql
+    /// CREATE TABLE t (a INT) SKEWED BY (a) ON (1, 5, 6) STORED AS DIRECTORIES
+    /// ```
+    fn supports_skewed_by(&self) -> bool {
+        false
+    }
+
     /// Returns true if the dialect supports the `LOAD extension` statement
     fn supports_load_extension(&self) -> bool {
         false
@@ -1228,6 +1345,64 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns true if the dialect supports `EXCLUDE` exclusion table constraints.
+    ///
+    /// <https://www.postgresql.org/docs/current/sql-createtable.html#SQL-CREATETABLE-EXCLUDE>
+    fn supports_exclusion_constraint(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the dialect supports extended statistics objects,
+    /// e.g. `CREATE STATISTICS s ON a, b FROM t`.
+    ///
+    /// <https://www.postgresql.org/docs/current/sql-createstatistics.html>
+    fn supports_extended_statistics(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the dialect supports `CREATE { SYNONYM | ALIAS } name FOR object`
+    /// and `DROP SYNONYM` statements.
+    ///
+    /// [MSSQL](https://learn.microsoft.com/en-us/sql/t-sql/statements/create-synonym-transact-sql)
+    fn supports_synonyms(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the dialect supports `CREATE CATALOG`, `ALTER CATALOG`
+    /// and `DROP CATALOG` statements.
+    ///
+    /// [Databricks](https://docs.databricks.com/aws/en/sql/language-manual/sql-ref-syntax-ddl-create-catalog)
+    fn supports_catalog_ddl(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the dialect supports `ALTER MATERIALIZED VIEW`
+    /// statements.
+    ///
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-altermaterializedview.html)
+    fn supports_alter_materialized_view(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the dialect supports system-versioned tables, e.g.
+    /// `CREATE TABLE t (...) WITH SYSTEM VERSIONING`,
+    /// `ALTER TABLE t { ADD | DROP } SYSTEM VERSIONING` and
+    /// `FOR SYSTEM_TIME` table version clauses.
+    ///
+    /// [MariaDB](https://mariadb.com/kb/en/system-versioned-tables/)
+    fn supports_system_versioned_tables(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the dialect supports `PERIOD FOR <name> (<start>, <end>)`
+    /// elements in the column list of a `CREATE TABLE` statement.
+    ///
+    /// [MariaDB](https://mariadb.com/kb/en/system-versioned-tables/)
+    /// [MSSQL](https://learn.microsoft.com/en-us/sql/relational-databases/tables/creating-a-system-versioned-temporal-table)
+    fn supports_create_table_period_definitions(&self) -> bool {
+        false
+    }
+
     /// Returns true if the dialect supports the `KEY` keyword as part of
     /// column-level constraints in a `CREATE TABLE` statement.
     ///
@@ -1268,6 +1443,16 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns true if this dialect supports the `FINAL` modifier after a
+    /// table reference in the `FROM` clause. For example:
+    ///
+    /// `SELECT * FROM tbl AS t FINAL`
+    ///
+    /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/select/from#final-modifier)
+    fn supports_table_final_modifier(&self) -> bool {
+        false
+    }
+
     /// Returns true if this dialect supports the `INSERT INTO ... SET col1 = 1, ...` syntax.
     ///
     /// MySQL: <https://dev.mysql.com/doc/refman/8.4/en/insert.html>
@@ -1297,12 +1482,32 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns true if this dialect supports matching the columns of an
+    /// `INSERT` source to the target table by name.
+    ///
+    /// e.g. `INSERT INTO t BY NAME SELECT 1 AS b, 2 AS a`
+    ///
+    /// DuckDB: <https://duckdb.org/docs/stable/sql/statements/insert#insert-into--by-name>
+    fn supports_insert_by_name(&self) -> bool {
+        false
+    }
+
     /// Returns true if this dialect supports `SET` statements without an explicit
     /// assignment operator such as `=`. For example: `SET SHOWPLAN_XML ON`.
     fn supports_set_stmt_without_operator(&self) -> bool {
         false
     }
 
+    /// Returns true if this dialect parses `ON`/`OFF` session option toggles
+    /// into [`Statement::SetSessionOption`].
+    ///
+    /// e.g. `SET NOCOUNT ON` or `SET IDENTITY_INSERT dbo.t ON`
+    ///
+    /// [`Statement::SetSessionOption`]: crate::ast::Statement::SetSessionOption
+    fn supports_set_session_option(&self) -> bool {
+        false
+    }
+
     /// Returns true if the specified keyword should be parsed as a column identifier.
     /// See [keywords::RESERVED_FOR_COLUMN_ALIAS]
     fn is_column_alias(&self, kw: &Keyword, _parser: &mut Parser) -> bool {
@@ -1415,6 +1620,17 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns true if the dialect supports setting and unsetting object tags
+    /// in `ALTER TABLE` and `ALTER COLUMN` operations.
+    /// Example:
+    /// ```sql
+    /// ALTER TABLE tbl SET TAG cost_center = 'finance'
+    /// ALTER TABLE tbl MODIFY COLUMN col UNSET TAG cost_center
+    /// ```
+    fn supports_alter_tag_operations(&self) -> bool {
+        false
+    }
+
     /// Returns true if the dialect supports `ALTER TABLE tbl DROP COLUMN c1, ..., cn`
     fn supports_comma_separated_drop_column_list(&self) -> bool {
         false
@@ -1485,6 +1701,88 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns true if the dialect supports `CREATE TABLE new COPY source`.
+    ///
+    /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#create_table_copy)
+    fn supports_create_table_copy(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the dialect allows the column list of a
+    /// `CREATE TABLE ... AS <query>` statement to contain only column names,
+    /// e.g. `CREATE TABLE t (a, b) AS SELECT 1, 2`.
+    ///
+    /// [DuckDB](https://duckdb.org/docs/sql/statements/create_table#create-table--as-select-ctas)
+    fn supports_create_table_as_column_names(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the dialect supports the `CREATE TABLE FUNCTION` statement.
+    ///
+    /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#create_table_function_statement)
+    fn supports_create_table_function(&self) -> bool {
+        false
+    }
+
+    /// Returns the zero-based position of the argument of `function_name` that is a
+    /// bare date/time part, such as `HOUR` in `TIMESTAMP_DIFF(t1, t2, HOUR)`.
+    ///
+    /// An argument in that position that is a date/time part keyword is parsed as
+    /// [FunctionArgExpr::DateTimeField](crate::ast::FunctionArgExpr::DateTimeField)
+    /// rather than as a column reference.
+    fn date_part_argument_position(&self, _function_name: &ObjectName) -> Option<usize> {
+        None
+    }
+
+    /// Returns true if the dialect supports the `INHERIT` and `NO INHERIT`
+    /// operations of `ALTER TABLE`.
+    ///
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-altertable.html)
+    fn supports_alter_table_inherit(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the dialect supports adding several columns in a single
+    /// `ADD [COLUMN]` operation of `ALTER TABLE`, either as a parenthesized list
+    /// or as a comma-separated list of column definitions.
+    ///
+    /// [MySQL](https://dev.mysql.com/doc/refman/8.4/en/alter-table.html)
+    /// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/alter-table-column)
+    fn supports_alter_table_add_multiple_columns(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the dialect supports the `FORCE` operation of `ALTER TABLE`.
+    ///
+    /// [MySQL](https://dev.mysql.com/doc/refman/8.4/en/alter-table.html)
+    fn supports_alter_table_force(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the dialect supports scheduled events, i.e. the
+    /// `CREATE EVENT`, `ALTER EVENT` and `DROP EVENT` statements.
+    ///
+    /// [MySQL](https://dev.mysql.com/doc/refman/8.4/en/create-event.html)
+    fn supports_event_scheduler(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the dialect supports `FOR variable IN (query) DO ... END FOR` loops.
+    ///
+    /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/procedural-language#for-in)
+    fn supports_for_in_loop(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the dialect supports `REPEAT ... UNTIL ... END REPEAT` loops
+    /// together with the `BREAK`, `LEAVE`, `CONTINUE` and `ITERATE` statements.
+    ///
+    /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/procedural-language#loops)
+    /// [Snowflake](https://docs.snowflake.com/en/sql-reference/snowflake-scripting/loops)
+    fn supports_repeat_loop(&self) -> bool {
+        false
+    }
+
     /// Returns true if the dialect supports `SEMANTIC_VIEW()` table functions.
     ///
     /// ```sql
@@ -1654,6 +1952,32 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns true if this dialect supports `ANY`, `ALL` and `ASOF` join
+    /// strictness keywords.
+    ///
+    /// Example:
+    /// ```sql
+    /// SELECT * FROM t1 ANY LEFT JOIN t2 ON t1.id = t2.id;
+    /// SELECT * FROM t1 ASOF JOIN t2 ON t1.id = t2.id AND t1.ts >= t2.ts;
+    /// ```
+    ///
+    /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/select/join#supported-types-of-join)
+    fn supports_join_strictness(&self) -> bool {
+        false
+    }
+
+    /// Returns true if this dialect supports the `GLOBAL IN` operator.
+    ///
+    /// Example:
+    /// ```sql
+    /// SELECT * FROM t WHERE id GLOBAL NOT IN (SELECT id FROM distributed_table);
+    /// ```
+    ///
+    /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/operators/in#distributed-subqueries)
+    fn supports_global_in(&self) -> bool {
+        false
+    }
+
     /// Returns true if this dialect supports the `INTERPOLATE` clause
     /// in `ORDER BY` expressions.
     ///
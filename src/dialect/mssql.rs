@@ -113,11 +113,26 @@ impl Dialect for MsSqlDialect {
         true
     }
 
+    /// See: <https://learn.microsoft.com/en-us/sql/t-sql/statements/set-statements-transact-sql>
+    fn supports_set_session_option(&self) -> bool {
+        true
+    }
+
     /// See: <https://learn.microsoft.com/en-us/sql/relational-databases/tables/querying-data-in-a-system-versioned-temporal-table>
     fn supports_table_versioning(&self) -> bool {
         true
     }
 
+    /// See: <https://learn.microsoft.com/en-us/sql/relational-databases/tables/creating-a-system-versioned-temporal-table>
+    fn supports_create_table_period_definitions(&self) -> bool {
+        true
+    }
+
+    /// See: <https://learn.microsoft.com/en-us/sql/t-sql/statements/create-synonym-transact-sql>
+    fn supports_synonyms(&self) -> bool {
+        true
+    }
+
     /// See <https://learn.microsoft.com/en-us/sql/t-sql/language-elements/slash-star-comment-transact-sql?view=sql-server-ver16>
     fn supports_nested_comments(&self) -> bool {
         true
@@ -128,6 +143,21 @@ impl Dialect for MsSqlDialect {
         true
     }
 
+    /// See <https://learn.microsoft.com/en-us/sql/t-sql/statements/delete-transact-sql>
+    fn supports_dml_top(&self) -> bool {
+        true
+    }
+
+    /// See <https://learn.microsoft.com/en-us/sql/t-sql/queries/option-clause-transact-sql>
+    fn supports_option_clause(&self) -> bool {
+        true
+    }
+
+    /// See <https://learn.microsoft.com/en-us/sql/t-sql/statements/create-table-transact-sql>
+    fn supports_not_for_replication(&self) -> bool {
+        true
+    }
+
     /// See <https://learn.microsoft.com/en-us/sql/relational-databases/security/authentication-access/server-level-roles>
     fn get_reserved_grantees_types(&self) -> &[GranteesType] {
         &[GranteesType::Public]
@@ -159,6 +189,8 @@ impl Dialect for MsSqlDialect {
             | Keyword::THROW
             | Keyword::RAISERROR
             | Keyword::MERGE => false,
+            // `OPTION` starts the trailing query hints clause
+            Keyword::OPTION => explicit,
             _ => explicit || self.is_column_alias(kw, parser),
         }
     }
@@ -193,6 +225,8 @@ impl Dialect for MsSqlDialect {
             | Keyword::THROW
             | Keyword::RAISERROR
             | Keyword::MERGE => false,
+            // `OPTION` starts the trailing query hints clause
+            Keyword::OPTION => explicit,
             _ => explicit || self.is_table_alias(kw, parser),
         }
     }
@@ -356,6 +390,7 @@ impl MsSqlDialect {
             or_alter,
             temporary: false,
             or_replace: false,
+            if_not_exists: false,
             is_constraint: false,
             name,
             period: Some(period),
@@ -127,6 +127,10 @@ impl Dialect for MySqlDialect {
         }
     }
 
+    fn supports_niladic_sysdate(&self) -> bool {
+        true
+    }
+
     fn require_interval_qualifier(&self) -> bool {
         true
     }
@@ -216,6 +220,32 @@ impl Dialect for MySqlDialect {
     fn supports_key_column_option(&self) -> bool {
         true
     }
+
+    /// See: <https://dev.mysql.com/doc/refman/8.4/en/alter-table.html>
+    fn supports_alter_table_force(&self) -> bool {
+        true
+    }
+
+    /// See: <https://dev.mysql.com/doc/refman/8.4/en/alter-table.html>
+    fn supports_alter_table_add_multiple_columns(&self) -> bool {
+        true
+    }
+
+    /// See: <https://dev.mysql.com/doc/refman/8.4/en/create-event.html>
+    fn supports_event_scheduler(&self) -> bool {
+        true
+    }
+
+    /// MariaDB system-versioned tables
+    /// See: <https://mariadb.com/kb/en/system-versioned-tables/>
+    fn supports_system_versioned_tables(&self) -> bool {
+        true
+    }
+
+    /// See: <https://mariadb.com/kb/en/system-versioned-tables/>
+    fn supports_create_table_period_definitions(&self) -> bool {
+        true
+    }
 }
 
 /// `LOCK TABLES`
@@ -69,6 +69,10 @@ impl Dialect for OracleDialect {
         true
     }
 
+    fn supports_niladic_sysdate(&self) -> bool {
+        true
+    }
+
     fn supports_boolean_literals(&self) -> bool {
         false
     }
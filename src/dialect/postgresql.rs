@@ -33,7 +33,9 @@ use crate::keywords::Keyword;
 use crate::parser::{Parser, ParserError};
 use crate::tokenizer::Token;
 
-use super::keywords::RESERVED_FOR_IDENTIFIER;
+use super::keywords::{
+    RESERVED_FOR_COLUMN_ALIAS, RESERVED_FOR_IDENTIFIER, RESERVED_FOR_TABLE_ALIAS,
+};
 
 /// A [`Dialect`] for [PostgreSQL](https://www.postgresql.org/)
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -58,6 +60,50 @@ const NOT_PREC: u8 = 30;
 const AND_PREC: u8 = 20;
 const OR_PREC: u8 = 10;
 
+/// Keywords that PostgreSQL only accepts as a column alias when preceded by `AS`.
+///
+/// See the `AS_LABEL` entries in <https://github.com/postgres/postgres/blob/master/src/include/parser/kwlist.h>
+const AS_LABEL_KEYWORDS: &[Keyword] = &[
+    Keyword::ARRAY,
+    Keyword::AS,
+    Keyword::CHAR,
+    Keyword::CHARACTER,
+    Keyword::CREATE,
+    Keyword::DAY,
+    Keyword::EXCEPT,
+    Keyword::FETCH,
+    Keyword::FILTER,
+    Keyword::FOR,
+    Keyword::FROM,
+    Keyword::GRANT,
+    Keyword::GROUP,
+    Keyword::HAVING,
+    Keyword::HOUR,
+    Keyword::INTERSECT,
+    Keyword::INTO,
+    Keyword::ISNULL,
+    Keyword::LIMIT,
+    Keyword::MINUTE,
+    Keyword::MONTH,
+    Keyword::NOTNULL,
+    Keyword::OFFSET,
+    Keyword::ON,
+    Keyword::ORDER,
+    Keyword::OVER,
+    Keyword::PRECISION,
+    Keyword::RETURNING,
+    Keyword::SECOND,
+    Keyword::TO,
+    Keyword::UNION,
+    Keyword::VARYING,
+    Keyword::WHERE,
+    Keyword::WINDOW,
+    Keyword::WITH,
+    Keyword::WITHIN,
+    Keyword::WITHOUT,
+    Keyword::YEAR,
+];
+
 impl Dialect for PostgreSqlDialect {
     fn identifier_quote_style(&self, _identifier: &str) -> Option<char> {
         Some('"')
@@ -91,6 +137,16 @@ impl Dialect for PostgreSqlDialect {
         }
     }
 
+    /// See <https://www.postgresql.org/docs/current/sql-syntax-lexical.html#SQL-SYNTAX-IDENTIFIERS>
+    fn is_column_alias(&self, kw: &Keyword, _parser: &mut Parser) -> bool {
+        !RESERVED_FOR_COLUMN_ALIAS.contains(kw) && !AS_LABEL_KEYWORDS.contains(kw)
+    }
+
+    fn is_table_alias(&self, kw: &Keyword, _parser: &mut Parser) -> bool {
+        // PostgreSQL has no `FORMAT` or `SORT BY` query clauses
+        matches!(kw, Keyword::FORMAT | Keyword::SORT) || !RESERVED_FOR_TABLE_ALIAS.contains(kw)
+    }
+
     /// See <https://www.postgresql.org/docs/current/sql-createoperator.html>
     fn is_custom_operator_part(&self, ch: char) -> bool {
         matches!(
@@ -157,6 +213,10 @@ impl Dialect for PostgreSqlDialect {
         true
     }
 
+    fn supports_null_treatment_after_function_args(&self) -> bool {
+        false
+    }
+
     fn supports_group_by_expr(&self) -> bool {
         true
     }
@@ -247,6 +307,11 @@ impl Dialect for PostgreSqlDialect {
         true
     }
 
+    /// See <https://www.postgresql.org/docs/current/functions-string.html>
+    fn supports_normalize_function(&self) -> bool {
+        true
+    }
+
     /// Return true if the dialect supports empty projections in SELECT statements
     ///
     /// Example
@@ -318,4 +383,23 @@ impl Dialect for PostgreSqlDialect {
     fn supports_xml_expressions(&self) -> bool {
         true
     }
+
+    /// See <https://www.postgresql.org/docs/current/sql-altertable.html>
+    fn supports_alter_table_inherit(&self) -> bool {
+        true
+    }
+
+    /// See <https://www.postgresql.org/docs/current/sql-createtable.html#SQL-CREATETABLE-EXCLUDE>
+    fn supports_exclusion_constraint(&self) -> bool {
+        true
+    }
+
+    fn supports_extended_statistics(&self) -> bool {
+        true
+    }
+
+    /// See <https://www.postgresql.org/docs/current/sql-altermaterializedview.html>
+    fn supports_alter_materialized_view(&self) -> bool {
+        true
+    }
 }
@@ -129,6 +129,10 @@ impl Dialect for RedshiftSqlDialect {
         true
     }
 
+    fn supports_niladic_sysdate(&self) -> bool {
+        true
+    }
+
     fn allow_extract_single_quotes(&self) -> bool {
         true
     }
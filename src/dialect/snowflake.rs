@@ -27,14 +27,15 @@ use crate::ast::helpers::stmt_data_loading::{
     FileStagingCommand, StageLoadSelectItem, StageLoadSelectItemKind, StageParamsObject,
 };
 use crate::ast::{
-    AlterTable, AlterTableOperation, AlterTableType, CatalogSyncNamespaceMode, ColumnOption,
-    ColumnPolicy, ColumnPolicyProperty, ContactEntry, CopyIntoSnowflakeKind, CreateTable,
-    CreateTableLikeKind, DollarQuotedString, Ident, IdentityParameters, IdentityProperty,
-    IdentityPropertyFormatKind, IdentityPropertyKind, IdentityPropertyOrder, InitializeKind,
-    Insert, MultiTableInsertIntoClause, MultiTableInsertType, MultiTableInsertValue,
-    MultiTableInsertValues, MultiTableInsertWhenClause, ObjectName, ObjectNamePart,
-    RefreshModeKind, RowAccessPolicy, ShowObjects, SqlOption, Statement, StorageLifecyclePolicy,
-    StorageSerializationPolicy, TableObject, TagsColumnOption, Value, WrappedCollection,
+    AlterIntegrationOperation, AlterTable, AlterTableOperation, AlterTableType,
+    CatalogSyncNamespaceMode, ColumnOption, ColumnPolicy, ColumnPolicyProperty, ContactEntry,
+    CopyIntoSnowflakeKind, CreateTable, CreateTableLikeKind, DescribeAlias, DollarQuotedString,
+    Ident, IdentityParameters, IdentityProperty, IdentityPropertyFormatKind, IdentityPropertyKind,
+    IdentityPropertyOrder, InitializeKind, Insert, MultiTableInsertIntoClause,
+    MultiTableInsertType, MultiTableInsertValue, MultiTableInsertValues,
+    MultiTableInsertWhenClause, ObjectName, ObjectNamePart, RefreshModeKind, RowAccessPolicy,
+    ShowObjects, SqlOption, Statement, StorageLifecyclePolicy, StorageSerializationPolicy,
+    TableObject, TagsColumnOption, Value, WrappedCollection,
 };
 use crate::dialect::{Dialect, Precedence};
 use crate::keywords::Keyword;
@@ -281,10 +282,39 @@ impl Dialect for SnowflakeDialect {
             return Some(parse_alter_session(parser, set));
         }
 
+        if parser.parse_keyword(Keyword::ALTER) {
+            if peek_integration(parser, true) {
+                return Some(parse_alter_integration(parser));
+            }
+            parser.prev_token();
+        }
+
+        if parser.parse_keyword(Keyword::DROP) {
+            if peek_integration(parser, true) {
+                return Some(parse_drop_integration(parser));
+            }
+            parser.prev_token();
+        }
+
+        if let Some(kw) = parser.parse_one_of_keywords(&[Keyword::DESC, Keyword::DESCRIBE]) {
+            if peek_integration(parser, true) {
+                let describe_alias = match kw {
+                    Keyword::DESC => DescribeAlias::Desc,
+                    _ => DescribeAlias::Describe,
+                };
+                return Some(parse_describe_integration(describe_alias, parser));
+            }
+            parser.prev_token();
+        }
+
         if parser.parse_keyword(Keyword::CREATE) {
             // possibly CREATE STAGE
             //[ OR  REPLACE ]
             let or_replace = parser.parse_keywords(&[Keyword::OR, Keyword::REPLACE]);
+
+            if peek_integration(parser, false) {
+                return Some(parse_create_integration(or_replace, parser));
+            }
             // LOCAL | GLOBAL
             let global = match parser.parse_one_of_keywords(&[Keyword::LOCAL, Keyword::GLOBAL]) {
                 Some(Keyword::LOCAL) => Some(false),
@@ -440,6 +470,11 @@ impl Dialect for SnowflakeDialect {
         true
     }
 
+    /// See <https://docs.snowflake.com/en/sql-reference/sql/desc>
+    fn supports_describe_object_types(&self) -> bool {
+        true
+    }
+
     fn allow_extract_custom(&self) -> bool {
         true
     }
@@ -682,6 +717,21 @@ impl Dialect for SnowflakeDialect {
     fn supports_comma_separated_trim(&self) -> bool {
         true
     }
+
+    /// See <https://docs.snowflake.com/en/sql-reference/snowflake-scripting/repeat>
+    fn supports_repeat_loop(&self) -> bool {
+        true
+    }
+
+    /// See <https://docs.snowflake.com/en/sql-reference/sql/alter-table-column>
+    fn supports_alter_table_add_multiple_columns(&self) -> bool {
+        true
+    }
+
+    /// See <https://docs.snowflake.com/en/sql-reference/sql/alter-table>
+    fn supports_alter_tag_operations(&self) -> bool {
+        true
+    }
 }
 
 // Peeks ahead to identify tokens that are expected after
@@ -1253,6 +1303,127 @@ pub fn parse_create_stage(
     })
 }
 
+/// The kinds of integration objects, e.g. `CREATE STORAGE INTEGRATION`.
+const INTEGRATION_KINDS: [Keyword; 5] = [
+    Keyword::API,
+    Keyword::CATALOG,
+    Keyword::NOTIFICATION,
+    Keyword::SECURITY,
+    Keyword::STORAGE,
+];
+
+/// Returns true if the next tokens are `<kind> INTEGRATION <name>`, or
+/// `INTEGRATION <name>` when `kind_optional` is set.
+fn peek_integration(parser: &Parser, kind_optional: bool) -> bool {
+    let is_integration =
+        |token: &Token| matches!(token, Token::Word(w) if w.keyword == Keyword::INTEGRATION);
+    let [first, second, third] = parser.peek_tokens_ref();
+    match &first.token {
+        Token::Word(w) if INTEGRATION_KINDS.contains(&w.keyword) => {
+            is_integration(&second.token) && matches!(third.token, Token::Word(_))
+        }
+        token => kind_optional && is_integration(token) && matches!(second.token, Token::Word(_)),
+    }
+}
+
+/// Parses `[ <kind> ] INTEGRATION`, returning the kind if present.
+fn parse_integration_kind(parser: &mut Parser) -> Result<Option<Ident>, ParserError> {
+    if parser.parse_keyword(Keyword::INTEGRATION) {
+        return Ok(None);
+    }
+    let kind = parser.parse_identifier()?;
+    parser.expect_keyword_is(Keyword::INTEGRATION)?;
+    Ok(Some(kind))
+}
+
+/// Parses a space separated list of `<property> = <value>` integration properties.
+fn parse_integration_options(parser: &mut Parser) -> Result<Vec<SqlOption>, ParserError> {
+    let mut options = vec![];
+    while matches!(parser.peek_token_ref().token, Token::Word(_))
+        && parser.peek_nth_token_ref(1).token == Token::Eq
+    {
+        options.push(parser.parse_sql_option()?);
+    }
+    Ok(options)
+}
+
+/// Parses a `CREATE [ OR REPLACE ] <kind> INTEGRATION` statement.
+///
+/// See <https://docs.snowflake.com/en/sql-reference/sql/create-integration>
+pub fn parse_create_integration(
+    or_replace: bool,
+    parser: &mut Parser,
+) -> Result<Statement, ParserError> {
+    let kind = parser.parse_identifier()?;
+    parser.expect_keyword_is(Keyword::INTEGRATION)?;
+    let if_not_exists = parser.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+    let name = parser.parse_object_name(false)?;
+    let options = parse_integration_options(parser)?;
+    Ok(Statement::CreateIntegration {
+        or_replace,
+        kind,
+        if_not_exists,
+        name,
+        options,
+    })
+}
+
+/// Parses an `ALTER [ <kind> ] INTEGRATION` statement.
+///
+/// See <https://docs.snowflake.com/en/sql-reference/sql/alter-integration>
+fn parse_alter_integration(parser: &mut Parser) -> Result<Statement, ParserError> {
+    let kind = parse_integration_kind(parser)?;
+    let if_exists = parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+    let name = parser.parse_object_name(false)?;
+    let operation = if parser.parse_keyword(Keyword::SET) {
+        let options = parse_integration_options(parser)?;
+        if options.is_empty() {
+            return parser.expected_ref("integration property after SET", parser.peek_token_ref());
+        }
+        AlterIntegrationOperation::Set(options)
+    } else if parser.parse_keyword(Keyword::UNSET) {
+        AlterIntegrationOperation::Unset(parser.parse_comma_separated(Parser::parse_identifier)?)
+    } else {
+        return parser.expected_ref("SET or UNSET", parser.peek_token_ref());
+    };
+    Ok(Statement::AlterIntegration {
+        kind,
+        if_exists,
+        name,
+        operation,
+    })
+}
+
+/// Parses a `DROP [ <kind> ] INTEGRATION` statement.
+///
+/// See <https://docs.snowflake.com/en/sql-reference/sql/drop-integration>
+fn parse_drop_integration(parser: &mut Parser) -> Result<Statement, ParserError> {
+    let kind = parse_integration_kind(parser)?;
+    let if_exists = parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+    let name = parser.parse_object_name(false)?;
+    Ok(Statement::DropIntegration {
+        kind,
+        if_exists,
+        name,
+    })
+}
+
+/// Parses a `DESC[RIBE] [ <kind> ] INTEGRATION` statement.
+///
+/// See <https://docs.snowflake.com/en/sql-reference/sql/desc-integration>
+fn parse_describe_integration(
+    describe_alias: DescribeAlias,
+    parser: &mut Parser,
+) -> Result<Statement, ParserError> {
+    let kind = parse_integration_kind(parser)?;
+    let name = parser.parse_object_name(false)?;
+    Ok(Statement::DescribeIntegration {
+        describe_alias,
+        kind,
+        name,
+    })
+}
+
 pub fn parse_stage_name_identifier(parser: &mut Parser) -> Result<Ident, ParserError> {
     let mut ident = String::new();
     while let Some(next_token) = parser.next_token_no_skip() {
@@ -1704,7 +1875,11 @@ fn parse_identity_property(parser: &mut Parser) -> Result<IdentityProperty, Pars
         Some(Keyword::NOORDER) => Some(IdentityPropertyOrder::NoOrder),
         _ => None,
     };
-    Ok(IdentityProperty { parameters, order })
+    Ok(IdentityProperty {
+        parameters,
+        order,
+        not_for_replication: false,
+    })
 }
 
 /// Parsing a policy property of column option
@@ -1806,8 +1981,10 @@ fn parse_multi_table_insert(
         ignore: false,
         into: false,
         table: TableObject::TableName(ObjectName(vec![])), // Not used for multi-table insert
+        with_hints: vec![],
         table_alias: None,
         columns: vec![],
+        by_name: false,
         overwrite,
         source: Some(source),
         assignments: vec![],
@@ -58,6 +58,10 @@ impl Dialect for SQLiteDialect {
         true
     }
 
+    fn supports_null_treatment_after_function_args(&self) -> bool {
+        false
+    }
+
     fn supports_start_transaction_modifier(&self) -> bool {
         true
     }
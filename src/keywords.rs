@@ -118,6 +118,7 @@ define_keywords!(
     AND,
     ANTI,
     ANY,
+    API,
     APPLICATION,
     APPLY,
     APPLYBUDGET,
@@ -172,6 +173,7 @@ define_keywords!(
     BOOST,
     BOTH,
     BOX,
+    BREAK,
     BRIN,
     BROWSE,
     BTREE,
@@ -185,6 +187,7 @@ define_keywords!(
     CACHE,
     CALL,
     CALLED,
+    CALLER,
     CANONICAL,
     CARDINALITY,
     CASCADE,
@@ -240,12 +243,14 @@ define_keywords!(
     COMMITTED,
     COMMUTATOR,
     COMPATIBLE,
+    COMPLETION,
     COMPRESS,
     COMPRESSION,
     COMPUPDATE,
     COMPUTE,
     CONCURRENTLY,
     CONDITION,
+    CONFIGURATION,
     CONFLICT,
     CONNECT,
     CONNECTION,
@@ -253,8 +258,10 @@ define_keywords!(
     CONNECT_BY_ROOT,
     CONSTRAINT,
     CONTACT,
+    CONTAINED,
     CONTAINS,
     CONTINUE,
+    CONVERSION,
     CONVERT,
     COPY,
     COPY_OPTIONS,
@@ -333,11 +340,14 @@ define_keywords!(
     DETACH,
     DETAIL,
     DETERMINISTIC,
+    DICTIONARY,
     DIMENSIONS,
+    DIRECTORIES,
     DIRECTORY,
     DISABLE,
     DISCARD,
     DISCONNECT,
+    DISK,
     DISTINCT,
     DISTINCTROW,
     DISTKEY,
@@ -370,6 +380,7 @@ define_keywords!(
     END,
     END_EXEC = "END-EXEC",
     ENDPOINT,
+    ENDS,
     END_FRAME,
     END_PARTITION,
     ENFORCED,
@@ -419,6 +430,7 @@ define_keywords!(
     FETCH,
     FIELDS,
     FILE,
+    FILEFORMAT,
     FILES,
     FILE_FORMAT,
     FILL,
@@ -446,6 +458,7 @@ define_keywords!(
     FOREIGN,
     FORMAT,
     FORMATTED,
+    FORMAT_OPTIONS,
     FORWARD,
     FRAME_ROW,
     FREE,
@@ -476,12 +489,15 @@ define_keywords!(
     GROUPING,
     GROUPS,
     GZIP,
+    HANDLER,
     HASH,
     HASHES,
     HAVING,
     HEADER,
     HEAP,
+    HIDDEN,
     HIGH_PRIORITY,
+    HINT,
     HISTORY,
     HIVEVAR,
     HOLD,
@@ -515,6 +531,7 @@ define_keywords!(
     INHERITS,
     INITIALIZE,
     INITIALLY,
+    INLINE,
     INNER,
     INOUT,
     INPATH,
@@ -548,11 +565,13 @@ define_keywords!(
     INVOKER,
     IO,
     IS,
+    ISNULL,
     ISODOW,
     ISOLATION,
     ISOWEEK,
     ISOYEAR,
     ITEMS,
+    ITERATE,
     JAR,
     JOIN,
     JSON,
@@ -575,6 +594,7 @@ define_keywords!(
     LEADING,
     LEAKPROOF,
     LEAST,
+    LEAVE,
     LEFT,
     LEFTARG,
     LEVEL,
@@ -613,6 +633,7 @@ define_keywords!(
     MANAGEDLOCATION,
     MANIFEST,
     MAP,
+    MAPPING,
     MASKING,
     MATCH,
     MATCHED,
@@ -700,6 +721,7 @@ define_keywords!(
     NOSUPERUSER,
     NOT,
     NOTHING,
+    NOTIFICATION,
     NOTIFY,
     NOTNULL,
     NOWAIT,
@@ -765,6 +787,7 @@ define_keywords!(
     PARALLEL,
     PARAMETER,
     PARQUET,
+    PARSER,
     PART,
     PARTIAL,
     PARTITION,
@@ -785,6 +808,7 @@ define_keywords!(
     PERCENT_RANK,
     PERIOD,
     PERMISSIVE,
+    PERSISTED,
     PERSISTENT,
     PIVOT,
     PLACING,
@@ -812,6 +836,7 @@ define_keywords!(
     PRINT,
     PRIOR,
     PRIVILEGES,
+    PROCEDURAL,
     PROCEDURE,
     PROCESSLIST,
     PROFILE,
@@ -867,6 +892,7 @@ define_keywords!(
     RENAME,
     REORG,
     REPAIR,
+    REPEAT,
     REPEATABLE,
     REPLACE,
     REPLACE_INVALID_CHARACTERS,
@@ -908,10 +934,12 @@ define_keywords!(
     ROW_NUMBER,
     RULE,
     RUN,
+    RUNNING,
     SAFE,
     SAFE_CAST,
     SAMPLE,
     SAVEPOINT,
+    SCHEDULE,
     SCHEMA,
     SCHEMAS,
     SCOPE,
@@ -954,7 +982,9 @@ define_keywords!(
     SIMILAR,
     SIMPLE,
     SIZE,
+    SKEWED,
     SKIP,
+    SLAVE,
     SLOW,
     SMALLINT,
     SNAPSHOT,
@@ -1022,6 +1052,7 @@ define_keywords!(
     SYMMETRIC,
     SYNC,
     SYNONYM,
+    SYSDATE,
     SYSTEM,
     SYSTEM_TIME,
     SYSTEM_USER,
@@ -1035,6 +1066,7 @@ define_keywords!(
     TASK,
     TBLPROPERTIES,
     TEMP,
+    TEMPLATE,
     TEMPORARY,
     TEMPTABLE,
     TERMINATED,
@@ -1066,6 +1098,7 @@ define_keywords!(
     TRAILING,
     TRAN,
     TRANSACTION,
+    TRANSFORM,
     TRANSIENT,
     TRANSLATE,
     TRANSLATE_REGEX,
@@ -1078,11 +1111,13 @@ define_keywords!(
     TRUE,
     TRUNCATE,
     TRUNCATECOLUMNS,
+    TRUSTED,
     TRY,
     TRY_CAST,
     TRY_CONVERT,
     TSQUERY,
     TSVECTOR,
+    TTL,
     TUPLE,
     TYPE,
     TYPMOD_IN,
@@ -1130,6 +1165,7 @@ define_keywords!(
     VALID,
     VALIDATE,
     VALIDATION_MODE,
+    VALIDATOR,
     VALUE,
     VALUES,
     VALUE_OF,
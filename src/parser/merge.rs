@@ -18,7 +18,7 @@ use alloc::{boxed::Box, format, vec, vec::Vec};
 use crate::{
     ast::{
         Merge, MergeAction, MergeClause, MergeClauseKind, MergeInsertExpr, MergeInsertKind,
-        MergeUpdateExpr, ObjectName, OutputClause, SetExpr,
+        MergeUpdateExpr, ObjectName, OutputClause, SetExpr, TableFactor,
     },
     dialect::{BigQueryDialect, GenericDialect, MySqlDialect},
     keywords::Keyword,
@@ -46,7 +46,19 @@ impl Parser<'_> {
         let optimizer_hints = self.maybe_parse_optimizer_hints()?;
         let into = self.parse_keyword(Keyword::INTO);
 
-        let table = self.parse_table_factor()?;
+        let mut table = self.parse_table_factor()?;
+        // MSSQL allows the target's alias to follow its table hints:
+        // `MERGE t WITH (HOLDLOCK) AS tgt USING ...`
+        if let TableFactor::Table {
+            alias: alias @ None,
+            with_hints,
+            ..
+        } = &mut table
+        {
+            if !with_hints.is_empty() {
+                *alias = self.maybe_parse_table_alias()?;
+            }
+        }
 
         self.expect_keyword_is(Keyword::USING)?;
         let source = self.parse_table_factor()?;
@@ -57,6 +69,7 @@ impl Parser<'_> {
             Some(keyword) => Some(self.parse_output(keyword, self.get_current_token().clone())?),
             None => None,
         };
+        let options = self.maybe_parse_query_hints()?;
 
         Ok(Merge {
             merge_token: merge_token.into(),
@@ -67,6 +80,7 @@ impl Parser<'_> {
             on: Box::new(on),
             clauses,
             output,
+            options,
         })
     }
 
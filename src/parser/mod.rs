@@ -248,6 +248,10 @@ pub struct ParserOptions {
     /// Controls if the parser expects a semi-colon token
     /// between statements. Default is `true`.
     pub require_semicolon_stmt_delimiter: bool,
+    /// Controls if statements that cannot be parsed are returned as
+    /// [`Statement::Unparsed`] instead of failing the whole batch.
+    /// Default is `false`.
+    pub unparsed_statements: bool,
 }
 
 impl Default for ParserOptions {
@@ -256,6 +260,7 @@ impl Default for ParserOptions {
             trailing_commas: false,
             unescape: true,
             require_semicolon_stmt_delimiter: true,
+            unparsed_statements: false,
         }
     }
 }
@@ -288,6 +293,24 @@ impl ParserOptions {
         self.unescape = unescape;
         self
     }
+
+    /// Set if statements that fail to parse are kept as
+    /// [`Statement::Unparsed`]. Defaults to false.
+    ///
+    /// If this option is `true`, the following SQL parses into a
+    /// [`Statement::Query`] followed by a [`Statement::Unparsed`]
+    /// which displays as `FROBNICATE CLUSTER 7`.
+    ///
+    /// ```sql
+    /// SELECT 1; FROBNICATE CLUSTER 7;
+    /// ```
+    ///
+    /// An unparsed statement ends at the next `;` that is not inside a
+    /// `BEGIN ... END` block or a `CASE ... END` expression.
+    pub fn with_unparsed_statements(mut self, unparsed_statements: bool) -> Self {
+        self.unparsed_statements = unparsed_statements;
+        self
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -304,6 +327,9 @@ enum ParserState {
     /// CREATE TABLE foo (abc BIGINT NOT NULL);
     /// ```
     ColumnDefinition,
+    /// The state when parsing a `WHERE` clause. This rejects a bare `DEFAULT`
+    /// keyword, which is only meaningful as a value in `INSERT` and `UPDATE`.
+    Where,
 }
 
 /// A SQL Parser
@@ -359,6 +385,10 @@ pub struct Parser<'a> {
     options: ParserOptions,
     /// Ensures the stack does not overflow by limiting recursion depth.
     recursion_counter: RecursionCounter,
+    /// The SQL text the tokens were produced from, if known and needed.
+    /// Used to keep the original text of [`Statement::Unparsed`] statements
+    /// and of `CREATE VIRTUAL TABLE` module arguments.
+    source: Option<SourceText>,
 }
 
 impl<'a> Parser<'a> {
@@ -385,6 +415,7 @@ impl<'a> Parser<'a> {
             dialect,
             recursion_counter: RecursionCounter::new(DEFAULT_REMAINING_DEPTH),
             options: ParserOptions::new().with_trailing_commas(dialect.supports_trailing_commas()),
+            source: None,
         }
     }
 
@@ -446,6 +477,7 @@ impl<'a> Parser<'a> {
     pub fn with_tokens_with_locations(mut self, tokens: Vec<TokenWithSpan>) -> Self {
         self.tokens = tokens;
         self.index = 0;
+        self.source = None;
         self
     }
 
@@ -473,7 +505,16 @@ impl<'a> Parser<'a> {
         let tokens = Tokenizer::new(self.dialect, sql)
             .with_unescape(self.options.unescape)
             .tokenize_with_location()?;
-        Ok(self.with_tokens_with_locations(tokens))
+        // Only keep a copy of the input when a statement may slice it
+        let needs_source = self.options.unparsed_statements
+            || tokens
+                .iter()
+                .any(|t| matches!(&t.token, Token::Word(w) if w.keyword == Keyword::VIRTUAL));
+        let mut parser = self.with_tokens_with_locations(tokens);
+        if needs_source {
+            parser.source = Some(SourceText::new(sql));
+        }
+        Ok(parser)
     }
 
     /// Parse potentially multiple statements
@@ -520,13 +561,148 @@ impl<'a> Parser<'a> {
                 return self.expected_ref("end of statement", self.peek_token_ref());
             }
 
-            let statement = self.parse_statement()?;
+            let statement = if self.options.unparsed_statements {
+                self.parse_statement_or_unparsed()
+            } else {
+                self.parse_statement()?
+            };
             stmts.push(statement);
             expecting_statement_delimiter = true;
         }
         Ok(stmts)
     }
 
+    /// Parse a single statement, falling back to [`Statement::Unparsed`]
+    /// holding everything up to the next `;` if the statement cannot be
+    /// parsed or is not followed by a statement delimiter.
+    fn parse_statement_or_unparsed(&mut self) -> Statement {
+        let start = self.index;
+        if let Ok(statement) = self.parse_statement() {
+            match &self.peek_token_ref().token {
+                Token::SemiColon | Token::EOF => return statement,
+                Token::Word(word) if word.keyword == Keyword::END => return statement,
+                _ if !self.options.require_semicolon_stmt_delimiter => return statement,
+                _ => {}
+            }
+        }
+
+        let end = self.unparsed_statement_end(start);
+        self.index = end;
+
+        let tokens: Vec<TokenWithSpan> = self.tokens[start..end]
+            .iter()
+            .filter(|t| !matches!(t.token, Token::Whitespace(_)))
+            .cloned()
+            .collect();
+        let sql = self.source_text(start, end);
+        Statement::Unparsed {
+            tokens: tokens.into(),
+            sql,
+        }
+    }
+
+    /// Returns the SQL text of the tokens in `start..end`, without leading
+    /// and trailing whitespace or comments.
+    ///
+    /// The text is sliced from the original input when the parser has it, so
+    /// literals and comments are kept verbatim. Without the original text
+    /// (e.g. when the parser was given tokens directly), it is rebuilt from
+    /// the tokens.
+    fn source_text(&self, start: usize, end: usize) -> String {
+        let range = &self.tokens[start..end];
+        let mut tokens = range
+            .iter()
+            .filter(|t| !matches!(t.token, Token::Whitespace(_)));
+        let source = match (&self.source, tokens.next()) {
+            (Some(source), Some(first)) => {
+                let last = tokens.next_back().unwrap_or(first);
+                source.slice(first.span.start, last.span.end)
+            }
+            _ => None,
+        };
+        match source {
+            Some(source) => source.to_string(),
+            None => range
+                .iter()
+                .map(|t| t.token.to_string())
+                .collect::<String>()
+                .trim()
+                .to_string(),
+        }
+    }
+
+    /// Returns the index of the `;` or EOF token ending the statement that
+    /// starts at token index `start`.
+    ///
+    /// A `;` inside a `BEGIN ... END` block or a `CASE ... END` expression
+    /// does not end the statement, so e.g. a procedure body is kept whole.
+    fn unparsed_statement_end(&self, start: usize) -> usize {
+        let mut depth = 0usize;
+        let mut end = start;
+        while let Some(token) = self.tokens.get(end) {
+            match &token.token {
+                Token::EOF => break,
+                Token::SemiColon if depth == 0 => break,
+                Token::Word(w) if w.keyword == Keyword::CASE => depth += 1,
+                Token::Word(w) if w.keyword == Keyword::BEGIN => {
+                    // `BEGIN [TRANSACTION | WORK ...]` starts a transaction, not a block
+                    let next = self.tokens[end + 1..]
+                        .iter()
+                        .find(|t| !matches!(t.token, Token::Whitespace(_)));
+                    let is_transaction = match next.map(|t| &t.token) {
+                        None | Some(Token::SemiColon | Token::EOF) => true,
+                        Some(Token::Word(w)) => matches!(
+                            w.keyword,
+                            Keyword::TRANSACTION
+                                | Keyword::TRAN
+                                | Keyword::WORK
+                                | Keyword::DEFERRED
+                                | Keyword::IMMEDIATE
+                                | Keyword::EXCLUSIVE
+                                | Keyword::ISOLATION
+                                | Keyword::READ
+                        ),
+                        Some(_) => false,
+                    };
+                    if !is_transaction {
+                        depth += 1;
+                    }
+                }
+                Token::Word(w) if w.keyword == Keyword::END => {
+                    // `END CASE`, `END IF`, `END LOOP` etc. are a single closing
+                    // token, so the keyword after `END` must not open a block
+                    let next = self.tokens[end + 1..]
+                        .iter()
+                        .position(|t| !matches!(t.token, Token::Whitespace(_)))
+                        .map(|offset| end + 1 + offset);
+                    let closing_keyword = next.and_then(|idx| match &self.tokens[idx].token {
+                        Token::Word(w) => Some((idx, w)),
+                        _ => None,
+                    });
+                    match closing_keyword {
+                        Some((idx, w)) if w.keyword == Keyword::CASE => {
+                            depth = depth.saturating_sub(1);
+                            end = idx;
+                        }
+                        // IF, WHILE, REPEAT, FOR and LOOP blocks are not tracked
+                        Some((idx, w))
+                            if matches!(
+                                w.keyword,
+                                Keyword::IF | Keyword::WHILE | Keyword::REPEAT | Keyword::FOR
+                            ) || w.value.eq_ignore_ascii_case("LOOP") =>
+                        {
+                            end = idx;
+                        }
+                        _ => depth = depth.saturating_sub(1),
+                    }
+                }
+                _ => {}
+            }
+            end += 1;
+        }
+        end
+    }
+
     /// Convenience method to parse a string with one or more SQL
     /// statements into produce an Abstract Syntax Tree (AST).
     ///
@@ -615,6 +791,20 @@ impl<'a> Parser<'a> {
                     self.prev_token();
                     self.parse_while().map(Into::into)
                 }
+                Keyword::FOR if self.dialect.supports_for_in_loop() => {
+                    self.prev_token();
+                    self.parse_for_in_loop().map(Into::into)
+                }
+                Keyword::REPEAT if self.dialect.supports_repeat_loop() => {
+                    self.prev_token();
+                    self.parse_repeat().map(Into::into)
+                }
+                Keyword::BREAK | Keyword::LEAVE | Keyword::CONTINUE | Keyword::ITERATE
+                    if self.dialect.supports_repeat_loop() =>
+                {
+                    self.prev_token();
+                    self.parse_loop_control().map(Into::into)
+                }
                 Keyword::RAISE => {
                     self.prev_token();
                     self.parse_raise_stmt().map(Into::into)
@@ -719,6 +909,9 @@ impl<'a> Parser<'a> {
                     self.parse_vacuum()
                 }
                 Keyword::RESET => self.parse_reset().map(Into::into),
+                Keyword::REFRESH if self.peek_keywords(&[Keyword::MATERIALIZED, Keyword::VIEW]) => {
+                    self.parse_refresh_materialized_view()
+                }
                 _ => self.expected("an SQL statement", next_token),
             },
             Token::LParen => {
@@ -816,6 +1009,63 @@ impl<'a> Parser<'a> {
         Ok(WhileStatement { while_block })
     }
 
+    /// Parse a `FOR ... IN (query) DO ... END FOR` statement.
+    ///
+    /// See [Statement::For]
+    fn parse_for_in_loop(&mut self) -> Result<ForStatement, ParserError> {
+        self.expect_keyword_is(Keyword::FOR)?;
+        let variable = self.parse_identifier()?;
+        self.expect_keyword_is(Keyword::IN)?;
+        self.expect_token(&Token::LParen)?;
+        let query = self.parse_query()?;
+        self.expect_token(&Token::RParen)?;
+        self.expect_keyword_is(Keyword::DO)?;
+        let body = self.parse_statement_list(&[Keyword::END])?;
+        self.expect_keywords(&[Keyword::END, Keyword::FOR])?;
+
+        Ok(ForStatement {
+            variable,
+            query,
+            body,
+        })
+    }
+
+    /// Parse a `REPEAT ... UNTIL condition END REPEAT` statement.
+    ///
+    /// See [Statement::Repeat]
+    fn parse_repeat(&mut self) -> Result<RepeatStatement, ParserError> {
+        self.expect_keyword_is(Keyword::REPEAT)?;
+        let body = self.parse_statement_list(&[Keyword::UNTIL])?;
+        self.expect_keyword_is(Keyword::UNTIL)?;
+        let until = self.parse_expr()?;
+        self.expect_keywords(&[Keyword::END, Keyword::REPEAT])?;
+
+        Ok(RepeatStatement { body, until })
+    }
+
+    /// Parse a `BREAK`, `LEAVE`, `CONTINUE` or `ITERATE` statement with an optional label.
+    ///
+    /// See [Statement::LoopControl]
+    fn parse_loop_control(&mut self) -> Result<LoopControlStatement, ParserError> {
+        let kind = match self.expect_one_of_keywords(&[
+            Keyword::BREAK,
+            Keyword::LEAVE,
+            Keyword::CONTINUE,
+            Keyword::ITERATE,
+        ])? {
+            Keyword::BREAK => LoopControlKind::Break,
+            Keyword::LEAVE => LoopControlKind::Leave,
+            Keyword::CONTINUE => LoopControlKind::Continue,
+            _ => LoopControlKind::Iterate,
+        };
+        let label = match self.peek_token_ref().token {
+            Token::Word(_) => Some(self.parse_identifier()?),
+            _ => None,
+        };
+
+        Ok(LoopControlStatement { kind, label })
+    }
+
     /// Parses an expression and associated list of statements
     /// belonging to a conditional statement like `IF` or `WHEN` or `WHILE`.
     ///
@@ -1461,10 +1711,10 @@ impl<'a> Parser<'a> {
     /// Parse `UNLISTEN` statement.
     pub fn parse_unlisten(&mut self) -> Result<Statement, ParserError> {
         let channel = if self.consume_token(&Token::Mul) {
-            Ident::new(Expr::Wildcard(AttachedToken::empty()).to_string())
+            UnlistenChannel::All
         } else {
             match self.parse_identifier() {
-                Ok(expr) => expr,
+                Ok(name) => UnlistenChannel::Specific { name },
                 _ => {
                     self.prev_token();
                     return self.expected_ref("wildcard or identifier", self.peek_token_ref());
@@ -1542,6 +1792,9 @@ impl<'a> Parser<'a> {
             | Keyword::LOCALTIMESTAMP => {
                 Ok(Some(self.parse_time_functions(ObjectName::from(vec![w.to_ident(w_span)]))?))
             }
+            Keyword::SYSDATE if self.dialect.supports_niladic_sysdate() => {
+                Ok(Some(self.parse_time_functions(ObjectName::from(vec![w.to_ident(w_span)]))?))
+            }
             Keyword::CASE => Ok(Some(self.parse_case_expr()?)),
             Keyword::CONVERT => Ok(Some(self.parse_convert_expr(false)?)),
             Keyword::TRY_CONVERT if self.dialect.supports_try_convert() => Ok(Some(self.parse_convert_expr(true)?)),
@@ -1567,6 +1820,12 @@ impl<'a> Parser<'a> {
             Keyword::POSITION if self.peek_token_ref().token == Token::LParen => {
                 Ok(Some(self.parse_position_expr(w.to_ident(w_span))?))
             }
+            Keyword::NORMALIZE
+                if self.dialect.supports_normalize_function()
+                    && self.peek_token_ref().token == Token::LParen =>
+            {
+                Ok(Some(self.parse_normalize_expr(w.to_ident(w_span))?))
+            }
             Keyword::SUBSTR | Keyword::SUBSTRING => {
                 self.prev_token();
                 Ok(Some(self.parse_substring()?))
@@ -1766,6 +2025,7 @@ impl<'a> Parser<'a> {
                         data_type: DataType::Binary(None),
                         array: false,
                         format: None,
+                        charset: None,
                     })
                 }
                 data_type => Ok(Expr::TypedString(TypedString {
@@ -1800,6 +2060,12 @@ impl<'a> Parser<'a> {
                 // We first try to parse the word and following tokens as a special expression, and if that fails,
                 // we rollback and try to parse it as an identifier.
                 let w = w.clone();
+                if w.keyword == Keyword::DEFAULT
+                    && matches!(self.state, ParserState::Where)
+                    && !matches!(self.peek_token_ref().token, Token::LParen | Token::Period)
+                {
+                    return parser_err!("DEFAULT is not allowed in this context", span.start);
+                }
                 match self.try_parse(|parser| parser.parse_expr_prefix_by_reserved_word(&w, span)) {
                     // This word indicated an expression prefix and parsing was successful
                     Ok(Some(expr)) => Ok(expr),
@@ -2458,7 +2724,8 @@ impl<'a> Parser<'a> {
             });
         }
 
-        let mut args = self.parse_function_argument_list()?;
+        let date_part_position = self.dialect.date_part_argument_position(&name);
+        let mut args = self.parse_function_argument_list_with_date_part(date_part_position)?;
         let mut parameters = FunctionArguments::None;
         // ClickHouse aggregations support parametric functions like `HISTOGRAM(0.5, 0.6)(x, y)`
         // which (0.5, 0.6) is a parameter to the function.
@@ -2493,10 +2760,11 @@ impl<'a> Parser<'a> {
 
         // Syntax for null treatment shows up either in the args list
         // or after the function call, but not both.
-        let null_treatment = if args
-            .clauses
-            .iter()
-            .all(|clause| !matches!(clause, FunctionArgumentClause::IgnoreOrRespectNulls(_)))
+        let null_treatment = if self.dialect.supports_null_treatment_after_function_args()
+            && args
+                .clauses
+                .iter()
+                .all(|clause| !matches!(clause, FunctionArgumentClause::IgnoreOrRespectNulls(_)))
         {
             self.parse_null_treatment()?
         } else {
@@ -2805,6 +3073,11 @@ impl<'a> Parser<'a> {
         let expr = self.parse_expr()?;
         self.expect_keyword_is(Keyword::AS)?;
         let data_type = self.parse_data_type()?;
+        let charset = if self.parse_keywords(&[Keyword::CHARACTER, Keyword::SET]) {
+            Some(self.parse_object_name(false)?)
+        } else {
+            None
+        };
         let array = self.parse_keyword(Keyword::ARRAY);
         let format = self.parse_optional_cast_format()?;
         self.expect_token(&Token::RParen)?;
@@ -2814,6 +3087,7 @@ impl<'a> Parser<'a> {
             data_type,
             array,
             format,
+            charset,
         })
     }
 
@@ -2913,6 +3187,31 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse a `NORMALIZE(<expr> [, <form>])` expression, where `form` is one
+    /// of the `NFC`, `NFD`, `NFKC` or `NFKD` keywords.
+    ///
+    /// Falls back to an ordinary function call if the arguments don't match.
+    pub fn parse_normalize_expr(&mut self, ident: Ident) -> Result<Expr, ParserError> {
+        let normalize_expr = self.maybe_parse(|p| {
+            p.expect_token(&Token::LParen)?;
+            let expr = p.parse_expr()?;
+            let form = if p.consume_token(&Token::Comma) {
+                Some(p.parse_normalization_form()?)
+            } else {
+                None
+            };
+            p.expect_token(&Token::RParen)?;
+            Ok(Expr::Normalize {
+                expr: Box::new(expr),
+                form,
+            })
+        })?;
+        match normalize_expr {
+            Some(expr) => Ok(expr),
+            None => self.parse_function(ObjectName::from(vec![ident])),
+        }
+    }
+
     /// Parse `SUBSTRING`/`SUBSTR` expressions: `SUBSTRING(expr FROM start FOR length)` or `SUBSTR(expr, start, length)`.
     pub fn parse_substring(&mut self) -> Result<Expr, ParserError> {
         let shorthand = match self.expect_one_of_keywords(&[Keyword::SUBSTR, Keyword::SUBSTRING])? {
@@ -3318,7 +3617,15 @@ impl<'a> Parser<'a> {
                 (leading_precision, last_field, fsec_precision)
             } else {
                 let leading_precision = self.parse_optional_precision()?;
-                if self.parse_keyword(Keyword::TO) {
+                // `TO` only continues the qualifier when a temporal unit follows it,
+                // e.g. not in ClickHouse `TTL d + INTERVAL 1 DAY TO DISK 'slow'`
+                let index = self.index;
+                let has_last_field =
+                    self.parse_keyword(Keyword::TO) && self.next_token_is_temporal_unit();
+                if !has_last_field {
+                    self.index = index;
+                }
+                if has_last_field {
                     let last_field = Some(self.parse_date_time_field()?);
                     let fsec_precision = if last_field == Some(DateTimeField::Second) {
                         self.parse_optional_precision()?
@@ -4002,6 +4309,35 @@ impl<'a> Parser<'a> {
                         time_zone: Box::new(self.parse_subexpr(precedence)?),
                     })
                 }
+                Keyword::GLOBAL if self.dialect.supports_global_in() => {
+                    let negated = self.parse_keyword(Keyword::NOT);
+                    self.expect_keyword_is(Keyword::IN)?;
+                    match self.parse_in(expr, negated)? {
+                        Expr::InList {
+                            expr,
+                            list,
+                            negated,
+                            ..
+                        } => Ok(Expr::InList {
+                            expr,
+                            list,
+                            negated,
+                            global: true,
+                        }),
+                        Expr::InSubquery {
+                            expr,
+                            subquery,
+                            negated,
+                            ..
+                        } => Ok(Expr::InSubquery {
+                            expr,
+                            subquery,
+                            negated,
+                            global: true,
+                        }),
+                        _ => parser_err!("Expected: ( after GLOBAL IN", span.start),
+                    }
+                }
                 Keyword::NOT
                 | Keyword::IN
                 | Keyword::BETWEEN
@@ -4096,6 +4432,7 @@ impl<'a> Parser<'a> {
                 data_type: self.parse_data_type()?,
                 array: false,
                 format: None,
+                charset: None,
             })
         } else if Token::ExclamationMark == *tok && self.dialect.supports_factorial_operator() {
             Ok(Expr::UnaryOp {
@@ -4306,6 +4643,7 @@ impl<'a> Parser<'a> {
                 expr: Box::new(expr),
                 subquery,
                 negated,
+                global: false,
             },
             None => Expr::InList {
                 expr: Box::new(expr),
@@ -4315,6 +4653,7 @@ impl<'a> Parser<'a> {
                     self.parse_comma_separated(Parser::parse_expr)?
                 },
                 negated,
+                global: false,
             },
         };
         self.expect_token(&Token::RParen)?;
@@ -4344,6 +4683,7 @@ impl<'a> Parser<'a> {
             data_type: self.parse_data_type()?,
             array: false,
             format: None,
+            charset: None,
         })
     }
 
@@ -4695,12 +5035,13 @@ impl<'a> Parser<'a> {
     /// that matches, without consuming the token. Otherwise, returns [`None`].
     #[must_use]
     pub fn peek_one_of_keywords(&self, keywords: &[Keyword]) -> Option<Keyword> {
-        for keyword in keywords {
-            if self.peek_keyword(*keyword) {
-                return Some(*keyword);
-            }
+        match &self.peek_token_ref().token {
+            Token::Word(w) => keywords
+                .iter()
+                .find(|keyword| **keyword == w.keyword)
+                .copied(),
+            _ => None,
         }
-        None
     }
 
     /// If the current token is one of the given `keywords`, consume the token
@@ -5140,8 +5481,28 @@ impl<'a> Parser<'a> {
         let persistent = dialect_of!(self is DuckDbDialect)
             && self.parse_one_of_keywords(&[Keyword::PERSISTENT]).is_some();
         let create_view_params = self.parse_create_view_params()?;
-        if self.peek_keywords(&[Keyword::SNAPSHOT, Keyword::TABLE]) {
+        if self.dialect.supports_event_scheduler() && self.parse_keyword(Keyword::EVENT) {
+            let definer = match create_view_params {
+                None => None,
+                Some(CreateViewParams {
+                    algorithm: None,
+                    definer,
+                    security: None,
+                }) => definer,
+                Some(_) => {
+                    return self.expected_ref(
+                        "VIEW after ALGORITHM or SQL SECURITY",
+                        self.peek_token_ref(),
+                    )
+                }
+            };
+            self.parse_create_event(definer).map(Into::into)
+        } else if self.peek_keywords(&[Keyword::SNAPSHOT, Keyword::TABLE]) {
             self.parse_create_snapshot_table().map(Into::into)
+        } else if self.dialect.supports_create_table_function()
+            && self.parse_keywords(&[Keyword::TABLE, Keyword::FUNCTION])
+        {
+            self.parse_create_table_function(or_replace).map(Into::into)
         } else if self.parse_keyword(Keyword::TABLE) {
             self.parse_create_table(or_replace, temporary, global, transient)
                 .map(Into::into)
@@ -5160,6 +5521,18 @@ impl<'a> Parser<'a> {
             self.parse_create_function(or_alter, or_replace, temporary)
         } else if self.parse_keyword(Keyword::DOMAIN) {
             self.parse_create_domain().map(Into::into)
+        } else if self.dialect.supports_extended_statistics()
+            && self.parse_keyword(Keyword::STATISTICS)
+        {
+            self.parse_create_statistics().map(Into::into)
+        } else if self.dialect.supports_catalog_ddl() && self.parse_keyword(Keyword::CATALOG) {
+            self.parse_create_catalog().map(Into::into)
+        } else if self.dialect.supports_synonyms()
+            && self
+                .peek_one_of_keywords(&[Keyword::SYNONYM, Keyword::ALIAS])
+                .is_some()
+        {
+            self.parse_create_synonym(or_replace).map(Into::into)
         } else if self.parse_keyword(Keyword::TRIGGER) {
             self.parse_create_trigger(temporary, or_alter, or_replace, false)
                 .map(Into::into)
@@ -5170,11 +5543,23 @@ impl<'a> Parser<'a> {
             self.parse_create_macro(or_replace, temporary)
         } else if self.parse_keyword(Keyword::SECRET) {
             self.parse_create_secret(or_replace, temporary, persistent)
+        } else if self.peek_user_mapping() {
+            self.expect_keywords(&[Keyword::USER, Keyword::MAPPING])?;
+            self.parse_pg_create_user_mapping().map(Into::into)
         } else if self.parse_keyword(Keyword::USER) {
             self.parse_create_user(or_replace).map(Into::into)
+        } else if self.parse_keyword(Keyword::PROCEDURE) {
+            self.parse_create_procedure(or_alter, or_replace)
+        } else if self
+            .peek_one_of_keywords(&[Keyword::TRUSTED, Keyword::PROCEDURAL, Keyword::LANGUAGE])
+            .is_some()
+        {
+            self.parse_create_language(or_replace).map(Into::into)
+        } else if self.parse_keyword(Keyword::TRANSFORM) {
+            self.parse_create_transform(or_replace).map(Into::into)
         } else if or_replace {
             self.expected_ref(
-                "[EXTERNAL] TABLE or [MATERIALIZED] VIEW or FUNCTION after CREATE OR REPLACE",
+                "[EXTERNAL] TABLE or [MATERIALIZED] VIEW or FUNCTION or PROCEDURE after CREATE OR REPLACE",
                 self.peek_token_ref(),
             )
         } else if self.parse_keyword(Keyword::EXTENSION) {
@@ -5197,8 +5582,6 @@ impl<'a> Parser<'a> {
             self.parse_create_collation().map(Into::into)
         } else if self.parse_keyword(Keyword::TYPE) {
             self.parse_create_type()
-        } else if self.parse_keyword(Keyword::PROCEDURE) {
-            self.parse_create_procedure(or_alter)
         } else if self.parse_keyword(Keyword::CONNECTOR) {
             self.parse_create_connector().map(Into::into)
         } else if self.parse_keyword(Keyword::OPERATOR) {
@@ -5212,6 +5595,12 @@ impl<'a> Parser<'a> {
             }
         } else if self.parse_keyword(Keyword::SERVER) {
             self.parse_pg_create_server()
+        } else if self.parse_keyword(Keyword::CONVERSION) {
+            self.parse_create_conversion(false).map(Into::into)
+        } else if self.parse_keywords(&[Keyword::DEFAULT, Keyword::CONVERSION]) {
+            self.parse_create_conversion(true).map(Into::into)
+        } else if self.parse_keywords(&[Keyword::TEXT, Keyword::SEARCH]) {
+            self.parse_create_text_search().map(Into::into)
         } else {
             self.expected_ref("an object type after CREATE", self.peek_token_ref())
         }
@@ -5412,11 +5801,11 @@ impl<'a> Parser<'a> {
         let table_name = self.parse_object_name(false)?;
         self.expect_keyword_is(Keyword::USING)?;
         let module_name = self.parse_identifier()?;
-        // SQLite docs note that module "arguments syntax is sufficiently
-        // general that the arguments can be made to appear as column
-        // definitions in a traditional CREATE TABLE statement", but
-        // we don't implement that.
-        let module_args = self.parse_parenthesized_column_list(Optional, false)?;
+        let module_args = if self.consume_token(&Token::LParen) {
+            Some(self.parse_virtual_table_module_args()?)
+        } else {
+            None
+        };
         Ok(Statement::CreateVirtualTable {
             name: table_name,
             if_not_exists,
@@ -5425,6 +5814,39 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse the arguments of a `CREATE VIRTUAL TABLE ... USING module(...)`
+    /// statement, after the opening parenthesis.
+    ///
+    /// SQLite docs note that module "arguments syntax is sufficiently general
+    /// that the arguments can be made to appear as column definitions in a
+    /// traditional CREATE TABLE statement", so each argument is kept as the
+    /// raw text between top-level commas.
+    fn parse_virtual_table_module_args(&mut self) -> Result<Vec<String>, ParserError> {
+        let mut args = vec![];
+        let mut arg_start = self.index;
+        let mut depth = 0usize;
+        loop {
+            let token = &self.next_token_no_skip().unwrap_or(&EOF_TOKEN).token;
+            match token {
+                Token::EOF => return self.expected_ref(")", self.peek_token_ref()),
+                Token::RParen if depth == 0 => {
+                    let arg = self.source_text(arg_start, self.index - 1);
+                    if !args.is_empty() || !arg.is_empty() {
+                        args.push(arg);
+                    }
+                    return Ok(args);
+                }
+                Token::Comma if depth == 0 => {
+                    args.push(self.source_text(arg_start, self.index - 1));
+                    arg_start = self.index;
+                }
+                Token::LParen => depth += 1,
+                Token::RParen => depth -= 1,
+                _ => {}
+            }
+        }
+    }
+
     /// Parse a `CREATE SCHEMA` statement.
     pub fn parse_create_schema(&mut self) -> Result<Statement, ParserError> {
         let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
@@ -6162,6 +6584,135 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse a MySQL `CREATE EVENT` statement, after the `EVENT` keyword.
+    ///
+    /// See [CreateEvent] for the supported syntax.
+    pub fn parse_create_event(
+        &mut self,
+        definer: Option<GranteeName>,
+    ) -> Result<CreateEvent, ParserError> {
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parse_object_name(false)?;
+        self.expect_keywords(&[Keyword::ON, Keyword::SCHEDULE])?;
+        let schedule = self.parse_event_schedule()?;
+        let on_completion = self.parse_event_on_completion()?;
+        let status = self.parse_event_status()?;
+        let comment = if self.parse_keyword(Keyword::COMMENT) {
+            Some(self.parse_literal_string()?)
+        } else {
+            None
+        };
+        self.expect_keyword_is(Keyword::DO)?;
+        let body = Box::new(self.parse_statement()?);
+        Ok(CreateEvent {
+            definer,
+            if_not_exists,
+            name,
+            schedule,
+            on_completion,
+            status,
+            comment,
+            body,
+        })
+    }
+
+    /// Parse a MySQL `ALTER EVENT` statement, after the `EVENT` keyword.
+    ///
+    /// See [AlterEvent] for the supported syntax.
+    pub fn parse_alter_event(
+        &mut self,
+        definer: Option<GranteeName>,
+    ) -> Result<AlterEvent, ParserError> {
+        let name = self.parse_object_name(false)?;
+        let schedule = if self.parse_keywords(&[Keyword::ON, Keyword::SCHEDULE]) {
+            Some(self.parse_event_schedule()?)
+        } else {
+            None
+        };
+        let on_completion = self.parse_event_on_completion()?;
+        let rename_to = if self.parse_keywords(&[Keyword::RENAME, Keyword::TO]) {
+            Some(self.parse_object_name(false)?)
+        } else {
+            None
+        };
+        let status = self.parse_event_status()?;
+        let comment = if self.parse_keyword(Keyword::COMMENT) {
+            Some(self.parse_literal_string()?)
+        } else {
+            None
+        };
+        let body = if self.parse_keyword(Keyword::DO) {
+            Some(Box::new(self.parse_statement()?))
+        } else {
+            None
+        };
+        Ok(AlterEvent {
+            definer,
+            name,
+            schedule,
+            on_completion,
+            rename_to,
+            status,
+            comment,
+            body,
+        })
+    }
+
+    fn parse_event_schedule(&mut self) -> Result<EventSchedule, ParserError> {
+        match self.expect_one_of_keywords(&[Keyword::AT, Keyword::EVERY])? {
+            Keyword::AT => Ok(EventSchedule::At(self.parse_expr()?)),
+            _ => {
+                let quantity = self.parse_expr()?;
+                let unit = self.parse_date_time_field()?;
+                let starts = if self.parse_keyword(Keyword::STARTS) {
+                    Some(self.parse_expr()?)
+                } else {
+                    None
+                };
+                let ends = if self.parse_keyword(Keyword::ENDS) {
+                    Some(self.parse_expr()?)
+                } else {
+                    None
+                };
+                Ok(EventSchedule::Every {
+                    quantity,
+                    unit,
+                    starts,
+                    ends,
+                })
+            }
+        }
+    }
+
+    fn parse_event_on_completion(&mut self) -> Result<Option<EventOnCompletion>, ParserError> {
+        if !self.parse_keywords(&[Keyword::ON, Keyword::COMPLETION]) {
+            return Ok(None);
+        }
+        if self.parse_keyword(Keyword::NOT) {
+            self.expect_keyword_is(Keyword::PRESERVE)?;
+            Ok(Some(EventOnCompletion::NotPreserve))
+        } else {
+            self.expect_keyword_is(Keyword::PRESERVE)?;
+            Ok(Some(EventOnCompletion::Preserve))
+        }
+    }
+
+    fn parse_event_status(&mut self) -> Result<Option<EventStatus>, ParserError> {
+        if self.parse_keyword(Keyword::ENABLE) {
+            Ok(Some(EventStatus::Enable))
+        } else if self.parse_keyword(Keyword::DISABLE) {
+            if self.parse_keywords(&[Keyword::ON, Keyword::REPLICA]) {
+                Ok(Some(EventStatus::DisableOnReplica))
+            } else if self.parse_keywords(&[Keyword::ON, Keyword::SLAVE]) {
+                Ok(Some(EventStatus::DisableOnSlave))
+            } else {
+                Ok(Some(EventStatus::Disable))
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Parse a `CREATE TRIGGER` statement.
     pub fn parse_create_trigger(
         &mut self,
@@ -6176,6 +6727,7 @@ impl<'a> Parser<'a> {
             return self.expected_ref("an object type after CREATE", self.peek_token_ref());
         }
 
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
         let name = self.parse_object_name(false)?;
         let period = self.maybe_parse(|parser| parser.parse_trigger_period())?;
 
@@ -6237,6 +6789,7 @@ impl<'a> Parser<'a> {
             or_alter,
             temporary,
             or_replace,
+            if_not_exists,
             is_constraint,
             name,
             period,
@@ -6400,6 +6953,15 @@ impl<'a> Parser<'a> {
         let table_name = self.parse_object_name(false)?;
         let (columns, constraints) = self.parse_columns()?;
 
+        // Connection names such as `project.region.connection` are kept as written
+        let with_connection = if self.parse_keywords(&[Keyword::WITH, Keyword::CONNECTION]) {
+            Some(ObjectName::from(
+                self.parse_period_separated(|p| p.parse_identifier())?,
+            ))
+        } else {
+            None
+        };
+
         let hive_distribution = self.parse_hive_distribution()?;
         let hive_formats = self.parse_hive_formats()?;
 
@@ -6433,6 +6995,7 @@ impl<'a> Parser<'a> {
             .or_replace(or_replace)
             .if_not_exists(if_not_exists)
             .external(true)
+            .with_connection(with_connection)
             .file_format(file_format)
             .location(location)
             .build())
@@ -6577,7 +7140,6 @@ impl<'a> Parser<'a> {
 
         self.expect_keyword_is(Keyword::AS)?;
         let query = self.parse_query()?;
-        // Optional `WITH [ CASCADED | LOCAL ] CHECK OPTION` is widely supported here.
 
         let with_no_schema_binding = dialect_of!(self is RedshiftSqlDialect | GenericDialect)
             && self.parse_keywords(&[
@@ -6587,6 +7149,33 @@ impl<'a> Parser<'a> {
                 Keyword::BINDING,
             ]);
 
+        let with_check_option =
+            if self.parse_keywords(&[Keyword::WITH, Keyword::CHECK, Keyword::OPTION]) {
+                Some(ViewCheckOption::Plain)
+            } else if self.parse_keywords(&[
+                Keyword::WITH,
+                Keyword::LOCAL,
+                Keyword::CHECK,
+                Keyword::OPTION,
+            ]) {
+                Some(ViewCheckOption::Local)
+            } else if self.parse_keywords(&[
+                Keyword::WITH,
+                Keyword::CASCADED,
+                Keyword::CHECK,
+                Keyword::OPTION,
+            ]) {
+                Some(ViewCheckOption::Cascaded)
+            } else {
+                None
+            };
+
+        let with_data = if materialized && dialect_of!(self is PostgreSqlDialect | GenericDialect) {
+            self.parse_optional_with_data()
+        } else {
+            None
+        };
+
         Ok(CreateView {
             or_alter,
             name,
@@ -6599,6 +7188,8 @@ impl<'a> Parser<'a> {
             cluster_by,
             comment,
             with_no_schema_binding,
+            with_check_option,
+            with_data,
             if_not_exists,
             temporary,
             copy_grants,
@@ -6941,17 +7532,133 @@ impl<'a> Parser<'a> {
         })
     }
 
-    /// ```sql
-    ///     CREATE POLICY name ON table_name [ AS { PERMISSIVE | RESTRICTIVE } ]
-    ///     [ FOR { ALL | SELECT | INSERT | UPDATE | DELETE } ]
-    ///     [ TO { role_name | PUBLIC | CURRENT_USER | CURRENT_ROLE | SESSION_USER } [, ...] ]
-    ///     [ USING ( using_expression ) ]
-    ///     [ WITH CHECK ( with_check_expression ) ]
-    /// ```
+    /// Parse a PostgreSQL-specific [Statement::CreateStatistics] statement.
     ///
-    /// [PostgreSQL Documentation](https://www.postgresql.org/docs/current/sql-createpolicy.html)
-    pub fn parse_create_policy(&mut self) -> Result<CreatePolicy, ParserError> {
-        let name = self.parse_identifier()?;
+    /// [PostgreSQL Documentation](https://www.postgresql.org/docs/current/sql-createstatistics.html)
+    pub fn parse_create_statistics(&mut self) -> Result<CreateStatistics, ParserError> {
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parse_object_name(false)?;
+        let kinds = if self.consume_token(&Token::LParen) {
+            let kinds = self.parse_comma_separated(|p| p.parse_identifier())?;
+            self.expect_token(&Token::RParen)?;
+            kinds
+        } else {
+            vec![]
+        };
+        self.expect_keyword_is(Keyword::ON)?;
+        let expressions = self.parse_comma_separated(Parser::parse_expr)?;
+        self.expect_keyword_is(Keyword::FROM)?;
+        let table_name = self.parse_object_name(false)?;
+        Ok(CreateStatistics {
+            if_not_exists,
+            name,
+            kinds,
+            expressions,
+            table_name,
+        })
+    }
+
+    /// Parse a PostgreSQL-specific [Statement::AlterStatistics] statement.
+    ///
+    /// [PostgreSQL Documentation](https://www.postgresql.org/docs/current/sql-alterstatistics.html)
+    pub fn parse_alter_statistics(&mut self) -> Result<AlterStatistics, ParserError> {
+        let name = self.parse_object_name(false)?;
+        let operation = if self.parse_keywords(&[Keyword::RENAME, Keyword::TO]) {
+            AlterStatisticsOperation::RenameTo {
+                new_name: self.parse_identifier()?,
+            }
+        } else if self.parse_keywords(&[Keyword::OWNER, Keyword::TO]) {
+            AlterStatisticsOperation::OwnerTo(self.parse_owner()?)
+        } else if self.parse_keywords(&[Keyword::SET, Keyword::SCHEMA]) {
+            AlterStatisticsOperation::SetSchema {
+                schema_name: self.parse_object_name(false)?,
+            }
+        } else if self.parse_keywords(&[Keyword::SET, Keyword::STATISTICS]) {
+            AlterStatisticsOperation::SetStatistics {
+                value: self.parse_signed_number_value()?,
+            }
+        } else {
+            return self.expected_ref(
+                "RENAME TO, OWNER TO, SET SCHEMA or SET STATISTICS after ALTER STATISTICS",
+                self.peek_token_ref(),
+            );
+        };
+        Ok(AlterStatistics { name, operation })
+    }
+
+    /// Parse a Databricks `CREATE CATALOG` statement, after the `CATALOG` keyword.
+    ///
+    /// See [CreateCatalog] for the supported syntax.
+    pub fn parse_create_catalog(&mut self) -> Result<CreateCatalog, ParserError> {
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parse_identifier()?;
+        let managed_location = if self.parse_keywords(&[Keyword::MANAGED, Keyword::LOCATION]) {
+            Some(self.parse_literal_string()?)
+        } else {
+            None
+        };
+        let comment = if self.parse_keyword(Keyword::COMMENT) {
+            Some(self.parse_comment_value()?)
+        } else {
+            None
+        };
+        Ok(CreateCatalog {
+            if_not_exists,
+            name,
+            managed_location,
+            comment,
+        })
+    }
+
+    /// Parse a `CREATE { SYNONYM | ALIAS }` statement, starting at the `SYNONYM`
+    /// or `ALIAS` keyword.
+    ///
+    /// See [CreateSynonym] for the supported syntax.
+    pub fn parse_create_synonym(&mut self, or_replace: bool) -> Result<CreateSynonym, ParserError> {
+        let alias_keyword =
+            self.expect_one_of_keywords(&[Keyword::SYNONYM, Keyword::ALIAS])? == Keyword::ALIAS;
+        let name = self.parse_object_name(false)?;
+        self.expect_keyword_is(Keyword::FOR)?;
+        let for_object = self.parse_object_name(false)?;
+        Ok(CreateSynonym {
+            or_replace,
+            alias_keyword,
+            name,
+            for_object,
+        })
+    }
+
+    /// Parse a Databricks `ALTER CATALOG` statement, after the `CATALOG` keyword.
+    ///
+    /// See [AlterCatalog] for the supported syntax.
+    pub fn parse_alter_catalog(&mut self) -> Result<AlterCatalog, ParserError> {
+        let name = self.parse_identifier()?;
+        let operation = if self.parse_keywords(&[Keyword::OWNER, Keyword::TO]) {
+            AlterCatalogOperation::OwnerTo(self.parse_owner()?)
+        } else if self.parse_keywords(&[Keyword::RENAME, Keyword::TO]) {
+            AlterCatalogOperation::RenameTo {
+                new_name: self.parse_identifier()?,
+            }
+        } else {
+            return self.expected_ref(
+                "OWNER TO or RENAME TO after ALTER CATALOG",
+                self.peek_token_ref(),
+            );
+        };
+        Ok(AlterCatalog { name, operation })
+    }
+
+    /// ```sql
+    ///     CREATE POLICY name ON table_name [ AS { PERMISSIVE | RESTRICTIVE } ]
+    ///     [ FOR { ALL | SELECT | INSERT | UPDATE | DELETE } ]
+    ///     [ TO { role_name | PUBLIC | CURRENT_USER | CURRENT_ROLE | SESSION_USER } [, ...] ]
+    ///     [ USING ( using_expression ) ]
+    ///     [ WITH CHECK ( with_check_expression ) ]
+    /// ```
+    ///
+    /// [PostgreSQL Documentation](https://www.postgresql.org/docs/current/sql-createpolicy.html)
+    pub fn parse_create_policy(&mut self) -> Result<CreatePolicy, ParserError> {
+        let name = self.parse_identifier()?;
         self.expect_keyword_is(Keyword::ON)?;
         let table_name = self.parse_object_name(false)?;
 
@@ -7379,10 +8086,25 @@ impl<'a> Parser<'a> {
             ObjectType::Stage
         } else if self.parse_keyword(Keyword::TYPE) {
             ObjectType::Type
+        } else if self.peek_user_mapping() {
+            self.expect_keywords(&[Keyword::USER, Keyword::MAPPING])?;
+            return self.parse_pg_drop_user_mapping().map(Into::into);
         } else if self.parse_keyword(Keyword::USER) {
             ObjectType::User
         } else if self.parse_keyword(Keyword::STREAM) {
             ObjectType::Stream
+        } else if self.dialect.supports_event_scheduler() && self.parse_keyword(Keyword::EVENT) {
+            ObjectType::Event
+        } else if self.dialect.supports_extended_statistics()
+            && self.parse_keyword(Keyword::STATISTICS)
+        {
+            ObjectType::Statistics
+        } else if self.parse_keyword(Keyword::SERVER) {
+            ObjectType::Server
+        } else if self.dialect.supports_catalog_ddl() && self.parse_keyword(Keyword::CATALOG) {
+            ObjectType::Catalog
+        } else if self.dialect.supports_synonyms() && self.parse_keyword(Keyword::SYNONYM) {
+            ObjectType::Synonym
         } else if self.parse_keyword(Keyword::FUNCTION) {
             return self.parse_drop_function().map(Into::into);
         } else if self.parse_keyword(Keyword::POLICY) {
@@ -7399,6 +8121,14 @@ impl<'a> Parser<'a> {
             return self.parse_drop_trigger().map(Into::into);
         } else if self.parse_keyword(Keyword::EXTENSION) {
             return self.parse_drop_extension();
+        } else if self.parse_keyword(Keyword::LANGUAGE) {
+            return self.parse_drop_language(false).map(Into::into);
+        } else if self.parse_keywords(&[Keyword::PROCEDURAL, Keyword::LANGUAGE]) {
+            return self.parse_drop_language(true).map(Into::into);
+        } else if self.parse_keyword(Keyword::TRANSFORM) {
+            return self.parse_drop_transform().map(Into::into);
+        } else if self.parse_keyword(Keyword::CONVERSION) {
+            return self.parse_drop_conversion().map(Into::into);
         } else if self.parse_keyword(Keyword::OPERATOR) {
             // Check if this is DROP OPERATOR FAMILY or DROP OPERATOR CLASS
             return if self.parse_keyword(Keyword::FAMILY) {
@@ -7410,7 +8140,7 @@ impl<'a> Parser<'a> {
             };
         } else {
             return self.expected_ref(
-                "COLLATION, CONNECTOR, DATABASE, EXTENSION, FUNCTION, INDEX, OPERATOR, POLICY, PROCEDURE, ROLE, SCHEMA, SECRET, SEQUENCE, STAGE, TABLE, TRIGGER, TYPE, VIEW, MATERIALIZED VIEW or USER after DROP",
+                "COLLATION, CONNECTOR, DATABASE, EXTENSION, FUNCTION, INDEX, OPERATOR, POLICY, PROCEDURE, ROLE, SCHEMA, SECRET, SEQUENCE, SERVER, STAGE, TABLE, TRIGGER, TYPE, VIEW, MATERIALIZED VIEW or USER after DROP",
                 self.peek_token_ref(),
             );
         };
@@ -8195,6 +8925,248 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    /// Parse a PostgreSQL-specific [Statement::CreateLanguage] statement.
+    pub fn parse_create_language(
+        &mut self,
+        or_replace: bool,
+    ) -> Result<CreateLanguage, ParserError> {
+        let trusted = self.parse_keyword(Keyword::TRUSTED);
+        let procedural = self.parse_keyword(Keyword::PROCEDURAL);
+        self.expect_keyword_is(Keyword::LANGUAGE)?;
+        let name = self.parse_identifier()?;
+        let (handler, inline_handler, validator) = if self.parse_keyword(Keyword::HANDLER) {
+            let handler = self.parse_object_name(false)?;
+            let inline_handler = if self.parse_keyword(Keyword::INLINE) {
+                Some(self.parse_object_name(false)?)
+            } else {
+                None
+            };
+            let validator = if self.parse_keyword(Keyword::VALIDATOR) {
+                Some(self.parse_object_name(false)?)
+            } else {
+                None
+            };
+            (Some(handler), inline_handler, validator)
+        } else {
+            (None, None, None)
+        };
+        Ok(CreateLanguage {
+            or_replace,
+            trusted,
+            procedural,
+            name,
+            handler,
+            inline_handler,
+            validator,
+        })
+    }
+
+    /// Parse a PostgreSQL-specific [Statement::DropLanguage] statement.
+    pub fn parse_drop_language(&mut self, procedural: bool) -> Result<DropLanguage, ParserError> {
+        let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = self.parse_identifier()?;
+        let drop_behavior = self.parse_optional_drop_behavior();
+        Ok(DropLanguage {
+            procedural,
+            if_exists,
+            name,
+            drop_behavior,
+        })
+    }
+
+    /// Parse a PostgreSQL-specific [Statement::CreateTransform] statement.
+    pub fn parse_create_transform(
+        &mut self,
+        or_replace: bool,
+    ) -> Result<CreateTransform, ParserError> {
+        self.expect_keyword_is(Keyword::FOR)?;
+        let type_name = self.parse_data_type()?;
+        self.expect_keyword_is(Keyword::LANGUAGE)?;
+        let language = self.parse_identifier()?;
+        self.expect_token(&Token::LParen)?;
+        let mut from_sql = None;
+        let mut to_sql = None;
+        loop {
+            if from_sql.is_none() && self.parse_keyword(Keyword::FROM) {
+                self.expect_keywords(&[Keyword::SQL, Keyword::WITH, Keyword::FUNCTION])?;
+                from_sql = Some(self.parse_function_desc()?);
+            } else if to_sql.is_none() && self.parse_keyword(Keyword::TO) {
+                self.expect_keywords(&[Keyword::SQL, Keyword::WITH, Keyword::FUNCTION])?;
+                to_sql = Some(self.parse_function_desc()?);
+            } else {
+                return self.expected_ref("FROM SQL or TO SQL", self.peek_token_ref());
+            }
+            if !self.consume_token(&Token::Comma) {
+                break;
+            }
+        }
+        self.expect_token(&Token::RParen)?;
+        Ok(CreateTransform {
+            or_replace,
+            type_name,
+            language,
+            from_sql,
+            to_sql,
+        })
+    }
+
+    /// Parse a PostgreSQL-specific [Statement::DropTransform] statement.
+    pub fn parse_drop_transform(&mut self) -> Result<DropTransform, ParserError> {
+        let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        self.expect_keyword_is(Keyword::FOR)?;
+        let type_name = self.parse_data_type()?;
+        self.expect_keyword_is(Keyword::LANGUAGE)?;
+        let language = self.parse_identifier()?;
+        let drop_behavior = self.parse_optional_drop_behavior();
+        Ok(DropTransform {
+            if_exists,
+            type_name,
+            language,
+            drop_behavior,
+        })
+    }
+
+    /// Parse a PostgreSQL-specific [Statement::CreateConversion] statement.
+    pub fn parse_create_conversion(
+        &mut self,
+        default: bool,
+    ) -> Result<CreateConversion, ParserError> {
+        let name = self.parse_object_name(false)?;
+        self.expect_keyword_is(Keyword::FOR)?;
+        let source_encoding = self.parse_literal_string()?;
+        self.expect_keyword_is(Keyword::TO)?;
+        let destination_encoding = self.parse_literal_string()?;
+        self.expect_keyword_is(Keyword::FROM)?;
+        let function = self.parse_object_name(false)?;
+        Ok(CreateConversion {
+            default,
+            name,
+            source_encoding,
+            destination_encoding,
+            function,
+        })
+    }
+
+    /// Parse a PostgreSQL-specific [Statement::DropConversion] statement.
+    pub fn parse_drop_conversion(&mut self) -> Result<DropConversion, ParserError> {
+        let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = self.parse_object_name(false)?;
+        let drop_behavior = self.parse_optional_drop_behavior();
+        Ok(DropConversion {
+            if_exists,
+            name,
+            drop_behavior,
+        })
+    }
+
+    /// Parse a PostgreSQL-specific [Statement::CreateTextSearch] statement.
+    pub fn parse_create_text_search(&mut self) -> Result<CreateTextSearch, ParserError> {
+        let object_type = match self.expect_one_of_keywords(&[
+            Keyword::CONFIGURATION,
+            Keyword::DICTIONARY,
+            Keyword::PARSER,
+            Keyword::TEMPLATE,
+        ])? {
+            Keyword::CONFIGURATION => TextSearchObjectType::Configuration,
+            Keyword::DICTIONARY => TextSearchObjectType::Dictionary,
+            Keyword::PARSER => TextSearchObjectType::Parser,
+            Keyword::TEMPLATE => TextSearchObjectType::Template,
+            unexpected_keyword => return Err(ParserError::ParserError(
+                format!("Internal parser error: expected any of {{CONFIGURATION, DICTIONARY, PARSER, TEMPLATE}}, got {unexpected_keyword:?}"),
+            )),
+        };
+        let name = self.parse_object_name(false)?;
+        self.expect_token(&Token::LParen)?;
+        let options = self.parse_comma_separated(|p| p.parse_text_search_option())?;
+        self.expect_token(&Token::RParen)?;
+        Ok(CreateTextSearch {
+            object_type,
+            name,
+            options,
+        })
+    }
+
+    /// Parse a `name = value` option of a `CREATE TEXT SEARCH` statement.
+    ///
+    /// Unlike [Parser::parse_sql_option], the value may be the bare word `default`,
+    /// which names the built-in text search parser.
+    fn parse_text_search_option(&mut self) -> Result<SqlOption, ParserError> {
+        let key = self.parse_identifier()?;
+        self.expect_token(&Token::Eq)?;
+        let value = if self.peek_keyword(Keyword::DEFAULT) {
+            Expr::Identifier(self.parse_identifier()?)
+        } else {
+            self.parse_expr()?
+        };
+        Ok(SqlOption::KeyValue { key, value })
+    }
+
+    /// Parse a PostgreSQL-specific [Statement::AlterTextSearchConfiguration] statement.
+    pub fn parse_alter_text_search_configuration(
+        &mut self,
+    ) -> Result<AlterTextSearchConfiguration, ParserError> {
+        let name = self.parse_object_name(false)?;
+        let operation = match self.expect_one_of_keywords(&[
+            Keyword::ADD,
+            Keyword::ALTER,
+            Keyword::DROP,
+        ])? {
+            Keyword::ADD => {
+                self.expect_keywords(&[Keyword::MAPPING, Keyword::FOR])?;
+                let token_types = self.parse_comma_separated(|p| p.parse_identifier())?;
+                self.expect_keyword_is(Keyword::WITH)?;
+                let dictionaries = self.parse_comma_separated(|p| p.parse_object_name(false))?;
+                AlterTextSearchConfigurationOperation::AddMapping {
+                    token_types,
+                    dictionaries,
+                }
+            }
+            Keyword::ALTER => {
+                self.expect_keyword_is(Keyword::MAPPING)?;
+                let token_types = if self.parse_keyword(Keyword::FOR) {
+                    self.parse_comma_separated(|p| p.parse_identifier())?
+                } else {
+                    vec![]
+                };
+                if self.parse_keyword(Keyword::REPLACE) {
+                    let old_dictionary = self.parse_object_name(false)?;
+                    self.expect_keyword_is(Keyword::WITH)?;
+                    let new_dictionary = self.parse_object_name(false)?;
+                    AlterTextSearchConfigurationOperation::ReplaceMapping {
+                        token_types,
+                        old_dictionary,
+                        new_dictionary,
+                    }
+                } else {
+                    if token_types.is_empty() {
+                        return self.expected_ref("FOR or REPLACE", self.peek_token_ref());
+                    }
+                    self.expect_keyword_is(Keyword::WITH)?;
+                    let dictionaries =
+                        self.parse_comma_separated(|p| p.parse_object_name(false))?;
+                    AlterTextSearchConfigurationOperation::AlterMapping {
+                        token_types,
+                        dictionaries,
+                    }
+                }
+            }
+            Keyword::DROP => {
+                self.expect_keyword_is(Keyword::MAPPING)?;
+                let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+                self.expect_keyword_is(Keyword::FOR)?;
+                let token_types = self.parse_comma_separated(|p| p.parse_identifier())?;
+                AlterTextSearchConfigurationOperation::DropMapping {
+                    if_exists,
+                    token_types,
+                }
+            }
+            unexpected_keyword => return Err(ParserError::ParserError(
+                format!("Internal parser error: expected any of {{ADD, ALTER, DROP}}, got {unexpected_keyword:?}"),
+            )),
+        };
+        Ok(AlterTextSearchConfiguration { name, operation })
+    }
+
     /// Parse a[Statement::DropOperator] statement.
     ///
     pub fn parse_drop_operator(&mut self) -> Result<Statement, ParserError> {
@@ -8455,12 +9427,11 @@ impl<'a> Parser<'a> {
         }
     }
 
-    fn parse_optional_on_cluster(&mut self) -> Result<Option<Ident>, ParserError> {
-        if self.parse_keywords(&[Keyword::ON, Keyword::CLUSTER]) {
-            Ok(Some(self.parse_identifier()?))
-        } else {
-            Ok(None)
+    fn parse_optional_on_cluster(&mut self) -> Result<Option<ObjectNamePart>, ParserError> {
+        if !self.parse_keywords(&[Keyword::ON, Keyword::CLUSTER]) {
+            return Ok(None);
         }
+        Ok(Some(self.parse_object_name_part_or_macro()?))
     }
 
     /// Parse `CREATE TABLE` statement.
@@ -8501,9 +9472,44 @@ impl<'a> Parser<'a> {
         } else {
             None
         };
+        // BigQuery: `CLONE source FOR SYSTEM_TIME AS OF timestamp`
+        let version = if clone.is_some() {
+            self.maybe_parse_table_version()?
+        } else {
+            None
+        };
+
+        let copy = if self.dialect.supports_create_table_copy() && self.parse_keyword(Keyword::COPY)
+        {
+            Some(self.parse_object_name(allow_unquoted_hyphen)?)
+        } else {
+            None
+        };
 
         // parse optional column list (schema)
-        let (columns, constraints) = self.parse_columns()?;
+        let mut periods = vec![];
+        let column_names = if self.dialect.supports_create_table_as_column_names() {
+            self.maybe_parse(|p| p.parse_parenthesized_column_list(Mandatory, false))?
+        } else {
+            None
+        };
+        let (columns, constraints) = if let Some(column_names) = &column_names {
+            let columns = column_names
+                .iter()
+                .map(|name| ColumnDef {
+                    name: name.clone(),
+                    data_type: DataType::Unspecified,
+                    options: vec![],
+                })
+                .collect();
+            (columns, vec![])
+        } else {
+            self.parse_table_elements(
+                self.dialect
+                    .supports_create_table_period_definitions()
+                    .then_some(&mut periods),
+            )?
+        };
         let comment_after_column_def =
             if dialect_of!(self is HiveDialect) && self.parse_keyword(Keyword::COMMENT) {
                 let next_token = self.next_token();
@@ -8532,11 +9538,26 @@ impl<'a> Parser<'a> {
         // SQLite supports `WITHOUT ROWID` at the end of `CREATE TABLE`
         let without_rowid = self.parse_keywords(&[Keyword::WITHOUT, Keyword::ROWID]);
 
+        // Spark and Databricks place `USING data_source` before `PARTITIONED BY`
+        let using =
+            if self.dialect.supports_create_table_using() && self.parse_keyword(Keyword::USING) {
+                Some(self.parse_identifier()?)
+            } else {
+                None
+            };
         let hive_distribution = self.parse_hive_distribution()?;
         let clustered_by = self.parse_optional_clustered_by()?;
-        let hive_formats = self.parse_hive_formats()?;
+        let skewed_by = self.parse_optional_skewed_by()?;
+        let mut hive_formats = self.parse_hive_formats()?;
+        if let Some(format) = using {
+            hive_formats.get_or_insert_with(HiveFormat::default).storage =
+                Some(HiveIOFormat::Using { format });
+        }
 
+        // MariaDB accepts `WITH SYSTEM VERSIONING` before or after the table options
+        let mut with_system_versioning = self.parse_optional_with_system_versioning();
         let create_table_config = self.parse_optional_create_table_config()?;
+        with_system_versioning |= self.parse_optional_with_system_versioning();
 
         // ClickHouse supports `PRIMARY KEY`, before `ORDER BY`
         // https://clickhouse.com/docs/en/sql-reference/statements/create/table#primary-key
@@ -8615,10 +9636,20 @@ impl<'a> Parser<'a> {
             None
         };
 
+        // Column names without data types take their types from the query
+        if column_names.is_some() && query.is_none() {
+            return self.expected_ref(
+                "AS <query> after a column list without data types",
+                self.peek_token_ref(),
+            );
+        }
+
         Ok(CreateTableBuilder::new(table_name)
             .temporary(temporary)
             .columns(columns)
             .constraints(constraints)
+            .periods(periods)
+            .with_system_versioning(with_system_versioning)
             .or_replace(or_replace)
             .if_not_exists(if_not_exists)
             .transient(transient)
@@ -8629,11 +9660,14 @@ impl<'a> Parser<'a> {
             .without_rowid(without_rowid)
             .like(like)
             .clone_clause(clone)
+            .version(version)
+            .copy(copy)
             .comment_after_column_def(comment_after_column_def)
             .order_by(order_by)
             .on_commit(on_commit)
             .on_cluster(on_cluster)
             .clustered_by(clustered_by)
+            .skewed_by(skewed_by)
             .partition_by(create_table_config.partition_by)
             .cluster_by(create_table_config.cluster_by)
             .inherits(create_table_config.inherits)
@@ -8649,28 +9683,80 @@ impl<'a> Parser<'a> {
             .build())
     }
 
-    fn maybe_parse_create_table_like(
+    /// Parse a BigQuery `CREATE TABLE FUNCTION` statement, after the
+    /// `CREATE [OR REPLACE] TABLE FUNCTION` keywords.
+    ///
+    /// See [Statement::CreateTableFunction]
+    fn parse_create_table_function(
         &mut self,
-        allow_unquoted_hyphen: bool,
-    ) -> Result<Option<CreateTableLikeKind>, ParserError> {
-        let like = if self.dialect.supports_create_table_like_parenthesized()
-            && self.consume_token(&Token::LParen)
-        {
-            if self.parse_keyword(Keyword::LIKE) {
-                let name = self.parse_object_name(allow_unquoted_hyphen)?;
-                let defaults = if self.parse_keywords(&[Keyword::INCLUDING, Keyword::DEFAULTS]) {
-                    Some(CreateTableLikeDefaults::Including)
-                } else if self.parse_keywords(&[Keyword::EXCLUDING, Keyword::DEFAULTS]) {
-                    Some(CreateTableLikeDefaults::Excluding)
-                } else {
-                    None
-                };
-                self.expect_token(&Token::RParen)?;
-                Some(CreateTableLikeKind::Parenthesized(CreateTableLike {
-                    name,
-                    defaults,
-                }))
-            } else {
+        or_replace: bool,
+    ) -> Result<CreateTableFunction, ParserError> {
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let (name, args) = self.parse_create_function_name_and_params()?;
+
+        let returns = if self.parse_keywords(&[Keyword::RETURNS, Keyword::TABLE]) {
+            self.expect_token(&Token::Lt)?;
+            let mut columns = vec![];
+            let trailing_bracket = loop {
+                let field_name = Some(self.parse_identifier()?);
+                let (field_type, trailing_bracket) = self.parse_data_type_helper()?;
+                columns.push(StructField {
+                    field_name,
+                    field_type,
+                    options: None,
+                });
+                if trailing_bracket.0 || !self.consume_token(&Token::Comma) {
+                    break trailing_bracket;
+                }
+            };
+            if self.expect_closing_angle_bracket(trailing_bracket)?.0 {
+                return parser_err!(
+                    "unmatched > after RETURNS TABLE",
+                    self.peek_token_ref().span.start
+                );
+            }
+            Some(columns)
+        } else {
+            None
+        };
+
+        let options = self.maybe_parse_options(Keyword::OPTIONS)?;
+        self.expect_keyword_is(Keyword::AS)?;
+        let query = self.parse_query()?;
+
+        Ok(CreateTableFunction {
+            or_replace,
+            if_not_exists,
+            name,
+            args,
+            returns,
+            options,
+            query,
+        })
+    }
+
+    fn maybe_parse_create_table_like(
+        &mut self,
+        allow_unquoted_hyphen: bool,
+    ) -> Result<Option<CreateTableLikeKind>, ParserError> {
+        let like = if self.dialect.supports_create_table_like_parenthesized()
+            && self.consume_token(&Token::LParen)
+        {
+            if self.parse_keyword(Keyword::LIKE) {
+                let name = self.parse_object_name(allow_unquoted_hyphen)?;
+                let defaults = if self.parse_keywords(&[Keyword::INCLUDING, Keyword::DEFAULTS]) {
+                    Some(CreateTableLikeDefaults::Including)
+                } else if self.parse_keywords(&[Keyword::EXCLUDING, Keyword::DEFAULTS]) {
+                    Some(CreateTableLikeDefaults::Excluding)
+                } else {
+                    None
+                };
+                self.expect_token(&Token::RParen)?;
+                Some(CreateTableLikeKind::Parenthesized(CreateTableLike {
+                    name,
+                    defaults,
+                }))
+            } else {
                 // Rollback the '(' it's probably the columns list
                 self.prev_token();
                 None
@@ -8765,6 +9851,12 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parses an optional MariaDB `WITH SYSTEM VERSIONING` table option.
+    fn parse_optional_with_system_versioning(&mut self) -> bool {
+        self.dialect.supports_system_versioned_tables()
+            && self.parse_keywords(&[Keyword::WITH, Keyword::SYSTEM, Keyword::VERSIONING])
+    }
+
     /// Parse configuration like inheritance, partitioning, clustering information during the table creation.
     ///
     /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#syntax_2)
@@ -9092,6 +10184,16 @@ impl<'a> Parser<'a> {
 
     /// Parse columns and constraints.
     pub fn parse_columns(&mut self) -> Result<(Vec<ColumnDef>, Vec<TableConstraint>), ParserError> {
+        self.parse_table_elements(None)
+    }
+
+    /// Parse the column list of a `CREATE TABLE` statement. `PERIOD FOR`
+    /// definitions are accepted, and collected into `periods`, only when
+    /// `periods` is given.
+    fn parse_table_elements(
+        &mut self,
+        mut periods: Option<&mut Vec<PeriodDefinition>>,
+    ) -> Result<(Vec<ColumnDef>, Vec<TableConstraint>), ParserError> {
         let mut columns = vec![];
         let mut constraints = vec![];
         if !self.consume_token(&Token::LParen) || self.consume_token(&Token::RParen) {
@@ -9099,7 +10201,13 @@ impl<'a> Parser<'a> {
         }
 
         loop {
-            if let Some(constraint) = self.parse_optional_table_constraint()? {
+            if let Some(periods) = periods
+                .as_deref_mut()
+                .filter(|_| self.peek_keywords(&[Keyword::PERIOD, Keyword::FOR]))
+            {
+                self.expect_keywords(&[Keyword::PERIOD, Keyword::FOR])?;
+                periods.push(self.parse_period_definition()?);
+            } else if let Some(constraint) = self.parse_optional_table_constraint()? {
                 constraints.push(constraint);
             } else if let Token::Word(_) = &self.peek_token_ref().token {
                 columns.push(self.parse_column_def()?);
@@ -9131,6 +10239,22 @@ impl<'a> Parser<'a> {
         Ok((columns, constraints))
     }
 
+    /// Parse the remainder of a `PERIOD FOR <name> (<start_column>, <end_column>)`
+    /// table element, after the `PERIOD FOR` keywords.
+    fn parse_period_definition(&mut self) -> Result<PeriodDefinition, ParserError> {
+        let name = self.parse_identifier()?;
+        self.expect_token(&Token::LParen)?;
+        let start_column = self.parse_identifier()?;
+        self.expect_token(&Token::Comma)?;
+        let end_column = self.parse_identifier()?;
+        self.expect_token(&Token::RParen)?;
+        Ok(PeriodDefinition {
+            name,
+            start_column,
+            end_column,
+        })
+    }
+
     /// Parse procedure parameter.
     pub fn parse_procedure_param(&mut self) -> Result<ProcedureParam, ParserError> {
         let mode = if self.parse_keyword(Keyword::IN) {
@@ -9168,7 +10292,9 @@ impl<'a> Parser<'a> {
         optional_data_type: bool,
     ) -> Result<ColumnDef, ParserError> {
         let col_name = self.parse_identifier()?;
-        let data_type = if self.is_column_type_sqlite_unspecified() {
+        let data_type = if self.is_column_type_sqlite_unspecified()
+            || self.is_column_type_computed_unspecified()
+        {
             DataType::Unspecified
         } else if optional_data_type {
             self.maybe_parse(|parser| parser.parse_data_type())?
@@ -9201,6 +10327,13 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// MSSQL computed columns omit the data type, e.g. `total AS (price * qty)`
+    fn is_column_type_computed_unspecified(&mut self) -> bool {
+        dialect_of!(self is MsSqlDialect | GenericDialect)
+            && self.peek_keyword(Keyword::AS)
+            && self.peek_nth_token_ref(1).token == Token::LParen
+    }
+
     fn is_column_type_sqlite_unspecified(&mut self) -> bool {
         if dialect_of!(self is SQLiteDialect) {
             match &self.peek_token_ref().token {
@@ -9336,11 +10469,11 @@ impl<'a> Parser<'a> {
                 } else if on_delete.is_none()
                     && self.parse_keywords(&[Keyword::ON, Keyword::DELETE])
                 {
-                    on_delete = Some(self.parse_referential_action()?);
+                    on_delete = Some(self.parse_referential_action(Keyword::DELETE)?);
                 } else if on_update.is_none()
                     && self.parse_keywords(&[Keyword::ON, Keyword::UPDATE])
                 {
-                    on_update = Some(self.parse_referential_action()?);
+                    on_update = Some(self.parse_referential_action(Keyword::UPDATE)?);
                 } else {
                     break;
                 }
@@ -9362,11 +10495,14 @@ impl<'a> Parser<'a> {
                 .into(),
             ))
         } else if self.parse_keyword(Keyword::CHECK) {
+            let not_for_replication = self.parse_not_for_replication();
             self.expect_token(&Token::LParen)?;
             // since `CHECK` requires parentheses, we can parse the inner expression in ParserState::Normal
             let expr: Expr = self.with_state(ParserState::Normal, |p| p.parse_expr())?;
             self.expect_token(&Token::RParen)?;
 
+            let no_inherit = self.parse_keywords(&[Keyword::NO, Keyword::INHERIT]);
+
             let enforced = if self.parse_keyword(Keyword::ENFORCED) {
                 Some(true)
             } else if self.parse_keywords(&[Keyword::NOT, Keyword::ENFORCED]) {
@@ -9379,7 +10515,9 @@ impl<'a> Parser<'a> {
                 CheckConstraint {
                     name: None, // Column-level check constraints don't have names
                     expr: Box::new(expr),
+                    no_inherit,
                     enforced,
+                    not_for_replication,
                 }
                 .into(),
             ))
@@ -9426,7 +10564,7 @@ impl<'a> Parser<'a> {
                 self.parse_options(Keyword::OPTIONS)?,
             )))
         } else if self.parse_keyword(Keyword::AS)
-            && dialect_of!(self is MySqlDialect | SQLiteDialect | DuckDbDialect | GenericDialect)
+            && dialect_of!(self is MySqlDialect | SQLiteDialect | DuckDbDialect | MsSqlDialect | GenericDialect)
         {
             self.parse_optional_column_option_as()
         } else if self.parse_keyword(Keyword::SRID)
@@ -9452,6 +10590,7 @@ impl<'a> Parser<'a> {
                 IdentityPropertyKind::Identity(IdentityProperty {
                     parameters,
                     order: None,
+                    not_for_replication: self.parse_not_for_replication(),
                 }),
             )))
         } else if dialect_of!(self is SQLiteDialect | GenericDialect)
@@ -9485,7 +10624,14 @@ impl<'a> Parser<'a> {
     fn parse_optional_column_option_generated(
         &mut self,
     ) -> Result<Option<ColumnOption>, ParserError> {
-        if self.parse_keywords(&[Keyword::ALWAYS, Keyword::AS, Keyword::IDENTITY]) {
+        if self.parse_keywords(&[Keyword::ALWAYS, Keyword::AS, Keyword::ROW]) {
+            let boundary = match self.expect_one_of_keywords(&[Keyword::START, Keyword::END])? {
+                Keyword::START => GeneratedRowBoundary::Start,
+                _ => GeneratedRowBoundary::End,
+            };
+            let hidden = self.parse_keyword(Keyword::HIDDEN);
+            Ok(Some(ColumnOption::GeneratedAsRow { boundary, hidden }))
+        } else if self.parse_keywords(&[Keyword::ALWAYS, Keyword::AS, Keyword::IDENTITY]) {
             let mut sequence_options = vec![];
             if self.expect_token(&Token::LParen).is_ok() {
                 sequence_options = self.parse_create_sequence_options()?;
@@ -9562,6 +10708,11 @@ impl<'a> Parser<'a> {
             )
         } else if self.parse_keywords(&[Keyword::VIRTUAL]) {
             (GeneratedAs::Always, Some(GeneratedExpressionMode::Virtual))
+        } else if self.parse_keyword(Keyword::PERSISTED) {
+            (
+                GeneratedAs::ExpStored,
+                Some(GeneratedExpressionMode::Persisted),
+            )
         } else {
             (GeneratedAs::Always, None)
         };
@@ -9605,20 +10756,71 @@ impl<'a> Parser<'a> {
         Ok(clustered_by)
     }
 
+    /// Parse optional `SKEWED BY` clause for Hive/Generic dialects.
+    pub fn parse_optional_skewed_by(&mut self) -> Result<Option<SkewedBy>, ParserError> {
+        let skewed_by = if self.dialect.supports_skewed_by()
+            && self.parse_keywords(&[Keyword::SKEWED, Keyword::BY])
+        {
+            let columns = self.parse_parenthesized_column_list(Mandatory, false)?;
+
+            self.expect_keyword_is(Keyword::ON)?;
+            self.expect_token(&Token::LParen)?;
+            let values = self.parse_comma_separated(Parser::parse_expr)?;
+            self.expect_token(&Token::RParen)?;
+
+            // Each entry is either a parenthesized tuple of values, or, for a
+            // single skewed column, a bare value as in `ON (1, 5, 6)`
+            let bare_values = !values
+                .iter()
+                .all(|value| matches!(value, Expr::Tuple(_) | Expr::Nested(_)));
+            let on = values
+                .into_iter()
+                .map(|value| match value {
+                    Expr::Tuple(values) if !bare_values => values,
+                    Expr::Nested(value) if !bare_values => vec![*value],
+                    value => vec![value],
+                })
+                .collect();
+
+            let stored_as_directories =
+                self.parse_keywords(&[Keyword::STORED, Keyword::AS, Keyword::DIRECTORIES]);
+            Some(SkewedBy {
+                columns,
+                on,
+                bare_values,
+                stored_as_directories,
+            })
+        } else {
+            None
+        };
+        Ok(skewed_by)
+    }
+
     /// Parse a referential action used in foreign key clauses.
     ///
     /// Recognized forms: `RESTRICT`, `CASCADE`, `SET NULL`, `NO ACTION`, `SET DEFAULT`.
-    pub fn parse_referential_action(&mut self) -> Result<ReferentialAction, ParserError> {
+    ///
+    /// `event` is the `DELETE` or `UPDATE` keyword of the enclosing `ON` clause.
+    /// A column list after `SET NULL` / `SET DEFAULT` is only accepted for
+    /// `ON DELETE`.
+    pub fn parse_referential_action(
+        &mut self,
+        event: Keyword,
+    ) -> Result<ReferentialAction, ParserError> {
         if self.parse_keyword(Keyword::RESTRICT) {
             Ok(ReferentialAction::Restrict)
         } else if self.parse_keyword(Keyword::CASCADE) {
             Ok(ReferentialAction::Cascade)
         } else if self.parse_keywords(&[Keyword::SET, Keyword::NULL]) {
-            Ok(ReferentialAction::SetNull)
+            Ok(ReferentialAction::SetNull(
+                self.parse_referential_action_columns(event)?,
+            ))
         } else if self.parse_keywords(&[Keyword::NO, Keyword::ACTION]) {
             Ok(ReferentialAction::NoAction)
         } else if self.parse_keywords(&[Keyword::SET, Keyword::DEFAULT]) {
-            Ok(ReferentialAction::SetDefault)
+            Ok(ReferentialAction::SetDefault(
+                self.parse_referential_action_columns(event)?,
+            ))
         } else {
             self.expected_ref(
                 "one of RESTRICT, CASCADE, SET NULL, NO ACTION or SET DEFAULT",
@@ -9627,6 +10829,21 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse the optional column list of a `SET NULL` / `SET DEFAULT`
+    /// referential action, which is only allowed for `ON DELETE`.
+    fn parse_referential_action_columns(
+        &mut self,
+        event: Keyword,
+    ) -> Result<Vec<Ident>, ParserError> {
+        if event != Keyword::DELETE && self.peek_token_ref().token == Token::LParen {
+            return parser_err!(
+                "a column list is only supported for ON DELETE SET NULL or SET DEFAULT",
+                self.peek_token_ref().span.start
+            );
+        }
+        self.parse_parenthesized_column_list(Optional, false)
+    }
+
     /// Parse a `MATCH` kind for constraint references: `FULL`, `PARTIAL`, or `SIMPLE`.
     pub fn parse_match_kind(&mut self) -> Result<ConstraintReferenceMatchKind, ParserError> {
         if self.parse_keyword(Keyword::FULL) {
@@ -9681,18 +10898,45 @@ impl<'a> Parser<'a> {
                 && self.parse_keywords(&[Keyword::NOT, Keyword::ENFORCED])
             {
                 cc.enforced = Some(false);
+            } else if !cc.not_for_replication && self.parse_not_for_replication() {
+                cc.not_for_replication = true;
             } else {
                 break;
             }
         }
 
-        if cc.deferrable.is_some() || cc.initially.is_some() || cc.enforced.is_some() {
+        if cc.deferrable.is_some()
+            || cc.initially.is_some()
+            || cc.enforced.is_some()
+            || cc.not_for_replication
+        {
             Ok(Some(cc))
         } else {
             Ok(None)
         }
     }
 
+    /// Parse MSSQL's `NOT FOR REPLICATION` marker on identity columns and
+    /// foreign key or check constraints.
+    fn parse_not_for_replication(&mut self) -> bool {
+        self.dialect.supports_not_for_replication()
+            && self.parse_keywords(&[Keyword::NOT, Keyword::FOR, Keyword::REPLICATION])
+    }
+
+    /// Parse an `exclude_element WITH operator` pair of an `EXCLUDE` constraint.
+    fn parse_exclude_element(&mut self) -> Result<ExcludeElement, ParserError> {
+        let expr = self.parse_create_index_expr()?;
+        self.expect_keyword_is(Keyword::WITH)?;
+        let next_token = self.next_token();
+        let operator = match &next_token.token {
+            Token::EOF | Token::Comma | Token::RParen => {
+                return self.expected("an operator after WITH", next_token);
+            }
+            token => token.to_string(),
+        };
+        Ok(ExcludeElement { expr, operator })
+    }
+
     /// Parse an optional table constraint (e.g. `PRIMARY KEY`, `UNIQUE`, `FOREIGN KEY`, `CHECK`).
     pub fn parse_optional_table_constraint(
         &mut self,
@@ -9807,11 +11051,11 @@ impl<'a> Parser<'a> {
                     } else if on_delete.is_none()
                         && self.parse_keywords(&[Keyword::ON, Keyword::DELETE])
                     {
-                        on_delete = Some(self.parse_referential_action()?);
+                        on_delete = Some(self.parse_referential_action(Keyword::DELETE)?);
                     } else if on_update.is_none()
                         && self.parse_keywords(&[Keyword::ON, Keyword::UPDATE])
                     {
-                        on_update = Some(self.parse_referential_action()?);
+                        on_update = Some(self.parse_referential_action(Keyword::UPDATE)?);
                     } else {
                         break;
                     }
@@ -9835,10 +11079,13 @@ impl<'a> Parser<'a> {
                 ))
             }
             Token::Word(w) if w.keyword == Keyword::CHECK => {
+                let not_for_replication = self.parse_not_for_replication();
                 self.expect_token(&Token::LParen)?;
                 let expr = Box::new(self.parse_expr()?);
                 self.expect_token(&Token::RParen)?;
 
+                let no_inherit = self.parse_keywords(&[Keyword::NO, Keyword::INHERIT]);
+
                 let enforced = if self.parse_keyword(Keyword::ENFORCED) {
                     Some(true)
                 } else if self.parse_keywords(&[Keyword::NOT, Keyword::ENFORCED]) {
@@ -9851,7 +11098,39 @@ impl<'a> Parser<'a> {
                     CheckConstraint {
                         name,
                         expr,
+                        no_inherit,
                         enforced,
+                        not_for_replication,
+                    }
+                    .into(),
+                ))
+            }
+            Token::Word(w)
+                if w.keyword == Keyword::EXCLUDE
+                    && self.dialect.supports_exclusion_constraint()
+                    && (self.peek_keyword(Keyword::USING)
+                        || self.peek_token_ref().token == Token::LParen) =>
+            {
+                let index_type = self.parse_optional_using_then_index_type()?;
+                self.expect_token(&Token::LParen)?;
+                let elements = self.parse_comma_separated(Parser::parse_exclude_element)?;
+                self.expect_token(&Token::RParen)?;
+                let where_clause = if self.parse_keyword(Keyword::WHERE) {
+                    self.expect_token(&Token::LParen)?;
+                    let predicate = self.parse_expr()?;
+                    self.expect_token(&Token::RParen)?;
+                    Some(Box::new(predicate))
+                } else {
+                    None
+                };
+                let characteristics = self.parse_constraint_characteristics()?;
+                Ok(Some(
+                    ExclusionConstraint {
+                        name,
+                        index_type,
+                        elements,
+                        where_clause,
+                        characteristics,
                     }
                     .into(),
                 ))
@@ -10077,7 +11356,22 @@ impl<'a> Parser<'a> {
             _ => {
                 let name = self.parse_identifier()?;
                 self.expect_token(&Token::Eq)?;
-                let value = self.parse_expr()?;
+                // MSSQL nests options under `ON`, e.g. `SYSTEM_VERSIONING = ON (HISTORY_TABLE = h)`
+                if is_mssql
+                    && self.peek_keyword(Keyword::ON)
+                    && self.peek_nth_token_ref(1).token == Token::LParen
+                {
+                    let value = Expr::Identifier(self.parse_identifier()?);
+                    self.expect_token(&Token::LParen)?;
+                    let options = self.parse_comma_separated(Parser::parse_sql_option)?;
+                    self.expect_token(&Token::RParen)?;
+                    return Ok(SqlOption::KeyValueWithOptions {
+                        key: name,
+                        value,
+                        options,
+                    });
+                }
+                let value = self.parse_expr_or_default()?;
 
                 Ok(SqlOption::KeyValue { key: name, value })
             }
@@ -10197,10 +11491,77 @@ impl<'a> Parser<'a> {
         Ok(AlterTableOperation::AlterSortKey { columns })
     }
 
+    /// Parse a comma followed by another column definition of an unparenthesized
+    /// `ADD col1 type1, col2 type2` list, failing if the comma is instead followed
+    /// by the next `ALTER TABLE` operation.
+    fn parse_additional_column_def(&mut self) -> Result<ColumnDef, ParserError> {
+        self.expect_token(&Token::Comma)?;
+        let next_token = self.peek_token_ref();
+        if let Token::Word(w) = &next_token.token {
+            if w.quote_style.is_none()
+                && matches!(
+                    w.keyword,
+                    Keyword::ADD
+                        | Keyword::ALGORITHM
+                        | Keyword::ALTER
+                        | Keyword::ATTACH
+                        | Keyword::AUTO_INCREMENT
+                        | Keyword::CHANGE
+                        | Keyword::CLEAR
+                        | Keyword::CLUSTER
+                        | Keyword::CONVERT
+                        | Keyword::DELETE
+                        | Keyword::DETACH
+                        | Keyword::DISABLE
+                        | Keyword::DROP
+                        | Keyword::ENABLE
+                        | Keyword::FORCE
+                        | Keyword::FREEZE
+                        | Keyword::INHERIT
+                        | Keyword::LOCK
+                        | Keyword::MATERIALIZE
+                        | Keyword::MODIFY
+                        | Keyword::NO
+                        | Keyword::NOT
+                        | Keyword::OF
+                        | Keyword::ORDER
+                        | Keyword::OWNER
+                        | Keyword::PARTITION
+                        | Keyword::RECLUSTER
+                        | Keyword::REFRESH
+                        | Keyword::RENAME
+                        | Keyword::REPLICA
+                        | Keyword::RESET
+                        | Keyword::RESUME
+                        | Keyword::SET
+                        | Keyword::SUSPEND
+                        | Keyword::SWAP
+                        | Keyword::UNFREEZE
+                        | Keyword::UNSET
+                        | Keyword::UPDATE
+                        | Keyword::VALIDATE
+                )
+            {
+                return self.expected_ref("a column definition", next_token);
+            }
+        }
+        self.parse_column_def()
+    }
+
     /// Parse a single `ALTER TABLE` operation and return an `AlterTableOperation`.
     pub fn parse_alter_table_operation(&mut self) -> Result<AlterTableOperation, ParserError> {
         let operation = if self.parse_keyword(Keyword::ADD) {
-            if let Some(constraint) = self.parse_optional_table_constraint()? {
+            if dialect_of!(self is SnowflakeDialect|GenericDialect)
+                && self.parse_keywords(&[Keyword::SEARCH, Keyword::OPTIMIZATION])
+            {
+                AlterTableOperation::AddSearchOptimization {
+                    on: self.parse_optional_search_optimization_on()?,
+                }
+            } else if self.dialect.supports_system_versioned_tables()
+                && self.parse_keywords(&[Keyword::SYSTEM, Keyword::VERSIONING])
+            {
+                AlterTableOperation::AddSystemVersioning
+            } else if let Some(constraint) = self.parse_optional_table_constraint()? {
                 let not_valid = self.parse_keywords(&[Keyword::NOT, Keyword::VALID]);
                 AlterTableOperation::AddConstraint {
                     constraint,
@@ -10237,15 +11598,49 @@ impl<'a> Parser<'a> {
                         false
                     };
 
+                    if self.dialect.supports_alter_table_add_multiple_columns()
+                        && self.consume_token(&Token::LParen)
+                    {
+                        let column_defs = self.parse_comma_separated(Parser::parse_column_def)?;
+                        self.expect_token(&Token::RParen)?;
+                        return Ok(AlterTableOperation::AddColumns {
+                            column_keyword,
+                            if_not_exists,
+                            column_defs,
+                            parenthesized: true,
+                        });
+                    }
+
                     let column_def = self.parse_column_def()?;
 
                     let column_position = self.parse_column_position()?;
 
-                    AlterTableOperation::AddColumn {
-                        column_keyword,
-                        if_not_exists,
-                        column_def,
-                        column_position,
+                    let mut column_defs = vec![];
+                    if column_position.is_none()
+                        && self.dialect.supports_alter_table_add_multiple_columns()
+                    {
+                        while let Some(column_def) =
+                            self.maybe_parse(|p| p.parse_additional_column_def())?
+                        {
+                            column_defs.push(column_def);
+                        }
+                    }
+
+                    if !column_defs.is_empty() {
+                        column_defs.insert(0, column_def);
+                        AlterTableOperation::AddColumns {
+                            column_keyword,
+                            if_not_exists,
+                            column_defs,
+                            parenthesized: false,
+                        }
+                    } else {
+                        AlterTableOperation::AddColumn {
+                            column_keyword,
+                            if_not_exists,
+                            column_def,
+                            column_position,
+                        }
                     }
                 }
             }
@@ -10363,7 +11758,17 @@ impl<'a> Parser<'a> {
                 partition,
             }
         } else if self.parse_keyword(Keyword::DROP) {
-            if self.parse_keywords(&[Keyword::IF, Keyword::EXISTS, Keyword::PARTITION]) {
+            if dialect_of!(self is SnowflakeDialect|GenericDialect)
+                && self.parse_keywords(&[Keyword::SEARCH, Keyword::OPTIMIZATION])
+            {
+                AlterTableOperation::DropSearchOptimization {
+                    on: self.parse_optional_search_optimization_on()?,
+                }
+            } else if self.dialect.supports_system_versioned_tables()
+                && self.parse_keywords(&[Keyword::SYSTEM, Keyword::VERSIONING])
+            {
+                AlterTableOperation::DropSystemVersioning
+            } else if self.parse_keywords(&[Keyword::IF, Keyword::EXISTS, Keyword::PARTITION]) {
                 self.expect_token(&Token::LParen)?;
                 let partitions = self.parse_comma_separated(Parser::parse_expr)?;
                 self.expect_token(&Token::RParen)?;
@@ -10457,9 +11862,50 @@ impl<'a> Parser<'a> {
                 options,
                 column_position,
             }
+        } else if dialect_of!(self is ClickHouseDialect|GenericDialect)
+            && self.parse_keywords(&[Keyword::MODIFY, Keyword::TTL])
+        {
+            AlterTableOperation::ModifyTtl {
+                clauses: self.parse_comma_separated(Parser::parse_ttl_clause)?,
+            }
+        } else if dialect_of!(self is ClickHouseDialect|GenericDialect)
+            && self.parse_keywords(&[Keyword::REMOVE, Keyword::TTL])
+        {
+            AlterTableOperation::RemoveTtl
+        } else if dialect_of!(self is ClickHouseDialect|GenericDialect)
+            && self.parse_keywords(&[Keyword::MODIFY, Keyword::QUERY])
+        {
+            AlterTableOperation::ModifyQuery {
+                query: self.parse_query()?,
+            }
+        } else if dialect_of!(self is ClickHouseDialect|GenericDialect)
+            && self.parse_keywords(&[Keyword::COMMENT, Keyword::COLUMN])
+        {
+            let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+            let column = self.parse_identifier()?;
+            let comment = self.parse_literal_string()?;
+            AlterTableOperation::CommentColumn {
+                column,
+                comment,
+                if_exists,
+            }
+        } else if dialect_of!(self is MySqlDialect|GenericDialect)
+            && self.parse_keyword(Keyword::COMMENT)
+        {
+            let has_eq = self.consume_token(&Token::Eq);
+            let comment = self.parse_literal_string()?;
+            AlterTableOperation::SetComment { comment, has_eq }
         } else if self.parse_keyword(Keyword::MODIFY) {
-            let _ = self.parse_keyword(Keyword::COLUMN); // [ COLUMN ]
+            let has_column_keyword = self.parse_keyword(Keyword::COLUMN); // [ COLUMN ]
             let col_name = self.parse_identifier()?;
+            // Snowflake treats `MODIFY` as a synonym of `ALTER` for column tags
+            if let Some(op) = self.parse_alter_column_tag_operation()? {
+                return Ok(AlterTableOperation::ModifyColumnOperation {
+                    has_column_keyword,
+                    column_name: col_name,
+                    op,
+                });
+            }
             let data_type = self.parse_data_type()?;
             let mut options = vec![];
             while let Some(option) = self.parse_optional_column_option()? {
@@ -10498,10 +11944,35 @@ impl<'a> Parser<'a> {
                 }
             } else if self.parse_keywords(&[Keyword::DROP, Keyword::DEFAULT]) {
                 AlterColumnOperation::DropDefault {}
+            } else if self.parse_keywords(&[Keyword::DROP, Keyword::IDENTITY]) {
+                AlterColumnOperation::DropIdentity {
+                    if_exists: self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]),
+                }
+            } else if self.parse_keywords(&[Keyword::SET, Keyword::GENERATED]) {
+                self.parse_alter_column_set_generated()?
             } else if self.parse_keywords(&[Keyword::SET, Keyword::DATA, Keyword::TYPE]) {
                 self.parse_set_data_type(true)?
             } else if self.parse_keyword(Keyword::TYPE) {
                 self.parse_set_data_type(false)?
+            } else if let Some(op) = self.parse_alter_column_tag_operation()? {
+                op
+            } else if self.parse_keywords(&[Keyword::SET, Keyword::STATISTICS]) {
+                AlterColumnOperation::SetStatistics {
+                    value: self.parse_signed_number_value()?,
+                }
+            } else if self.parse_keywords(&[Keyword::SET, Keyword::STORAGE]) {
+                let storage = match self.expect_one_of_keywords(&[
+                    Keyword::PLAIN,
+                    Keyword::EXTERNAL,
+                    Keyword::EXTENDED,
+                    Keyword::MAIN,
+                ])? {
+                    Keyword::PLAIN => ColumnStorage::Plain,
+                    Keyword::EXTERNAL => ColumnStorage::External,
+                    Keyword::EXTENDED => ColumnStorage::Extended,
+                    _ => ColumnStorage::Main,
+                };
+                AlterColumnOperation::SetStorage { storage }
             } else if self.parse_keywords(&[Keyword::ADD, Keyword::GENERATED]) {
                 let generated_as = if self.parse_keyword(Keyword::ALWAYS) {
                     Some(GeneratedAs::Always)
@@ -10527,7 +11998,7 @@ impl<'a> Parser<'a> {
                 }
             } else {
                 let message = if is_postgresql {
-                    "SET/DROP NOT NULL, SET DEFAULT, SET DATA TYPE, or ADD GENERATED after ALTER COLUMN"
+                    "SET/DROP NOT NULL, SET DEFAULT, SET DATA TYPE, ADD/SET GENERATED, or DROP IDENTITY after ALTER COLUMN"
                 } else {
                     "SET/DROP NOT NULL, SET DEFAULT, or SET DATA TYPE after ALTER COLUMN"
                 };
@@ -10544,6 +12015,24 @@ impl<'a> Parser<'a> {
         {
             let new_owner = self.parse_owner()?;
             AlterTableOperation::OwnerTo { new_owner }
+        } else if self.parse_keywords(&[Keyword::SET, Keyword::SCHEMA]) {
+            let schema_name = self.parse_object_name(false)?;
+            AlterTableOperation::SetSchema { schema_name }
+        } else if self.dialect.supports_alter_table_inherit()
+            && self.parse_keyword(Keyword::INHERIT)
+        {
+            let parent = self.parse_object_name(false)?;
+            AlterTableOperation::Inherit { parent }
+        } else if self.dialect.supports_alter_table_inherit()
+            && self.parse_keywords(&[Keyword::NO, Keyword::INHERIT])
+        {
+            let parent = self.parse_object_name(false)?;
+            AlterTableOperation::NoInherit { parent }
+        } else if self.parse_keyword(Keyword::OF) {
+            let type_name = self.parse_object_name(false)?;
+            AlterTableOperation::Of { type_name }
+        } else if self.parse_keywords(&[Keyword::NOT, Keyword::OF]) {
+            AlterTableOperation::NotOf
         } else if dialect_of!(self is ClickHouseDialect|GenericDialect)
             && self.parse_keyword(Keyword::ATTACH)
         {
@@ -10584,11 +12073,35 @@ impl<'a> Parser<'a> {
                 partition,
                 with_name,
             }
+        } else if dialect_of!(self is ClickHouseDialect|GenericDialect)
+            && self.parse_keyword(Keyword::UPDATE)
+        {
+            let assignments = self.parse_comma_separated(Parser::parse_assignment)?;
+            let in_partition = self.parse_optional_in_partition()?;
+            self.expect_keyword_is(Keyword::WHERE)?;
+            AlterTableOperation::Update {
+                assignments,
+                in_partition,
+                selection: self.parse_expr()?,
+            }
+        } else if dialect_of!(self is ClickHouseDialect|GenericDialect)
+            && self.parse_keyword(Keyword::DELETE)
+        {
+            let in_partition = self.parse_optional_in_partition()?;
+            self.expect_keyword_is(Keyword::WHERE)?;
+            AlterTableOperation::Delete {
+                in_partition,
+                selection: self.parse_expr()?,
+            }
         } else if self.parse_keywords(&[Keyword::CLUSTER, Keyword::BY]) {
             self.expect_token(&Token::LParen)?;
             let exprs = self.parse_comma_separated(|parser| parser.parse_expr())?;
             self.expect_token(&Token::RParen)?;
             AlterTableOperation::ClusterBy { exprs }
+        } else if let Some(tag_list) = self.parse_optional_set_tag()? {
+            AlterTableOperation::SetTag { tag_list }
+        } else if let Some(tag_names) = self.parse_optional_unset_tag()? {
+            AlterTableOperation::UnsetTag { tag_names }
         } else if self.parse_keywords(&[Keyword::SUSPEND, Keyword::RECLUSTER]) {
             AlterTableOperation::SuspendRecluster
         } else if self.parse_keywords(&[Keyword::RESUME, Keyword::RECLUSTER]) {
@@ -10629,10 +12142,25 @@ impl<'a> Parser<'a> {
                 )?,
             };
             AlterTableOperation::Algorithm { equals, algorithm }
+        } else if self.dialect.supports_alter_table_force() && self.parse_keyword(Keyword::FORCE) {
+            AlterTableOperation::Force
         } else if self.parse_keyword(Keyword::AUTO_INCREMENT) {
             let equals = self.consume_token(&Token::Eq);
             let value = self.parse_number_value()?;
             AlterTableOperation::AutoIncrement { equals, value }
+        } else if self.parse_keywords(&[
+            Keyword::CONVERT,
+            Keyword::TO,
+            Keyword::CHARACTER,
+            Keyword::SET,
+        ]) {
+            let charset = self.parse_identifier()?;
+            let collation = if self.parse_keyword(Keyword::COLLATE) {
+                Some(self.parse_identifier()?)
+            } else {
+                None
+            };
+            AlterTableOperation::ConvertToCharacterSet { charset, collation }
         } else if self.parse_keywords(&[Keyword::REPLICA, Keyword::IDENTITY]) {
             let identity = if self.parse_keyword(Keyword::NOTHING) {
                 ReplicaIdentity::Nothing
@@ -10653,6 +12181,10 @@ impl<'a> Parser<'a> {
         } else if self.parse_keywords(&[Keyword::VALIDATE, Keyword::CONSTRAINT]) {
             let name = self.parse_identifier()?;
             AlterTableOperation::ValidateConstraint { name }
+        } else if self.parse_keyword_with_tokens(Keyword::RESET, &[Token::LParen]) {
+            let options = self.parse_comma_separated(|p| p.parse_object_name(false))?;
+            self.expect_token(&Token::RParen)?;
+            AlterTableOperation::ResetOptionsParens { options }
         } else {
             let mut options =
                 self.parse_options_with_keywords(&[Keyword::SET, Keyword::TBLPROPERTIES])?;
@@ -10666,15 +12198,110 @@ impl<'a> Parser<'a> {
                     AlterTableOperation::SetOptionsParens { options }
                 } else {
                     return self.expected_ref(
-                    "ADD, RENAME, PARTITION, SWAP, DROP, REPLICA IDENTITY, SET, or SET TBLPROPERTIES after ALTER TABLE",
-                    self.peek_token_ref(),
-                  );
+                        "ADD, RENAME, PARTITION, SWAP, DROP, REPLICA IDENTITY, SET, or SET TBLPROPERTIES after ALTER TABLE",
+                        self.peek_token_ref(),
+                    );
                 }
             }
         };
         Ok(operation)
     }
 
+    /// Parse the remainder of an `ALTER COLUMN ... SET GENERATED` operation,
+    /// including any trailing `SET sequence_option` and `RESTART` clauses.
+    fn parse_alter_column_set_generated(&mut self) -> Result<AlterColumnOperation, ParserError> {
+        let generated_as = if self.parse_keyword(Keyword::ALWAYS) {
+            GeneratedAs::Always
+        } else {
+            self.expect_keywords(&[Keyword::BY, Keyword::DEFAULT])?;
+            GeneratedAs::ByDefault
+        };
+        let mut sequence_options = vec![];
+        loop {
+            if self.parse_keyword(Keyword::RESTART) {
+                let with = self.parse_keyword(Keyword::WITH);
+                let restart = if with
+                    || matches!(
+                        self.peek_token_ref().token,
+                        Token::Number(..) | Token::Minus | Token::Plus
+                    ) {
+                    Some(self.parse_number()?)
+                } else {
+                    None
+                };
+                sequence_options.push(SequenceOptions::Restart(restart, with));
+            } else if self.parse_keyword(Keyword::SET) {
+                let options = self.parse_create_sequence_options()?;
+                if options.is_empty() {
+                    self.prev_token();
+                    break;
+                }
+                sequence_options.extend(options);
+            } else {
+                break;
+            }
+        }
+        Ok(AlterColumnOperation::SetGenerated {
+            generated_as,
+            sequence_options: if sequence_options.is_empty() {
+                None
+            } else {
+                Some(sequence_options)
+            },
+        })
+    }
+
+    /// Parse the optional `ON <method>(<target>) [, ...]` list of a Snowflake
+    /// `{ ADD | DROP } SEARCH OPTIMIZATION` operation.
+    fn parse_optional_search_optimization_on(&mut self) -> Result<Option<Vec<Expr>>, ParserError> {
+        if !self.parse_keyword(Keyword::ON) {
+            return Ok(None);
+        }
+        let on = self.parse_comma_separated(|p| {
+            let name = p.parse_object_name(false)?;
+            p.parse_function(name)
+        })?;
+        Ok(Some(on))
+    }
+
+    /// Parse an optional `SET TAG <tag_name> = '<tag_value>' [, ...]` clause,
+    /// returning `None` if the dialect does not support it or it is not present.
+    fn parse_optional_set_tag(&mut self) -> Result<Option<Vec<Tag>>, ParserError> {
+        if !self.dialect.supports_alter_tag_operations()
+            || !self.parse_keywords(&[Keyword::SET, Keyword::TAG])
+        {
+            return Ok(None);
+        }
+        Ok(Some(self.parse_comma_separated(Parser::parse_tag)?))
+    }
+
+    /// Parse an optional `UNSET TAG <tag_name> [, ...]` clause,
+    /// returning `None` if the dialect does not support it or it is not present.
+    fn parse_optional_unset_tag(&mut self) -> Result<Option<Vec<ObjectName>>, ParserError> {
+        if !self.dialect.supports_alter_tag_operations()
+            || !self.parse_keywords(&[Keyword::UNSET, Keyword::TAG])
+        {
+            return Ok(None);
+        }
+        Ok(Some(
+            self.parse_comma_separated(|p| p.parse_object_name(false))?,
+        ))
+    }
+
+    /// Parse the `SET TAG ...` / `UNSET TAG ...` column operations,
+    /// returning `None` if neither is present.
+    fn parse_alter_column_tag_operation(
+        &mut self,
+    ) -> Result<Option<AlterColumnOperation>, ParserError> {
+        if let Some(tag_list) = self.parse_optional_set_tag()? {
+            Ok(Some(AlterColumnOperation::SetTag { tag_list }))
+        } else if let Some(tag_names) = self.parse_optional_unset_tag()? {
+            Ok(Some(AlterColumnOperation::UnsetTag { tag_names }))
+        } else {
+            Ok(None)
+        }
+    }
+
     fn parse_set_data_type(&mut self, had_set: bool) -> Result<AlterColumnOperation, ParserError> {
         let data_type = self.parse_data_type()?;
         let using = if self.dialect.supports_alter_column_type_using()
@@ -10691,6 +12318,33 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse a ClickHouse TTL clause: `expr [DELETE | TO DISK 'name' | TO VOLUME 'name']`.
+    pub fn parse_ttl_clause(&mut self) -> Result<TtlClause, ParserError> {
+        let expr = self.parse_expr()?;
+        let action = if self.parse_keyword(Keyword::DELETE) {
+            Some(TtlAction::Delete)
+        } else if self.parse_keywords(&[Keyword::TO, Keyword::DISK]) {
+            Some(TtlAction::ToDisk(self.parse_literal_string()?))
+        } else if self.parse_keywords(&[Keyword::TO, Keyword::VOLUME]) {
+            Some(TtlAction::ToVolume(self.parse_literal_string()?))
+        } else {
+            None
+        };
+        Ok(TtlClause { expr, action })
+    }
+
+    /// Parse an optional ClickHouse `IN PARTITION <partition_expr>` clause of a mutation.
+    fn parse_optional_in_partition(&mut self) -> Result<Option<Partition>, ParserError> {
+        if !self.parse_keywords(&[Keyword::IN, Keyword::PARTITION]) {
+            return Ok(None);
+        }
+        if self.parse_keyword(Keyword::ID) {
+            Ok(Some(Partition::Identifier(self.parse_identifier()?)))
+        } else {
+            Ok(Some(Partition::Expr(self.parse_expr()?)))
+        }
+    }
+
     fn parse_part_or_partition(&mut self) -> Result<Partition, ParserError> {
         let keyword = self.expect_one_of_keywords(&[Keyword::PART, Keyword::PARTITION])?;
         match keyword {
@@ -10705,6 +12359,31 @@ impl<'a> Parser<'a> {
 
     /// Parse an `ALTER <object>` statement and dispatch to the appropriate alter handler.
     pub fn parse_alter(&mut self) -> Result<Statement, ParserError> {
+        if self.dialect.supports_event_scheduler() {
+            if self.parse_keyword(Keyword::DEFINER) {
+                self.expect_token(&Token::Eq)?;
+                let definer = self.parse_grantee_name()?;
+                self.expect_keyword_is(Keyword::EVENT)?;
+                return self.parse_alter_event(Some(definer)).map(Into::into);
+            } else if self.parse_keyword(Keyword::EVENT) {
+                return self.parse_alter_event(None).map(Into::into);
+            }
+        }
+        if self.dialect.supports_extended_statistics() && self.parse_keyword(Keyword::STATISTICS) {
+            return self.parse_alter_statistics().map(Into::into);
+        }
+        if self.dialect.supports_catalog_ddl() && self.parse_keyword(Keyword::CATALOG) {
+            return self.parse_alter_catalog().map(Into::into);
+        }
+        if self.dialect.supports_alter_materialized_view()
+            && self.parse_keywords(&[Keyword::MATERIALIZED, Keyword::VIEW])
+        {
+            return self.parse_alter_materialized_view();
+        }
+        if self.peek_user_mapping() {
+            self.expect_keywords(&[Keyword::USER, Keyword::MAPPING])?;
+            return self.parse_pg_alter_user_mapping().map(Into::into);
+        }
         let object_type = self.expect_one_of_keywords(&[
             Keyword::VIEW,
             Keyword::TYPE,
@@ -10713,6 +12392,7 @@ impl<'a> Parser<'a> {
             Keyword::INDEX,
             Keyword::FUNCTION,
             Keyword::AGGREGATE,
+            Keyword::PROCEDURE,
             Keyword::ROLE,
             Keyword::POLICY,
             Keyword::CONNECTOR,
@@ -10720,6 +12400,9 @@ impl<'a> Parser<'a> {
             Keyword::SCHEMA,
             Keyword::USER,
             Keyword::OPERATOR,
+            Keyword::SEQUENCE,
+            Keyword::TEXT,
+            Keyword::SERVER,
         ])?;
         match object_type {
             Keyword::SCHEMA => {
@@ -10755,6 +12438,7 @@ impl<'a> Parser<'a> {
             }
             Keyword::FUNCTION => self.parse_alter_function(AlterFunctionKind::Function),
             Keyword::AGGREGATE => self.parse_alter_function(AlterFunctionKind::Aggregate),
+            Keyword::PROCEDURE => self.parse_alter_function(AlterFunctionKind::Procedure),
             Keyword::OPERATOR => {
                 if self.parse_keyword(Keyword::FAMILY) {
                     self.parse_alter_operator_family().map(Into::into)
@@ -10768,9 +12452,16 @@ impl<'a> Parser<'a> {
             Keyword::POLICY => self.parse_alter_policy().map(Into::into),
             Keyword::CONNECTOR => self.parse_alter_connector(),
             Keyword::USER => self.parse_alter_user().map(Into::into),
+            Keyword::SEQUENCE => self.parse_alter_sequence(),
+            Keyword::TEXT => {
+                self.expect_keywords(&[Keyword::SEARCH, Keyword::CONFIGURATION])?;
+                self.parse_alter_text_search_configuration()
+                    .map(Into::into)
+            }
+            Keyword::SERVER => self.parse_pg_alter_server().map(Into::into),
             // unreachable because expect_one_of_keywords used above
             unexpected_keyword => Err(ParserError::ParserError(
-                format!("Internal parser error: expected any of {{VIEW, TYPE, COLLATION, TABLE, INDEX, FUNCTION, AGGREGATE, ROLE, POLICY, CONNECTOR, ICEBERG, SCHEMA, USER, OPERATOR}}, got {unexpected_keyword:?}"),
+                format!("Internal parser error: expected any of {{VIEW, TYPE, COLLATION, TABLE, INDEX, FUNCTION, AGGREGATE, ROLE, POLICY, CONNECTOR, ICEBERG, SCHEMA, USER, OPERATOR, SEQUENCE, TEXT, SERVER}}, got {unexpected_keyword:?}"),
             )),
         }
     }
@@ -10941,13 +12632,15 @@ impl<'a> Parser<'a> {
         Ok((actions, restrict))
     }
 
-    /// Parse an `ALTER FUNCTION` or `ALTER AGGREGATE` statement.
+    /// Parse an `ALTER FUNCTION`, `ALTER AGGREGATE` or `ALTER PROCEDURE` statement.
     pub fn parse_alter_function(
         &mut self,
         kind: AlterFunctionKind,
     ) -> Result<Statement, ParserError> {
         let (function, aggregate_star, aggregate_order_by) = match kind {
-            AlterFunctionKind::Function => (self.parse_function_desc()?, false, None),
+            AlterFunctionKind::Function | AlterFunctionKind::Procedure => {
+                (self.parse_function_desc()?, false, None)
+            }
             AlterFunctionKind::Aggregate => self.parse_alter_aggregate_signature()?,
         };
 
@@ -10960,7 +12653,7 @@ impl<'a> Parser<'a> {
             AlterFunctionOperation::SetSchema {
                 schema_name: self.parse_object_name(false)?,
             }
-        } else if matches!(kind, AlterFunctionKind::Function) && self.parse_keyword(Keyword::NO) {
+        } else if !matches!(kind, AlterFunctionKind::Aggregate) && self.parse_keyword(Keyword::NO) {
             if !self.parse_keyword(Keyword::DEPENDS) {
                 return self.expected_ref("DEPENDS after NO", self.peek_token_ref());
             }
@@ -10969,7 +12662,7 @@ impl<'a> Parser<'a> {
                 no: true,
                 extension_name: self.parse_object_name(false)?,
             }
-        } else if matches!(kind, AlterFunctionKind::Function)
+        } else if !matches!(kind, AlterFunctionKind::Aggregate)
             && self.parse_keyword(Keyword::DEPENDS)
         {
             self.expect_keywords(&[Keyword::ON, Keyword::EXTENSION])?;
@@ -10977,7 +12670,7 @@ impl<'a> Parser<'a> {
                 no: false,
                 extension_name: self.parse_object_name(false)?,
             }
-        } else if matches!(kind, AlterFunctionKind::Function) {
+        } else if !matches!(kind, AlterFunctionKind::Aggregate) {
             let (actions, restrict) = self.parse_alter_function_actions()?;
             AlterFunctionOperation::Actions { actions, restrict }
         } else {
@@ -11040,22 +12733,150 @@ impl<'a> Parser<'a> {
         .into())
     }
 
+    /// Parse an `ALTER SEQUENCE` statement.
+    ///
+    /// Only the operations shared with `ALTER TABLE`, such as `SET SCHEMA`,
+    /// are supported.
+    pub fn parse_alter_sequence(&mut self) -> Result<Statement, ParserError> {
+        let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = self.parse_object_name(false)?;
+        self.parse_alter_table_like(name, if_exists, AlterTableType::Sequence)
+    }
+
+    /// Parse the operations of an `ALTER VIEW` or `ALTER SEQUENCE` statement
+    /// that are represented as [AlterTable] with the given `table_type`.
+    fn parse_alter_table_like(
+        &mut self,
+        name: ObjectName,
+        if_exists: bool,
+        table_type: AlterTableType,
+    ) -> Result<Statement, ParserError> {
+        let operations = self.parse_comma_separated(Parser::parse_alter_table_operation)?;
+        let end_token = if self.peek_token_ref().token == Token::SemiColon {
+            self.peek_token_ref().clone()
+        } else {
+            self.get_current_token().clone()
+        };
+
+        Ok(AlterTable {
+            name,
+            if_exists,
+            only: false,
+            operations,
+            location: None,
+            on_cluster: None,
+            table_type: Some(table_type),
+            end_token: AttachedToken(end_token),
+        }
+        .into())
+    }
+
     /// Parse an `ALTER VIEW` statement.
     pub fn parse_alter_view(&mut self) -> Result<Statement, ParserError> {
+        let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
         let name = self.parse_object_name(false)?;
-        let columns = self.parse_parenthesized_column_list(Optional, false)?;
+        if self.peek_keywords(&[Keyword::SET, Keyword::SCHEMA]) {
+            return self.parse_alter_table_like(name, if_exists, AlterTableType::View);
+        }
+        let operation = self.parse_alter_view_operation()?;
+        Ok(Statement::AlterView {
+            if_exists,
+            name,
+            operation,
+        })
+    }
 
-        let with_options = self.parse_options(Keyword::WITH)?;
+    /// Parse an `ALTER MATERIALIZED VIEW` statement.
+    ///
+    /// See [Statement::AlterMaterializedView]
+    pub fn parse_alter_materialized_view(&mut self) -> Result<Statement, ParserError> {
+        let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = self.parse_object_name(false)?;
+        let operations =
+            self.parse_comma_separated(Parser::parse_alter_materialized_view_operation)?;
+        Ok(Statement::AlterMaterializedView {
+            if_exists,
+            name,
+            operations,
+        })
+    }
 
-        self.expect_keyword_is(Keyword::AS)?;
-        let query = self.parse_query()?;
+    /// Parse a single operation of an `ALTER MATERIALIZED VIEW` statement.
+    fn parse_alter_materialized_view_operation(
+        &mut self,
+    ) -> Result<AlterViewOperation, ParserError> {
+        let operation = if self.parse_keywords(&[Keyword::RENAME, Keyword::TO]) {
+            AlterViewOperation::RenameTo {
+                new_name: self.parse_object_name(false)?,
+            }
+        } else if self.parse_keywords(&[Keyword::OWNER, Keyword::TO]) {
+            AlterViewOperation::OwnerTo {
+                new_owner: self.parse_owner()?,
+            }
+        } else if self.parse_keywords(&[Keyword::SET, Keyword::SCHEMA]) {
+            AlterViewOperation::SetSchema {
+                schema_name: self.parse_object_name(false)?,
+            }
+        } else if self.peek_keyword(Keyword::SET) {
+            AlterViewOperation::SetOptions {
+                options: self.parse_options(Keyword::SET)?,
+            }
+        } else if self.parse_keyword(Keyword::RESET) {
+            self.expect_token(&Token::LParen)?;
+            let names = self.parse_comma_separated(|p| p.parse_identifier())?;
+            self.expect_token(&Token::RParen)?;
+            AlterViewOperation::ResetOptions { names }
+        } else {
+            return self.expected_ref(
+                "RENAME TO, OWNER TO, SET SCHEMA, SET or RESET after ALTER MATERIALIZED VIEW",
+                self.peek_token_ref(),
+            );
+        };
+        Ok(operation)
+    }
 
-        Ok(Statement::AlterView {
-            name,
-            columns,
-            query,
-            with_options,
-        })
+    /// Parse the operation of an `ALTER VIEW` statement.
+    pub fn parse_alter_view_operation(&mut self) -> Result<AlterViewOperation, ParserError> {
+        let operation = if self.parse_keywords(&[Keyword::RENAME, Keyword::TO]) {
+            AlterViewOperation::RenameTo {
+                new_name: self.parse_object_name(false)?,
+            }
+        } else if self.parse_keywords(&[Keyword::OWNER, Keyword::TO]) {
+            AlterViewOperation::OwnerTo {
+                new_owner: self.parse_owner()?,
+            }
+        } else if self.parse_keywords(&[Keyword::SET, Keyword::SECURE]) {
+            AlterViewOperation::SetSecure
+        } else if self.parse_keywords(&[Keyword::UNSET, Keyword::SECURE]) {
+            AlterViewOperation::UnsetSecure
+        } else if self.peek_keyword(Keyword::SET) {
+            AlterViewOperation::SetOptions {
+                options: self.parse_options(Keyword::SET)?,
+            }
+        } else if self.parse_keyword(Keyword::RESET) {
+            self.expect_token(&Token::LParen)?;
+            let names = self.parse_comma_separated(|p| p.parse_identifier())?;
+            self.expect_token(&Token::RParen)?;
+            AlterViewOperation::ResetOptions { names }
+        } else if self.parse_keyword(Keyword::ALTER) {
+            let _ = self.parse_keyword(Keyword::COLUMN); // [ COLUMN ]
+            let column = self.parse_identifier()?;
+            self.expect_keywords(&[Keyword::SET, Keyword::DEFAULT])?;
+            AlterViewOperation::AlterColumnSetDefault {
+                column,
+                default: self.parse_expr()?,
+            }
+        } else {
+            let columns = self.parse_parenthesized_column_list(Optional, false)?;
+            let with_options = self.parse_options(Keyword::WITH)?;
+            self.expect_keyword_is(Keyword::AS)?;
+            AlterViewOperation::As {
+                columns,
+                query: self.parse_query()?,
+                with_options,
+            }
+        };
+        Ok(operation)
     }
 
     /// Parse a [Statement::AlterType]
@@ -11485,14 +13306,23 @@ impl<'a> Parser<'a> {
     /// or `CALL procedure_name` statement
     pub fn parse_call(&mut self) -> Result<Statement, ParserError> {
         let object_name = self.parse_object_name(false)?;
-        if self.peek_token_ref().token == Token::LParen {
-            match self.parse_function(object_name)? {
-                Expr::Function(f) => Ok(Statement::Call(f)),
-                other => parser_err!(
-                    format!("Expected a simple procedure call but found: {other}"),
-                    self.peek_token_ref().span.start
-                ),
-            }
+        if self.consume_token(&Token::LParen) {
+            let args = self.parse_comma_separated0(Parser::parse_call_arg, Token::RParen)?;
+            self.expect_token(&Token::RParen)?;
+            Ok(Statement::Call(Function {
+                name: object_name,
+                uses_odbc_syntax: false,
+                parameters: FunctionArguments::None,
+                args: FunctionArguments::List(FunctionArgumentList {
+                    duplicate_treatment: None,
+                    args,
+                    clauses: vec![],
+                }),
+                over: None,
+                filter: None,
+                null_treatment: None,
+                within_group: vec![],
+            }))
         } else {
             Ok(Statement::Call(Function {
                 name: object_name,
@@ -11507,6 +13337,15 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse an argument of a `CALL` statement, which may be `DEFAULT`,
+    /// e.g. `CALL p(1, DEFAULT)`.
+    fn parse_call_arg(&mut self) -> Result<FunctionArg, ParserError> {
+        if self.peek_keyword(Keyword::DEFAULT) {
+            return Ok(FunctionArg::Unnamed(self.parse_expr_or_default()?.into()));
+        }
+        self.parse_function_args()
+    }
+
     /// Parse a copy statement
     pub fn parse_copy(&mut self) -> Result<Statement, ParserError> {
         let source;
@@ -12118,6 +13957,20 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse an optionally negative numeric literal, folding the sign into the
+    /// returned [`Value::Number`].
+    fn parse_signed_number_value(&mut self) -> Result<Value, ParserError> {
+        let negative = self.consume_token(&Token::Minus);
+        let next_token = self.next_token();
+        match next_token.token {
+            Token::Number(n, l) => {
+                let n = if negative { format!("-{n}") } else { n };
+                Ok(Value::Number(Self::parse(n, next_token.span.start)?, l))
+            }
+            _ => self.expected("literal number", next_token),
+        }
+    }
+
     /// Parse a numeric literal as an expression. Returns a [`Expr::UnaryOp`] if the number is signed,
     /// otherwise returns a [`Expr::Value`]
     pub fn parse_number(&mut self) -> Result<Expr, ParserError> {
@@ -12226,23 +14079,26 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse a unicode normalization form, i.e. one of `NFC`, `NFD`, `NFKC` or `NFKD`.
+    pub fn parse_normalization_form(&mut self) -> Result<NormalizationForm, ParserError> {
+        match self.parse_one_of_keywords(&[
+            Keyword::NFC,
+            Keyword::NFD,
+            Keyword::NFKC,
+            Keyword::NFKD,
+        ]) {
+            Some(Keyword::NFC) => Ok(NormalizationForm::NFC),
+            Some(Keyword::NFD) => Ok(NormalizationForm::NFD),
+            Some(Keyword::NFKC) => Ok(NormalizationForm::NFKC),
+            Some(Keyword::NFKD) => Ok(NormalizationForm::NFKD),
+            _ => self.expected_ref("unicode normalization form", self.peek_token_ref()),
+        }
+    }
+
     /// Parse a literal unicode normalization clause
     pub fn parse_unicode_is_normalized(&mut self, expr: Expr) -> Result<Expr, ParserError> {
         let neg = self.parse_keyword(Keyword::NOT);
-        let normalized_form = self.maybe_parse(|parser| {
-            match parser.parse_one_of_keywords(&[
-                Keyword::NFC,
-                Keyword::NFD,
-                Keyword::NFKC,
-                Keyword::NFKD,
-            ]) {
-                Some(Keyword::NFC) => Ok(NormalizationForm::NFC),
-                Some(Keyword::NFD) => Ok(NormalizationForm::NFD),
-                Some(Keyword::NFKC) => Ok(NormalizationForm::NFKC),
-                Some(Keyword::NFKD) => Ok(NormalizationForm::NFKD),
-                _ => parser.expected_ref("unicode normalization form", parser.peek_token_ref()),
-            }
-        })?;
+        let normalized_form = self.maybe_parse(|parser| parser.parse_normalization_form())?;
         if self.parse_keyword(Keyword::NORMALIZED) {
             return Ok(Expr::IsNormalized {
                 expr: Box::new(expr),
@@ -12593,7 +14449,14 @@ impl<'a> Parser<'a> {
                 Keyword::SET => Ok(DataType::Set(self.parse_string_values()?)),
                 Keyword::ARRAY => {
                     if self.dialect.supports_array_typedef_without_element_type() {
-                        Ok(DataType::Array(ArrayElemTypeDef::None))
+                        // Snowflake structured arrays, e.g. `ARRAY(NUMBER)`
+                        if self.peek_token_ref().token == Token::LParen {
+                            Ok(self.parse_sub_type(|internal_type| {
+                                DataType::Array(ArrayElemTypeDef::Parenthesis(internal_type))
+                            })?)
+                        } else {
+                            Ok(DataType::Array(ArrayElemTypeDef::None))
+                        }
                     } else if dialect_of!(self is ClickHouseDialect) {
                         Ok(self.parse_sub_type(|internal_type| {
                             DataType::Array(ArrayElemTypeDef::Parenthesis(internal_type))
@@ -13047,7 +14910,8 @@ impl<'a> Parser<'a> {
             None
         };
 
-        let (limit, limit_by) = if self.parse_keyword(Keyword::LIMIT) {
+        let leading_offset = offset.is_some();
+        let limit = if self.parse_keyword(Keyword::LIMIT) {
             let expr = self.parse_limit()?;
 
             if self.dialect.supports_limit_comma()
@@ -13066,26 +14930,57 @@ impl<'a> Parser<'a> {
                 }));
             }
 
-            let limit_by = if self.dialect.supports_limit_by() && self.parse_keyword(Keyword::BY) {
-                Some(self.parse_comma_separated(Parser::parse_expr)?)
-            } else {
-                None
-            };
+            if !leading_offset && self.parse_keyword(Keyword::OFFSET) {
+                offset = Some(self.parse_offset()?);
+            }
+
+            if self.dialect.supports_limit_by()
+                && !leading_offset
+                && self.parse_keyword(Keyword::BY)
+            {
+                let Some(limit) = expr else {
+                    return parser_err!(
+                        "LIMIT ALL is not supported with BY",
+                        self.peek_token_ref().span.start
+                    );
+                };
+                let limit_by = LimitBy {
+                    limit,
+                    offset: offset.take(),
+                    exprs: self.parse_comma_separated(Parser::parse_expr)?,
+                };
+                // An `OFFSET` after the `BY` list applies to the whole query,
+                // e.g. `LIMIT 1 BY x OFFSET 2`, and may precede the ordinary `LIMIT`
+                if self.parse_keyword(Keyword::OFFSET) {
+                    offset = Some(self.parse_offset()?);
+                }
+                // An ordinary `LIMIT [OFFSET]` may follow the `LIMIT ... BY` clause
+                let limit = if self.parse_keyword(Keyword::LIMIT) {
+                    let limit = self.parse_limit()?;
+                    if offset.is_none() && self.parse_keyword(Keyword::OFFSET) {
+                        offset = Some(self.parse_offset()?);
+                    }
+                    limit
+                } else {
+                    None
+                };
+                return Ok(Some(LimitClause::LimitOffset {
+                    limit,
+                    offset,
+                    limit_by: Some(limit_by),
+                }));
+            }
 
-            (Some(expr), limit_by)
+            Some(expr)
         } else {
-            (None, None)
+            None
         };
 
-        if offset.is_none() && limit.is_some() && self.parse_keyword(Keyword::OFFSET) {
-            offset = Some(self.parse_offset()?);
-        }
-
-        if offset.is_some() || (limit.is_some() && limit != Some(None)) || limit_by.is_some() {
+        if offset.is_some() || (limit.is_some() && limit != Some(None)) {
             Ok(Some(LimitClause::LimitOffset {
                 limit: limit.unwrap_or_default(),
                 offset,
-                limit_by: limit_by.unwrap_or_default(),
+                limit_by: None,
             }))
         } else {
             Ok(None)
@@ -13161,6 +15056,10 @@ impl<'a> Parser<'a> {
                 {
                     // Empty string here means default schema
                     parts.push(ObjectNamePart::Identifier(Ident::new("")));
+                } else if self.dialect.supports_object_name_macros()
+                    && self.peek_token_ref().token == Token::LBrace
+                {
+                    parts.push(self.parse_object_name_macro(vec![])?);
                 } else {
                     let ident = self.parse_identifier()?;
                     let part = if self
@@ -13172,6 +15071,8 @@ impl<'a> Parser<'a> {
                             self.parse_comma_separated0(Self::parse_function_args, Token::RParen)?;
                         self.expect_token(&Token::RParen)?;
                         ObjectNamePart::Function(ObjectNamePartFunction { name: ident, args })
+                    } else if self.peek_object_name_macro_continuation(&ident) {
+                        self.parse_object_name_macro(vec![MacroSegment::Text(ident)])?
                     } else {
                         ObjectNamePart::Identifier(ident)
                     };
@@ -13214,6 +15115,61 @@ impl<'a> Parser<'a> {
         Ok(ObjectName(parts))
     }
 
+    /// Parse a single object name part, which may contain `{macro}`
+    /// references if the dialect supports them, e.g. `{cluster}`.
+    fn parse_object_name_part_or_macro(&mut self) -> Result<ObjectNamePart, ParserError> {
+        if self.dialect.supports_object_name_macros()
+            && self.peek_token_ref().token == Token::LBrace
+        {
+            return self.parse_object_name_macro(vec![]);
+        }
+        let ident = self.parse_identifier()?;
+        if self.peek_object_name_macro_continuation(&ident) {
+            self.parse_object_name_macro(vec![MacroSegment::Text(ident)])
+        } else {
+            Ok(ObjectNamePart::Identifier(ident))
+        }
+    }
+
+    /// Returns true if the unquoted identifier just parsed is directly
+    /// followed by a `{macro}`, e.g. `db_{shard}`.
+    fn peek_object_name_macro_continuation(&self, ident: &Ident) -> bool {
+        self.dialect.supports_object_name_macros()
+            && ident.quote_style.is_none()
+            && self.peek_token_no_skip().token == Token::LBrace
+    }
+
+    /// Parse the rest of an object name part containing `{macro}`
+    /// references, after the already parsed `segments`. Text and macros
+    /// must not be separated by whitespace, e.g. `db_{shard}` or `{layer}_{shard}`.
+    fn parse_object_name_macro(
+        &mut self,
+        mut segments: Vec<MacroSegment>,
+    ) -> Result<ObjectNamePart, ParserError> {
+        loop {
+            // Whitespace may only precede the first segment
+            let next = if segments.is_empty() {
+                self.peek_token()
+            } else {
+                self.peek_token_no_skip()
+            };
+            match next.token {
+                Token::LBrace => {
+                    self.advance_token();
+                    let name = self.parse_identifier()?;
+                    self.expect_token(&Token::RBrace)?;
+                    segments.push(MacroSegment::Macro(name));
+                }
+                Token::Word(w) if w.quote_style.is_none() => {
+                    self.advance_token();
+                    segments.push(MacroSegment::Text(w.into_ident(next.span)));
+                }
+                _ => break,
+            }
+        }
+        Ok(ObjectNamePart::Macro(ObjectNamePartMacro { segments }))
+    }
+
     /// Parse identifiers
     pub fn parse_identifiers(&mut self) -> Result<Vec<Ident>, ParserError> {
         let mut idents = vec![];
@@ -13832,6 +15788,7 @@ impl<'a> Parser<'a> {
     /// Parse a `DELETE` statement and return `Statement::Delete`.
     pub fn parse_delete(&mut self, delete_token: TokenWithSpan) -> Result<Statement, ParserError> {
         let optimizer_hints = self.maybe_parse_optimizer_hints()?;
+        let top = self.maybe_parse_dml_top()?;
         let (tables, with_from_keyword) = if !self.parse_keyword(Keyword::FROM) {
             // `FROM` keyword is optional in BigQuery SQL.
             // https://cloud.google.com/bigquery/docs/reference/standard-sql/dml-syntax#delete_statement
@@ -13856,7 +15813,7 @@ impl<'a> Parser<'a> {
             None
         };
         let selection = if self.parse_keyword(Keyword::WHERE) {
-            Some(self.parse_expr()?)
+            Some(self.parse_where_expr()?)
         } else {
             None
         };
@@ -13875,10 +15832,12 @@ impl<'a> Parser<'a> {
         } else {
             None
         };
+        let options = self.maybe_parse_query_hints()?;
 
         Ok(Statement::Delete(Delete {
             delete_token: delete_token.into(),
             optimizer_hints,
+            top,
             tables,
             from: if with_from_keyword {
                 FromTable::WithFromKeyword(from)
@@ -13891,6 +15850,7 @@ impl<'a> Parser<'a> {
             output,
             order_by,
             limit,
+            options,
         }))
     }
 
@@ -13974,30 +15934,66 @@ impl<'a> Parser<'a> {
                         _ => None,
                     };
 
-                let has_table_keyword = if self.dialect.describe_requires_table_keyword() {
+                let object_type = if self.dialect.describe_requires_table_keyword() {
                     // only allow to use TABLE keyword for DESC|DESCRIBE statement
-                    self.parse_keyword(Keyword::TABLE)
+                    self.parse_describe_object_type()
                 } else {
-                    false
+                    None
                 };
 
                 let table_name = self.parse_object_name(false)?;
+                let partition = self.parse_insert_partition()?;
                 Ok(Statement::ExplainTable {
                     describe_alias,
                     hive_format,
-                    has_table_keyword,
+                    object_type,
                     table_name,
+                    partition,
                 })
             }
         }
     }
 
+    /// Parse the optional object type keyword of a `DESCRIBE` statement,
+    /// e.g. the `STAGE` in `DESCRIBE STAGE my_stage`.
+    fn parse_describe_object_type(&mut self) -> Option<ObjectType> {
+        if self.parse_keyword(Keyword::TABLE) {
+            return Some(ObjectType::Table);
+        }
+        if !self.dialect.supports_describe_object_types() {
+            return None;
+        }
+        if self.parse_keyword(Keyword::VIEW) {
+            Some(ObjectType::View)
+        } else if self.parse_keywords(&[Keyword::MATERIALIZED, Keyword::VIEW]) {
+            Some(ObjectType::MaterializedView)
+        } else if self.parse_keyword(Keyword::SCHEMA) {
+            Some(ObjectType::Schema)
+        } else if self.parse_keyword(Keyword::DATABASE) {
+            Some(ObjectType::Database)
+        } else if self.parse_keyword(Keyword::SEQUENCE) {
+            Some(ObjectType::Sequence)
+        } else if self.parse_keyword(Keyword::STAGE) {
+            Some(ObjectType::Stage)
+        } else if self.parse_keyword(Keyword::USER) {
+            Some(ObjectType::User)
+        } else if self.parse_keyword(Keyword::STREAM) {
+            Some(ObjectType::Stream)
+        } else {
+            None
+        }
+    }
+
     /// Parse a query expression, i.e. a `SELECT` statement optionally
     /// preceded with some `WITH` CTE declarations and optionally followed
     /// by `ORDER BY`. Unlike some other parse_... methods, this one doesn't
     /// expect the initial keyword to be already consumed
     #[cfg_attr(feature = "recursive-protection", recursive::recursive)]
     pub fn parse_query(&mut self) -> Result<Box<Query>, ParserError> {
+        if matches!(self.state, ParserState::Where) {
+            // A subquery in a `WHERE` clause is free to project `default`.
+            return self.with_state(ParserState::Normal, |parser| parser.parse_query());
+        }
         let _guard = self.recursion_counter.try_decrease()?;
         let with = if self.parse_keyword(Keyword::WITH) {
             let with_token = self.get_current_token();
@@ -14021,6 +16017,7 @@ impl<'a> Parser<'a> {
                 settings: None,
                 format_clause: None,
                 pipe_operators: vec![],
+                options: vec![],
             }
             .into())
         } else if self.parse_keyword(Keyword::UPDATE) {
@@ -14035,6 +16032,7 @@ impl<'a> Parser<'a> {
                 settings: None,
                 format_clause: None,
                 pipe_operators: vec![],
+                options: vec![],
             }
             .into())
         } else if self.parse_keyword(Keyword::DELETE) {
@@ -14049,6 +16047,7 @@ impl<'a> Parser<'a> {
                 settings: None,
                 format_clause: None,
                 pipe_operators: vec![],
+                options: vec![],
             }
             .into())
         } else if self.parse_keyword(Keyword::MERGE) {
@@ -14063,6 +16062,7 @@ impl<'a> Parser<'a> {
                 settings: None,
                 format_clause: None,
                 pipe_operators: vec![],
+                options: vec![],
             }
             .into())
         } else {
@@ -14090,14 +16090,10 @@ impl<'a> Parser<'a> {
                     locks.push(self.parse_lock()?);
                 }
             }
+            let options = self.maybe_parse_query_hints()?;
             let format_clause =
                 if self.dialect.supports_select_format() && self.parse_keyword(Keyword::FORMAT) {
-                    if self.parse_keyword(Keyword::NULL) {
-                        Some(FormatClause::Null)
-                    } else {
-                        let ident = self.parse_identifier()?;
-                        Some(FormatClause::Identifier(ident))
-                    }
+                    Some(self.parse_format_clause()?)
                 } else {
                     None
                 };
@@ -14119,6 +16115,7 @@ impl<'a> Parser<'a> {
                 settings,
                 format_clause,
                 pipe_operators,
+                options,
             }
             .into())
         }
@@ -14550,7 +16547,9 @@ impl<'a> Parser<'a> {
         // We parse the expression using a Pratt parser, as in `parse_expr()`.
         // Start by parsing a restricted SELECT or a `(subquery)`:
         let expr = if self.peek_keyword(Keyword::SELECT)
-            || (self.peek_keyword(Keyword::FROM) && self.dialect.supports_from_first_select())
+            || (self.peek_keyword(Keyword::FROM)
+                && (self.dialect.supports_from_first_select()
+                    || self.dialect.supports_pipe_operator()))
         {
             SetExpr::Select(self.parse_select().map(Box::new)?)
         } else if self.consume_token(&Token::LParen) {
@@ -14657,9 +16656,17 @@ impl<'a> Parser<'a> {
     pub fn parse_select(&mut self) -> Result<Select, ParserError> {
         let mut from_first = None;
 
-        if self.dialect.supports_from_first_select() && self.peek_keyword(Keyword::FROM) {
+        // Pipe syntax queries may start with a bare `FROM` clause, even in
+        // dialects that do not otherwise support `FROM ... SELECT ...`.
+        // See <https://cloud.google.com/bigquery/docs/reference/standard-sql/pipe-syntax#from_queries>
+        if (self.dialect.supports_from_first_select() || self.dialect.supports_pipe_operator())
+            && self.peek_keyword(Keyword::FROM)
+        {
             let from_token = self.expect_keyword(Keyword::FROM)?;
             let from = self.parse_table_with_joins()?;
+            if self.peek_keyword(Keyword::SELECT) && !self.dialect.supports_from_first_select() {
+                return self.expected_ref("end of FROM clause", self.peek_token_ref());
+            }
             if !self.peek_keyword(Keyword::SELECT) {
                 return Ok(Select {
                     select_token: AttachedToken(from_token),
@@ -14668,7 +16675,7 @@ impl<'a> Parser<'a> {
                     select_modifiers: None,
                     top: None,
                     top_before_distinct: false,
-                    projection: vec![],
+                    projection: vec![SelectItem::Wildcard(WildcardAdditionalOptions::default())],
                     exclude: None,
                     into: None,
                     from,
@@ -14790,7 +16797,7 @@ impl<'a> Parser<'a> {
         };
 
         let selection = if self.parse_keyword(Keyword::WHERE) {
-            Some(self.parse_expr()?)
+            Some(self.parse_where_expr()?)
         } else {
             None
         };
@@ -14849,6 +16856,29 @@ impl<'a> Parser<'a> {
             Default::default()
         };
 
+        // A `WHERE` following any of the trailing clauses is never legal;
+        // report it against the clause it was found after rather than
+        // failing later with a generic "end of statement" error.
+        if self.peek_keyword(Keyword::WHERE) {
+            let preceding_clause = if qualify.is_some() {
+                Some("QUALIFY")
+            } else if !named_windows.is_empty() {
+                Some("WINDOW")
+            } else if having.is_some() {
+                Some("HAVING")
+            } else if group_by != GroupByExpr::Expressions(vec![], vec![]) {
+                Some("GROUP BY")
+            } else {
+                None
+            };
+            if let Some(clause) = preceding_clause {
+                return parser_err!(
+                    format!("WHERE clause must appear before {clause}"),
+                    self.peek_token_ref().span.start
+                );
+            }
+        }
+
         Ok(Select {
             select_token: AttachedToken(select_token),
             optimizer_hints,
@@ -15030,6 +17060,11 @@ impl<'a> Parser<'a> {
         Ok(mode)
     }
 
+    /// Parse the condition of a `WHERE` clause.
+    fn parse_where_expr(&mut self) -> Result<Expr, ParserError> {
+        self.with_state(ParserState::Where, |parser| parser.parse_expr())
+    }
+
     /// Invoke `f` after first setting the parser's `ParserState` to `state`.
     ///
     /// Upon return, restores the parser's state to what it started at.
@@ -15145,7 +17180,7 @@ impl<'a> Parser<'a> {
         loop {
             let value = if let Some(expr) = self.try_parse_expr_sub_query()? {
                 expr
-            } else if let Ok(expr) = self.parse_expr() {
+            } else if let Ok(expr) = self.parse_expr_or_default() {
                 expr
             } else {
                 self.expected_ref("variable value", self.peek_token_ref())?
@@ -15185,11 +17220,13 @@ impl<'a> Parser<'a> {
             self.parse_object_name(false)?
         };
 
-        if !(self.consume_token(&Token::Eq) || self.parse_keyword(Keyword::TO)) {
+        // `TO` is only accepted for a single assignment, which `parse_set`
+        // falls back to so that the spelling is preserved.
+        if !self.consume_token(&Token::Eq) {
             return self.expected_ref("assignment operator", self.peek_token_ref());
         }
 
-        let value = self.parse_expr()?;
+        let value = self.parse_expr_or_default()?;
 
         Ok(SetAssignment { scope, name, value })
     }
@@ -15216,11 +17253,12 @@ impl<'a> Parser<'a> {
         if self.parse_keywords(&[Keyword::TIME, Keyword::ZONE])
             || self.parse_keyword(Keyword::TIMEZONE)
         {
-            if self.consume_token(&Token::Eq) || self.parse_keyword(Keyword::TO) {
+            if let Some(uses_to) = self.parse_set_assignment_operator() {
                 return Ok(Set::SingleAssignment {
                     scope,
                     hivevar,
                     variable: ObjectName::from(vec!["TIMEZONE".into()]),
+                    uses_to,
                     values: self.parse_set_values(false)?,
                 }
                 .into());
@@ -15250,6 +17288,13 @@ impl<'a> Parser<'a> {
                 collation_name,
             }
             .into());
+        } else if self.dialect.supports_set_names()
+            && self.parse_keywords(&[Keyword::CHARACTER, Keyword::SET])
+        {
+            return Ok(Set::SetCharacterSet {
+                charset_name: self.parse_identifier()?,
+            }
+            .into());
         } else if self.parse_keyword(Keyword::CHARACTERISTICS) {
             self.expect_keywords(&[Keyword::AS, Keyword::TRANSACTION])?;
             return Ok(Set::SetTransaction {
@@ -15317,6 +17362,7 @@ impl<'a> Parser<'a> {
                         scope,
                         hivevar,
                         variable: name,
+                        uses_to: false,
                         values: vec![value],
                     }
                     .into())
@@ -15339,12 +17385,13 @@ impl<'a> Parser<'a> {
             OneOrManyWithParens::One(self.parse_object_name(false)?)
         };
 
-        if self.consume_token(&Token::Eq) || self.parse_keyword(Keyword::TO) {
+        if let Some(uses_to) = self.parse_set_assignment_operator() {
             let stmt = match variables {
                 OneOrManyWithParens::One(var) => Set::SingleAssignment {
                     scope,
                     hivevar,
                     variable: var,
+                    uses_to,
                     values: self.parse_set_values(false)?,
                 },
                 OneOrManyWithParens::Many(vars) => Set::ParenthesizedAssignments {
@@ -15364,6 +17411,18 @@ impl<'a> Parser<'a> {
         self.expected_ref("equals sign or TO", self.peek_token_ref())
     }
 
+    /// Parse the `=` or `TO` between a `SET` variable and its values,
+    /// returning `Some(true)` for `TO`.
+    fn parse_set_assignment_operator(&mut self) -> Option<bool> {
+        if self.consume_token(&Token::Eq) {
+            Some(false)
+        } else if self.parse_keyword(Keyword::TO) {
+            Some(true)
+        } else {
+            None
+        }
+    }
+
     /// Parse session parameter assignments after `SET` when no `=` or `TO` is present.
     pub fn parse_set_session_params(&mut self) -> Result<Statement, ParserError> {
         if self.parse_keyword(Keyword::STATISTICS) {
@@ -15387,9 +17446,17 @@ impl<'a> Parser<'a> {
                 }))
                 .into(),
             )
-        } else if self.parse_keyword(Keyword::IDENTITY_INSERT) {
+        } else if self.peek_keyword(Keyword::IDENTITY_INSERT) {
+            let name = self.parse_identifier()?;
             let obj = self.parse_object_name(false)?;
             let value = self.parse_session_param_value()?;
+            if self.dialect.supports_set_session_option() {
+                return Ok(Statement::SetSessionOption {
+                    names: vec![name],
+                    target_table: Some(obj),
+                    value,
+                });
+            }
             Ok(Set::SetSessionParam(SetSessionParamKind::IdentityInsert(
                 SetSessionParamIdentityInsert { obj, value },
             ))
@@ -15411,6 +17478,19 @@ impl<'a> Parser<'a> {
                 .into(),
             )
         } else {
+            if self.dialect.supports_set_session_option() {
+                if let Some(stmt) = self.maybe_parse(|parser| {
+                    let names = parser.parse_comma_separated(Parser::parse_identifier)?;
+                    let value = parser.parse_session_param_value()?;
+                    Ok(Statement::SetSessionOption {
+                        names,
+                        target_table: None,
+                        value,
+                    })
+                })? {
+                    return Ok(stmt);
+                }
+            }
             let names = self.parse_comma_separated(|parser| {
                 let next_token = parser.next_token();
                 match next_token.token {
@@ -15550,6 +17630,9 @@ impl<'a> Parser<'a> {
             Keyword::PROCEDURE,
             Keyword::EVENT,
             Keyword::VIEW,
+            Keyword::DATABASE,
+            Keyword::SCHEMA,
+            Keyword::USER,
         ])? {
             Keyword::TABLE => Ok(ShowCreateObject::Table),
             Keyword::TRIGGER => Ok(ShowCreateObject::Trigger),
@@ -15557,14 +17640,36 @@ impl<'a> Parser<'a> {
             Keyword::PROCEDURE => Ok(ShowCreateObject::Procedure),
             Keyword::EVENT => Ok(ShowCreateObject::Event),
             Keyword::VIEW => Ok(ShowCreateObject::View),
+            Keyword::DATABASE => Ok(ShowCreateObject::Database),
+            Keyword::SCHEMA => Ok(ShowCreateObject::Schema),
+            Keyword::USER => Ok(ShowCreateObject::User),
             keyword => Err(ParserError::ParserError(format!(
                 "Unable to map keyword to ShowCreateObject: {keyword:?}"
             ))),
         }?;
 
         let obj_name = self.parse_object_name(false)?;
+        let format = if self.dialect.supports_select_format() && self.parse_keyword(Keyword::FORMAT)
+        {
+            Some(self.parse_format_clause()?)
+        } else {
+            None
+        };
+
+        Ok(Statement::ShowCreate {
+            obj_type,
+            obj_name,
+            format,
+        })
+    }
 
-        Ok(Statement::ShowCreate { obj_type, obj_name })
+    /// Parse the format of a ClickHouse `FORMAT` clause, after the `FORMAT` keyword.
+    fn parse_format_clause(&mut self) -> Result<FormatClause, ParserError> {
+        if self.parse_keyword(Keyword::NULL) {
+            Ok(FormatClause::Null)
+        } else {
+            Ok(FormatClause::Identifier(self.parse_identifier()?))
+        }
     }
 
     /// Parse `SHOW COLUMNS`/`SHOW FIELDS` and return a `ShowColumns` statement.
@@ -15715,6 +17820,7 @@ impl<'a> Parser<'a> {
         let mut joins = vec![];
         loop {
             let global = self.parse_keyword(Keyword::GLOBAL);
+            let mut strictness = self.parse_join_strictness();
             let join = if self.parse_keyword(Keyword::CROSS) {
                 let join_operator = if self.parse_keyword(Keyword::JOIN) {
                     JoinOperator::CrossJoin(JoinConstraint::None)
@@ -15736,6 +17842,7 @@ impl<'a> Parser<'a> {
                 Join {
                     relation,
                     global,
+                    strictness,
                     join_operator,
                 }
             } else if self.parse_keyword(Keyword::OUTER) {
@@ -15744,6 +17851,7 @@ impl<'a> Parser<'a> {
                 Join {
                     relation: self.parse_table_factor()?,
                     global,
+                    strictness,
                     join_operator: JoinOperator::OuterApply,
                 }
             } else if self.parse_keyword(Keyword::ASOF) {
@@ -15754,6 +17862,7 @@ impl<'a> Parser<'a> {
                 Join {
                     relation,
                     global,
+                    strictness,
                     join_operator: JoinOperator::AsOf {
                         match_condition,
                         constraint: self.parse_join_constraint(false)?,
@@ -15770,6 +17879,9 @@ impl<'a> Parser<'a> {
                 let join_operator_type = match peek_keyword {
                     Keyword::INNER | Keyword::JOIN => {
                         let inner = self.parse_keyword(Keyword::INNER); // [ INNER ]
+                        if inner && strictness.is_none() {
+                            strictness = self.parse_join_strictness();
+                        }
                         self.expect_keyword_is(Keyword::JOIN)?;
                         if inner {
                             JoinOperator::Inner
@@ -15780,6 +17892,9 @@ impl<'a> Parser<'a> {
                     kw @ Keyword::LEFT | kw @ Keyword::RIGHT => {
                         let _ = self.next_token(); // consume LEFT/RIGHT
                         let is_left = kw == Keyword::LEFT;
+                        if strictness.is_none() {
+                            strictness = self.parse_join_strictness();
+                        }
                         let join_type = self.parse_one_of_keywords(&[
                             Keyword::OUTER,
                             Keyword::SEMI,
@@ -15837,6 +17952,9 @@ impl<'a> Parser<'a> {
                     }
                     Keyword::FULL => {
                         let _ = self.next_token(); // consume FULL
+                        if strictness.is_none() {
+                            strictness = self.parse_join_strictness();
+                        }
                         let _ = self.parse_keyword(Keyword::OUTER); // [ OUTER ]
                         self.expect_keyword_is(Keyword::JOIN)?;
                         JoinOperator::FullOuter
@@ -15852,6 +17970,9 @@ impl<'a> Parser<'a> {
                         return self
                             .expected_ref("a join type after NATURAL", self.peek_token_ref());
                     }
+                    _ if strictness.is_some() => {
+                        return self.expected_ref("JOIN", self.peek_token_ref());
+                    }
                     _ => break,
                 };
                 let mut relation = self.parse_table_factor()?;
@@ -15872,6 +17993,7 @@ impl<'a> Parser<'a> {
                 Join {
                     relation,
                     global,
+                    strictness,
                     join_operator: join_operator_type(join_constraint),
                 }
             };
@@ -15880,6 +18002,18 @@ impl<'a> Parser<'a> {
         Ok(joins)
     }
 
+    /// Parses an optional ClickHouse join strictness (`ANY`, `ALL` or `ASOF`).
+    fn parse_join_strictness(&mut self) -> Option<JoinStrictness> {
+        if !self.dialect.supports_join_strictness() {
+            return None;
+        }
+        match self.parse_one_of_keywords(&[Keyword::ANY, Keyword::ALL, Keyword::ASOF])? {
+            Keyword::ANY => Some(JoinStrictness::Any),
+            Keyword::ALL => Some(JoinStrictness::All),
+            _ => Some(JoinStrictness::Asof),
+        }
+    }
+
     fn peek_parens_less_nested_join(&self) -> bool {
         matches!(
             self.peek_token_ref().token,
@@ -16068,6 +18202,7 @@ impl<'a> Parser<'a> {
                     settings: None,
                     format_clause: None,
                     pipe_operators: vec![],
+                    options: vec![],
                 }),
                 alias,
                 sample: None,
@@ -16092,11 +18227,7 @@ impl<'a> Parser<'a> {
             };
 
             let with_offset_alias = if with_offset {
-                match self.parse_optional_alias(keywords::RESERVED_FOR_COLUMN_ALIAS) {
-                    Ok(Some(alias)) => Some(alias),
-                    Ok(None) => None,
-                    Err(e) => return Err(e),
-                }
+                self.maybe_parse_table_alias()?
             } else {
                 None
             };
@@ -16194,6 +18325,9 @@ impl<'a> Parser<'a> {
                 }
             };
 
+            let final_modifier =
+                self.dialect.supports_table_final_modifier() && self.parse_keyword(Keyword::FINAL);
+
             if !self.dialect.supports_table_sample_before_alias() {
                 if let Some(parsed_sample) = self.maybe_parse_table_sample()? {
                     sample = Some(TableSampleKind::AfterTableAlias(parsed_sample));
@@ -16211,6 +18345,7 @@ impl<'a> Parser<'a> {
                 json_path,
                 sample,
                 index_hints,
+                final_modifier,
             };
 
             while let Some(kw) = self.parse_one_of_keywords(&[Keyword::PIVOT, Keyword::UNPIVOT]) {
@@ -16261,6 +18396,7 @@ impl<'a> Parser<'a> {
             json_path: None,
             sample: None,
             index_hints: vec![],
+            final_modifier: false,
         })
     }
 
@@ -16586,10 +18722,26 @@ impl<'a> Parser<'a> {
 
         let measures = if self.parse_keyword(Keyword::MEASURES) {
             self.parse_comma_separated(|p| {
+                // `RUNNING`/`FINAL` are only semantics when followed by the start
+                // of an expression, so `running + 1` still refers to a column.
+                let semantics = match p.peek_nth_token_ref(1).token {
+                    Token::Word(ref w) if w.keyword != Keyword::AS => {
+                        match p.parse_one_of_keywords(&[Keyword::RUNNING, Keyword::FINAL]) {
+                            Some(Keyword::RUNNING) => Some(MeasureSemantics::Running),
+                            Some(Keyword::FINAL) => Some(MeasureSemantics::Final),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                };
                 let expr = p.parse_expr()?;
                 let _ = p.parse_keyword(Keyword::AS);
                 let alias = p.parse_identifier()?;
-                Ok(Measure { expr, alias })
+                Ok(Measure {
+                    semantics,
+                    expr,
+                    alias,
+                })
             })?
         } else {
             vec![]
@@ -16637,6 +18789,8 @@ impl<'a> Parser<'a> {
                     Some(AfterMatchSkip::ToFirst(self.parse_identifier()?))
                 } else if self.parse_keywords(&[Keyword::TO, Keyword::LAST]) {
                     Some(AfterMatchSkip::ToLast(self.parse_identifier()?))
+                } else if self.parse_keyword(Keyword::TO) {
+                    Some(AfterMatchSkip::To(self.parse_identifier()?))
                 } else {
                     let found = self.next_token();
                     return self.expected("after match skip option", found);
@@ -16721,6 +18875,11 @@ impl<'a> Parser<'a> {
                 Token::Mul => RepetitionQuantifier::ZeroOrMore,
                 Token::Plus => RepetitionQuantifier::OneOrMore,
                 Token::Placeholder(s) if s == "?" => RepetitionQuantifier::AtMostOne,
+                // `{-` starts an exclusion rather than a quantifier range
+                Token::LBrace if self.peek_token_ref().token == Token::Minus => {
+                    self.prev_token();
+                    break;
+                }
                 Token::LBrace => {
                     // quantifier is a range like {n} or {n,} or {,m} or {n,m}
                     let token = self.next_token();
@@ -16798,10 +18957,8 @@ impl<'a> Parser<'a> {
     /// Parses a the timestamp version specifier (i.e. query historical data)
     pub fn maybe_parse_table_version(&mut self) -> Result<Option<TableVersion>, ParserError> {
         if self.dialect.supports_table_versioning() {
-            if self.parse_keywords(&[Keyword::FOR, Keyword::SYSTEM_TIME, Keyword::AS, Keyword::OF])
-            {
-                let expr = self.parse_expr()?;
-                return Ok(Some(TableVersion::ForSystemTimeAsOf(expr)));
+            if self.parse_keywords(&[Keyword::FOR, Keyword::SYSTEM_TIME]) {
+                return self.parse_for_system_time_version().map(Some);
             } else if self.peek_keyword(Keyword::CHANGES) {
                 return self.parse_table_version_changes().map(Some);
             } else if self.peek_keyword(Keyword::AT) || self.peek_keyword(Keyword::BEFORE) {
@@ -16815,10 +18972,55 @@ impl<'a> Parser<'a> {
                 let expr = Expr::Value(self.parse_number_value()?);
                 return Ok(Some(TableVersion::VersionAsOf(expr)));
             }
+        } else if self.dialect.supports_system_versioned_tables()
+            && self.parse_keywords(&[Keyword::FOR, Keyword::SYSTEM_TIME])
+        {
+            return self.parse_for_system_time_version().map(Some);
         }
         Ok(None)
     }
 
+    /// Parses the remainder of a `FOR SYSTEM_TIME` table version clause.
+    ///
+    /// Syntax:
+    /// ```sql
+    /// FOR SYSTEM_TIME
+    ///   { AS OF <expr>
+    ///   | FROM <expr> TO <expr>
+    ///   | BETWEEN <expr> AND <expr>
+    ///   | CONTAINED IN (<expr>, <expr>)
+    ///   | ALL }
+    /// ```
+    fn parse_for_system_time_version(&mut self) -> Result<TableVersion, ParserError> {
+        if self.parse_keywords(&[Keyword::AS, Keyword::OF]) {
+            Ok(TableVersion::ForSystemTimeAsOf(self.parse_expr()?))
+        } else if self.parse_keyword(Keyword::FROM) {
+            let from = self.parse_expr()?;
+            self.expect_keyword_is(Keyword::TO)?;
+            let to = self.parse_expr()?;
+            Ok(TableVersion::ForSystemTimeFromTo { from, to })
+        } else if self.parse_keyword(Keyword::BETWEEN) {
+            let low = self.parse_subexpr(self.dialect.prec_value(Precedence::Between))?;
+            self.expect_keyword_is(Keyword::AND)?;
+            let high = self.parse_subexpr(self.dialect.prec_value(Precedence::Between))?;
+            Ok(TableVersion::ForSystemTimeBetween { low, high })
+        } else if self.parse_keywords(&[Keyword::CONTAINED, Keyword::IN]) {
+            self.expect_token(&Token::LParen)?;
+            let from = self.parse_expr()?;
+            self.expect_token(&Token::Comma)?;
+            let to = self.parse_expr()?;
+            self.expect_token(&Token::RParen)?;
+            Ok(TableVersion::ForSystemTimeContainedIn { from, to })
+        } else if self.parse_keyword(Keyword::ALL) {
+            Ok(TableVersion::ForSystemTimeAll)
+        } else {
+            self.expected_ref(
+                "AS OF, FROM, BETWEEN, CONTAINED IN or ALL after FOR SYSTEM_TIME",
+                self.peek_token_ref(),
+            )
+        }
+    }
+
     /// Parses the Snowflake `CHANGES` clause for change tracking queries.
     ///
     /// Syntax:
@@ -17376,6 +19578,7 @@ impl<'a> Parser<'a> {
             } else {
                 let object_type = self.parse_one_of_keywords(&[
                     Keyword::SEQUENCE,
+                    Keyword::CATALOG,
                     Keyword::DATABASE,
                     Keyword::SCHEMA,
                     Keyword::TABLE,
@@ -17393,6 +19596,7 @@ impl<'a> Parser<'a> {
                 let objects =
                     self.parse_comma_separated(|p| p.parse_object_name_inner(false, true));
                 match object_type {
+                    Some(Keyword::CATALOG) => Some(GrantObjects::Catalogs(objects?)),
                     Some(Keyword::DATABASE) => Some(GrantObjects::Databases(objects?)),
                     Some(Keyword::SCHEMA) => Some(GrantObjects::Schemas(objects?)),
                     Some(Keyword::SEQUENCE) => Some(GrantObjects::Sequences(objects?)),
@@ -17492,6 +19696,10 @@ impl<'a> Parser<'a> {
             Ok(Action::ResolveAll)
         } else if self.parse_keywords(&[Keyword::READ, Keyword::SESSION]) {
             Ok(Action::ReadSession)
+        } else if self.parse_keywords(&[Keyword::USE, Keyword::CATALOG]) {
+            Ok(Action::UseCatalog)
+        } else if self.parse_keywords(&[Keyword::USE, Keyword::SCHEMA]) {
+            Ok(Action::UseSchema)
 
         // Single-word privileges
         } else if self.parse_keyword(Keyword::APPLY) {
@@ -17577,6 +19785,8 @@ impl<'a> Parser<'a> {
             Some(ActionCreateObjectType::DataExchangeListing)
         } else if self.parse_keywords(&[Keyword::EXTERNAL, Keyword::VOLUME]) {
             Some(ActionCreateObjectType::ExternalVolume)
+        } else if self.parse_keywords(&[Keyword::MATERIALIZED, Keyword::VIEW]) {
+            Some(ActionCreateObjectType::MaterializedView)
         } else if self.parse_keywords(&[Keyword::FAILOVER, Keyword::GROUP]) {
             Some(ActionCreateObjectType::FailoverGroup)
         } else if self.parse_keywords(&[Keyword::NETWORK, Keyword::POLICY]) {
@@ -17593,6 +19803,10 @@ impl<'a> Parser<'a> {
             Some(ActionCreateObjectType::Application)
         } else if self.parse_keyword(Keyword::DATABASE) {
             Some(ActionCreateObjectType::Database)
+        } else if self.parse_keyword(Keyword::CATALOG) {
+            Some(ActionCreateObjectType::Catalog)
+        } else if self.parse_keyword(Keyword::FUNCTION) {
+            Some(ActionCreateObjectType::Function)
         } else if self.parse_keyword(Keyword::INTEGRATION) {
             Some(ActionCreateObjectType::Integration)
         } else if self.parse_keyword(Keyword::ROLE) {
@@ -17601,8 +19815,12 @@ impl<'a> Parser<'a> {
             Some(ActionCreateObjectType::Schema)
         } else if self.parse_keyword(Keyword::SHARE) {
             Some(ActionCreateObjectType::Share)
+        } else if self.parse_keyword(Keyword::TABLE) {
+            Some(ActionCreateObjectType::Table)
         } else if self.parse_keyword(Keyword::USER) {
             Some(ActionCreateObjectType::User)
+        } else if self.parse_keyword(Keyword::VIEW) {
+            Some(ActionCreateObjectType::View)
         } else if self.parse_keyword(Keyword::WAREHOUSE) {
             Some(ActionCreateObjectType::Warehouse)
         } else {
@@ -17709,6 +19927,17 @@ impl<'a> Parser<'a> {
             let user = name.0.pop().unwrap().as_ident().unwrap().clone();
             let host = self.parse_identifier()?;
             Ok(GranteeName::UserHost { user, host })
+        } else if self.dialect.supports_user_host_grantee()
+            && matches!(self.peek_token_ref().token, Token::SingleQuotedString(_))
+            && matches!(name.0.as_slice(), [part] if part.as_ident().is_some_and(|ident| {
+                ident.quote_style.is_none() && ident.value.len() > 1 && ident.value.ends_with('@')
+            }))
+        {
+            // Dialects allowing `@` in identifiers tokenize `user@'host'` as `user@` followed by `'host'`
+            let mut user = name.0.pop().unwrap().as_ident().unwrap().clone();
+            user.value.pop();
+            let host = self.parse_identifier()?;
+            Ok(GranteeName::UserHost { user, host })
         } else {
             Ok(GranteeName::ObjectName(name))
         }
@@ -17847,11 +20076,24 @@ impl<'a> Parser<'a> {
             let table = self.parse_keyword(Keyword::TABLE);
             let table_object = self.parse_table_object()?;
 
+            // MSSQL-specific table hints:
+            let with_hints = if self.peek_keyword_with_tokens(Keyword::WITH, &[Token::LParen]) {
+                self.expect_keyword_is(Keyword::WITH)?;
+                self.expect_token(&Token::LParen)?;
+                let hints = self.parse_comma_separated(Parser::parse_expr)?;
+                self.expect_token(&Token::RParen)?;
+                hints
+            } else {
+                vec![]
+            };
+
             let table_alias = if self.dialect.supports_insert_table_alias()
                 && !self.peek_sub_query()
                 && self
                     .peek_one_of_keywords(&[Keyword::DEFAULT, Keyword::VALUES])
                     .is_none()
+                && !(self.dialect.supports_insert_by_name()
+                    && self.peek_keywords(&[Keyword::BY, Keyword::NAME]))
             {
                 if self.parse_keyword(Keyword::AS) {
                     Some(TableAliasWithoutColumns {
@@ -17869,6 +20111,9 @@ impl<'a> Parser<'a> {
                 None
             };
 
+            let by_name = self.dialect.supports_insert_by_name()
+                && self.parse_keywords(&[Keyword::BY, Keyword::NAME]);
+
             let is_mysql = dialect_of!(self is MySqlDialect);
 
             let (columns, partitioned, after_columns, output, source, assignments) = if self
@@ -18001,12 +20246,14 @@ impl<'a> Parser<'a> {
                 optimizer_hints,
                 or,
                 table: table_object,
+                with_hints,
                 table_alias,
                 ignore,
                 into,
                 overwrite,
                 partitioned,
                 columns,
+                by_name,
                 after_columns,
                 source,
                 assignments,
@@ -18143,6 +20390,7 @@ impl<'a> Parser<'a> {
     /// Parse an `UPDATE` statement and return `Statement::Update`.
     pub fn parse_update(&mut self, update_token: TokenWithSpan) -> Result<Statement, ParserError> {
         let optimizer_hints = self.maybe_parse_optimizer_hints()?;
+        let top = self.maybe_parse_dml_top()?;
         let or = self.parse_conflict_clause();
         let table = self.parse_table_and_joins()?;
         let from_before_set = if self.parse_keyword(Keyword::FROM) {
@@ -18165,7 +20413,7 @@ impl<'a> Parser<'a> {
             from_before_set
         };
         let selection = if self.parse_keyword(Keyword::WHERE) {
-            Some(self.parse_expr()?)
+            Some(self.parse_where_expr()?)
         } else {
             None
         };
@@ -18186,9 +20434,11 @@ impl<'a> Parser<'a> {
         } else {
             None
         };
+        let options = self.maybe_parse_query_hints()?;
         Ok(Update {
             update_token: update_token.into(),
             optimizer_hints,
+            top,
             table,
             assignments,
             from,
@@ -18198,16 +20448,54 @@ impl<'a> Parser<'a> {
             or,
             order_by,
             limit,
+            options,
         }
         .into())
     }
 
-    /// Parse a `var = expr` assignment, used in an UPDATE statement
-    pub fn parse_assignment(&mut self) -> Result<Assignment, ParserError> {
-        let target = self.parse_assignment_target()?;
-        self.expect_token(&Token::Eq)?;
-        let value = self.parse_expr()?;
-        Ok(Assignment { target, value })
+    /// Parse a `var = expr` assignment, used in an UPDATE statement
+    pub fn parse_assignment(&mut self) -> Result<Assignment, ParserError> {
+        let target = self.parse_assignment_target()?;
+        self.expect_token(&Token::Eq)?;
+        let value = if matches!(target, AssignmentTarget::Tuple(_))
+            && self.peek_token_ref().token == Token::LParen
+            && !matches!(
+                self.peek_nth_token_ref(1).token,
+                Token::Word(Word {
+                    keyword: Keyword::SELECT | Keyword::WITH,
+                    ..
+                })
+            ) {
+            // e.g. `(a, b) = (DEFAULT, 1)`
+            self.expect_token(&Token::LParen)?;
+            let mut exprs = self.parse_comma_separated(Parser::parse_expr_or_default)?;
+            self.expect_token(&Token::RParen)?;
+            if exprs.len() == 1 {
+                Expr::Nested(Box::new(exprs.remove(0)))
+            } else {
+                Expr::Tuple(exprs)
+            }
+        } else {
+            self.parse_expr_or_default()?
+        };
+        Ok(Assignment { target, value })
+    }
+
+    /// Parse an expression, or the `DEFAULT` keyword as [Expr::Default].
+    ///
+    /// This is used for the positions where `DEFAULT` may be used as a
+    /// value, i.e. in `VALUES` lists and `UPDATE` or `SET` assignments.
+    pub fn parse_expr_or_default(&mut self) -> Result<Expr, ParserError> {
+        if self.peek_keyword(Keyword::DEFAULT)
+            && !matches!(
+                self.peek_nth_token_ref(1).token,
+                Token::LParen | Token::Period
+            )
+        {
+            self.advance_token();
+            return Ok(Expr::Default);
+        }
+        self.parse_expr()
     }
 
     /// Parse the left-hand side of an assignment, used in an UPDATE statement
@@ -18228,7 +20516,7 @@ impl<'a> Parser<'a> {
             self.maybe_parse(|p| {
                 let name = p.parse_expr()?;
                 let operator = p.parse_function_named_arg_operator()?;
-                let arg = p.parse_wildcard_expr()?.into();
+                let arg = p.parse_named_function_arg_value()?;
                 Ok(FunctionArg::ExprNamed {
                     name,
                     arg,
@@ -18239,7 +20527,7 @@ impl<'a> Parser<'a> {
             self.maybe_parse(|p| {
                 let name = p.parse_identifier()?;
                 let operator = p.parse_function_named_arg_operator()?;
-                let arg = p.parse_wildcard_expr()?.into();
+                let arg = p.parse_named_function_arg_value()?;
                 Ok(FunctionArg::Named {
                     name,
                     arg,
@@ -18272,6 +20560,35 @@ impl<'a> Parser<'a> {
         Ok(FunctionArg::Unnamed(arg_expr))
     }
 
+    /// Parse a bare date/time part function argument such as `HOUR` or
+    /// `WEEK(MONDAY)`, if `enabled` and the argument consists of exactly that.
+    fn maybe_parse_date_part_function_arg(
+        &mut self,
+        enabled: bool,
+    ) -> Result<Option<FunctionArg>, ParserError> {
+        if !enabled {
+            return Ok(None);
+        }
+        self.maybe_parse(|parser| {
+            let field = parser.parse_date_time_field()?;
+            if matches!(field, DateTimeField::Custom(_))
+                || !matches!(parser.peek_token_ref().token, Token::Comma | Token::RParen)
+            {
+                return parser.expected_ref("date/time part", parser.peek_token_ref());
+            }
+            Ok(FunctionArg::Unnamed(FunctionArgExpr::DateTimeField(field)))
+        })
+    }
+
+    /// Parse the value of a named function argument, which may be the
+    /// `DEFAULT` keyword, e.g. Snowflake's `CHANGES(INFORMATION => DEFAULT)`.
+    fn parse_named_function_arg_value(&mut self) -> Result<FunctionArgExpr, ParserError> {
+        if self.peek_keyword(Keyword::DEFAULT) {
+            return Ok(self.parse_expr_or_default()?.into());
+        }
+        Ok(self.parse_wildcard_expr()?.into())
+    }
+
     fn parse_function_named_arg_operator(&mut self) -> Result<FunctionArgOperator, ParserError> {
         if self.parse_keyword(Keyword::VALUE) {
             return Ok(FunctionArgOperator::Value);
@@ -18342,6 +20659,17 @@ impl<'a> Parser<'a> {
     /// FIRST_VALUE(x IGNORE NULL);
     /// ```
     fn parse_function_argument_list(&mut self) -> Result<FunctionArgumentList, ParserError> {
+        self.parse_function_argument_list_with_date_part(None)
+    }
+
+    /// Like [Self::parse_function_argument_list], but parses the argument at
+    /// `date_part_position` as a bare date/time part when possible.
+    ///
+    /// See [Dialect::date_part_argument_position]
+    fn parse_function_argument_list_with_date_part(
+        &mut self,
+        date_part_position: Option<usize>,
+    ) -> Result<FunctionArgumentList, ParserError> {
         let mut clauses = vec![];
 
         // Handle clauses that may exist with an empty argument list
@@ -18365,7 +20693,17 @@ impl<'a> Parser<'a> {
         }
 
         let duplicate_treatment = self.parse_duplicate_treatment()?;
-        let args = self.parse_comma_separated(Parser::parse_function_args)?;
+        let mut position = 0;
+        let args = self.parse_comma_separated(|parser| {
+            let arg = match parser
+                .maybe_parse_date_part_function_arg(Some(position) == date_part_position)?
+            {
+                Some(arg) => arg,
+                None => parser.parse_function_args()?,
+            };
+            position += 1;
+            Ok(arg)
+        })?;
 
         if self.dialect.supports_window_function_null_treatment_arg() {
             if let Some(null_treatment) = self.parse_null_treatment()? {
@@ -18636,23 +20974,20 @@ impl<'a> Parser<'a> {
         &mut self,
     ) -> Result<Option<ExceptSelectItem>, ParserError> {
         let opt_except = if self.parse_keyword(Keyword::EXCEPT) {
-            if self.peek_token_ref().token == Token::LParen {
-                let idents = self.parse_parenthesized_column_list(Mandatory, false)?;
-                match &idents[..] {
-                    [] => {
-                        return self.expected_ref(
-                            "at least one column should be parsed by the expect clause",
-                            self.peek_token_ref(),
-                        )?;
-                    }
-                    [first, idents @ ..] => Some(ExceptSelectItem {
-                        first_element: first.clone(),
-                        additional_elements: idents.to_vec(),
-                    }),
-                }
+            if self.consume_token(&Token::LParen) {
+                // `parse_comma_separated` parses at least one element, so an
+                // empty list fails with "Expected: identifier, found: )"
+                let mut idents =
+                    self.parse_comma_separated(Parser::parse_select_item_except_column)?;
+                self.expect_token(&Token::RParen)?;
+                let first_element = idents.remove(0);
+                Some(ExceptSelectItem {
+                    first_element,
+                    additional_elements: idents,
+                })
             } else {
                 // Clickhouse allows EXCEPT column_name
-                let ident = self.parse_identifier()?;
+                let ident = self.parse_select_item_except_column()?;
                 Some(ExceptSelectItem {
                     first_element: ident,
                     additional_elements: vec![],
@@ -18665,6 +21000,21 @@ impl<'a> Parser<'a> {
         Ok(opt_except)
     }
 
+    /// Parse a single column name of a wildcard `EXCEPT`, rejecting
+    /// qualified names such as `s.a`.
+    fn parse_select_item_except_column(&mut self) -> Result<Ident, ParserError> {
+        let ident = self.parse_identifier()?;
+        if self.peek_token_ref().token == Token::Period {
+            return parser_err!(
+                format!(
+                    "EXCEPT expects unqualified column names, found qualified name starting with {ident}"
+                ),
+                ident.span.start
+            );
+        }
+        Ok(ident)
+    }
+
     /// Parse a [`Rename`](RenameSelectItem) information for wildcard select items.
     pub fn parse_optional_select_item_rename(
         &mut self,
@@ -18885,6 +21235,86 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse an optional MSSQL `OPTION ( <query_hint> [ ,...n ] )` clause.
+    ///
+    /// See <https://learn.microsoft.com/en-us/sql/t-sql/queries/option-clause-transact-sql>
+    fn maybe_parse_query_hints(&mut self) -> Result<Vec<QueryHint>, ParserError> {
+        if self.dialect.supports_option_clause()
+            && self.peek_keyword_with_tokens(Keyword::OPTION, &[Token::LParen])
+        {
+            self.expect_keyword_is(Keyword::OPTION)?;
+            self.expect_token(&Token::LParen)?;
+            let hints = self.parse_comma_separated(Parser::parse_query_hint)?;
+            self.expect_token(&Token::RParen)?;
+            Ok(hints)
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    /// Parse a single query hint of an MSSQL `OPTION` clause.
+    pub fn parse_query_hint(&mut self) -> Result<QueryHint, ParserError> {
+        if self.parse_keywords(&[Keyword::OPTIMIZE, Keyword::FOR]) {
+            if self.parse_keyword(Keyword::UNKNOWN) {
+                return Ok(QueryHint::OptimizeForUnknown);
+            }
+            self.expect_token(&Token::LParen)?;
+            let variables = self.parse_comma_separated(|p| {
+                let name = p.parse_identifier()?;
+                let value = if p.parse_keyword(Keyword::UNKNOWN) {
+                    None
+                } else {
+                    p.expect_token(&Token::Eq)?;
+                    Some(p.parse_expr()?)
+                };
+                Ok(OptimizeForVariable { name, value })
+            })?;
+            self.expect_token(&Token::RParen)?;
+            Ok(QueryHint::OptimizeFor(variables))
+        } else if self.parse_keywords(&[Keyword::USE, Keyword::HINT]) {
+            self.expect_token(&Token::LParen)?;
+            let hints = self.parse_comma_separated(|p| Ok(p.parse_value()?.value))?;
+            self.expect_token(&Token::RParen)?;
+            Ok(QueryHint::UseHint(hints))
+        } else {
+            let mut keywords = vec![];
+            while let Token::Word(_) = self.peek_token_ref().token {
+                keywords.push(self.parse_identifier()?);
+            }
+            if keywords.is_empty() {
+                return self.expected_ref("a query hint", self.peek_token_ref());
+            }
+            let has_eq = self.consume_token(&Token::Eq);
+            let value =
+                if has_eq || !matches!(self.peek_token_ref().token, Token::Comma | Token::RParen) {
+                    Some(self.parse_expr()?)
+                } else {
+                    None
+                };
+            Ok(QueryHint::Keyword {
+                keywords,
+                has_eq,
+                value,
+            })
+        }
+    }
+
+    /// Parse an optional `TOP` clause of an `UPDATE` or `DELETE` statement.
+    fn maybe_parse_dml_top(&mut self) -> Result<Option<Top>, ParserError> {
+        let is_top = self.dialect.supports_dml_top()
+            && self.peek_keyword(Keyword::TOP)
+            && matches!(
+                self.peek_nth_token_ref(1).token,
+                Token::LParen | Token::Number(_, _)
+            );
+        if is_top {
+            self.expect_keyword_is(Keyword::TOP)?;
+            Ok(Some(self.parse_top()?))
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Parse a LIMIT clause
     pub fn parse_limit(&mut self) -> Result<Option<Expr>, ParserError> {
         if self.parse_keyword(Keyword::ALL) {
@@ -19041,7 +21471,7 @@ impl<'a> Parser<'a> {
                 content: if allow_empty && parser.peek_token_ref().token == Token::RParen {
                     vec![]
                 } else {
-                    parser.parse_comma_separated(Parser::parse_expr)?
+                    parser.parse_comma_separated(Parser::parse_expr_or_default)?
                 },
                 closing_token: parser.expect_token(&Token::RParen)?.into(),
             })
@@ -19350,7 +21780,7 @@ impl<'a> Parser<'a> {
             (false, _) => Token::SemiColon,
         };
 
-        let parameters = self.parse_comma_separated0(Parser::parse_expr, end_token)?;
+        let parameters = self.parse_comma_separated0(Parser::parse_execute_arg, end_token)?;
 
         if has_parentheses {
             self.expect_token(&Token::RParen)?;
@@ -19384,6 +21814,34 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse an argument of an `EXECUTE` statement, which may be `DEFAULT`
+    /// either positionally or for a named parameter, e.g. `EXEC p @a = DEFAULT`.
+    fn parse_execute_arg(&mut self) -> Result<Expr, ParserError> {
+        if self.peek_nth_token_ref(1).token == Token::Eq
+            && matches!(
+                self.peek_nth_token_ref(2).token,
+                Token::Word(Word {
+                    keyword: Keyword::DEFAULT,
+                    ..
+                })
+            )
+        {
+            if let Some(expr) = self.maybe_parse(|p| {
+                let name = p.parse_identifier()?;
+                p.expect_token(&Token::Eq)?;
+                let value = p.parse_expr_or_default()?;
+                Ok(Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(name)),
+                    op: BinaryOperator::Eq,
+                    right: Box::new(value),
+                })
+            })? {
+                return Ok(expr);
+            }
+        }
+        self.parse_expr_or_default()
+    }
+
     /// Parse a SQL `PREPARE` statement
     pub fn parse_prepare(&mut self) -> Result<Statement, ParserError> {
         let name = self.parse_identifier()?;
@@ -19548,9 +22006,14 @@ impl<'a> Parser<'a> {
         let name = self.parse_object_name(false)?;
 
         // ClickHouse-specific options
-        let on_cluster = self.parse_optional_on_cluster()?;
+        let clickhouse = dialect_of!(self is ClickHouseDialect | GenericDialect);
+        let on_cluster = if clickhouse {
+            self.parse_optional_on_cluster()?
+        } else {
+            None
+        };
 
-        let partition = if self.parse_keyword(Keyword::PARTITION) {
+        let partition = if clickhouse && self.parse_keyword(Keyword::PARTITION) {
             if self.parse_keyword(Keyword::ID) {
                 Some(Partition::Identifier(self.parse_identifier()?))
             } else {
@@ -19560,11 +22023,13 @@ impl<'a> Parser<'a> {
             None
         };
 
-        let include_final = self.parse_keyword(Keyword::FINAL);
+        let include_final = clickhouse && self.parse_keyword(Keyword::FINAL);
 
-        let deduplicate = if self.parse_keyword(Keyword::DEDUPLICATE) {
+        let deduplicate = if clickhouse && self.parse_keyword(Keyword::DEDUPLICATE) {
             if self.parse_keyword(Keyword::BY) {
-                Some(Deduplicate::ByExpression(self.parse_expr()?))
+                Some(Deduplicate::ByExpressions(
+                    self.parse_comma_separated(Parser::parse_expr)?,
+                ))
             } else {
                 Some(Deduplicate::All)
             }
@@ -19573,13 +22038,14 @@ impl<'a> Parser<'a> {
         };
 
         // Databricks-specific options
-        let predicate = if self.parse_keyword(Keyword::WHERE) {
+        let databricks = dialect_of!(self is DatabricksDialect | GenericDialect);
+        let predicate = if databricks && self.parse_keyword(Keyword::WHERE) {
             Some(self.parse_expr()?)
         } else {
             None
         };
 
-        let zorder = if self.parse_keywords(&[Keyword::ZORDER, Keyword::BY]) {
+        let zorder = if databricks && self.parse_keywords(&[Keyword::ZORDER, Keyword::BY]) {
             self.expect_token(&Token::LParen)?;
             let columns = self.parse_comma_separated(|p| p.parse_expr())?;
             self.expect_token(&Token::RParen)?;
@@ -19615,14 +22081,17 @@ impl<'a> Parser<'a> {
         if self.parse_keywords(&[Keyword::AS]) {
             data_type = Some(self.parse_data_type()?)
         }
-        let sequence_options = self.parse_create_sequence_options()?;
-        // [ OWNED BY { table_name.column_name | NONE } ]
+        let mut sequence_options = self.parse_create_sequence_options()?;
+        // [ OWNED BY { table_name.column_name | NONE } ], which may be
+        // followed by further options
         let owned_by = if self.parse_keywords(&[Keyword::OWNED, Keyword::BY]) {
-            if self.parse_keywords(&[Keyword::NONE]) {
-                Some(ObjectName::from(vec![Ident::new("NONE")]))
+            let owned_by = if self.parse_keywords(&[Keyword::NONE]) {
+                ObjectName::from(vec![Ident::new("NONE")])
             } else {
-                Some(self.parse_object_name(false)?)
-            }
+                self.parse_object_name(false)?
+            };
+            sequence_options.extend(self.parse_create_sequence_options()?);
+            Some(owned_by)
         } else {
             None
         };
@@ -19638,44 +22107,38 @@ impl<'a> Parser<'a> {
 
     fn parse_create_sequence_options(&mut self) -> Result<Vec<SequenceOptions>, ParserError> {
         let mut sequence_options = vec![];
-        //[ INCREMENT [ BY ] increment ]
-        if self.parse_keywords(&[Keyword::INCREMENT]) {
-            if self.parse_keywords(&[Keyword::BY]) {
-                sequence_options.push(SequenceOptions::IncrementBy(self.parse_number()?, true));
-            } else {
-                sequence_options.push(SequenceOptions::IncrementBy(self.parse_number()?, false));
-            }
-        }
-        //[ MINVALUE minvalue | NO MINVALUE ]
-        if self.parse_keyword(Keyword::MINVALUE) {
-            sequence_options.push(SequenceOptions::MinValue(Some(self.parse_number()?)));
-        } else if self.parse_keywords(&[Keyword::NO, Keyword::MINVALUE]) {
-            sequence_options.push(SequenceOptions::MinValue(None));
-        }
-        //[ MAXVALUE maxvalue | NO MAXVALUE ]
-        if self.parse_keywords(&[Keyword::MAXVALUE]) {
-            sequence_options.push(SequenceOptions::MaxValue(Some(self.parse_number()?)));
-        } else if self.parse_keywords(&[Keyword::NO, Keyword::MAXVALUE]) {
-            sequence_options.push(SequenceOptions::MaxValue(None));
-        }
-
-        //[ START [ WITH ] start ]
-        if self.parse_keywords(&[Keyword::START]) {
-            if self.parse_keywords(&[Keyword::WITH]) {
-                sequence_options.push(SequenceOptions::StartWith(self.parse_number()?, true));
+        // Options may be given in any order
+        loop {
+            //[ INCREMENT [ BY ] increment ]
+            let option = if self.parse_keywords(&[Keyword::INCREMENT]) {
+                let by = self.parse_keywords(&[Keyword::BY]);
+                SequenceOptions::IncrementBy(self.parse_number()?, by)
+            //[ MINVALUE minvalue | NO MINVALUE ]
+            } else if self.parse_keyword(Keyword::MINVALUE) {
+                SequenceOptions::MinValue(Some(self.parse_number()?))
+            } else if self.parse_keywords(&[Keyword::NO, Keyword::MINVALUE]) {
+                SequenceOptions::MinValue(None)
+            //[ MAXVALUE maxvalue | NO MAXVALUE ]
+            } else if self.parse_keywords(&[Keyword::MAXVALUE]) {
+                SequenceOptions::MaxValue(Some(self.parse_number()?))
+            } else if self.parse_keywords(&[Keyword::NO, Keyword::MAXVALUE]) {
+                SequenceOptions::MaxValue(None)
+            //[ START [ WITH ] start ]
+            } else if self.parse_keywords(&[Keyword::START]) {
+                let with = self.parse_keywords(&[Keyword::WITH]);
+                SequenceOptions::StartWith(self.parse_number()?, with)
+            //[ CACHE cache ]
+            } else if self.parse_keywords(&[Keyword::CACHE]) {
+                SequenceOptions::Cache(self.parse_number()?)
+            // [ [ NO ] CYCLE ]
+            } else if self.parse_keywords(&[Keyword::NO, Keyword::CYCLE]) {
+                SequenceOptions::Cycle(true)
+            } else if self.parse_keywords(&[Keyword::CYCLE]) {
+                SequenceOptions::Cycle(false)
             } else {
-                sequence_options.push(SequenceOptions::StartWith(self.parse_number()?, false));
-            }
-        }
-        //[ CACHE cache ]
-        if self.parse_keywords(&[Keyword::CACHE]) {
-            sequence_options.push(SequenceOptions::Cache(self.parse_number()?));
-        }
-        // [ [ NO ] CYCLE ]
-        if self.parse_keywords(&[Keyword::NO, Keyword::CYCLE]) {
-            sequence_options.push(SequenceOptions::Cycle(true));
-        } else if self.parse_keywords(&[Keyword::CYCLE]) {
-            sequence_options.push(SequenceOptions::Cycle(false));
+                break;
+            };
+            sequence_options.push(option);
         }
 
         Ok(sequence_options)
@@ -19724,6 +22187,143 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    /// Parse an `ALTER SERVER` statement.
+    ///
+    /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-alterserver.html)
+    pub fn parse_pg_alter_server(&mut self) -> Result<AlterServer, ParserError> {
+        let name = self.parse_object_name(false)?;
+        let operation = if self.parse_keywords(&[Keyword::OWNER, Keyword::TO]) {
+            AlterServerOperation::OwnerTo(self.parse_owner()?)
+        } else if self.parse_keywords(&[Keyword::RENAME, Keyword::TO]) {
+            AlterServerOperation::RenameTo {
+                new_name: self.parse_identifier()?,
+            }
+        } else {
+            let version = if self.parse_keyword(Keyword::VERSION) {
+                Some(self.parse_identifier()?)
+            } else {
+                None
+            };
+            let options = if self.parse_keyword(Keyword::OPTIONS) {
+                self.expect_token(&Token::LParen)?;
+                let options = self.parse_comma_separated(Parser::parse_alter_server_option)?;
+                self.expect_token(&Token::RParen)?;
+                Some(options)
+            } else {
+                None
+            };
+            if version.is_none() && options.is_none() {
+                return self.expected_ref(
+                    "VERSION, OPTIONS, OWNER TO or RENAME TO after ALTER SERVER",
+                    self.peek_token_ref(),
+                );
+            }
+            AlterServerOperation::Update { version, options }
+        };
+        Ok(AlterServer { name, operation })
+    }
+
+    /// Parse a single `[ ADD | SET | DROP ] option ['value']` entry of an
+    /// `ALTER SERVER` or `ALTER USER MAPPING` options list.
+    fn parse_alter_server_option(&mut self) -> Result<AlterServerOption, ParserError> {
+        let action = match self.parse_one_of_keywords(&[Keyword::ADD, Keyword::SET, Keyword::DROP])
+        {
+            Some(Keyword::ADD) => Some(AlterServerOptionAction::Add),
+            Some(Keyword::SET) => Some(AlterServerOptionAction::Set),
+            Some(Keyword::DROP) => Some(AlterServerOptionAction::Drop),
+            _ => None,
+        };
+        let key = self.parse_identifier()?;
+        let value = if action == Some(AlterServerOptionAction::Drop) {
+            None
+        } else {
+            Some(self.parse_identifier()?)
+        };
+        Ok(AlterServerOption { action, key, value })
+    }
+
+    /// Returns true if the next tokens start a `USER MAPPING` clause rather
+    /// than a `USER` statement for a user named `mapping`.
+    fn peek_user_mapping(&mut self) -> bool {
+        self.peek_keywords(&[Keyword::USER, Keyword::MAPPING, Keyword::FOR])
+            || self.peek_keywords(&[Keyword::USER, Keyword::MAPPING, Keyword::IF])
+    }
+
+    /// Parse the user of a `USER MAPPING` statement, after the `FOR` keyword.
+    fn parse_user_mapping_user(&mut self) -> Result<Owner, ParserError> {
+        if self.parse_keyword(Keyword::USER) {
+            Ok(Owner::User)
+        } else if self.parse_keyword(Keyword::PUBLIC) {
+            Ok(Owner::Public)
+        } else {
+            self.parse_owner()
+        }
+    }
+
+    /// Parse a `CREATE USER MAPPING` statement, after the `USER MAPPING` keywords.
+    ///
+    /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createusermapping.html)
+    pub fn parse_pg_create_user_mapping(&mut self) -> Result<CreateUserMapping, ParserError> {
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        self.expect_keyword_is(Keyword::FOR)?;
+        let user = self.parse_user_mapping_user()?;
+        self.expect_keyword_is(Keyword::SERVER)?;
+        let server_name = self.parse_object_name(false)?;
+        let options = if self.parse_keyword(Keyword::OPTIONS) {
+            self.expect_token(&Token::LParen)?;
+            let options = self.parse_comma_separated(|p| {
+                let key = p.parse_identifier()?;
+                let value = p.parse_identifier()?;
+                Ok(CreateServerOption { key, value })
+            })?;
+            self.expect_token(&Token::RParen)?;
+            Some(options)
+        } else {
+            None
+        };
+        Ok(CreateUserMapping {
+            if_not_exists,
+            user,
+            server_name,
+            options,
+        })
+    }
+
+    /// Parse an `ALTER USER MAPPING` statement, after the `USER MAPPING` keywords.
+    ///
+    /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-alterusermapping.html)
+    pub fn parse_pg_alter_user_mapping(&mut self) -> Result<AlterUserMapping, ParserError> {
+        self.expect_keyword_is(Keyword::FOR)?;
+        let user = self.parse_user_mapping_user()?;
+        self.expect_keyword_is(Keyword::SERVER)?;
+        let server_name = self.parse_object_name(false)?;
+        self.expect_keyword_is(Keyword::OPTIONS)?;
+        self.expect_token(&Token::LParen)?;
+        let options = self.parse_comma_separated(Parser::parse_alter_server_option)?;
+        self.expect_token(&Token::RParen)?;
+        Ok(AlterUserMapping {
+            user,
+            server_name,
+            options,
+        })
+    }
+
+    /// Parse a `DROP USER MAPPING` statement, after the `USER MAPPING` keywords.
+    ///
+    /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-dropusermapping.html)
+    pub fn parse_pg_drop_user_mapping(&mut self) -> Result<DropUserMapping, ParserError> {
+        let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        self.expect_keyword_is(Keyword::FOR)?;
+        let user = self.parse_user_mapping_user()?;
+        self.expect_keyword_is(Keyword::SERVER)?;
+        let server_name = self.parse_object_name(false)?;
+        Ok(DropUserMapping {
+            if_exists,
+            user,
+            server_name,
+        })
+    }
+
     /// The index of the first unprocessed token.
     pub fn index(&self) -> usize {
         self.index
@@ -19746,25 +22346,73 @@ impl<'a> Parser<'a> {
     }
 
     /// Parse `CREATE PROCEDURE` statement.
-    pub fn parse_create_procedure(&mut self, or_alter: bool) -> Result<Statement, ParserError> {
+    pub fn parse_create_procedure(
+        &mut self,
+        or_alter: bool,
+        or_replace: bool,
+    ) -> Result<Statement, ParserError> {
         let name = self.parse_object_name(false)?;
         let params = self.parse_optional_procedure_parameters()?;
 
+        let return_type = if self.parse_keyword(Keyword::RETURNS) {
+            Some(self.parse_data_type()?)
+        } else {
+            None
+        };
+
         let language = if self.parse_keyword(Keyword::LANGUAGE) {
             Some(self.parse_identifier()?)
         } else {
             None
         };
 
-        self.expect_keyword_is(Keyword::AS)?;
+        let execute_as = if self.parse_keywords(&[Keyword::EXECUTE, Keyword::AS]) {
+            if self.parse_keyword(Keyword::CALLER) {
+                Some(ProcedureExecuteAs::Caller)
+            } else if self.parse_keywords(&[Keyword::RESTRICTED, Keyword::CALLER]) {
+                Some(ProcedureExecuteAs::RestrictedCaller)
+            } else if self.parse_keyword(Keyword::OWNER) {
+                Some(ProcedureExecuteAs::Owner)
+            } else {
+                return self.expected_ref(
+                    "CALLER, RESTRICTED CALLER or OWNER after EXECUTE AS",
+                    self.peek_token_ref(),
+                );
+            }
+        } else {
+            None
+        };
+
+        let options = if self.peek_keyword(Keyword::OPTIONS) {
+            Some(self.parse_options(Keyword::OPTIONS)?)
+        } else {
+            None
+        };
 
-        let body = self.parse_conditional_statements(&[Keyword::END])?;
+        let body = if self.parse_keyword(Keyword::AS) {
+            match self.peek_token_ref().token {
+                Token::SingleQuotedString(_) | Token::DollarQuotedString(_) => {
+                    CreateProcedureBody::AsDefinition(self.parse_expr()?)
+                }
+                _ => CreateProcedureBody::AsStatements(
+                    self.parse_conditional_statements(&[Keyword::END])?,
+                ),
+            }
+        } else if self.peek_keyword(Keyword::BEGIN) {
+            CreateProcedureBody::BeginEnd(self.parse_conditional_statements(&[Keyword::END])?)
+        } else {
+            return self.expected_ref("AS or BEGIN", self.peek_token_ref());
+        };
 
         Ok(Statement::CreateProcedure {
             name,
             or_alter,
+            or_replace,
             params,
+            return_type,
             language,
+            execute_as,
+            options,
             body,
         })
     }
@@ -20501,6 +23149,32 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parses an optional `WITH [ NO ] DATA` clause of a materialized view.
+    fn parse_optional_with_data(&mut self) -> Option<bool> {
+        if self.parse_keywords(&[Keyword::WITH, Keyword::DATA]) {
+            Some(true)
+        } else if self.parse_keywords(&[Keyword::WITH, Keyword::NO, Keyword::DATA]) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Parses a `REFRESH MATERIALIZED VIEW` statement.
+    ///
+    /// See [Statement::RefreshMaterializedView]
+    fn parse_refresh_materialized_view(&mut self) -> Result<Statement, ParserError> {
+        self.expect_keywords(&[Keyword::MATERIALIZED, Keyword::VIEW])?;
+        let concurrently = self.parse_keyword(Keyword::CONCURRENTLY);
+        let name = self.parse_object_name(false)?;
+        let with_data = self.parse_optional_with_data();
+        Ok(Statement::RefreshMaterializedView {
+            concurrently,
+            name,
+            with_data,
+        })
+    }
+
     /// Parses a RESET statement
     fn parse_reset(&mut self) -> Result<ResetStatement, ParserError> {
         if self.parse_keyword(Keyword::ALL) {
@@ -20514,6 +23188,61 @@ impl<'a> Parser<'a> {
     }
 }
 
+/// Returns the byte offset of `location` in `sql`, counting lines and
+/// columns the same way as the [`Tokenizer`].
+/// The SQL text a [`Parser`] was created from, with an index of line starts
+/// to map token [`Location`]s back to byte offsets.
+#[derive(Debug, Clone)]
+struct SourceText {
+    sql: String,
+    /// Byte offset of the start of each line, and whether the line is
+    /// ASCII-only, in which case columns map directly to byte offsets.
+    lines: Vec<(usize, bool)>,
+}
+
+impl SourceText {
+    fn new(sql: &str) -> Self {
+        let mut lines = vec![];
+        let mut start = 0;
+        for line in sql.split_inclusive('\n') {
+            lines.push((start, line.is_ascii()));
+            start += line.len();
+        }
+        if sql.is_empty() || sql.ends_with('\n') {
+            lines.push((start, true));
+        }
+        Self {
+            sql: sql.to_string(),
+            lines,
+        }
+    }
+
+    /// Returns the byte offset of a 1-based line and column `location`.
+    fn offset(&self, location: Location) -> Option<usize> {
+        let index = usize::try_from(location.line).ok()?.checked_sub(1)?;
+        let column = usize::try_from(location.column).ok()?.checked_sub(1)?;
+        let &(start, ascii) = self.lines.get(index)?;
+        let end = self
+            .lines
+            .get(index + 1)
+            .map_or(self.sql.len(), |&(next, _)| next);
+        if ascii {
+            return (start + column <= end).then_some(start + column);
+        }
+        let line = &self.sql[start..end];
+        line.char_indices()
+            .map(|(offset, _)| offset)
+            .chain(core::iter::once(line.len()))
+            .nth(column)
+            .map(|offset| start + offset)
+    }
+
+    /// Returns the text between the `start` and `end` locations.
+    fn slice(&self, start: Location, end: Location) -> Option<&str> {
+        self.sql.get(self.offset(start)?..self.offset(end)?)
+    }
+}
+
 fn maybe_prefixed_expr(expr: Expr, prefix: Option<Ident>) -> Expr {
     if let Some(prefix) = prefix {
         Expr::Prefixed {
@@ -15,12 +15,50 @@
 // specific language governing permissions and limitations
 // under the License.
 
-/// This module contains internal utilities used for testing the library.
-/// While technically public, the library's users are not supposed to rely
-/// on this module, as it will change without notice.
+//! Utilities for asserting how SQL is parsed and re-serialized.
+//!
+//! This module provides the same helpers the crate uses in its own test
+//! suite, so that downstream crates (for example ones that wrap the parser or
+//! add rewrite passes) can assert round-trip behavior in the same way.
+//!
+//! Downstream test suites should enable the `test-utils` feature to opt into
+//! this module. For compatibility with crates that already import it, the
+//! module is currently also available without the feature.
+//!
+//! The entry point is [`TestedDialects`], which runs the parser for one or
+//! more dialects and asserts that they all produce the same result. Helpers
+//! such as [`all_dialects`], [`all_dialects_where`] and
+//! [`all_dialects_except`] build a [`TestedDialects`] for common sets of
+//! dialects.
+//!
+//! Note that the AST's `PartialEq` implementations ignore source locations
+//! (see [`Span`](crate::tokenizer::Span)), so expected nodes can be built
+//! without spans, e.g. using [`Value::with_empty_span`] or the [`number`] and
+//! [`single_quoted_string`] helpers below.
+//!
+//! # Example
+//!
+//! ```
+//! use sqlparser::ast::Statement;
+//! use sqlparser::dialect::{GenericDialect, PostgreSqlDialect};
+//! use sqlparser::test_utils::TestedDialects;
+//!
+//! let dialects = TestedDialects::new(vec![
+//!     Box::new(GenericDialect {}),
+//!     Box::new(PostgreSqlDialect {}),
+//! ]);
+//!
+//! // Asserts that the statement parses identically for both dialects and
+//! // that it is serialized back to the exact same string.
+//! let stmt = dialects.verified_stmt("CREATE TABLE t (a INT)");
+//! assert!(matches!(stmt, Statement::CreateTable(_)));
+//!
+//! // Asserts that the statement is serialized to the given canonical form.
+//! dialects.one_statement_parses_to("select 1", "SELECT 1");
+//! ```
 //
 // Integration tests (i.e. everything under `tests/`) import this
-// via `tests/test_utils/helpers`.
+// via `tests/test_utils/mod.rs`.
 
 #[cfg(not(feature = "std"))]
 use alloc::{
@@ -42,8 +80,11 @@ use pretty_assertions::assert_eq;
 /// Tests use the methods on this struct to invoke the parser on one or
 /// multiple dialects.
 pub struct TestedDialects {
+    /// The dialects to run the parser with.
     pub dialects: Vec<Box<dyn Dialect>>,
+    /// Optional parser options applied to every dialect.
     pub options: Option<ParserOptions>,
+    /// Optional recursion limit applied to every dialect.
     pub recursion_limit: Option<usize>,
 }
 
@@ -57,6 +98,7 @@ impl TestedDialects {
         }
     }
 
+    /// Create a TestedDialects with the given dialects and parser options.
     pub fn new_with_options(dialects: Vec<Box<dyn Dialect>>, options: ParserOptions) -> Self {
         Self {
             dialects,
@@ -65,6 +107,7 @@ impl TestedDialects {
         }
     }
 
+    /// Set the recursion limit used when parsing.
     pub fn with_recursion_limit(mut self, recursion_limit: usize) -> Self {
         self.recursion_limit = Some(recursion_limit);
         self
@@ -108,6 +151,8 @@ impl TestedDialects {
             .1
     }
 
+    /// Run the given parser method on `sql` for all of `self.dialects`,
+    /// assert that they return the same result, and return that result.
     pub fn run_parser_method<F, T: Debug + PartialEq>(&self, sql: &str, f: F) -> T
     where
         F: Fn(&mut Parser) -> T,
@@ -140,8 +185,8 @@ impl TestedDialects {
     ///
     /// In general, the canonical SQL should be the same (see crate
     /// documentation for rationale) and you should prefer the `verified_`
-    /// variants in testing, such as  [`verified_statement`] or
-    /// [`verified_query`].
+    /// variants in testing, such as  [`verified_stmt`](Self::verified_stmt) or
+    /// [`verified_query`](Self::verified_query).
     ///
     /// If `canonical` is non empty,this function additionally asserts
     /// that:
@@ -152,7 +197,7 @@ impl TestedDialects {
     /// 2. re-serializing the result of parsing `sql` produces the same
     ///    `canonical` sql string
     ///
-    ///  For multiple statements, use [`statements_parse_to`].
+    ///  For multiple statements, use [`statements_parse_to`](Self::statements_parse_to).
     pub fn one_statement_parses_to(&self, sql: &str, canonical: &str) -> Statement {
         let mut statements = self.parse_sql_statements(sql).expect(sql);
         assert_eq!(statements.len(), 1);
@@ -168,7 +213,7 @@ impl TestedDialects {
         only_statement
     }
 
-    /// The same as [`one_statement_parses_to`] but it works for a multiple statements
+    /// The same as [`one_statement_parses_to`](Self::one_statement_parses_to) but it works for a multiple statements
     pub fn statements_parse_to(&self, sql: &str, canonical: &str) -> Vec<Statement> {
         let statements = self.parse_sql_statements(sql).expect(sql);
         if !canonical.is_empty() && sql != canonical {
@@ -295,7 +340,7 @@ pub fn all_dialects() -> TestedDialects {
     ])
 }
 
-// Returns all available dialects with the specified parser options
+/// Returns all available dialects with the specified parser options.
 pub fn all_dialects_with_options(options: ParserOptions) -> TestedDialects {
     TestedDialects::new_with_options(all_dialects().dialects, options)
 }
@@ -319,6 +364,7 @@ where
     all_dialects_where(|d| !except(d))
 }
 
+/// Asserts that the string representations of `actual` equal `expected`.
 pub fn assert_eq_vec<T: ToString>(expected: &[&str], actual: &[T]) {
     assert_eq!(
         expected,
@@ -326,6 +372,7 @@ pub fn assert_eq_vec<T: ToString>(expected: &[&str], actual: &[T]) {
     );
 }
 
+/// Returns the single item of `v`, panicking if it doesn't contain exactly one item.
 pub fn only<T>(v: impl IntoIterator<Item = T>) -> T {
     let mut iter = v.into_iter();
     if let (Some(item), None) = (iter.next(), iter.next()) {
@@ -335,6 +382,7 @@ pub fn only<T>(v: impl IntoIterator<Item = T>) -> T {
     }
 }
 
+/// Returns the expression of an unnamed [`SelectItem`], panicking otherwise.
 pub fn expr_from_projection(item: &SelectItem) -> &Expr {
     match item {
         SelectItem::UnnamedExpr(expr) => expr,
@@ -342,6 +390,8 @@ pub fn expr_from_projection(item: &SelectItem) -> &Expr {
     }
 }
 
+/// Returns the single operation of an `ALTER TABLE` statement on the
+/// table `expected_name`, panicking otherwise.
 pub fn alter_table_op_with_name(stmt: Statement, expected_name: &str) -> AlterTableOperation {
     match stmt {
         Statement::AlterTable(alter_table) => {
@@ -355,6 +405,7 @@ pub fn alter_table_op_with_name(stmt: Statement, expected_name: &str) -> AlterTa
     }
 }
 
+/// The same as [`alter_table_op_with_name`] for a table named `tab`.
 pub fn alter_table_op(stmt: Statement) -> AlterTableOperation {
     alter_table_op_with_name(stmt, "tab")
 }
@@ -369,6 +420,7 @@ pub fn single_quoted_string(s: impl Into<String>) -> Value {
     Value::SingleQuotedString(s.into())
 }
 
+/// Creates a [`TableAlias`] without column names.
 pub fn table_alias(explicit: bool, name: impl Into<String>) -> Option<TableAlias> {
     Some(TableAlias {
         explicit,
@@ -378,6 +430,7 @@ pub fn table_alias(explicit: bool, name: impl Into<String>) -> Option<TableAlias
     })
 }
 
+/// Creates a [`TableFactor::Table`] with the given single-part name.
 pub fn table(name: impl Into<String>) -> TableFactor {
     TableFactor::Table {
         name: ObjectName::from(vec![Ident::new(name.into())]),
@@ -390,9 +443,11 @@ pub fn table(name: impl Into<String>) -> TableFactor {
         json_path: None,
         sample: None,
         index_hints: vec![],
+        final_modifier: false,
     }
 }
 
+/// Creates a [`TableFactor::Table`] with the given name.
 pub fn table_from_name(name: ObjectName) -> TableFactor {
     TableFactor::Table {
         name,
@@ -405,9 +460,11 @@ pub fn table_from_name(name: ObjectName) -> TableFactor {
         json_path: None,
         sample: None,
         index_hints: vec![],
+        final_modifier: false,
     }
 }
 
+/// Creates a [`TableFactor::Table`] with the given name and alias.
 pub fn table_with_alias(
     name: impl Into<String>,
     with_as_keyword: bool,
@@ -424,17 +481,21 @@ pub fn table_with_alias(
         json_path: None,
         sample: None,
         index_hints: vec![],
+        final_modifier: false,
     }
 }
 
+/// Creates a `NATURAL JOIN` with the given relation.
 pub fn join(relation: TableFactor) -> Join {
     Join {
         relation,
         global: false,
+        strictness: None,
         join_operator: JoinOperator::Join(JoinConstraint::Natural),
     }
 }
 
+/// Creates a call to `function` with the given unnamed arguments.
 pub fn call(function: &str, args: impl IntoIterator<Item = Expr>) -> Expr {
     Expr::Function(Function {
         name: ObjectName::from(vec![Ident::new(function)]),
@@ -411,6 +411,7 @@ fn parse_create_view_if_not_exists() {
             cluster_by,
             comment,
             with_no_schema_binding: late_binding,
+            with_check_option: None,
             if_not_exists,
             temporary,
             ..
@@ -591,6 +592,75 @@ fn parse_create_table_with_options() {
     bigquery().verified_stmt(sql);
 }
 
+#[test]
+fn parse_create_table_partition_by_cluster_by_options() {
+    let sql = concat!(
+        "CREATE TABLE d.t (x INT64, ts TIMESTAMP) ",
+        "PARTITION BY DATE(ts) ",
+        "CLUSTER BY a, b ",
+        "OPTIONS(expiration_timestamp = TIMESTAMP '2030-01-01')"
+    );
+    match bigquery().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable {
+            partition_by,
+            cluster_by,
+            table_options,
+            ..
+        }) => {
+            assert_eq!(partition_by.unwrap().to_string(), "DATE(ts)");
+            assert_eq!(
+                cluster_by,
+                Some(WrappedCollection::NoWrapping(vec![
+                    Expr::Identifier(Ident::new("a")),
+                    Expr::Identifier(Ident::new("b")),
+                ]))
+            );
+            match table_options {
+                CreateTableOptions::Options(options) => {
+                    assert_eq!(options.len(), 1);
+                    assert_eq!(
+                        options[0].to_string(),
+                        "expiration_timestamp = TIMESTAMP '2030-01-01'"
+                    );
+                }
+                other => panic!("Expected: OPTIONS, got: {other:?}"),
+            }
+        }
+        _ => unreachable!(),
+    }
+
+    let sql = concat!(
+        "CREATE TABLE d.t (x INT64, y INT64) ",
+        "PARTITION BY RANGE_BUCKET(y, GENERATE_ARRAY(0, 100, 10)) ",
+        "CLUSTER BY x ",
+        "OPTIONS(require_partition_filter = true)"
+    );
+    match bigquery().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable {
+            partition_by,
+            cluster_by,
+            table_options,
+            ..
+        }) => {
+            assert_eq!(
+                partition_by.unwrap().to_string(),
+                "RANGE_BUCKET(y, GENERATE_ARRAY(0, 100, 10))"
+            );
+            assert!(cluster_by.is_some());
+            assert!(matches!(table_options, CreateTableOptions::Options(_)));
+        }
+        _ => unreachable!(),
+    }
+
+    bigquery().verified_stmt(
+        "CREATE TABLE d.t (x INT64) PARTITION BY _PARTITIONDATE CLUSTER BY x OPTIONS(description = 'd')",
+    );
+    bigquery().verified_stmt("CREATE TABLE d.t (x INT64) PARTITION BY _PARTITIONDATE CLUSTER BY x");
+    bigquery().verified_stmt(
+        "CREATE TABLE d.t (x INT64) PARTITION BY _PARTITIONDATE OPTIONS(require_partition_filter = true)",
+    );
+}
+
 #[test]
 fn parse_create_external_table_with_options() {
     bigquery().verified_stmt(
@@ -1756,6 +1826,7 @@ fn parse_table_time_travel() {
                 json_path: None,
                 sample: None,
                 index_hints: vec![],
+                final_modifier: false,
             },
             joins: vec![]
         },]
@@ -1786,6 +1857,7 @@ fn parse_join_constraint_unnest_alias() {
                 with_ordinality: false,
             },
             global: false,
+            strictness: None,
             join_operator: JoinOperator::Join(JoinConstraint::On(Expr::BinaryOp {
                 left: Box::new(Expr::Identifier("c1".into())),
                 op: BinaryOperator::Eq,
@@ -1866,6 +1938,7 @@ fn parse_merge() {
                     json_path: None,
                     sample: None,
                     index_hints: vec![],
+                    final_modifier: false,
                 },
                 table
             );
@@ -1881,6 +1954,7 @@ fn parse_merge() {
                     json_path: None,
                     sample: None,
                     index_hints: vec![],
+                    final_modifier: false,
                 },
                 source
             );
@@ -2008,7 +2082,7 @@ fn parse_merge() {
                                 explicit_row: false,
                                 rows: vec![Parens::with_empty_span(vec![
                                     Expr::value(number("1")),
-                                    Expr::Identifier(Ident::new("DEFAULT")),
+                                    Expr::Default,
                                 ])]
                             }),
                             insert_predicate: None,
@@ -2027,7 +2101,7 @@ fn parse_merge() {
                                 explicit_row: false,
                                 rows: vec![Parens::with_empty_span(vec![
                                     Expr::value(number("1")),
-                                    Expr::Identifier(Ident::new("DEFAULT")),
+                                    Expr::Default,
                                 ])]
                             }),
                             insert_predicate: None,
@@ -2461,6 +2535,58 @@ fn bigquery_select_expr_star() {
     bigquery().verified_only_select("SELECT myfunc()[0].* FROM T");
 }
 
+#[test]
+fn test_qualified_wildcard_except_replace() {
+    let select = bigquery().verified_only_select(
+        "SELECT t.* EXCEPT (internal_id), s.payload.* REPLACE (amount / 100 AS amount) FROM t, s",
+    );
+    match &select.projection[0] {
+        SelectItem::QualifiedWildcard(SelectItemQualifiedWildcardKind::ObjectName(name), opts) => {
+            assert_eq!(name, &ObjectName::from(vec![Ident::new("t")]));
+            assert_eq!(
+                opts.opt_except,
+                Some(ExceptSelectItem {
+                    first_element: Ident::new("internal_id"),
+                    additional_elements: vec![],
+                })
+            );
+        }
+        _ => unreachable!(),
+    }
+    match &select.projection[1] {
+        SelectItem::QualifiedWildcard(SelectItemQualifiedWildcardKind::ObjectName(name), opts) => {
+            assert_eq!(
+                name,
+                &ObjectName::from(vec![Ident::new("s"), Ident::new("payload")])
+            );
+            assert_eq!(
+                opts.opt_replace,
+                Some(ReplaceSelectItem {
+                    items: vec![Box::new(ReplaceSelectElement {
+                        expr: bigquery().verified_expr("amount / 100"),
+                        column_name: Ident::new("amount"),
+                        as_keyword: true,
+                    })],
+                })
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    bigquery().verified_only_select("SELECT t.* EXCEPT (a, b) REPLACE (a + 1 AS c) FROM t");
+
+    // EXCEPT entries are single column names
+    assert_eq!(
+        bigquery()
+            .parse_sql_statements("SELECT t.* EXCEPT (a, s.b) FROM t")
+            .unwrap_err(),
+        ParserError::ParserError(
+            "EXCEPT expects unqualified column names, found qualified name starting with s"
+                .to_string()
+        )
+    );
+}
+
 #[test]
 fn test_select_as_struct() {
     for (sql, parse_to) in [
@@ -2741,7 +2867,7 @@ fn test_export_data() {
                 limit_clause: Some(LimitClause::LimitOffset {
                     limit: Some(Expr::Value(number("10").with_empty_span())),
                     offset: None,
-                    limit_by: vec![],
+                    limit_by: None,
                 }),
                 fetch: None,
                 locks: vec![],
@@ -2749,6 +2875,7 @@ fn test_export_data() {
                 settings: None,
                 format_clause: None,
                 pipe_operators: vec![],
+                options: vec![],
             })
         })
     );
@@ -2847,7 +2974,7 @@ fn test_export_data() {
                 limit_clause: Some(LimitClause::LimitOffset {
                     limit: Some(Expr::Value(number("10").with_empty_span())),
                     offset: None,
-                    limit_by: vec![],
+                    limit_by: None,
                 }),
                 fetch: None,
                 locks: vec![],
@@ -2855,6 +2982,7 @@ fn test_export_data() {
                 settings: None,
                 format_clause: None,
                 pipe_operators: vec![],
+                options: vec![],
             })
         })
     );
@@ -2925,3 +3053,431 @@ fn test_create_snapshot_table() {
         "CREATE SNAPSHOT TABLE IF NOT EXISTS dataset_id.table1 CLONE dataset_id.table2 FOR SYSTEM_TIME AS OF TIMESTAMP_SUB(CURRENT_TIMESTAMP(), INTERVAL 1 HOUR) OPTIONS(expiration_timestamp = TIMESTAMP '2025-01-01 00:00:00 UTC')",
     );
 }
+
+#[test]
+fn parse_pipe_syntax_from_query() {
+    let sql = "FROM orders |> WHERE amount > 0 |> AGGREGATE SUM(amount) AS total GROUP BY region |> ORDER BY total DESC";
+    let query = bigquery().verified_query(sql);
+    assert_eq!(
+        query.body.as_select().unwrap().flavor,
+        SelectFlavor::FromFirstNoSelect
+    );
+    assert_eq!(query.pipe_operators.len(), 3);
+    assert!(matches!(
+        query.pipe_operators[1],
+        PipeOperator::Aggregate { .. }
+    ));
+
+    // Example from <https://cloud.google.com/bigquery/docs/reference/standard-sql/pipe-syntax>
+    bigquery().verified_stmt(
+        "FROM mydataset.Produce |> WHERE sales > 0 |> AGGREGATE SUM(sales) AS total_sales, COUNT(*) AS num_sales GROUP BY item |> EXTEND total_sales / num_sales AS average |> JOIN mydataset.Items USING(item) |> SELECT item, average |> LIMIT 10",
+    );
+
+    // A bare FROM clause is a valid query on its own
+    bigquery().verified_stmt("FROM orders");
+
+    // FROM-first SELECT is not BigQuery syntax, pipe queries need `|>`
+    assert_eq!(
+        bigquery()
+            .parse_sql_statements("FROM orders SELECT amount")
+            .unwrap_err(),
+        ParserError::ParserError("Expected: end of FROM clause, found: SELECT".to_string())
+    );
+}
+
+#[test]
+fn parse_table_time_travel_expressions() {
+    bigquery().verified_stmt(
+        "SELECT * FROM t1 FOR SYSTEM_TIME AS OF TIMESTAMP_SUB(CURRENT_TIMESTAMP(), INTERVAL 1 HOUR) AS t",
+    );
+    bigquery().verified_stmt(
+        "SELECT * FROM t1 FOR SYSTEM_TIME AS OF TIMESTAMP '2024-01-01 00:00:00' JOIN t2 ON t1.id = t2.id",
+    );
+}
+
+#[test]
+fn parse_array_agg_in_args_clauses() {
+    let select = bigquery()
+        .verified_only_select("SELECT ARRAY_AGG(x IGNORE NULLS ORDER BY y DESC LIMIT 10) FROM t");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function {
+            args: FunctionArguments::List(FunctionArgumentList { args, clauses, .. }),
+            null_treatment,
+            ..
+        }) => {
+            assert_eq!(
+                args,
+                &vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(
+                    Expr::Identifier(Ident::new("x"))
+                ))]
+            );
+            assert_eq!(
+                clauses,
+                &vec![
+                    FunctionArgumentClause::IgnoreOrRespectNulls(NullTreatment::IgnoreNulls),
+                    FunctionArgumentClause::OrderBy(vec![OrderByExpr {
+                        expr: Expr::Identifier(Ident::new("y")),
+                        options: OrderByOptions {
+                            asc: Some(false),
+                            nulls_first: None,
+                        },
+                        with_fill: None,
+                    }]),
+                    FunctionArgumentClause::Limit(Expr::value(number("10"))),
+                ]
+            );
+            assert_eq!(null_treatment, &None);
+        }
+        e => panic!("unexpected expression: {e:?}"),
+    }
+
+    bigquery().verified_stmt("SELECT ARRAY_AGG(x RESPECT NULLS) FROM t");
+    bigquery().verified_stmt("SELECT LAST_VALUE(x IGNORE NULLS) OVER (ORDER BY y) FROM t");
+}
+
+#[test]
+fn parse_create_procedure_with_options() {
+    let sql = "CREATE OR REPLACE PROCEDURE mydataset.create_customer (IN name STRING, OUT id INT64) OPTIONS(strict_mode = false) BEGIN SELECT name; END";
+    match bigquery().verified_stmt(sql) {
+        Statement::CreateProcedure {
+            or_replace,
+            name,
+            params,
+            options,
+            body,
+            ..
+        } => {
+            assert!(or_replace);
+            assert_eq!(name.to_string(), "mydataset.create_customer");
+            assert_eq!(
+                params
+                    .unwrap()
+                    .into_iter()
+                    .map(|p| p.mode)
+                    .collect::<Vec<_>>(),
+                vec![Some(ArgMode::In), Some(ArgMode::Out)]
+            );
+            assert_eq!(
+                options,
+                Some(vec![SqlOption::KeyValue {
+                    key: Ident::new("strict_mode"),
+                    value: Expr::Value(Value::Boolean(false).with_empty_span()),
+                }])
+            );
+            assert!(matches!(
+                body,
+                CreateProcedureBody::BeginEnd(ConditionalStatements::BeginEnd(_))
+            ));
+        }
+        s => panic!("unexpected statement: {s:?}"),
+    }
+
+    assert_eq!(
+        bigquery()
+            .parse_sql_statements("CREATE OR REPLACE INDEX idx ON t (a)")
+            .unwrap_err(),
+        ParserError::ParserError(
+            "Expected: [EXTERNAL] TABLE or [MATERIALIZED] VIEW or FUNCTION or PROCEDURE after CREATE OR REPLACE, found: INDEX"
+                .to_string()
+        )
+    );
+}
+
+#[test]
+fn parse_unnest_with_offset_alias() {
+    let select =
+        bigquery().verified_only_select("SELECT * FROM UNNEST(arr) AS item WITH OFFSET AS pos");
+    match &select.from[0].relation {
+        TableFactor::UNNEST {
+            alias: Some(alias),
+            with_offset: true,
+            with_offset_alias: Some(offset_alias),
+            with_ordinality: false,
+            ..
+        } => {
+            assert_eq!(Ident::new("item"), alias.name);
+            assert!(offset_alias.explicit);
+            assert_eq!(Ident::new("pos"), offset_alias.name);
+        }
+        r => unreachable!("{r:?}"),
+    }
+
+    bigquery().verified_stmt("SELECT * FROM UNNEST([1, 2]) WITH OFFSET pos");
+    bigquery().verified_stmt("SELECT * FROM UNNEST([1, 2]) AS x WITH OFFSET WHERE x > 1");
+    bigquery().verified_stmt(
+        "SELECT * FROM t, UNNEST(t.arr) AS item WITH OFFSET AS pos JOIN u ON u.id = item",
+    );
+}
+
+#[test]
+fn parse_create_table_function() {
+    let sql = "CREATE OR REPLACE TABLE FUNCTION ds.tf(x INT64, y STRING) RETURNS TABLE<a INT64, b STRUCT<c STRING>> OPTIONS(description = 'd') AS (SELECT x AS a, STRUCT(y AS c) AS b)";
+    match bigquery().verified_stmt(sql) {
+        Statement::CreateTableFunction(CreateTableFunction {
+            or_replace,
+            if_not_exists,
+            name,
+            args,
+            returns: Some(returns),
+            options: Some(options),
+            ..
+        }) => {
+            assert!(or_replace);
+            assert!(!if_not_exists);
+            assert_eq!("ds.tf", name.to_string());
+            assert_eq!(2, args.len());
+            assert_eq!(
+                vec![Some(Ident::new("a")), Some(Ident::new("b"))],
+                returns
+                    .into_iter()
+                    .map(|c| c.field_name)
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(1, options.len());
+        }
+        s => unreachable!("{s:?}"),
+    }
+
+    bigquery().verified_stmt("CREATE TABLE FUNCTION IF NOT EXISTS tf() AS SELECT 1 AS x");
+    bigquery().verified_stmt(
+        "CREATE TABLE FUNCTION tf(n INT64) RETURNS TABLE<x INT64> AS (SELECT n AS x)",
+    );
+    assert!(bigquery()
+        .parse_sql_statements("CREATE TABLE FUNCTION tf() RETURNS TABLE<a INT64>> AS SELECT 1")
+        .is_err());
+}
+
+#[test]
+fn parse_create_table_copy_clone_like() {
+    match bigquery().verified_stmt("CREATE TABLE t COPY other") {
+        Statement::CreateTable(CreateTable { copy, .. }) => {
+            assert_eq!(Some("other".to_string()), copy.map(|c| c.to_string()))
+        }
+        s => unreachable!("{s:?}"),
+    }
+    bigquery()
+        .verified_stmt("CREATE TABLE IF NOT EXISTS ds.t COPY ds.other OPTIONS(description = 'x')");
+
+    match bigquery().verified_stmt("CREATE TABLE t CLONE other FOR SYSTEM_TIME AS OF ts") {
+        Statement::CreateTable(CreateTable {
+            clone: Some(clone),
+            version: Some(TableVersion::ForSystemTimeAsOf(_)),
+            ..
+        }) => assert_eq!("other", clone.to_string()),
+        s => unreachable!("{s:?}"),
+    }
+    bigquery().verified_stmt(
+        "CREATE TABLE t CLONE other FOR SYSTEM_TIME AS OF TIMESTAMP_SUB(CURRENT_TIMESTAMP(), INTERVAL 1 HOUR) OPTIONS(description = 'x')",
+    );
+    bigquery().verified_stmt("CREATE TABLE t CLONE other");
+    bigquery().verified_stmt("CREATE TABLE t LIKE other");
+}
+
+#[test]
+fn parse_assert_statements() {
+    match bigquery()
+        .verified_stmt("ASSERT EXISTS (SELECT 1 FROM t WHERE x IS NULL) AS 'nulls found'")
+    {
+        Statement::Assert {
+            condition: Expr::Exists { negated, .. },
+            message: Some(message),
+        } => {
+            assert!(!negated);
+            assert_eq!("'nulls found'", message.to_string());
+        }
+        s => unreachable!("{s:?}"),
+    }
+    bigquery().verified_stmt("ASSERT (SELECT COUNT(*) FROM t) > 0 AS 'table must not be empty'");
+    bigquery().verified_stmt("ASSERT NOT EXISTS (SELECT 1 FROM t WHERE x < 0)");
+    bigquery().verified_stmt("ASSERT (SELECT MAX(ts) FROM t) >= CURRENT_DATE() AND 1 = 1");
+}
+
+#[test]
+fn parse_for_in_loop() {
+    let sql = "FOR rec IN (SELECT id, amount FROM t) DO \
+        IF rec.amount > 100 THEN INSERT INTO big VALUES (rec.id); ELSE CONTINUE; END IF; \
+        END FOR";
+    match bigquery().verified_stmt(sql) {
+        Statement::For(ForStatement {
+            variable,
+            query,
+            body,
+        }) => {
+            assert_eq!(Ident::new("rec"), variable);
+            assert_eq!("SELECT id, amount FROM t", query.to_string());
+            assert_eq!(1, body.len());
+            assert!(matches!(body[0], Statement::If(_)));
+        }
+        _ => unreachable!(),
+    }
+
+    bigquery().verified_stmt("FOR x IN (SELECT 1) DO END FOR");
+    bigquery().verified_stmt("FOR x IN (SELECT * FROM t) DO SELECT x.a; BREAK; END FOR");
+}
+
+#[test]
+fn parse_repeat_loop_and_loop_control() {
+    match bigquery().verified_stmt("REPEAT SET x = x + 1; UNTIL x >= 10 END REPEAT") {
+        Statement::Repeat(RepeatStatement { body, until }) => {
+            assert_eq!(1, body.len());
+            assert_eq!("x >= 10", until.to_string());
+        }
+        _ => unreachable!(),
+    }
+
+    assert_eq!(
+        bigquery().verified_stmt("LEAVE outer_loop"),
+        Statement::LoopControl(LoopControlStatement {
+            kind: LoopControlKind::Leave,
+            label: Some(Ident::new("outer_loop")),
+        })
+    );
+    bigquery().verified_stmt("BREAK");
+    bigquery().verified_stmt("CONTINUE");
+    bigquery().verified_stmt("ITERATE lbl");
+    bigquery()
+        .verified_stmt("REPEAT IF x > 5 THEN BREAK; END IF; SET x = x + 1; UNTIL false END REPEAT");
+
+    // REPEAT and LEAVE remain usable as function and column names
+    bigquery().verified_stmt("SELECT REPEAT('a', 3), leave FROM t");
+}
+
+#[test]
+fn parse_date_part_function_arguments() {
+    let select = bigquery().verified_only_select(
+        "SELECT TIMESTAMP_DIFF(t1, t2, HOUR), DATE_TRUNC(d, MONTH), LAST_DAY(d, WEEK(MONDAY)) FROM t",
+    );
+    let date_part_args: Vec<_> = select
+        .projection
+        .iter()
+        .map(|item| match expr_from_projection(item) {
+            Expr::Function(Function {
+                args: FunctionArguments::List(list),
+                ..
+            }) => list.args.last().cloned().unwrap(),
+            e => unreachable!("{e:?}"),
+        })
+        .collect();
+    assert_eq!(
+        date_part_args,
+        vec![
+            FunctionArg::Unnamed(FunctionArgExpr::DateTimeField(DateTimeField::Hour)),
+            FunctionArg::Unnamed(FunctionArgExpr::DateTimeField(DateTimeField::Month)),
+            FunctionArg::Unnamed(FunctionArgExpr::DateTimeField(DateTimeField::Week(Some(
+                Ident::new("MONDAY")
+            )))),
+        ]
+    );
+
+    bigquery().verified_stmt("SELECT TIMESTAMP_TRUNC(ts, DAY, 'UTC') FROM t");
+    bigquery().verified_stmt("SELECT DATE_DIFF(d1, d2, WEEK(SUNDAY)) FROM t");
+
+    // Column references in the date part position and unknown functions are untouched
+    let select =
+        bigquery().verified_only_select("SELECT DATE_TRUNC(d, part_col), MY_FUNC(d, HOUR) FROM t");
+    for item in &select.projection {
+        match expr_from_projection(item) {
+            Expr::Function(Function {
+                args: FunctionArguments::List(list),
+                ..
+            }) => assert!(matches!(
+                list.args[1],
+                FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Identifier(_)))
+            )),
+            e => unreachable!("{e:?}"),
+        }
+    }
+}
+
+#[test]
+fn parse_create_external_table_with_connection() {
+    let sql = "CREATE EXTERNAL TABLE ds.ext WITH CONNECTION `proj.region.conn` OPTIONS(uris = ['gs://bucket/*.csv'], format = 'CSV')";
+    match bigquery().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable {
+            name,
+            external,
+            columns,
+            with_connection,
+            table_options,
+            ..
+        }) => {
+            assert_eq!("ds.ext", name.to_string());
+            assert!(external);
+            assert!(columns.is_empty());
+            assert_eq!(
+                Some(ObjectName::from(vec![Ident::with_quote(
+                    '`',
+                    "proj.region.conn"
+                )])),
+                with_connection
+            );
+            assert!(
+                matches!(table_options, CreateTableOptions::Options(options) if options.len() == 2)
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    bigquery().verified_stmt(
+        "CREATE OR REPLACE EXTERNAL TABLE ds.ext (a INT64, b STRING) WITH CONNECTION `proj`.us.conn OPTIONS(format = 'PARQUET')",
+    );
+}
+
+#[test]
+fn parse_create_snapshot_table_clone_time_travel() {
+    let sql = "CREATE SNAPSHOT TABLE ds.snap CLONE ds.src FOR SYSTEM_TIME AS OF ts OPTIONS(expiration_timestamp = TIMESTAMP '2025-01-01')";
+    match bigquery().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable {
+            snapshot,
+            clone,
+            version,
+            ..
+        }) => {
+            assert!(snapshot);
+            assert_eq!(Some("ds.src".to_string()), clone.map(|c| c.to_string()));
+            assert_eq!(
+                Some(TableVersion::ForSystemTimeAsOf(Expr::Identifier(
+                    Ident::new("ts")
+                ))),
+                version
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_create_view_column_options_and_check_option() {
+    match bigquery().verified_stmt(
+        "CREATE VIEW ds.v (a OPTIONS(description = 'first'), b) OPTIONS(friendly_name = 'f') AS SELECT 1, 2",
+    ) {
+        Statement::CreateView(CreateView {
+            columns, options, ..
+        }) => {
+            assert_eq!(2, columns.len());
+            assert!(matches!(
+                columns[0].options,
+                Some(ColumnOptions::CommaSeparated(_))
+            ));
+            assert_eq!(None, columns[1].options);
+            assert!(matches!(options, CreateTableOptions::Options(_)));
+        }
+        _ => unreachable!(),
+    }
+    bigquery_and_generic()
+        .verified_stmt("CREATE VIEW v AS SELECT a FROM t WITH CASCADED CHECK OPTION");
+}
+
+#[test]
+fn parse_hyphenated_project_in_from() {
+    let select =
+        bigquery().verified_only_select("SELECT * FROM my-project.dataset.table WHERE a - b > 1");
+    assert_eq!(
+        select.from[0].relation,
+        table_from_name(ObjectName::from(vec![
+            Ident::new("my-project"),
+            Ident::new("dataset"),
+            Ident::new("table"),
+        ])),
+    );
+}
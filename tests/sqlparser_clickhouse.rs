@@ -496,7 +496,10 @@ fn parse_optimize_table() {
             ..
         } => {
             assert_eq!(name.to_string(), "t0");
-            assert_eq!(on_cluster, Some(Ident::new("cluster")));
+            assert_eq!(
+                on_cluster,
+                Some(ObjectNamePart::Identifier(Ident::new("cluster")))
+            );
             assert_eq!(
                 partition,
                 Some(Partition::Identifier(Ident::with_quote('\'', "2024-07")))
@@ -504,7 +507,9 @@ fn parse_optimize_table() {
             assert!(include_final);
             assert_eq!(
                 deduplicate,
-                Some(Deduplicate::ByExpression(Identifier(Ident::new("id"))))
+                Some(Deduplicate::ByExpressions(vec![Identifier(Ident::new(
+                    "id"
+                ))]))
             );
         }
         _ => unreachable!(),
@@ -968,6 +973,72 @@ fn parse_limit_by() {
         .expect_err("BY with OFFSET but without LIMIT");
 }
 
+#[test]
+fn parse_limit_by_with_limit() {
+    clickhouse_and_generic()
+        .verified_stmt("SELECT * FROM events ORDER BY ts DESC LIMIT 3 BY user_id LIMIT 100");
+    clickhouse_and_generic()
+        .verified_stmt("SELECT * FROM events LIMIT 3 BY user_id LIMIT 100 OFFSET 10");
+
+    let query = clickhouse_and_generic()
+        .verified_query("SELECT * FROM events LIMIT 2 OFFSET 1 BY x, y LIMIT 100");
+    assert_eq!(
+        Some(LimitClause::LimitOffset {
+            limit: Some(Expr::value(number("100"))),
+            offset: None,
+            limit_by: Some(LimitBy {
+                limit: Expr::value(number("2")),
+                offset: Some(Offset {
+                    value: Expr::value(number("1")),
+                    rows: OffsetRows::None,
+                }),
+                exprs: vec![
+                    Expr::Identifier(Ident::new("x")),
+                    Expr::Identifier(Ident::new("y")),
+                ],
+            }),
+        }),
+        query.limit_clause
+    );
+
+    // An OFFSET after the BY list is the query-level OFFSET
+    let query = clickhouse_and_generic()
+        .verified_query("SELECT * FROM events LIMIT 1 OFFSET 1 BY x OFFSET 2");
+    assert_eq!(
+        Some(LimitClause::LimitOffset {
+            limit: None,
+            offset: Some(Offset {
+                value: Expr::value(number("2")),
+                rows: OffsetRows::None,
+            }),
+            limit_by: Some(LimitBy {
+                limit: Expr::value(number("1")),
+                offset: Some(Offset {
+                    value: Expr::value(number("1")),
+                    rows: OffsetRows::None,
+                }),
+                exprs: vec![Expr::Identifier(Ident::new("x"))],
+            }),
+        }),
+        query.limit_clause
+    );
+    clickhouse_and_generic().verified_stmt("SELECT * FROM events LIMIT 1 BY x OFFSET 2");
+    clickhouse_and_generic().one_statement_parses_to(
+        "SELECT * FROM events LIMIT 1 BY x OFFSET 2 LIMIT 10",
+        "SELECT * FROM events LIMIT 1 BY x LIMIT 10 OFFSET 2",
+    );
+    clickhouse_and_generic()
+        .parse_sql_statements("SELECT * FROM events LIMIT 1 BY x OFFSET 2 LIMIT 10 OFFSET 3")
+        .expect_err("two query-level OFFSETs");
+
+    clickhouse_and_generic()
+        .parse_sql_statements("SELECT * FROM events LIMIT 1 BY x LIMIT 2 BY y")
+        .expect_err("only one LIMIT BY clause");
+    clickhouse_and_generic()
+        .parse_sql_statements("SELECT * FROM events LIMIT ALL BY x")
+        .expect_err("LIMIT ALL BY");
+}
+
 #[test]
 fn parse_settings_in_query() {
     fn check_settings(sql: &str, expected: Vec<Setting>) {
@@ -1127,6 +1198,16 @@ fn parse_select_star_except_no_parens() {
         "SELECT * EXCEPT prev_status FROM anomalies",
         "SELECT * EXCEPT (prev_status) FROM anomalies",
     );
+
+    assert_eq!(
+        clickhouse()
+            .parse_sql_statements("SELECT * EXCEPT s.prev_status FROM anomalies")
+            .unwrap_err(),
+        ParserError(
+            "EXCEPT expects unqualified column names, found qualified name starting with s"
+                .to_string()
+        )
+    );
 }
 
 #[test]
@@ -1199,7 +1280,7 @@ fn parse_select_order_by_with_fill_interpolate() {
         Some(LimitClause::LimitOffset {
             limit: Some(Expr::value(number("2"))),
             offset: None,
-            limit_by: vec![]
+            limit_by: None
         })
     );
 }
@@ -1400,6 +1481,40 @@ fn test_prewhere() {
     }
 }
 
+#[test]
+fn parse_table_final_modifier() {
+    let select = clickhouse()
+        .verified_only_select("SELECT * FROM t FINAL PREWHERE sign = 1 WHERE date > '2024-01-01'");
+    match &select.from[0].relation {
+        TableFactor::Table {
+            alias,
+            final_modifier,
+            ..
+        } => {
+            assert!(alias.is_none());
+            assert!(*final_modifier);
+        }
+        _ => unreachable!(),
+    }
+    assert!(select.prewhere.is_some());
+    assert!(select.selection.is_some());
+
+    clickhouse().verified_stmt("SELECT * FROM t AS x FINAL SAMPLE 0.1 PREWHERE a = 1 WHERE b = 2");
+    clickhouse().verified_stmt("SELECT * FROM t FINAL SAMPLE 1 / 10 OFFSET 1 / 2 PREWHERE a = 1");
+    clickhouse().verified_stmt("SELECT * FROM t FINAL JOIN u FINAL ON t.id = u.id");
+    // Without the modifier support, FINAL is an ordinary table alias
+    let select = TestedDialects::new(vec![Box::new(GenericDialect {})])
+        .verified_only_select("SELECT * FROM t FINAL");
+    match &select.from[0].relation {
+        TableFactor::Table {
+            alias: Some(alias),
+            final_modifier: false,
+            ..
+        } => assert_eq!(Ident::new("FINAL"), alias.name),
+        t => unreachable!("{t:?}"),
+    }
+}
+
 #[test]
 fn parse_use() {
     let valid_object_names = [
@@ -1685,13 +1800,15 @@ fn parse_explain_table() {
         Statement::ExplainTable {
             describe_alias,
             hive_format,
-            has_table_keyword,
+            object_type,
             table_name,
+            partition,
         } => {
             pretty_assertions::assert_eq!(describe_alias, DescribeAlias::Explain);
             pretty_assertions::assert_eq!(hive_format, None);
-            pretty_assertions::assert_eq!(has_table_keyword, true);
+            pretty_assertions::assert_eq!(object_type, Some(ObjectType::Table));
             pretty_assertions::assert_eq!("test_identifier", table_name.to_string());
+            pretty_assertions::assert_eq!(partition, None);
         }
         _ => panic!("Unexpected Statement, must be ExplainTable"),
     }
@@ -1739,3 +1856,404 @@ fn clickhouse_and_generic() -> TestedDialects {
         Box::new(GenericDialect {}),
     ])
 }
+
+#[test]
+fn parse_set_session_variables() {
+    match clickhouse().verified_stmt("SET allow_experimental_object_type = 1") {
+        Statement::Set(Set::SingleAssignment {
+            variable,
+            uses_to,
+            values,
+            ..
+        }) => {
+            assert_eq!(
+                variable,
+                ObjectName::from(vec![Ident::new("allow_experimental_object_type")])
+            );
+            assert!(!uses_to);
+            assert_eq!(values, vec![Expr::value(number("1"))]);
+        }
+        s => panic!("unexpected statement: {s:?}"),
+    }
+    clickhouse().verified_stmt("SET max_threads = 8");
+    clickhouse().verified_stmt("SET join_use_nulls = ON");
+}
+
+#[test]
+fn parse_with_fill_time_series() {
+    clickhouse().verified_stmt(
+        "SELECT ts, value FROM t ORDER BY ts WITH FILL FROM toDate('2024-01-01') TO toDate('2024-02-01') STEP INTERVAL 1 DAY INTERPOLATE (value AS previous_value)",
+    );
+    clickhouse().verified_stmt("SELECT ts FROM t ORDER BY ts WITH FILL");
+    match clickhouse().verified_query(
+        "SELECT ts, value FROM t ORDER BY ts WITH FILL STEP INTERVAL 1 DAY INTERPOLATE (value, other AS other + 1)",
+    ) {
+        Query {
+            order_by:
+                Some(OrderBy {
+                    interpolate: Some(Interpolate { exprs: Some(exprs) }),
+                    ..
+                }),
+            ..
+        } => {
+            assert_eq!(Ident::new("value"), exprs[0].column);
+            assert_eq!(None, exprs[0].expr);
+            assert_eq!(Ident::new("other"), exprs[1].column);
+            assert!(exprs[1].expr.is_some());
+        }
+        q => unreachable!("{q:?}"),
+    }
+}
+
+#[test]
+fn parse_join_strictness_and_global() {
+    clickhouse().verified_stmt("SELECT * FROM t1 ASOF JOIN t2 ON t1.id = t2.id AND t1.ts >= t2.ts");
+    clickhouse().verified_stmt("SELECT * FROM t1 ASOF LEFT JOIN t2 USING(id, ts)");
+    clickhouse().verified_stmt("SELECT * FROM t1 ALL INNER JOIN t2 USING(id)");
+    clickhouse().verified_stmt("SELECT * FROM t1 AS a ANY RIGHT JOIN t2 AS b ON a.id = b.id");
+    clickhouse().one_statement_parses_to(
+        "SELECT * FROM t1 LEFT ANY JOIN t2 ON t1.id = t2.id",
+        "SELECT * FROM t1 ANY LEFT JOIN t2 ON t1.id = t2.id",
+    );
+
+    let select = clickhouse()
+        .verified_only_select("SELECT * FROM t1 GLOBAL ANY LEFT JOIN t2 ON t1.id = t2.id");
+    let join = &select.from[0].joins[0];
+    assert!(join.global);
+    assert_eq!(Some(JoinStrictness::Any), join.strictness);
+    assert!(matches!(join.join_operator, JoinOperator::Left(_)));
+
+    clickhouse()
+        .parse_sql_statements("SELECT * FROM t1 ANY t2")
+        .expect_err("strictness without JOIN");
+}
+
+#[test]
+fn parse_global_in() {
+    match clickhouse().verified_expr("x GLOBAL IN (SELECT id FROM t)") {
+        Expr::InSubquery {
+            negated, global, ..
+        } => {
+            assert!(!negated);
+            assert!(global);
+        }
+        e => unreachable!("{e:?}"),
+    }
+    match clickhouse().verified_expr("x GLOBAL NOT IN (1, 2)") {
+        Expr::InList {
+            negated, global, ..
+        } => {
+            assert!(negated);
+            assert!(global);
+        }
+        e => unreachable!("{e:?}"),
+    }
+    clickhouse().verified_stmt(
+        "SELECT * FROM t WHERE a GLOBAL IN (SELECT a FROM d) AND b NOT IN (1) OR c GLOBAL NOT IN (2)",
+    );
+}
+
+#[test]
+fn parse_table_function_call_as_table() {
+    let select = clickhouse().verified_only_select("SELECT * FROM remote('host', db, tbl) AS r");
+    match &select.from[0].relation {
+        TableFactor::Table {
+            name,
+            args: Some(args),
+            alias: Some(alias),
+            ..
+        } => {
+            assert_eq!("remote", name.to_string());
+            assert_eq!(3, args.args.len());
+            assert_eq!(Ident::new("r"), alias.name);
+        }
+        r => unreachable!("{r:?}"),
+    }
+
+    clickhouse().verified_stmt("SELECT * FROM numbers(10)");
+    clickhouse().verified_stmt("SELECT * FROM s3('url', format => 'CSV')");
+    clickhouse()
+        .verified_stmt("SELECT * FROM file('a.csv', 'CSV', 'a UInt32') JOIN numbers(3) ON true");
+}
+
+#[test]
+fn parse_show_create_with_format() {
+    assert_eq!(
+        clickhouse().verified_stmt("SHOW CREATE TABLE db.t FORMAT TSVRaw"),
+        Statement::ShowCreate {
+            obj_type: ShowCreateObject::Table,
+            obj_name: ObjectName::from(vec![Ident::new("db"), Ident::new("t")]),
+            format: Some(FormatClause::Identifier(Ident::new("TSVRaw"))),
+        }
+    );
+    clickhouse().verified_stmt("SHOW CREATE VIEW v FORMAT TabSeparatedRaw");
+    clickhouse().verified_stmt("SHOW CREATE DATABASE db");
+}
+
+#[test]
+fn parse_alter_table_update_and_delete_mutations() {
+    match clickhouse_and_generic()
+        .verified_stmt("ALTER TABLE t UPDATE a = 1, b = b + 1 IN PARTITION 2024 WHERE id > 0")
+    {
+        Statement::AlterTable(AlterTable { operations, .. }) => {
+            assert_eq!(operations.len(), 1);
+            match &operations[0] {
+                AlterTableOperation::Update {
+                    assignments,
+                    in_partition,
+                    selection,
+                } => {
+                    assert_eq!(assignments.len(), 2);
+                    assert_eq!(assignments[1].to_string(), "b = b + 1");
+                    assert_eq!(
+                        in_partition,
+                        &Some(Partition::Expr(Expr::value(number("2024"))))
+                    );
+                    assert_eq!(selection.to_string(), "id > 0");
+                }
+                _ => unreachable!(),
+            }
+        }
+        _ => unreachable!(),
+    }
+
+    match clickhouse_and_generic().verified_stmt("ALTER TABLE t DELETE WHERE id = 1") {
+        Statement::AlterTable(AlterTable { operations, .. }) => {
+            assert_eq!(
+                operations,
+                vec![AlterTableOperation::Delete {
+                    in_partition: None,
+                    selection: BinaryOp {
+                        left: Box::new(Identifier(Ident::new("id"))),
+                        op: BinaryOperator::Eq,
+                        right: Box::new(Expr::value(number("1"))),
+                    },
+                }]
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    clickhouse_and_generic().verified_stmt("ALTER TABLE t UPDATE a = 1 WHERE 1");
+    clickhouse_and_generic()
+        .verified_stmt("ALTER TABLE t DELETE IN PARTITION ID '2024' WHERE id IN (1, 2)");
+    clickhouse_and_generic()
+        .verified_stmt("ALTER TABLE db.t ON CLUSTER c DELETE IN PARTITION 'p' WHERE x = 1");
+
+    assert_eq!(
+        clickhouse().parse_sql_statements("ALTER TABLE t DELETE IN PARTITION p"),
+        Err(ParserError("Expected: WHERE, found: EOF".to_string()))
+    );
+}
+
+#[test]
+fn parse_alter_table_modify_and_remove_ttl() {
+    match clickhouse_and_generic().verified_stmt("ALTER TABLE t MODIFY TTL d + INTERVAL 30 DAY") {
+        Statement::AlterTable(AlterTable { operations, .. }) => match &operations[0] {
+            AlterTableOperation::ModifyTtl { clauses } => {
+                assert_eq!(clauses.len(), 1);
+                assert_eq!(clauses[0].expr.to_string(), "d + INTERVAL 30 DAY");
+                assert_eq!(clauses[0].action, None);
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+
+    let sql = "ALTER TABLE t MODIFY TTL d + INTERVAL 1 MONTH DELETE, d + INTERVAL 1 WEEK TO DISK 'slow', d TO VOLUME 'cold'";
+    match clickhouse_and_generic().verified_stmt(sql) {
+        Statement::AlterTable(AlterTable { operations, .. }) => match &operations[0] {
+            AlterTableOperation::ModifyTtl { clauses } => {
+                assert_eq!(
+                    clauses.iter().map(|c| c.action.clone()).collect::<Vec<_>>(),
+                    vec![
+                        Some(TtlAction::Delete),
+                        Some(TtlAction::ToDisk("slow".to_string())),
+                        Some(TtlAction::ToVolume("cold".to_string())),
+                    ]
+                );
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+
+    match clickhouse_and_generic().verified_stmt("ALTER TABLE t REMOVE TTL") {
+        Statement::AlterTable(AlterTable { operations, .. }) => {
+            assert_eq!(operations, vec![AlterTableOperation::RemoveTtl]);
+        }
+        _ => unreachable!(),
+    }
+
+    clickhouse().verified_stmt("ALTER TABLE t MODIFY COLUMN ttl Int32");
+}
+
+#[test]
+fn parse_optimize_table_deduplicate_by_columns() {
+    match clickhouse_and_generic()
+        .verified_stmt("OPTIMIZE TABLE t ON CLUSTER c PARTITION ID 'x' FINAL DEDUPLICATE BY a, b")
+    {
+        Statement::OptimizeTable {
+            include_final,
+            deduplicate,
+            predicate,
+            zorder,
+            ..
+        } => {
+            assert!(include_final);
+            assert_eq!(
+                deduplicate,
+                Some(Deduplicate::ByExpressions(vec![
+                    Identifier(Ident::new("a")),
+                    Identifier(Ident::new("b")),
+                ]))
+            );
+            assert!(predicate.is_none());
+            assert!(zorder.is_none());
+        }
+        _ => unreachable!(),
+    }
+
+    // The Databricks form is not accepted by ClickHouse
+    assert_eq!(
+        clickhouse()
+            .parse_sql_statements("OPTIMIZE delta_t WHERE dt = '2024-01-01' ZORDER BY (c1, c2)")
+            .unwrap_err(),
+        ParserError("Expected: end of statement, found: WHERE".to_string())
+    );
+    assert_eq!(
+        clickhouse()
+            .parse_sql_statements("OPTIMIZE TABLE t ZORDER BY (c1)")
+            .unwrap_err(),
+        ParserError("Expected: end of statement, found: ZORDER".to_string())
+    );
+}
+
+#[test]
+fn parse_alter_table_modify_query() {
+    let sql = "ALTER TABLE mv MODIFY QUERY SELECT a, count() AS c FROM t GROUP BY a SETTINGS max_threads = 2";
+    match clickhouse_and_generic().verified_stmt(sql) {
+        Statement::AlterTable(AlterTable { operations, .. }) => match &operations[0] {
+            AlterTableOperation::ModifyQuery { query } => {
+                let select = query.body.as_select().unwrap();
+                assert_eq!(
+                    select.group_by,
+                    GroupByExpr::Expressions(vec![Identifier(Ident::new("a"))], vec![])
+                );
+                assert_eq!(
+                    query.settings,
+                    Some(vec![Setting {
+                        key: Ident::new("max_threads"),
+                        value: Expr::value(number("2")),
+                    }])
+                );
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+
+    clickhouse_and_generic()
+        .verified_stmt("ALTER TABLE db.mv ON CLUSTER c MODIFY QUERY SELECT * FROM t WHERE x > 1");
+}
+
+#[test]
+fn parse_alter_table_comment_column() {
+    assert_eq!(
+        alter_table_op(
+            clickhouse_and_generic().verified_stmt("ALTER TABLE tab COMMENT COLUMN c 'text'")
+        ),
+        AlterTableOperation::CommentColumn {
+            column: Ident::new("c"),
+            comment: "text".to_string(),
+            if_exists: false,
+        }
+    );
+    assert_eq!(
+        alter_table_op(
+            clickhouse_and_generic()
+                .verified_stmt("ALTER TABLE tab ON CLUSTER c COMMENT COLUMN IF EXISTS c 'text'")
+        ),
+        AlterTableOperation::CommentColumn {
+            column: Ident::new("c"),
+            comment: "text".to_string(),
+            if_exists: true,
+        }
+    );
+
+    // The MySQL table comment form is not a ClickHouse operation
+    assert!(clickhouse()
+        .parse_sql_statements("ALTER TABLE tab COMMENT 'text'")
+        .is_err());
+}
+
+#[test]
+fn parse_on_cluster_macro() {
+    match clickhouse_and_generic().verified_stmt("ALTER TABLE t ON CLUSTER {cluster} DROP COLUMN a")
+    {
+        Statement::AlterTable(AlterTable { on_cluster, .. }) => {
+            assert_eq!(
+                on_cluster,
+                Some(ObjectNamePart::Macro(ObjectNamePartMacro {
+                    segments: vec![MacroSegment::Macro(Ident::new("cluster"))],
+                }))
+            );
+        }
+        _ => unreachable!(),
+    }
+    clickhouse_and_generic().verified_stmt(
+        "CREATE TABLE t ON CLUSTER {cluster} (a Int32) ENGINE = MergeTree ORDER BY a",
+    );
+    clickhouse_and_generic().verified_stmt("TRUNCATE TABLE t ON CLUSTER cluster_{layer}");
+
+    // Macros may also appear in table names, alone or mixed with text
+    match clickhouse_and_generic()
+        .verified_stmt("CREATE TABLE db_{shard}.t_{layer}_local ON CLUSTER {cluster} (a Int32)")
+    {
+        Statement::CreateTable(CreateTable { name, .. }) => {
+            assert_eq!(
+                name,
+                ObjectName(vec![
+                    ObjectNamePart::Macro(ObjectNamePartMacro {
+                        segments: vec![
+                            MacroSegment::Text(Ident::new("db_")),
+                            MacroSegment::Macro(Ident::new("shard")),
+                        ],
+                    }),
+                    ObjectNamePart::Macro(ObjectNamePartMacro {
+                        segments: vec![
+                            MacroSegment::Text(Ident::new("t_")),
+                            MacroSegment::Macro(Ident::new("layer")),
+                            MacroSegment::Text(Ident::new("_local")),
+                        ],
+                    }),
+                ])
+            );
+        }
+        _ => unreachable!(),
+    }
+    clickhouse_and_generic().verified_stmt("SELECT * FROM {database}.t");
+    clickhouse_and_generic().verified_stmt("INSERT INTO db_{shard}.t VALUES (1)");
+
+    // Whitespace ends the name, and other dialects do not accept macros
+    clickhouse()
+        .parse_sql_statements("SELECT * FROM db_ {shard}.t")
+        .expect_err("macro separated from the name");
+    TestedDialects::new(vec![Box::new(sqlparser::dialect::PostgreSqlDialect {})])
+        .parse_sql_statements("ALTER TABLE t ON CLUSTER {cluster} DROP COLUMN a")
+        .expect_err("macros are ClickHouse specific");
+}
+
+#[test]
+fn parse_create_table_as_with_column_names() {
+    match clickhouse().verified_stmt("CREATE TABLE t (a, b) ENGINE = Memory AS SELECT 1, 2") {
+        Statement::CreateTable(CreateTable { columns, query, .. }) => {
+            assert_eq!(columns.len(), 2);
+            assert!(columns
+                .iter()
+                .all(|c| c.data_type == DataType::Unspecified && c.options.is_empty()));
+            assert!(query.is_some());
+        }
+        _ => unreachable!(),
+    }
+}
@@ -40,7 +40,7 @@ use sqlparser::dialect::{
 use sqlparser::keywords::{Keyword, ALL_KEYWORDS};
 use sqlparser::parser::{Parser, ParserError, ParserOptions};
 use sqlparser::tokenizer::Tokenizer;
-use sqlparser::tokenizer::{Location, Span};
+use sqlparser::tokenizer::{Location, Span, Token};
 use test_utils::{
     all_dialects, all_dialects_where, all_dialects_with_options, alter_table_op, assert_eq_vec,
     call, expr_from_projection, join, number, only, table, table_alias, table_from_name,
@@ -462,6 +462,7 @@ fn parse_update_set_from() {
         Statement::Update(Update {
             update_token: AttachedToken::empty(),
             optimizer_hints: vec![],
+            top: None,
             table: TableWithJoins {
                 relation: table_from_name(ObjectName::from(vec![Ident::new("t1")])),
                 joins: vec![],
@@ -518,6 +519,7 @@ fn parse_update_set_from() {
                         settings: None,
                         format_clause: None,
                         pipe_operators: vec![],
+                        options: vec![],
                     }),
                     alias: table_alias(true, "t2"),
                     sample: None,
@@ -539,7 +541,8 @@ fn parse_update_set_from() {
             output: None,
             or: None,
             order_by: vec![],
-            limit: None
+            limit: None,
+            options: vec![],
         })
     );
 
@@ -561,8 +564,10 @@ fn parse_update_with_table_alias() {
             order_by: _,
             limit: None,
             optimizer_hints,
+            top: None,
             update_token: _,
             output: _,
+            options: _,
         }) if optimizer_hints.is_empty() => {
             assert_eq!(
                 TableWithJoins {
@@ -577,6 +582,7 @@ fn parse_update_with_table_alias() {
                         json_path: None,
                         sample: None,
                         index_hints: vec![],
+                        final_modifier: false,
                     },
                     joins: vec![],
                 },
@@ -679,6 +685,7 @@ fn parse_select_with_table_alias() {
                 json_path: None,
                 sample: None,
                 index_hints: vec![],
+                final_modifier: false,
             },
             joins: vec![],
         }]
@@ -877,6 +884,7 @@ fn parse_where_delete_with_alias_statement() {
                     json_path: None,
                     sample: None,
                     index_hints: vec![],
+                    final_modifier: false,
                 },
                 from[0].relation,
             );
@@ -893,6 +901,7 @@ fn parse_where_delete_with_alias_statement() {
                         json_path: None,
                         sample: None,
                         index_hints: vec![],
+                        final_modifier: false,
                     },
                     joins: vec![],
                 }]),
@@ -937,7 +946,7 @@ fn parse_simple_select() {
     let expected_limit_clause = LimitClause::LimitOffset {
         limit: Some(Expr::value(number("5"))),
         offset: None,
-        limit_by: vec![],
+        limit_by: None,
     };
     assert_eq!(Some(expected_limit_clause), select.limit_clause);
 }
@@ -961,7 +970,7 @@ fn parse_limit_is_not_an_alias() {
     let expected_limit_clause = LimitClause::LimitOffset {
         limit: Some(Expr::value(number("1"))),
         offset: None,
-        limit_by: vec![],
+        limit_by: None,
     };
     assert_eq!(Some(expected_limit_clause), ast.limit_clause);
 
@@ -969,7 +978,7 @@ fn parse_limit_is_not_an_alias() {
     let expected_limit_clause = LimitClause::LimitOffset {
         limit: Some(Expr::value(number("5"))),
         offset: None,
-        limit_by: vec![],
+        limit_by: None,
     };
     assert_eq!(Some(expected_limit_clause), ast.limit_clause);
 }
@@ -2041,6 +2050,7 @@ fn parse_not_precedence() {
                     (Value::SingleQuotedString("a".into())).with_empty_span()
                 )],
                 negated: true,
+                global: false,
             }),
         },
     );
@@ -2310,6 +2320,7 @@ fn parse_in_list() {
                     Expr::Value((Value::SingleQuotedString("MED".to_string())).with_empty_span()),
                 ],
                 negated,
+                global: false,
             },
             select.selection.unwrap()
         );
@@ -2327,6 +2338,7 @@ fn parse_in_subquery() {
             expr: Box::new(Expr::Identifier(Ident::new("segment"))),
             subquery: Box::new(verified_query("SELECT segm FROM bar")),
             negated: false,
+            global: false,
         },
         select.selection.unwrap()
     );
@@ -2343,6 +2355,7 @@ fn parse_in_union() {
                 "(SELECT segm FROM bar) UNION (SELECT segm FROM bar2)"
             )),
             negated: false,
+            global: false,
         },
         select.selection.unwrap()
     );
@@ -2656,7 +2669,7 @@ fn parse_select_order_by_limit() {
     let expected_limit_clause = LimitClause::LimitOffset {
         limit: Some(Expr::value(number("2"))),
         offset: None,
-        limit_by: vec![],
+        limit_by: None,
     };
     assert_eq!(Some(expected_limit_clause), select.limit_clause);
 }
@@ -2822,7 +2835,7 @@ fn parse_select_order_by_nulls_order() {
     let expected_limit_clause = LimitClause::LimitOffset {
         limit: Some(Expr::value(number("2"))),
         offset: None,
-        limit_by: vec![],
+        limit_by: None,
     };
     assert_eq!(Some(expected_limit_clause), select.limit_clause);
 }
@@ -3087,6 +3100,7 @@ fn parse_cast() {
             data_type: DataType::BigInt(None),
             array: false,
             format: None,
+            charset: None,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -3100,6 +3114,7 @@ fn parse_cast() {
             data_type: DataType::TinyInt(None),
             array: false,
             format: None,
+            charset: None,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -3132,6 +3147,7 @@ fn parse_cast() {
             })),
             array: false,
             format: None,
+            charset: None,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -3145,6 +3161,7 @@ fn parse_cast() {
             data_type: DataType::Clob(None),
             array: false,
             format: None,
+            charset: None,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -3158,6 +3175,7 @@ fn parse_cast() {
             data_type: DataType::Clob(Some(50)),
             array: false,
             format: None,
+            charset: None,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -3171,6 +3189,7 @@ fn parse_cast() {
             data_type: DataType::Binary(Some(50)),
             array: false,
             format: None,
+            charset: None,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -3184,6 +3203,7 @@ fn parse_cast() {
             data_type: DataType::Varbinary(Some(BinaryLength::IntegerLength { length: 50 })),
             array: false,
             format: None,
+            charset: None,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -3197,6 +3217,7 @@ fn parse_cast() {
             data_type: DataType::Blob(None),
             array: false,
             format: None,
+            charset: None,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -3210,6 +3231,7 @@ fn parse_cast() {
             data_type: DataType::Blob(Some(50)),
             array: false,
             format: None,
+            charset: None,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -3223,6 +3245,7 @@ fn parse_cast() {
             data_type: DataType::JSONB,
             array: false,
             format: None,
+            charset: None,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -3239,6 +3262,7 @@ fn parse_try_cast() {
             data_type: DataType::BigInt(None),
             array: false,
             format: None,
+            charset: None,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -3905,7 +3929,9 @@ fn parse_create_table() {
                                 option: ColumnOption::Check(CheckConstraint {
                                     name: None,
                                     expr: Box::new(verified_expr("constrained > 0")),
+                                    no_inherit: false,
                                     enforced: None,
+                                    not_for_replication: false,
                                 }),
                             },
                         ],
@@ -3982,7 +4008,7 @@ fn parse_create_table() {
                         foreign_table: ObjectName::from(vec!["othertable4".into()]),
                         referred_columns: vec!["lat".into()],
                         on_delete: Some(ReferentialAction::Cascade),
-                        on_update: Some(ReferentialAction::SetDefault),
+                        on_update: Some(ReferentialAction::SetDefault(vec![])),
                         match_kind: None,
                         characteristics: None,
                     }
@@ -3994,7 +4020,7 @@ fn parse_create_table() {
                         foreign_table: ObjectName::from(vec!["othertable4".into()]),
                         referred_columns: vec!["longitude".into()],
                         on_delete: None,
-                        on_update: Some(ReferentialAction::SetNull),
+                        on_update: Some(ReferentialAction::SetNull(vec![])),
                         match_kind: None,
                         characteristics: None,
                     }
@@ -4098,7 +4124,8 @@ fn parse_create_table_with_constraint_characteristics() {
                         characteristics: Some(ConstraintCharacteristics {
                             deferrable: Some(true),
                             initially: Some(DeferrableInitial::Deferred),
-                            enforced: None
+                            enforced: None,
+                            not_for_replication: false,
                         }),
                     }
                     .into(),
@@ -4115,6 +4142,7 @@ fn parse_create_table_with_constraint_characteristics() {
                             deferrable: Some(true),
                             initially: Some(DeferrableInitial::Immediate),
                             enforced: None,
+                            not_for_replication: false,
                         }),
                     }
                     .into(),
@@ -4125,12 +4153,13 @@ fn parse_create_table_with_constraint_characteristics() {
                         foreign_table: ObjectName::from(vec!["othertable4".into()]),
                         referred_columns: vec!["lat".into()],
                         on_delete: Some(ReferentialAction::Cascade),
-                        on_update: Some(ReferentialAction::SetDefault),
+                        on_update: Some(ReferentialAction::SetDefault(vec![])),
                         match_kind: None,
                         characteristics: Some(ConstraintCharacteristics {
                             deferrable: Some(false),
                             initially: Some(DeferrableInitial::Deferred),
                             enforced: Some(false),
+                            not_for_replication: false,
                         }),
                     }
                     .into(),
@@ -4141,12 +4170,13 @@ fn parse_create_table_with_constraint_characteristics() {
                         foreign_table: ObjectName::from(vec!["othertable4".into()]),
                         referred_columns: vec!["longitude".into()],
                         on_delete: None,
-                        on_update: Some(ReferentialAction::SetNull),
+                        on_update: Some(ReferentialAction::SetNull(vec![])),
                         match_kind: None,
                         characteristics: Some(ConstraintCharacteristics {
                             deferrable: Some(false),
                             initially: Some(DeferrableInitial::Immediate),
                             enforced: Some(true),
+                            not_for_replication: false,
                         }),
                     }
                     .into(),
@@ -4213,6 +4243,7 @@ fn parse_create_table_column_constraint_characteristics() {
                 deferrable,
                 initially,
                 enforced,
+                not_for_replication: false,
             })
         } else {
             None
@@ -4397,6 +4428,74 @@ fn parse_create_table_with_multiple_on_delete_fails() {
     .expect_err("should have failed");
 }
 
+#[test]
+fn parse_create_table_with_multiple_named_column_constraints() {
+    let sql = "CREATE TABLE products (\
+        price NUMERIC \
+        CONSTRAINT positive_price CHECK (price > 0) \
+        NOT NULL \
+        CONSTRAINT not_too_big CHECK (price < 1000000) \
+        CHECK (price <> 13) \
+        CONSTRAINT price_unique UNIQUE\
+        )";
+    let columns = match verified_stmt(sql) {
+        Statement::CreateTable(CreateTable { columns, .. }) => columns,
+        _ => unreachable!(),
+    };
+    let check = |expr: &str| {
+        ColumnOption::Check(CheckConstraint {
+            name: None,
+            expr: Box::new(verified_expr(expr)),
+            no_inherit: false,
+            enforced: None,
+            not_for_replication: false,
+        })
+    };
+    assert_eq!(
+        columns[0].options,
+        vec![
+            ColumnOptionDef {
+                name: Some("positive_price".into()),
+                option: check("price > 0"),
+            },
+            ColumnOptionDef {
+                name: None,
+                option: ColumnOption::NotNull,
+            },
+            ColumnOptionDef {
+                name: Some("not_too_big".into()),
+                option: check("price < 1000000"),
+            },
+            ColumnOptionDef {
+                name: None,
+                option: check("price <> 13"),
+            },
+            ColumnOptionDef {
+                name: Some("price_unique".into()),
+                option: ColumnOption::Unique(UniqueConstraint {
+                    name: None,
+                    index_name: None,
+                    index_type_display: KeyOrIndexDisplay::None,
+                    index_type: None,
+                    columns: vec![],
+                    index_options: vec![],
+                    characteristics: None,
+                    nulls_distinct: NullsDistinctOption::None,
+                }),
+            },
+        ]
+    );
+
+    // A constraint name must be followed by the constraint it names
+    assert_eq!(
+        parse_sql_statements("CREATE TABLE t (a INT CONSTRAINT c1 CONSTRAINT c2 NOT NULL)")
+            .unwrap_err(),
+        ParserError::ParserError(
+            "Expected: constraint details after CONSTRAINT <name>, found: CONSTRAINT".to_string()
+        )
+    );
+}
+
 #[test]
 fn parse_assert() {
     let sql = "ASSERT (SELECT COUNT(*) FROM my_table) > 0";
@@ -4541,6 +4640,7 @@ fn parse_create_table_as_table() {
         settings: None,
         format_clause: None,
         pipe_operators: vec![],
+        options: vec![],
     });
 
     match verified_stmt(sql1) {
@@ -4567,6 +4667,7 @@ fn parse_create_table_as_table() {
         settings: None,
         format_clause: None,
         pipe_operators: vec![],
+        options: vec![],
     });
 
     match verified_stmt(sql2) {
@@ -5008,7 +5109,12 @@ fn test_alter_table_with_on_cluster() {
             name, on_cluster, ..
         }) => {
             assert_eq!(name.to_string(), "t");
-            assert_eq!(on_cluster, Some(Ident::with_quote('\'', "cluster")));
+            assert_eq!(
+                on_cluster,
+                Some(ObjectNamePart::Identifier(Ident::with_quote(
+                    '\'', "cluster"
+                )))
+            );
         }
         _ => unreachable!(),
     }
@@ -5020,7 +5126,10 @@ fn test_alter_table_with_on_cluster() {
             name, on_cluster, ..
         }) => {
             assert_eq!(name.to_string(), "t");
-            assert_eq!(on_cluster, Some(Ident::new("cluster_name")));
+            assert_eq!(
+                on_cluster,
+                Some(ObjectNamePart::Identifier(Ident::new("cluster_name")))
+            );
         }
         _ => unreachable!(),
     }
@@ -5053,10 +5162,14 @@ fn parse_alter_view() {
     let sql = "ALTER VIEW myschema.myview AS SELECT foo FROM bar";
     match verified_stmt(sql) {
         Statement::AlterView {
+            if_exists: false,
             name,
-            columns,
-            query,
-            with_options,
+            operation:
+                AlterViewOperation::As {
+                    columns,
+                    query,
+                    with_options,
+                },
         } => {
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<Ident>::new(), columns);
@@ -5071,7 +5184,10 @@ fn parse_alter_view() {
 fn parse_alter_view_with_options() {
     let sql = "ALTER VIEW v WITH (foo = 'bar', a = 123) AS SELECT 1";
     match verified_stmt(sql) {
-        Statement::AlterView { with_options, .. } => {
+        Statement::AlterView {
+            operation: AlterViewOperation::As { with_options, .. },
+            ..
+        } => {
             assert_eq!(
                 vec![
                     SqlOption::KeyValue {
@@ -5097,10 +5213,14 @@ fn parse_alter_view_with_columns() {
     let sql = "ALTER VIEW v (has, cols) AS SELECT 1, 2";
     match verified_stmt(sql) {
         Statement::AlterView {
+            if_exists: false,
             name,
-            columns,
-            query,
-            with_options,
+            operation:
+                AlterViewOperation::As {
+                    columns,
+                    query,
+                    with_options,
+                },
         } => {
             assert_eq!("v", name.to_string());
             assert_eq!(columns, vec![Ident::new("has"), Ident::new("cols")]);
@@ -5403,26 +5523,28 @@ fn run_explain_analyze(
 #[test]
 fn parse_explain_table() {
     let validate_explain =
-        |query: &str, expected_describe_alias: DescribeAlias, expected_table_keyword| {
+        |query: &str, expected_describe_alias: DescribeAlias, expected_object_type| {
             match verified_stmt(query) {
                 Statement::ExplainTable {
                     describe_alias,
                     hive_format,
-                    has_table_keyword,
+                    object_type,
                     table_name,
+                    partition,
                 } => {
                     assert_eq!(describe_alias, expected_describe_alias);
                     assert_eq!(hive_format, None);
-                    assert_eq!(has_table_keyword, expected_table_keyword);
+                    assert_eq!(object_type, expected_object_type);
                     assert_eq!("test_identifier", table_name.to_string());
+                    assert_eq!(partition, None);
                 }
                 _ => panic!("Unexpected Statement, must be ExplainTable"),
             }
         };
 
-    validate_explain("EXPLAIN test_identifier", DescribeAlias::Explain, false);
-    validate_explain("DESCRIBE test_identifier", DescribeAlias::Describe, false);
-    validate_explain("DESC test_identifier", DescribeAlias::Desc, false);
+    validate_explain("EXPLAIN test_identifier", DescribeAlias::Explain, None);
+    validate_explain("DESCRIBE test_identifier", DescribeAlias::Describe, None);
+    validate_explain("DESC test_identifier", DescribeAlias::Desc, None);
 }
 
 #[test]
@@ -6323,11 +6445,19 @@ fn parse_interval_all() {
         expr_from_projection(only(&select.projection)),
     );
 
-    let result = parse_sql_statements("SELECT INTERVAL '1' SECOND TO SECOND");
+    // PostgreSQL does not accept `TO` as a bare column alias
+    let result = all_dialects_where(|d| !d.is::<PostgreSqlDialect>())
+        .parse_sql_statements("SELECT INTERVAL '1' SECOND TO SECOND");
     assert_eq!(
         ParserError::ParserError("Expected: end of statement, found: SECOND".to_string()),
         result.unwrap_err(),
     );
+    let result = TestedDialects::new(vec![Box::new(PostgreSqlDialect {})])
+        .parse_sql_statements("SELECT INTERVAL '1' SECOND TO SECOND");
+    assert_eq!(
+        ParserError::ParserError("Expected: end of statement, found: TO".to_string()),
+        result.unwrap_err(),
+    );
 
     let result = parse_sql_statements("SELECT INTERVAL '10' HOUR (1) TO HOUR (2)");
     assert_eq!(
@@ -6582,6 +6712,7 @@ fn interval_disallow_interval_expr_double_colon() {
             data_type: DataType::Text,
             array: false,
             format: None,
+            charset: None,
         }
     )
 }
@@ -6694,6 +6825,7 @@ fn parse_interval_and_or_xor() {
         settings: None,
         format_clause: None,
         pipe_operators: vec![],
+        options: vec![],
     }))];
 
     assert_eq!(actual_ast, expected_ast);
@@ -7308,6 +7440,7 @@ fn parse_implicit_join() {
                 joins: vec![Join {
                     relation: table_from_name(ObjectName::from(vec!["t1b".into()])),
                     global: false,
+                    strictness: None,
                     join_operator: JoinOperator::Join(JoinConstraint::Natural),
                 }],
             },
@@ -7316,6 +7449,7 @@ fn parse_implicit_join() {
                 joins: vec![Join {
                     relation: table_from_name(ObjectName::from(vec!["t2b".into()])),
                     global: false,
+                    strictness: None,
                     join_operator: JoinOperator::Join(JoinConstraint::Natural),
                 }],
             },
@@ -7332,6 +7466,7 @@ fn parse_cross_join() {
         Join {
             relation: table_from_name(ObjectName::from(vec![Ident::new("t2")])),
             global: false,
+            strictness: None,
             join_operator: JoinOperator::CrossJoin(JoinConstraint::None),
         },
         only(only(select.from).joins),
@@ -7344,6 +7479,7 @@ fn parse_cross_join_constraint() {
         Join {
             relation: table_from_name(ObjectName::from(vec![Ident::new("t2")])),
             global: false,
+            strictness: None,
             join_operator: JoinOperator::CrossJoin(constraint),
         }
     }
@@ -7391,8 +7527,10 @@ fn parse_joins_on() {
                 json_path: None,
                 sample: None,
                 index_hints: vec![],
+                final_modifier: false,
             },
             global,
+            strictness: None,
             join_operator: f(JoinConstraint::On(Expr::BinaryOp {
                 left: Box::new(Expr::Identifier("c1".into())),
                 op: BinaryOperator::Eq,
@@ -7533,8 +7671,10 @@ fn parse_joins_using() {
                 json_path: None,
                 sample: None,
                 index_hints: vec![],
+                final_modifier: false,
             },
             global: false,
+            strictness: None,
             join_operator: f(JoinConstraint::Using(vec![ObjectName::from(vec![
                 "c1".into()
             ])])),
@@ -7628,8 +7768,10 @@ fn parse_natural_join() {
                 json_path: None,
                 sample: None,
                 index_hints: vec![],
+                final_modifier: false,
             },
             global: false,
+            strictness: None,
             join_operator: f(JoinConstraint::Natural),
         }
     }
@@ -7935,6 +8077,7 @@ fn parse_derived_tables() {
                 joins: vec![Join {
                     relation: table_from_name(ObjectName::from(vec!["t2".into()])),
                     global: false,
+                    strictness: None,
                     join_operator: JoinOperator::Join(JoinConstraint::Natural),
                 }],
             }),
@@ -8335,11 +8478,13 @@ fn parse_create_view() {
             cluster_by,
             comment,
             with_no_schema_binding: late_binding,
+            with_check_option: None,
             if_not_exists,
             temporary,
             to,
             params,
             name_before_not_exists: _,
+            with_data: _,
             secure: _,
             copy_grants: _,
         }) => {
@@ -8454,11 +8599,13 @@ fn parse_create_view_temporary() {
             cluster_by,
             comment,
             with_no_schema_binding: late_binding,
+            with_check_option: None,
             if_not_exists,
             temporary,
             to,
             params,
             name_before_not_exists: _,
+            with_data: _,
             secure: _,
             copy_grants: _,
         }) => {
@@ -8496,11 +8643,13 @@ fn parse_create_or_replace_view() {
             cluster_by,
             comment,
             with_no_schema_binding: late_binding,
+            with_check_option: None,
             if_not_exists,
             temporary,
             to,
             params,
             name_before_not_exists: _,
+            with_data: _,
             secure: _,
             copy_grants: _,
         }) => {
@@ -8542,11 +8691,13 @@ fn parse_create_or_replace_materialized_view() {
             cluster_by,
             comment,
             with_no_schema_binding: late_binding,
+            with_check_option: None,
             if_not_exists,
             temporary,
             to,
             params,
             name_before_not_exists: _,
+            with_data: _,
             secure: _,
             copy_grants: _,
         }) => {
@@ -8584,11 +8735,13 @@ fn parse_create_materialized_view() {
             cluster_by,
             comment,
             with_no_schema_binding: late_binding,
+            with_check_option: None,
             if_not_exists,
             temporary,
             to,
             params,
             name_before_not_exists: _,
+            with_data: _,
             secure: _,
             copy_grants: _,
         }) => {
@@ -8626,11 +8779,13 @@ fn parse_create_materialized_view_with_cluster_by() {
             cluster_by,
             comment,
             with_no_schema_binding: late_binding,
+            with_check_option: None,
             if_not_exists,
             temporary,
             to,
             params,
             name_before_not_exists: _,
+            with_data: _,
             secure: _,
             copy_grants: _,
         }) => {
@@ -8774,7 +8929,7 @@ fn parse_offset() {
             value: Expr::value(number("2")),
             rows: OffsetRows::Rows,
         }),
-        limit_by: vec![],
+        limit_by: None,
     });
     let ast = dialects.verified_query("SELECT foo FROM bar OFFSET 2 ROWS");
     assert_eq!(&ast.limit_clause, expected_limit_clause);
@@ -8803,7 +8958,7 @@ fn parse_offset() {
             value: Expr::value(number("0")),
             rows: OffsetRows::Rows,
         }),
-        limit_by: vec![],
+        limit_by: None,
     };
     let ast = dialects.verified_query("SELECT 'foo' OFFSET 0 ROWS");
     assert_eq!(ast.limit_clause, Some(expected_limit_clause));
@@ -8813,7 +8968,7 @@ fn parse_offset() {
             value: Expr::value(number("1")),
             rows: OffsetRows::Row,
         }),
-        limit_by: vec![],
+        limit_by: None,
     };
     let ast = dialects.verified_query("SELECT 'foo' OFFSET 1 ROW");
     assert_eq!(ast.limit_clause, Some(expected_limit_clause));
@@ -8823,7 +8978,7 @@ fn parse_offset() {
             value: Expr::value(number("2")),
             rows: OffsetRows::None,
         }),
-        limit_by: vec![],
+        limit_by: None,
     };
     let ast = dialects.verified_query("SELECT 'foo' OFFSET 2");
     assert_eq!(ast.limit_clause, Some(expected_limit_clause));
@@ -8882,7 +9037,7 @@ fn parse_fetch() {
             value: Expr::value(number("2")),
             rows: OffsetRows::Rows,
         }),
-        limit_by: vec![],
+        limit_by: None,
     });
     assert_eq!(ast.limit_clause, expected_limit_clause);
     assert_eq!(ast.fetch, fetch_first_two_rows_only);
@@ -8906,7 +9061,7 @@ fn parse_fetch() {
             value: Expr::value(number("2")),
             rows: OffsetRows::Rows,
         }),
-        limit_by: vec![],
+        limit_by: None,
     });
     assert_eq!(&ast.limit_clause, expected_limit_clause);
     assert_eq!(ast.fetch, fetch_first_two_rows_only);
@@ -9037,6 +9192,7 @@ fn lateral_function() {
                     alias: None,
                 },
                 global: false,
+                strictness: None,
                 join_operator: JoinOperator::Left(JoinConstraint::None),
             }],
         }],
@@ -9191,10 +9347,12 @@ fn parse_set_variable() {
             scope,
             hivevar,
             variable,
+            uses_to,
             values,
         }) => {
             assert_eq!(scope, None);
             assert!(!hivevar);
+            assert!(!uses_to);
             assert_eq!(variable, ObjectName::from(vec!["SOMETHING".into()]));
             assert_eq!(
                 values,
@@ -9211,10 +9369,12 @@ fn parse_set_variable() {
             scope,
             hivevar,
             variable,
+            uses_to,
             values,
         }) => {
             assert_eq!(scope, Some(ContextModifier::Global));
             assert!(!hivevar);
+            assert!(!uses_to);
             assert_eq!(variable, ObjectName::from(vec!["VARIABLE".into()]));
             assert_eq!(
                 values,
@@ -9293,7 +9453,7 @@ fn parse_set_variable() {
         );
     }
 
-    one_statement_parses_to("SET SOMETHING TO '1'", "SET SOMETHING = '1'");
+    verified_stmt("SET SOMETHING TO '1'");
 }
 
 #[test]
@@ -9303,10 +9463,12 @@ fn parse_set_role_as_variable() {
             scope,
             hivevar,
             variable,
+            uses_to,
             values,
         }) => {
             assert_eq!(scope, None);
             assert!(!hivevar);
+            assert!(!uses_to);
             assert_eq!(variable, ObjectName::from(vec!["role".into()]));
             assert_eq!(
                 values,
@@ -9334,7 +9496,8 @@ fn parse_double_colon_cast_at_timezone() {
                 )),
                 data_type: DataType::Timestamp(None, TimezoneInfo::None),
                 array: false,
-                format: None
+                format: None,
+                charset: None,
             }),
             time_zone: Box::new(Expr::Value(
                 (Value::SingleQuotedString("Europe/Brussels".to_string())).with_empty_span()
@@ -9351,10 +9514,12 @@ fn parse_set_time_zone() {
             scope,
             hivevar,
             variable,
+            uses_to,
             values,
         }) => {
             assert_eq!(scope, None);
             assert!(!hivevar);
+            assert!(!uses_to);
             assert_eq!(variable, ObjectName::from(vec!["TIMEZONE".into()]));
             assert_eq!(
                 values,
@@ -9366,7 +9531,7 @@ fn parse_set_time_zone() {
         _ => unreachable!(),
     }
 
-    one_statement_parses_to("SET TIME ZONE TO 'UTC'", "SET TIMEZONE = 'UTC'");
+    one_statement_parses_to("SET TIME ZONE TO 'UTC'", "SET TIMEZONE TO 'UTC'");
 }
 
 #[test]
@@ -9999,6 +10164,7 @@ fn parse_merge() {
                     json_path: None,
                     sample: None,
                     index_hints: vec![],
+                    final_modifier: false,
                 }
             );
             assert_eq!(table, table_no_into);
@@ -10051,6 +10217,7 @@ fn parse_merge() {
                         settings: None,
                         format_clause: None,
                         pipe_operators: vec![],
+                        options: vec![],
                     }),
                     alias: table_alias(true, "stg"),
                     sample: None,
@@ -10465,7 +10632,7 @@ fn test_placeholder() {
             value: Expr::Value((Value::Placeholder("$2".into())).with_empty_span()),
             rows: OffsetRows::None,
         }),
-        limit_by: vec![],
+        limit_by: None,
     };
     assert_eq!(ast.limit_clause, Some(expected_limit_clause));
 
@@ -10553,7 +10720,7 @@ fn parse_offset_and_limit() {
             value: Expr::value(number("2")),
             rows: OffsetRows::None,
         }),
-        limit_by: vec![],
+        limit_by: None,
     });
     let ast = verified_query(sql);
     assert_eq!(ast.limit_clause, expected_limit_clause);
@@ -10582,7 +10749,7 @@ fn parse_offset_and_limit() {
             },
             rows: OffsetRows::None,
         }),
-        limit_by: vec![],
+        limit_by: None,
     };
     assert_eq!(ast.limit_clause, Some(expected_limit_clause),);
 
@@ -11311,6 +11478,7 @@ fn parse_pivot_table() {
                 json_path: None,
                 sample: None,
                 index_hints: vec![],
+                final_modifier: false,
             }),
             aggregate_functions: vec![
                 expected_function("a", None),
@@ -11391,6 +11559,7 @@ fn parse_pivot_table() {
                 json_path: None,
                 sample: None,
                 index_hints: vec![],
+                final_modifier: false,
             }),
             aggregate_functions: vec![
                 ExprWithAlias {
@@ -11470,6 +11639,7 @@ fn parse_unpivot_table() {
             json_path: None,
             sample: None,
             index_hints: vec![],
+            final_modifier: false,
         }),
         null_inclusion: None,
         value: Expr::Identifier(Ident::new("quantity")),
@@ -11726,6 +11896,7 @@ fn parse_select_table_with_index_hints() {
                 json_path: None,
                 sample: None,
                 index_hints: vec![],
+                final_modifier: false,
             },
             joins: vec![],
         }]
@@ -11755,6 +11926,7 @@ fn parse_pivot_unpivot_table() {
                     json_path: None,
                     sample: None,
                     index_hints: vec![],
+                    final_modifier: false,
                 }),
                 null_inclusion: None,
                 value: Expr::Identifier(Ident::new("population")),
@@ -11837,6 +12009,63 @@ fn parse_non_latin_identifiers() {
         .is_err());
 }
 
+#[test]
+fn parse_standalone_object_name_and_ident() {
+    let expected = ObjectName::from(vec![
+        Ident::with_quote('"', "db"),
+        Ident::with_quote('"', "my.schema"),
+        Ident::with_quote('"', "table"),
+    ]);
+    assert_eq!(
+        ObjectName::parse(&PostgreSqlDialect {}, r#""db"."my.schema"."table""#).unwrap(),
+        expected
+    );
+
+    let expected = ObjectName::from(vec![
+        Ident::with_quote('[', "db"),
+        Ident::with_quote('[', "t.1"),
+    ]);
+    assert_eq!(
+        ObjectName::parse(&MsSqlDialect {}, "[db].[t.1]").unwrap(),
+        expected
+    );
+
+    let expected = ObjectName::from(vec![Ident::with_quote('`', "db"), Ident::new("t")]);
+    assert_eq!(
+        ObjectName::parse(&MySqlDialect {}, "`db`.t").unwrap(),
+        expected
+    );
+
+    // Reserved keywords are accepted as name parts
+    assert_eq!(
+        ObjectName::parse(&GenericDialect {}, "public.select").unwrap(),
+        ObjectName::from(vec![Ident::new("public"), Ident::new("select")])
+    );
+
+    // Quoting is dialect specific
+    assert!(ObjectName::parse(&PostgreSqlDialect {}, "`db`.t").is_err());
+
+    assert_eq!(
+        ObjectName::parse(&GenericDialect {}, "db.t extra").unwrap_err(),
+        ParserError::ParserError("Expected: EOF, found: extra at Line: 1, Column: 6".to_string())
+    );
+    assert!(ObjectName::parse(&GenericDialect {}, "").is_err());
+
+    assert_eq!(
+        Ident::parse(&MsSqlDialect {}, "[my table]").unwrap(),
+        Ident::with_quote('[', "my table")
+    );
+    assert_eq!(
+        Ident::parse(&GenericDialect {}, "select").unwrap(),
+        Ident::new("select")
+    );
+    assert_eq!(
+        Ident::parse(&GenericDialect {}, r#""a.b""#).unwrap(),
+        Ident::with_quote('"', "a.b")
+    );
+    assert!(Ident::parse(&GenericDialect {}, "a.b").is_err());
+}
+
 #[test]
 fn parse_trailing_comma() {
     // At the moment, DuckDB is the only dialect that allows
@@ -12473,6 +12702,7 @@ fn parse_unload() {
                 settings: None,
                 format_clause: None,
                 pipe_operators: vec![],
+                options: vec![],
             })),
             to: Ident {
                 value: "s3://...".to_string(),
@@ -13226,14 +13456,17 @@ fn test_match_recognize() {
             }],
             measures: vec![
                 Measure {
+                    semantics: None,
                     expr: call("MATCH_NUMBER", []),
                     alias: Ident::new("match_number"),
                 },
                 Measure {
+                    semantics: None,
                     expr: call("FIRST", [Expr::Identifier(Ident::new("price_date"))]),
                     alias: Ident::new("start_date"),
                 },
                 Measure {
+                    semantics: None,
                     expr: call("LAST", [Expr::Identifier(Ident::new("price_date"))]),
                     alias: Ident::new("end_date"),
                 },
@@ -13670,6 +13903,7 @@ fn test_dictionary_syntax() {
                     data_type: DataType::Timestamp(None, TimezoneInfo::None),
                     array: false,
                     format: None,
+                    charset: None,
                 }),
             },
             DictionaryField {
@@ -13682,6 +13916,7 @@ fn test_dictionary_syntax() {
                     data_type: DataType::Timestamp(None, TimezoneInfo::None),
                     array: false,
                     format: None,
+                    charset: None,
                 }),
             },
         ]),
@@ -13979,6 +14214,7 @@ fn test_extract_seconds_ok() {
                 },
                 array: false,
                 format: None,
+                charset: None,
             }),
         }
     );
@@ -14010,6 +14246,7 @@ fn test_extract_seconds_ok() {
                     },
                     array: false,
                     format: None,
+                    charset: None,
                 }),
             })],
             exclude: None,
@@ -14038,6 +14275,7 @@ fn test_extract_seconds_ok() {
         settings: None,
         format_clause: None,
         pipe_operators: vec![],
+        options: vec![],
     }))];
 
     assert_eq!(actual_ast, expected_ast);
@@ -14068,6 +14306,7 @@ fn test_extract_seconds_single_quote_ok() {
                 },
                 array: false,
                 format: None,
+                charset: None,
             }),
         }
     )
@@ -14089,7 +14328,10 @@ fn test_truncate_table_with_on_cluster() {
     let sql = "TRUNCATE TABLE t ON CLUSTER cluster_name";
     match all_dialects().verified_stmt(sql) {
         Statement::Truncate(truncate) => {
-            assert_eq!(truncate.on_cluster, Some(Ident::new("cluster_name")));
+            assert_eq!(
+                truncate.on_cluster,
+                Some(ObjectNamePart::Identifier(Ident::new("cluster_name")))
+            );
         }
         _ => panic!("Expected: TRUNCATE TABLE statement"),
     }
@@ -14852,14 +15094,32 @@ fn parse_unlisten_channel() {
 
     match dialects.verified_stmt("UNLISTEN test1") {
         Statement::UNLISTEN { channel } => {
-            assert_eq!(Ident::new("test1"), channel);
+            assert_eq!(
+                UnlistenChannel::Specific {
+                    name: Ident::new("test1")
+                },
+                channel
+            );
         }
         _ => unreachable!(),
     };
 
     match dialects.verified_stmt("UNLISTEN *") {
         Statement::UNLISTEN { channel } => {
-            assert_eq!(Ident::new("*"), channel);
+            assert_eq!(UnlistenChannel::All, channel);
+        }
+        _ => unreachable!(),
+    };
+
+    // a quoted `*` is a regular channel name
+    match dialects.verified_stmt(r#"UNLISTEN "*""#) {
+        Statement::UNLISTEN { channel } => {
+            assert_eq!(
+                UnlistenChannel::Specific {
+                    name: Ident::with_quote('"', "*")
+                },
+                channel
+            );
         }
         _ => unreachable!(),
     };
@@ -16177,13 +16437,9 @@ fn test_select_from_first() {
     let q1 = "FROM capitals";
     let q2 = "FROM capitals SELECT *";
 
-    for (q, flavor, projection) in [
-        (q1, SelectFlavor::FromFirstNoSelect, vec![]),
-        (
-            q2,
-            SelectFlavor::FromFirst,
-            vec![SelectItem::Wildcard(WildcardAdditionalOptions::default())],
-        ),
+    for (q, flavor) in [
+        (q1, SelectFlavor::FromFirstNoSelect),
+        (q2, SelectFlavor::FromFirst),
     ] {
         let ast = dialects.verified_query(q);
         let expected = Query {
@@ -16194,7 +16450,7 @@ fn test_select_from_first() {
                 distinct: None,
                 select_modifiers: None,
                 top: None,
-                projection,
+                projection: vec![SelectItem::Wildcard(WildcardAdditionalOptions::default())],
                 exclude: None,
                 top_before_distinct: false,
                 into: None,
@@ -16229,6 +16485,7 @@ fn test_select_from_first() {
             settings: None,
             format_clause: None,
             pipe_operators: vec![],
+            options: vec![],
         };
         assert_eq!(expected, ast);
     }
@@ -17629,6 +17886,7 @@ SELECT * FROM tbl2
         trailing_commas: false,
         unescape: true,
         require_semicolon_stmt_delimiter: false,
+        unparsed_statements: false,
     });
     let stmts = dialects.parse_sql_statements(sql).unwrap();
     assert_eq!(stmts.len(), 2);
@@ -18833,3 +19091,655 @@ fn parse_non_pg_dialects_keep_xml_names_as_regular_identifiers() {
     let dialects = all_dialects_except(|d| d.supports_xml_expressions());
     dialects.verified_only_select("SELECT xml FROM t");
 }
+
+#[test]
+fn parse_select_having_without_group_by() {
+    let select = all_dialects().verified_only_select("SELECT COUNT(*) FROM t HAVING COUNT(*) > 1");
+    assert_eq!(select.group_by, GroupByExpr::Expressions(vec![], vec![]));
+    assert!(select.having.is_some());
+
+    all_dialects().verified_stmt("SELECT SUM(a) FROM t WHERE b = 1 HAVING SUM(a) > 10 ORDER BY 1");
+}
+
+#[test]
+fn parse_select_misplaced_where() {
+    for (sql, clause) in [
+        ("SELECT a FROM t GROUP BY a WHERE a > 1", "GROUP BY"),
+        (
+            "SELECT a FROM t GROUP BY a HAVING COUNT(*) > 1 WHERE a > 1",
+            "HAVING",
+        ),
+    ] {
+        assert_eq!(
+            all_dialects().parse_sql_statements(sql).unwrap_err(),
+            ParserError::ParserError(format!("WHERE clause must appear before {clause}"))
+        );
+    }
+
+    assert_eq!(
+        Parser::parse_sql(&GenericDialect {}, "SELECT a FROM t GROUP BY a WHERE a > 1")
+            .unwrap_err(),
+        ParserError::ParserError(
+            "WHERE clause must appear before GROUP BY at Line: 1, Column: 28".to_string()
+        )
+    );
+
+    // QUALIFY before ORDER BY, with or without WHERE, is accepted everywhere
+    all_dialects().verified_stmt(
+        "SELECT a FROM t QUALIFY ROW_NUMBER() OVER (PARTITION BY a ORDER BY b) = 1 ORDER BY a",
+    );
+    all_dialects().verified_stmt(
+        "SELECT a FROM t WHERE b > 0 QUALIFY RANK() OVER (ORDER BY b) <= 3 ORDER BY a LIMIT 10",
+    );
+
+    // A WHERE belonging to the enclosing statement is not misplaced
+    pg_and_generic().verified_stmt(
+        "INSERT INTO t SELECT a, COUNT(*) FROM s GROUP BY a ON CONFLICT(a) DO UPDATE SET n = EXCLUDED.n WHERE t.n < EXCLUDED.n",
+    );
+}
+
+#[test]
+fn parse_default_expr_in_values_and_assignments() {
+    match verified_stmt("INSERT INTO t (a, b) VALUES (DEFAULT, 1), (2, DEFAULT)") {
+        Statement::Insert(Insert {
+            source: Some(source),
+            ..
+        }) => match *source.body {
+            SetExpr::Values(Values { rows, .. }) => {
+                assert_eq!(
+                    rows[0].content,
+                    vec![Expr::Default, Expr::value(number("1"))]
+                );
+                assert_eq!(
+                    rows[1].content,
+                    vec![Expr::value(number("2")), Expr::Default]
+                );
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+
+    match verified_stmt("UPDATE t SET a = DEFAULT, b = 1") {
+        Statement::Update(Update { assignments, .. }) => {
+            assert_eq!(
+                assignments[0],
+                Assignment {
+                    target: AssignmentTarget::ColumnName(ObjectName::from(vec![Ident::new("a")])),
+                    value: Expr::Default,
+                }
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    verified_stmt("UPDATE t SET (a, b) = (DEFAULT, 1)");
+
+    // a quoted `"DEFAULT"` is still an identifier
+    let dialects = all_dialects_where(|d| d.is_delimited_identifier_start('"'));
+    match dialects.verified_stmt(r#"UPDATE t SET a = "DEFAULT""#) {
+        Statement::Update(Update { assignments, .. }) => {
+            assert_eq!(
+                assignments[0].value,
+                Expr::Identifier(Ident::with_quote('"', "DEFAULT"))
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    match verified_stmt("CALL p(1, DEFAULT)") {
+        Statement::Call(Function {
+            args: FunctionArguments::List(FunctionArgumentList { args, .. }),
+            ..
+        }) => {
+            assert_eq!(
+                args[1],
+                FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Default))
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    match pg_and_generic().verified_stmt("CREATE TABLE t (a INT) WITH (fillfactor = DEFAULT)") {
+        Statement::CreateTable(CreateTable {
+            table_options: CreateTableOptions::With(options),
+            ..
+        }) => {
+            assert_eq!(
+                options,
+                vec![SqlOption::KeyValue {
+                    key: Ident::new("fillfactor"),
+                    value: Expr::Default,
+                }]
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // elsewhere, an unquoted `default` is still an identifier
+    let dialects = TestedDialects::new(vec![
+        Box::new(GenericDialect {}),
+        Box::new(ClickHouseDialect {}),
+    ]);
+    let select = dialects.verified_only_select("SELECT default FROM t");
+    assert_eq!(
+        expr_from_projection(only(&select.projection)),
+        &Expr::Identifier(Ident::new("default"))
+    );
+    dialects.verified_stmt("SELECT * FROM t WHERE a IN (SELECT default FROM u)");
+
+    // but DEFAULT is never a value in a WHERE clause
+    for sql in [
+        "SELECT * FROM t WHERE a = DEFAULT",
+        "UPDATE t SET a = 1 WHERE b = DEFAULT",
+        "DELETE FROM t WHERE DEFAULT IS NULL",
+    ] {
+        assert_eq!(
+            ParserError::ParserError("DEFAULT is not allowed in this context".to_string()),
+            all_dialects().parse_sql_statements(sql).unwrap_err(),
+            "{sql}"
+        );
+    }
+}
+
+#[test]
+fn parse_normalize_as_plain_function() {
+    let dialects = all_dialects_where(|d| !d.supports_normalize_function());
+    let select = dialects.verified_only_select("SELECT normalize(s)");
+    assert_eq!(
+        expr_from_projection(only(&select.projection)),
+        &call("normalize", [Expr::Identifier(Ident::new("s"))])
+    );
+    dialects.verified_stmt("SELECT normalize(s, 'NFC')");
+}
+
+#[test]
+fn parse_special_form_functions_preserve_spelling() {
+    match verified_expr("SUBSTRING(name FROM 2 FOR 3)") {
+        Expr::Substring {
+            substring_from,
+            substring_for,
+            special,
+            shorthand,
+            ..
+        } => {
+            assert!(!special);
+            assert!(!shorthand);
+            assert_eq!(substring_from, Some(Box::new(Expr::value(number("2")))));
+            assert_eq!(substring_for, Some(Box::new(Expr::value(number("3")))));
+        }
+        _ => unreachable!(),
+    }
+    match verified_expr("SUBSTRING(name, 2, 3)") {
+        Expr::Substring { special, .. } => assert!(special),
+        _ => unreachable!(),
+    }
+    // neither spelling is rewritten into the other
+    for sql in [
+        "SUBSTRING(name FROM 2)",
+        "SUBSTRING(name FOR 3)",
+        "SUBSTRING(name, 2)",
+        "SUBSTR(name, 2, 3)",
+        "POSITION('a' IN name)",
+        "OVERLAY(name PLACING 'x' FROM 2)",
+        "OVERLAY(name PLACING 'x' FROM 2 FOR 3)",
+    ] {
+        assert_eq!(verified_expr(sql).to_string(), sql);
+    }
+
+    match verified_expr("POSITION('a' IN name)") {
+        Expr::Position { expr, r#in } => {
+            assert_eq!(
+                *expr,
+                Expr::value(Value::SingleQuotedString("a".to_string()))
+            );
+            assert_eq!(*r#in, Expr::Identifier(Ident::new("name")));
+        }
+        _ => unreachable!(),
+    }
+
+    match verified_expr("OVERLAY(name PLACING 'x' FROM 2 FOR 3)") {
+        Expr::Overlay {
+            overlay_what,
+            overlay_from,
+            overlay_for,
+            ..
+        } => {
+            assert_eq!(
+                *overlay_what,
+                Expr::value(Value::SingleQuotedString("x".to_string()))
+            );
+            assert_eq!(*overlay_from, Expr::value(number("2")));
+            assert_eq!(overlay_for, Some(Box::new(Expr::value(number("3")))));
+        }
+        _ => unreachable!(),
+    }
+
+    match verified_expr("(a, b) OVERLAPS (c, d)") {
+        Expr::BinaryOp { left, op, right } => {
+            assert_eq!(op, BinaryOperator::Overlaps);
+            assert!(matches!(*left, Expr::Tuple(_)));
+            assert!(matches!(*right, Expr::Tuple(_)));
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_null_treatment_after_function_args_support() {
+    let sql = "SELECT LAG(x) IGNORE NULLS OVER (ORDER BY y) FROM t";
+    all_dialects_where(|d| d.supports_null_treatment_after_function_args()).verified_stmt(sql);
+
+    // `IGNORE` / `RESPECT` are plain aliases where the syntax isn't supported
+    let dialects = all_dialects_where(|d| !d.supports_null_treatment_after_function_args());
+    assert!(dialects.parse_sql_statements(sql).is_err());
+    let select = dialects.verified_only_select("SELECT max(x) AS ignore, min(x) AS respect FROM t");
+    assert_eq!(
+        select.projection[1],
+        SelectItem::ExprWithAlias {
+            expr: call("min", [Expr::Identifier(Ident::new("x"))]),
+            alias: Ident::new("respect"),
+        }
+    );
+}
+
+#[test]
+fn parse_aggregate_filter_without_window() {
+    let dialects = all_dialects_where(|d| d.supports_filter_during_aggregation());
+    let select = dialects.verified_only_select("SELECT COUNT(*) FILTER (WHERE active) FROM t");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function { filter, over, .. }) => {
+            assert_eq!(
+                filter,
+                &Some(Box::new(Expr::Identifier(Ident::new("active"))))
+            );
+            assert_eq!(over, &None);
+        }
+        e => panic!("unexpected expression: {e:?}"),
+    }
+}
+
+#[test]
+fn parse_temporal_functions_with_date_part_arguments() {
+    let dialects = TestedDialects::new(vec![
+        Box::new(BigQueryDialect {}),
+        Box::new(SnowflakeDialect {}),
+        Box::new(MsSqlDialect {}),
+        Box::new(RedshiftSqlDialect {}),
+    ]);
+    dialects.verified_stmt("SELECT EXTRACT(EPOCH FROM ts)");
+    dialects.verified_stmt("SELECT EXTRACT(DAYOFWEEK FROM d)");
+    dialects.verified_stmt("SELECT DATEADD(day, 5, d)");
+    dialects.verified_stmt("SELECT DATEDIFF(hour, a, b)");
+    dialects.verified_stmt("SELECT DATE_PART('year', d)");
+    dialects.verified_stmt("SELECT DATE_ADD(d, INTERVAL 5 DAY)");
+
+    let select = all_dialects_where(|d| d.allow_extract_custom())
+        .verified_only_select("SELECT EXTRACT(fiscal_quarter FROM d)");
+    assert_eq!(
+        &Expr::Extract {
+            field: DateTimeField::Custom(Ident::new("fiscal_quarter")),
+            syntax: ExtractSyntax::From,
+            expr: Box::new(Expr::Identifier(Ident::new("d"))),
+        },
+        expr_from_projection(only(&select.projection)),
+    );
+    assert!(
+        all_dialects_where(|d| !d.allow_extract_custom() && !d.allow_extract_single_quotes())
+            .parse_sql_statements("SELECT EXTRACT(fiscal_quarter FROM d)")
+            .is_err()
+    );
+}
+
+#[test]
+fn parse_niladic_datetime_functions_round_trip() {
+    for sql in [
+        "SELECT CURRENT_TIMESTAMP",
+        "SELECT CURRENT_TIMESTAMP()",
+        "SELECT CURRENT_TIMESTAMP(3)",
+        "SELECT CURRENT_DATE, CURRENT_TIME, CURRENT_TIME(6)",
+        "SELECT LOCALTIME, LOCALTIME(2), LOCALTIMESTAMP, LOCALTIMESTAMP(0)",
+        "SELECT SYSDATE()",
+        "SELECT GETDATE(), GETUTCDATE()",
+    ] {
+        all_dialects().verified_stmt(sql);
+    }
+
+    let args_of = |sql: &str| match expr_from_projection(only(
+        &all_dialects().verified_only_select(sql).projection,
+    )) {
+        Expr::Function(Function { args, .. }) => args.clone(),
+        e => panic!("unexpected expression: {e:?}"),
+    };
+    assert_eq!(args_of("SELECT CURRENT_TIMESTAMP"), FunctionArguments::None);
+    assert_eq!(
+        args_of("SELECT CURRENT_TIMESTAMP()"),
+        FunctionArguments::List(FunctionArgumentList {
+            duplicate_treatment: None,
+            args: vec![],
+            clauses: vec![],
+        })
+    );
+    assert_eq!(
+        args_of("SELECT CURRENT_TIMESTAMP(3)"),
+        FunctionArguments::List(FunctionArgumentList {
+            duplicate_treatment: None,
+            args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::value(
+                number("3")
+            )))],
+            clauses: vec![],
+        })
+    );
+
+    let select =
+        all_dialects_where(|d| d.supports_niladic_sysdate()).verified_only_select("SELECT SYSDATE");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function { name, args, .. }) => {
+            assert_eq!(name.to_string(), "SYSDATE");
+            assert_eq!(args, &FunctionArguments::None);
+        }
+        e => panic!("unexpected expression: {e:?}"),
+    }
+    let select = all_dialects_where(|d| !d.supports_niladic_sysdate())
+        .verified_only_select("SELECT SYSDATE");
+    assert_eq!(
+        expr_from_projection(only(&select.projection)),
+        &Expr::Identifier(Ident::new("SYSDATE"))
+    );
+}
+
+#[test]
+fn parse_array_and_struct_data_type_spellings() {
+    let cases: Vec<(Box<dyn Dialect>, &str)> = vec![
+        (Box::new(PostgreSqlDialect {}), "INT[]"),
+        (Box::new(PostgreSqlDialect {}), "INT[3][4]"),
+        (Box::new(PostgreSqlDialect {}), "TEXT[][]"),
+        (Box::new(BigQueryDialect {}), "ARRAY<INT64>"),
+        (Box::new(BigQueryDialect {}), "ARRAY<ARRAY<ARRAY<INT64>>>"),
+        (
+            Box::new(BigQueryDialect {}),
+            "ARRAY<STRUCT<x INT64, y ARRAY<STRUCT<z STRING>>>>",
+        ),
+        (Box::new(HiveDialect {}), "ARRAY<ARRAY<INT>>"),
+        (Box::new(SnowflakeDialect {}), "ARRAY"),
+        (Box::new(DuckDbDialect {}), "INT[3]"),
+        (Box::new(DuckDbDialect {}), "INT[3][]"),
+        (Box::new(DuckDbDialect {}), "STRUCT(x INT, y VARCHAR[])[]"),
+        (Box::new(ClickHouseDialect {}), "Array(Array(Int32))"),
+    ];
+    for (dialect, data_type) in cases {
+        TestedDialects::new(vec![dialect])
+            .verified_stmt(&format!("CREATE TABLE t (a {data_type})"));
+    }
+
+    // Snowflake structured arrays use the parenthesized spelling
+    let snowflake = TestedDialects::new(vec![Box::new(SnowflakeDialect {})]);
+    let select = snowflake.verified_only_select("SELECT CAST(x AS Array(Array(INT)))");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Cast { data_type, .. } => assert_eq!(
+            data_type,
+            &DataType::Array(ArrayElemTypeDef::Parenthesis(Box::new(DataType::Array(
+                ArrayElemTypeDef::Parenthesis(Box::new(DataType::Int(None)))
+            ))))
+        ),
+        e => panic!("unexpected expression: {e:?}"),
+    }
+    snowflake.one_statement_parses_to(
+        "CREATE TABLE t (a ARRAY(VARCHAR))",
+        "CREATE TABLE t (a Array(VARCHAR))",
+    );
+
+    match TestedDialects::new(vec![Box::new(PostgreSqlDialect {})])
+        .verified_stmt("CREATE TABLE t (a INT[3][4])")
+    {
+        Statement::CreateTable(CreateTable { columns, .. }) => assert_eq!(
+            columns[0].data_type,
+            DataType::Array(ArrayElemTypeDef::SquareBracket(
+                Box::new(DataType::Array(ArrayElemTypeDef::SquareBracket(
+                    Box::new(DataType::Int(None)),
+                    Some(3)
+                ))),
+                Some(4)
+            ))
+        ),
+        s => panic!("unexpected statement: {s:?}"),
+    }
+}
+
+#[test]
+fn parse_window_frame_placeholder_bounds() {
+    let generic = TestedDialects::new(vec![Box::new(GenericDialect {})]);
+    let select = generic.verified_only_select(
+        "SELECT SUM(x) OVER (ORDER BY d ROWS BETWEEN ? PRECEDING AND $2 FOLLOWING) FROM t",
+    );
+    match expr_from_projection(&select.projection[0]) {
+        Expr::Function(Function {
+            over:
+                Some(WindowType::WindowSpec(WindowSpec {
+                    window_frame: Some(window_frame),
+                    ..
+                })),
+            ..
+        }) => {
+            assert_eq!(
+                WindowFrameBound::Preceding(Some(Box::new(Expr::Value(
+                    (Value::Placeholder("?".into())).with_empty_span()
+                )))),
+                window_frame.start_bound
+            );
+            assert_eq!(
+                Some(WindowFrameBound::Following(Some(Box::new(Expr::Value(
+                    (Value::Placeholder("$2".into())).with_empty_span()
+                ))))),
+                window_frame.end_bound
+            );
+        }
+        e => unreachable!("{e:?}"),
+    }
+
+    generic.verified_stmt("SELECT SUM(x) OVER (ROWS BETWEEN CURRENT ROW AND :n FOLLOWING) FROM t");
+    generic.verified_stmt(
+        "SELECT SUM(x) OVER w, COUNT(*) OVER () FROM t WINDOW w AS (ROWS ? PRECEDING)",
+    );
+}
+
+#[test]
+fn parse_unparsed_statements() {
+    let dialects = all_dialects_with_options(ParserOptions::new().with_unparsed_statements(true));
+
+    let sql = "SELECT 1; FROBNICATE CLUSTER 7; SELECT a FROM t";
+    let stmts = dialects.parse_sql_statements(sql).unwrap();
+    assert_eq!(stmts.len(), 3);
+    assert!(matches!(stmts[0], Statement::Query(_)));
+    match &stmts[1] {
+        Statement::Unparsed { tokens, sql } => {
+            assert_eq!(sql, "FROBNICATE CLUSTER 7");
+            assert_eq!(
+                tokens.0.iter().map(|t| t.token.clone()).collect::<Vec<_>>(),
+                vec![
+                    Token::make_word("FROBNICATE", None),
+                    Token::make_keyword("CLUSTER"),
+                    Token::Number("7".to_string(), false),
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+    assert!(matches!(stmts[2], Statement::Query(_)));
+    assert_eq!(
+        stmts
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("; "),
+        "SELECT 1; FROBNICATE CLUSTER 7; SELECT a FROM t"
+    );
+
+    // A recognized statement that fails mid-parse is kept verbatim
+    match dialects
+        .parse_sql_statements("SELECT 1 FROM ;")
+        .unwrap()
+        .as_slice()
+    {
+        [Statement::Unparsed { sql, .. }] => assert_eq!(sql, "SELECT 1 FROM"),
+        stmts => panic!("unexpected statements: {stmts:?}"),
+    }
+
+    // The original text is sliced from the input, keeping literal spelling
+    // and comments
+    let parse_unparsed = |sql: &str| {
+        for dialect in &dialects.dialects {
+            let stmts = Parser::new(&**dialect)
+                .with_options(ParserOptions::new().with_unparsed_statements(true))
+                .try_with_sql(&format!("{sql}; SELECT 1"))
+                .unwrap()
+                .parse_statements()
+                .unwrap();
+            match stmts.as_slice() {
+                [unparsed @ Statement::Unparsed { .. }, Statement::Query(_)] => {
+                    assert_eq!(unparsed.to_string(), sql)
+                }
+                stmts => panic!("unexpected statements: {stmts:?}"),
+            }
+        }
+    };
+    parse_unparsed("FROBNICATE x'0a' /* keep me */ 'It''s'  ,\n  7");
+    parse_unparsed("FROBNICATE 'héllo' /* ü */,\n  'wörld'  x'0a'");
+
+    // A `;` inside a BEGIN ... END block or CASE ... END does not end the statement
+    parse_unparsed(concat!(
+        "CREATE PROCEDURE p() BEGIN FROBNICATE 1; ",
+        "IF x THEN FROBNICATE CASE WHEN a THEN 1; ELSE 2 END; END IF; END",
+    ));
+    parse_unparsed("FROBNICATE BEGIN CASE x WHEN 1 THEN FROBNICATE 2; END CASE; END");
+
+    // Like the rest of the AST, equality ignores source locations
+    for dialect in &dialects.dialects {
+        let parse = |sql: &str| {
+            Parser::new(&**dialect)
+                .with_options(ParserOptions::new().with_unparsed_statements(true))
+                .try_with_sql(sql)
+                .unwrap()
+                .parse_statements()
+                .unwrap()
+        };
+        assert_eq!(parse("FROBNICATE 7"), parse(" FROBNICATE 7"));
+        assert_ne!(parse("FROBNICATE 7"), parse("FROBNICATE 8"));
+    }
+
+    // `END CASE` closes the block, so the following statements are parsed
+    match dialects
+        .parse_sql_statements("FROBNICATE CASE x WHEN 1 THEN 2 END CASE; SELECT 1; SELECT 2")
+        .unwrap()
+        .as_slice()
+    {
+        [Statement::Unparsed { sql, .. }, Statement::Query(_), Statement::Query(_)] => {
+            assert_eq!(sql, "FROBNICATE CASE x WHEN 1 THEN 2 END CASE")
+        }
+        stmts => panic!("unexpected statements: {stmts:?}"),
+    }
+
+    // Without the option the batch is rejected
+    assert!(all_dialects()
+        .parse_sql_statements("SELECT 1; FROBNICATE CLUSTER 7;")
+        .is_err());
+}
+
+#[test]
+fn parse_keywords_as_implicit_aliases() {
+    let column_alias = |dialect: Box<dyn Dialect>, kw: &str| {
+        Parser::parse_sql(&*dialect, &format!("SELECT x {kw} FROM t")).is_ok()
+    };
+    let table_alias = |dialect: Box<dyn Dialect>, kw: &str| {
+        Parser::parse_sql(&*dialect, &format!("SELECT * FROM orders {kw}")).is_ok()
+    };
+
+    // (keyword, generic, postgres, snowflake)
+    let column_cases = [
+        ("value", true, true, true),
+        ("format", true, true, true),
+        ("order_", true, true, true),
+        ("year", true, false, true),
+        ("day", true, false, true),
+        ("over", true, false, true),
+        ("limit", false, false, true),
+        ("to", true, false, true),
+        ("isnull", true, false, true),
+    ];
+    for (kw, generic, postgres, snowflake) in column_cases {
+        assert_eq!(
+            column_alias(Box::new(GenericDialect {}), kw),
+            generic,
+            "{kw}"
+        );
+        assert_eq!(
+            column_alias(Box::new(PostgreSqlDialect {}), kw),
+            postgres,
+            "{kw}"
+        );
+        assert_eq!(
+            column_alias(Box::new(SnowflakeDialect {}), kw),
+            snowflake,
+            "{kw}"
+        );
+    }
+
+    // (keyword, generic, postgres, clickhouse)
+    let table_cases = [
+        ("order_", true, true, true),
+        ("value", true, true, true),
+        ("year", true, true, true),
+        ("format", false, true, false),
+        ("limit", false, false, false),
+    ];
+    for (kw, generic, postgres, clickhouse) in table_cases {
+        assert_eq!(
+            table_alias(Box::new(GenericDialect {}), kw),
+            generic,
+            "{kw}"
+        );
+        assert_eq!(
+            table_alias(Box::new(PostgreSqlDialect {}), kw),
+            postgres,
+            "{kw}"
+        );
+        assert_eq!(
+            table_alias(Box::new(ClickHouseDialect {}), kw),
+            clickhouse,
+            "{kw}"
+        );
+    }
+
+    let pg = TestedDialects::new(vec![Box::new(PostgreSqlDialect {})]);
+    pg.verified_only_select("SELECT x AS year FROM t");
+    pg.one_statement_parses_to("SELECT x value FROM t", "SELECT x AS value FROM t");
+    pg.verified_stmt("SELECT * FROM orders format");
+}
+
+#[test]
+fn parse_alter_materialized_view_unsupported() {
+    let sql = "ALTER MATERIALIZED VIEW mv RENAME TO mv2";
+    all_dialects_where(|d| d.supports_alter_materialized_view()).verified_stmt(sql);
+    assert!(
+        all_dialects_where(|d| !d.supports_alter_materialized_view())
+            .parse_sql_statements(sql)
+            .is_err()
+    );
+}
+
+#[test]
+fn parse_create_synonym_unsupported() {
+    let sql = "CREATE SYNONYM s FOR db.t";
+    all_dialects_where(|d| d.supports_synonyms()).verified_stmt(sql);
+    assert!(all_dialects_where(|d| !d.supports_synonyms())
+        .parse_sql_statements(sql)
+        .is_err());
+}
+
+#[test]
+fn parse_create_table_as_with_column_names_unsupported() {
+    let ansi = TestedDialects::new(vec![Box::new(AnsiDialect {})]);
+    assert!(ansi
+        .parse_sql_statements("CREATE TABLE t (a, b) AS SELECT 1, 2")
+        .is_err());
+}
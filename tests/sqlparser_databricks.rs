@@ -422,7 +422,8 @@ fn data_type_timestamp_ntz() {
             )))),
             data_type: DataType::TimestampNtz(None),
             array: false,
-            format: None
+            format: None,
+            charset: None,
         }
     );
 
@@ -738,3 +739,272 @@ fn parse_cte_without_as() {
         .parse_sql_statements("WITH cte (SELECT 1) SELECT * FROM cte")
         .is_err());
 }
+
+#[test]
+fn parse_table_time_travel_with_alias_and_join() {
+    databricks().verified_stmt("SELECT * FROM t1 VERSION AS OF 123 AS t JOIN t2 ON t.id = t2.id");
+    databricks().verified_stmt("SELECT * FROM t1 TIMESTAMP AS OF '2024-01-01' AS t WHERE t.id = 1");
+}
+
+#[test]
+fn parse_databricks_cast_forms() {
+    let cast = |kind, expr, data_type| Expr::Cast {
+        kind,
+        expr: Box::new(expr),
+        data_type,
+        array: false,
+        format: None,
+        charset: None,
+    };
+    let x = || Expr::Identifier(Ident::new("x"));
+
+    assert_eq!(
+        databricks().verified_expr("TRY_CAST(x AS INT)"),
+        cast(CastKind::TryCast, x(), DataType::Int(None))
+    );
+    assert_eq!(
+        databricks().verified_expr("CAST(x AS INT)"),
+        cast(CastKind::Cast, x(), DataType::Int(None))
+    );
+    assert_eq!(
+        databricks().verified_expr("x::INT"),
+        cast(CastKind::DoubleColon, x(), DataType::Int(None))
+    );
+
+    // `::` binds tighter than unary minus
+    assert_eq!(
+        databricks().verified_expr("-x::INT"),
+        Expr::UnaryOp {
+            op: UnaryOperator::Minus,
+            expr: Box::new(cast(CastKind::DoubleColon, x(), DataType::Int(None))),
+        }
+    );
+    // ... but looser than field access
+    assert_eq!(
+        databricks().verified_expr("s.f::INT"),
+        cast(
+            CastKind::DoubleColon,
+            Expr::CompoundIdentifier(vec![Ident::new("s"), Ident::new("f")]),
+            DataType::Int(None)
+        )
+    );
+    match databricks().verified_expr("named_struct('a', 1).a::STRING") {
+        Expr::Cast {
+            kind: CastKind::DoubleColon,
+            expr,
+            data_type: DataType::String(None),
+            ..
+        } => assert!(matches!(*expr, Expr::CompoundFieldAccess { .. })),
+        e => panic!("unexpected expression: {e:?}"),
+    }
+
+    // the cast is part of the lambda body
+    match databricks().verified_expr("transform(arr, x -> x::INT)") {
+        Expr::Function(Function {
+            args: FunctionArguments::List(FunctionArgumentList { args, .. }),
+            ..
+        }) => assert_eq!(
+            args[1],
+            FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Lambda(LambdaFunction {
+                params: OneOrManyWithParens::One(LambdaFunctionParameter {
+                    name: Ident::new("x"),
+                    data_type: None,
+                }),
+                body: Box::new(cast(CastKind::DoubleColon, x(), DataType::Int(None))),
+                syntax: LambdaSyntax::Arrow,
+            })))
+        ),
+        e => panic!("unexpected expression: {e:?}"),
+    }
+}
+
+#[test]
+fn parse_grant_unity_catalog_privileges() {
+    match databricks().verified_stmt("GRANT USE CATALOG ON CATALOG main TO principal") {
+        Statement::Grant(Grant {
+            privileges,
+            objects,
+            ..
+        }) => {
+            assert_eq!(privileges, Privileges::Actions(vec![Action::UseCatalog]));
+            assert_eq!(
+                objects,
+                Some(GrantObjects::Catalogs(vec![ObjectName::from(vec![
+                    Ident::new("main")
+                ])]))
+            );
+        }
+        s => panic!("unexpected statement: {s:?}"),
+    }
+    databricks().verified_stmt("GRANT USE SCHEMA, SELECT ON SCHEMA main.sales TO `data engineers`");
+    databricks().verified_stmt("GRANT CREATE TABLE ON SCHEMA main.sales TO `alice@example.com`");
+    databricks().verified_stmt("GRANT CREATE MATERIALIZED VIEW ON SCHEMA main.sales TO analysts");
+    databricks().verified_stmt("GRANT CREATE SCHEMA ON CATALOG main TO analysts");
+    databricks().verified_stmt("GRANT ALL PRIVILEGES ON CATALOG main TO admins");
+    databricks().verified_stmt("REVOKE USE CATALOG ON CATALOG main FROM principal");
+}
+
+#[test]
+fn parse_create_catalog() {
+    assert_eq!(
+        databricks_and_generic().verified_stmt("CREATE CATALOG IF NOT EXISTS dev COMMENT 'x'"),
+        Statement::CreateCatalog(CreateCatalog {
+            if_not_exists: true,
+            name: Ident::new("dev"),
+            managed_location: None,
+            comment: Some("x".to_string()),
+        })
+    );
+    databricks_and_generic().verified_stmt("CREATE CATALOG dev");
+    databricks_and_generic()
+        .verified_stmt("CREATE CATALOG dev MANAGED LOCATION 's3://bucket/dev' COMMENT 'dev data'");
+}
+
+#[test]
+fn parse_alter_catalog() {
+    assert_eq!(
+        databricks_and_generic().verified_stmt("ALTER CATALOG dev OWNER TO admins"),
+        Statement::AlterCatalog(AlterCatalog {
+            name: Ident::new("dev"),
+            operation: AlterCatalogOperation::OwnerTo(Owner::Ident(Ident::new("admins"))),
+        })
+    );
+    databricks_and_generic().verified_stmt("ALTER CATALOG dev OWNER TO `data engineers`");
+    databricks_and_generic().verified_stmt("ALTER CATALOG dev RENAME TO prod");
+
+    assert_eq!(
+        databricks()
+            .parse_sql_statements("ALTER CATALOG dev")
+            .unwrap_err(),
+        ParserError::ParserError(
+            "Expected: OWNER TO or RENAME TO after ALTER CATALOG, found: EOF".to_string()
+        )
+    );
+}
+
+#[test]
+fn parse_drop_catalog() {
+    match databricks_and_generic().verified_stmt("DROP CATALOG IF EXISTS dev CASCADE") {
+        Statement::Drop {
+            object_type,
+            if_exists,
+            names,
+            cascade,
+            ..
+        } => {
+            assert_eq!(object_type, ObjectType::Catalog);
+            assert!(if_exists);
+            assert_eq!(names, vec![ObjectName::from(vec![Ident::new("dev")])]);
+            assert!(cascade);
+        }
+        _ => unreachable!(),
+    }
+    databricks_and_generic().verified_stmt("DROP CATALOG dev");
+}
+
+#[test]
+fn parse_unity_catalog_three_part_names() {
+    databricks().verified_stmt("USE CATALOG main");
+    databricks().verified_stmt("SHOW CATALOGS LIKE 'd%'");
+    databricks().verified_stmt("USE SCHEMA main.sales");
+    databricks().verified_stmt("CREATE SCHEMA IF NOT EXISTS main.sales");
+    databricks().verified_stmt("CREATE TABLE main.sales.orders (id INT)");
+    databricks().verified_stmt("INSERT INTO main.sales.orders VALUES (1)");
+    databricks().verified_stmt("SELECT o.id FROM main.sales.orders AS o");
+    databricks().verified_stmt("DROP TABLE main.sales.orders");
+}
+
+#[test]
+fn parse_databricks_copy_into() {
+    let sql =
+        "COPY INTO t FROM 's3://path' FILEFORMAT = PARQUET COPY_OPTIONS ('mergeSchema' = 'true')";
+    assert_eq!(
+        databricks().verified_stmt(sql),
+        Statement::CopyIntoDatabricks(CopyIntoDatabricks {
+            into: ObjectName::from(vec![Ident::new("t")]),
+            into_columns: None,
+            from: "s3://path".to_string(),
+            file_format: Ident::new("PARQUET"),
+            validate: None,
+            files: None,
+            pattern: None,
+            format_options: vec![],
+            copy_options: vec![SqlOption::KeyValue {
+                key: Ident::with_quote('\'', "mergeSchema"),
+                value: Expr::value(Value::SingleQuotedString("true".to_string())),
+            }],
+        })
+    );
+
+    match databricks().verified_stmt(
+        "COPY INTO main.s.t (a, b) FROM '/mnt/raw' FILEFORMAT = CSV VALIDATE 10 ROWS PATTERN = '*.csv' FORMAT_OPTIONS ('header' = 'true', 'inferSchema' = 'true')",
+    ) {
+        Statement::CopyIntoDatabricks(copy_into) => {
+            assert_eq!(
+                copy_into.into_columns,
+                Some(vec![Ident::new("a"), Ident::new("b")])
+            );
+            assert_eq!(
+                copy_into.validate,
+                Some(CopyIntoDatabricksValidate::Rows(10))
+            );
+            assert_eq!(copy_into.pattern, Some("*.csv".to_string()));
+            assert_eq!(copy_into.format_options.len(), 2);
+        }
+        _ => unreachable!(),
+    }
+
+    databricks().verified_stmt(
+        "COPY INTO t FROM '/mnt/raw' FILEFORMAT = JSON VALIDATE FILES = ('a.json', 'b.json')",
+    );
+    databricks().verified_stmt("COPY INTO t FROM '/mnt/raw' FILEFORMAT = AVRO VALIDATE ALL");
+}
+
+#[test]
+fn parse_create_table_using_partitioned_by() {
+    match databricks()
+        .verified_stmt("CREATE TABLE t USING DELTA PARTITIONED BY (dt) AS SELECT * FROM s")
+    {
+        Statement::CreateTable(CreateTable {
+            hive_formats,
+            hive_distribution,
+            query,
+            ..
+        }) => {
+            assert_eq!(
+                hive_formats.and_then(|f| f.storage),
+                Some(HiveIOFormat::Using {
+                    format: Ident::new("DELTA")
+                })
+            );
+            assert!(matches!(
+                hive_distribution,
+                HiveDistributionStyle::PARTITIONED { .. }
+            ));
+            assert!(query.is_some());
+        }
+        _ => unreachable!(),
+    }
+
+    databricks().verified_stmt(
+        "CREATE TABLE t (a INT, dt DATE) USING DELTA PARTITIONED BY (dt) LOCATION '/mnt/t'",
+    );
+    databricks().verified_stmt("CREATE TABLE t USING DELTA AS SELECT 1");
+}
+
+#[test]
+fn parse_optimize_table_rejects_clickhouse_options() {
+    databricks().verified_stmt("OPTIMIZE delta_t WHERE dt = '2024-01-01' ZORDER BY (c1, c2)");
+
+    for sql in [
+        "OPTIMIZE TABLE t ON CLUSTER c",
+        "OPTIMIZE TABLE t PARTITION ID 'x'",
+        "OPTIMIZE TABLE t FINAL",
+        "OPTIMIZE TABLE t DEDUPLICATE BY a, b",
+    ] {
+        assert!(
+            databricks().parse_sql_statements(sql).is_err(),
+            "expected error for {sql}"
+        );
+    }
+}
@@ -390,6 +390,7 @@ fn test_duckdb_specific_int_types() {
                 data_type: data_type.clone(),
                 array: false,
                 format: None,
+                charset: None,
             },
             expr_from_projection(&select.projection[0])
         );
@@ -748,6 +749,7 @@ fn test_duckdb_union_datatype() {
                 }
             ],
             constraints: Default::default(),
+            periods: Default::default(),
             hive_distribution: HiveDistributionStyle::NONE,
             hive_formats: None,
             file_format: Default::default(),
@@ -756,6 +758,8 @@ fn test_duckdb_union_datatype() {
             without_rowid: Default::default(),
             like: Default::default(),
             clone: Default::default(),
+            copy: Default::default(),
+            with_connection: Default::default(),
             comment: Default::default(),
             on_commit: Default::default(),
             on_cluster: Default::default(),
@@ -794,6 +798,8 @@ fn test_duckdb_union_datatype() {
             distkey: Default::default(),
             sortkey: Default::default(),
             backup: Default::default(),
+            with_system_versioning: Default::default(),
+            skewed_by: Default::default(),
         }),
         stmt
     );
@@ -906,3 +912,137 @@ fn test_duckdb_lambda_function() {
     let sql_transform = "SELECT list_transform([1, 2, 3], lambda x : x * 2)";
     duckdb().verified_stmt(sql_transform);
 }
+
+#[test]
+fn test_duckdb_qualify_order_by() {
+    duckdb().verified_stmt(
+        "SELECT a, b FROM t QUALIFY ROW_NUMBER() OVER (PARTITION BY a ORDER BY b) = 1 ORDER BY a",
+    );
+    duckdb().verified_stmt(
+        "SELECT a FROM t WHERE b > 0 QUALIFY RANK() OVER (ORDER BY b) <= 3 ORDER BY a LIMIT 10",
+    );
+}
+
+#[test]
+fn test_duckdb_from_first_select() {
+    let select =
+        duckdb().verified_only_select("FROM orders SELECT customer_id, count(*) GROUP BY ALL");
+    assert_eq!(select.flavor, SelectFlavor::FromFirst);
+    assert_eq!(select.projection.len(), 2);
+    assert_eq!(select.group_by, GroupByExpr::All(vec![]));
+
+    let select = duckdb().verified_only_select("FROM orders");
+    assert_eq!(select.flavor, SelectFlavor::FromFirstNoSelect);
+    assert_eq!(
+        select.projection,
+        vec![SelectItem::Wildcard(WildcardAdditionalOptions::default())]
+    );
+
+    duckdb()
+        .verified_stmt("FROM orders SELECT customer_id UNION ALL FROM returns SELECT customer_id");
+    duckdb().verified_stmt("FROM orders UNION BY NAME FROM returns ORDER BY 1");
+    duckdb().verified_stmt("(FROM orders) EXCEPT (FROM returns SELECT *)");
+}
+
+#[test]
+fn test_duckdb_set_operations_by_name() {
+    let query = duckdb().verified_query("SELECT * FROM a UNION BY NAME SELECT * FROM b");
+    match *query.body {
+        SetExpr::SetOperation {
+            op: SetOperator::Union,
+            set_quantifier,
+            ..
+        } => assert_eq!(set_quantifier, SetQuantifier::ByName),
+        _ => unreachable!(),
+    }
+    duckdb().verified_stmt("SELECT * FROM a UNION ALL BY NAME SELECT * FROM b");
+    duckdb().verified_stmt("SELECT * FROM a EXCEPT BY NAME SELECT * FROM b");
+
+    // `BY` is still usable as a table alias
+    duckdb().verified_stmt("SELECT * FROM a AS by UNION ALL BY NAME SELECT * FROM b");
+}
+
+#[test]
+fn test_duckdb_insert_by_name() {
+    match duckdb().verified_stmt("INSERT INTO t BY NAME SELECT 42 AS b, 32 AS a") {
+        Statement::Insert(Insert {
+            by_name, columns, ..
+        }) => {
+            assert!(by_name);
+            assert!(columns.is_empty());
+        }
+        _ => unreachable!(),
+    }
+    duckdb().verified_stmt("INSERT OR REPLACE INTO t BY NAME SELECT * FROM s");
+    duckdb().verified_stmt("INSERT INTO t BY NAME FROM s");
+    duckdb().verified_stmt("INSERT INTO t BY NAME SELECT * FROM s RETURNING *");
+}
+
+#[test]
+fn test_duckdb_describe_query() {
+    match duckdb().verified_stmt("DESCRIBE SELECT * FROM t") {
+        Statement::Explain {
+            describe_alias,
+            statement,
+            ..
+        } => {
+            assert_eq!(describe_alias, DescribeAlias::Describe);
+            assert_eq!(statement.to_string(), "SELECT * FROM t");
+        }
+        _ => unreachable!(),
+    }
+    duckdb().verified_stmt("DESC SELECT a FROM t WHERE b > 1");
+    duckdb().verified_stmt("DESCRIBE t");
+}
+
+#[test]
+fn parse_set_session_variables() {
+    duckdb().verified_stmt("SET memory_limit = '10GB'");
+    duckdb().verified_stmt("SET threads TO 4");
+    duckdb().verified_stmt("SET GLOBAL search_path = 'db1,db2'");
+    duckdb().verified_stmt("SET SESSION enable_progress_bar = true");
+    duckdb().verified_stmt("SET default_null_order = DEFAULT");
+}
+
+#[test]
+fn parse_create_table_as_with_column_names() {
+    match duckdb().verified_stmt("CREATE OR REPLACE TABLE t (a, b) AS SELECT 1, 2") {
+        Statement::CreateTable(CreateTable {
+            or_replace,
+            columns,
+            query,
+            ..
+        }) => {
+            assert!(or_replace);
+            assert_eq!(
+                columns,
+                vec![
+                    ColumnDef {
+                        name: Ident::new("a"),
+                        data_type: DataType::Unspecified,
+                        options: vec![],
+                    },
+                    ColumnDef {
+                        name: Ident::new("b"),
+                        data_type: DataType::Unspecified,
+                        options: vec![],
+                    },
+                ]
+            );
+            assert!(query.is_some());
+        }
+        _ => unreachable!(),
+    }
+
+    // Typed column lists are unaffected
+    duckdb().verified_stmt("CREATE TABLE t (a INT, b TEXT) AS SELECT 1, 'x'");
+
+    assert_eq!(
+        duckdb()
+            .parse_sql_statements("CREATE TABLE t (a, b)")
+            .unwrap_err(),
+        ParserError::ParserError(
+            "Expected: AS <query> after a column list without data types, found: EOF".to_string()
+        )
+    );
+}
@@ -21,9 +21,10 @@
 //! is also tested (on the inputs it can handle).
 
 use sqlparser::ast::{
-    ClusteredBy, CommentDef, CreateFunction, CreateFunctionBody, CreateFunctionUsing, CreateTable,
-    Expr, Function, FunctionArgumentList, FunctionArguments, Ident, ObjectName, OrderByExpr,
-    OrderByOptions, SelectItem, Set, Statement, TableFactor, UnaryOperator, Use, Value,
+    BinaryOperator, ClusteredBy, CommentDef, CreateFunction, CreateFunctionBody,
+    CreateFunctionUsing, CreateTable, DescribeAlias, Expr, Function, FunctionArgumentList,
+    FunctionArguments, HiveDescribeFormat, Ident, ObjectName, OrderByExpr, OrderByOptions,
+    SelectItem, Set, SkewedBy, Statement, TableFactor, UnaryOperator, Use, Value,
 };
 use sqlparser::dialect::{AnsiDialect, GenericDialect, HiveDialect};
 use sqlparser::parser::ParserError;
@@ -225,6 +226,68 @@ fn create_table_with_clustered_by() {
     );
 }
 
+#[test]
+fn create_table_with_skewed_by() {
+    let sql = concat!(
+        "CREATE TABLE db.table_name (a INT, b STRING)",
+        " PARTITIONED BY (c INT)",
+        " CLUSTERED BY (a) INTO 4 BUCKETS",
+        " SKEWED BY (a, b) ON ((1, 'x'), (2, 'y')) STORED AS DIRECTORIES",
+        " ROW FORMAT DELIMITED FIELDS TERMINATED BY ','",
+        " STORED AS TEXTFILE"
+    );
+    match hive_and_generic().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable { skewed_by, .. }) => {
+            assert_eq!(
+                skewed_by.unwrap(),
+                SkewedBy {
+                    columns: vec![Ident::new("a"), Ident::new("b")],
+                    on: vec![
+                        vec![
+                            Expr::value(number("1")),
+                            Expr::value(Value::SingleQuotedString("x".to_string())),
+                        ],
+                        vec![
+                            Expr::value(number("2")),
+                            Expr::value(Value::SingleQuotedString("y".to_string())),
+                        ],
+                    ],
+                    bare_values: false,
+                    stored_as_directories: true,
+                }
+            )
+        }
+        _ => unreachable!(),
+    }
+
+    // A single skewed column may list bare values
+    match hive_and_generic().verified_stmt("CREATE TABLE t (a INT) SKEWED BY (a) ON (1, 5, 6)") {
+        Statement::CreateTable(CreateTable { skewed_by, .. }) => {
+            let skewed_by = skewed_by.unwrap();
+            assert!(skewed_by.bare_values);
+            assert_eq!(
+                skewed_by.on,
+                vec![
+                    vec![Expr::value(number("1"))],
+                    vec![Expr::value(number("5"))],
+                    vec![Expr::value(number("6"))],
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+    hive_and_generic().verified_stmt("CREATE TABLE t (a INT) SKEWED BY (a) ON ((1), (5), (6))");
+    hive_and_generic().verified_stmt("CREATE TABLE t (a INT) SKEWED BY (a) ON ((1) + 2, 5)");
+
+    // missing ON values
+    assert_eq!(
+        hive_and_generic()
+            .parse_sql_statements("CREATE TABLE t (a INT) SKEWED BY (a)")
+            .unwrap_err(),
+        ParserError::ParserError("Expected: ON, found: EOF".to_string())
+    );
+}
+
 // Turning off this test until we can parse identifiers starting with numbers :(
 #[test]
 fn test_identifier() {
@@ -380,6 +443,7 @@ fn set_statement_with_minus() {
                 Ident::new("java"),
                 Ident::new("opts")
             ]),
+            uses_to: false,
             values: vec![Expr::UnaryOp {
                 op: UnaryOperator::Minus,
                 expr: Box::new(Expr::Identifier(Ident::new("Xmx4g")))
@@ -471,6 +535,7 @@ fn parse_delimited_identifiers() {
             json_path: _,
             sample: _,
             index_hints: _,
+            final_modifier: _,
         } => {
             assert_eq!(
                 ObjectName::from(vec![Ident::with_quote('"', "a table")]),
@@ -568,3 +633,36 @@ fn hive() -> TestedDialects {
 fn hive_and_generic() -> TestedDialects {
     TestedDialects::new(vec![Box::new(HiveDialect {}), Box::new(GenericDialect {})])
 }
+
+#[test]
+fn parse_describe_partition() {
+    match hive().verified_stmt("DESCRIBE FORMATTED db.t PARTITION (dt = 'x')") {
+        Statement::ExplainTable {
+            describe_alias,
+            hive_format,
+            object_type,
+            table_name,
+            partition,
+        } => {
+            assert_eq!(describe_alias, DescribeAlias::Describe);
+            assert_eq!(hive_format, Some(HiveDescribeFormat::Formatted));
+            assert_eq!(object_type, None);
+            assert_eq!(table_name.to_string(), "db.t");
+            assert_eq!(
+                partition,
+                Some(vec![Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Ident::new("dt"))),
+                    op: BinaryOperator::Eq,
+                    right: Box::new(Expr::value(Value::SingleQuotedString("x".to_string()))),
+                }])
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    hive().one_statement_parses_to(
+        "DESC EXTENDED db.t PARTITION (dt='x', hr=1)",
+        "DESC EXTENDED db.t PARTITION (dt = 'x', hr = 1)",
+    );
+    hive().verified_stmt("DESC db.t");
+}
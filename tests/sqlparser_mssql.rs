@@ -75,7 +75,8 @@ fn parse_table_time_travel() {
                 with_ordinality: false,
                 json_path: None,
                 sample: None,
-                index_hints: vec![]
+                index_hints: vec![],
+                final_modifier: false,
             },
             joins: vec![]
         },]
@@ -128,49 +129,53 @@ fn parse_create_procedure() {
         ms().verified_stmt(sql),
         Statement::CreateProcedure {
             or_alter: true,
-            body: ConditionalStatements::BeginEnd(BeginEndStatements {
-                begin_token: AttachedToken::empty(),
-                statements: vec![Statement::Query(Box::new(Query {
-                    with: None,
-                    limit_clause: None,
-                    fetch: None,
-                    locks: vec![],
-                    for_clause: None,
-                    order_by: None,
-                    settings: None,
-                    format_clause: None,
-                    pipe_operators: vec![],
-                    body: Box::new(SetExpr::Select(Box::new(Select {
-                        select_token: AttachedToken::empty(),
-                        optimizer_hints: vec![],
-                        distinct: None,
-                        select_modifiers: None,
-                        top: None,
-                        top_before_distinct: false,
-                        projection: vec![SelectItem::UnnamedExpr(Expr::Value(
-                            (number("1")).with_empty_span()
-                        ))],
-                        exclude: None,
-                        into: None,
-                        from: vec![],
-                        lateral_views: vec![],
-                        prewhere: None,
-                        selection: None,
-                        group_by: GroupByExpr::Expressions(vec![], vec![]),
-                        cluster_by: vec![],
-                        distribute_by: vec![],
-                        sort_by: vec![],
-                        having: None,
-                        named_window: vec![],
-                        window_before_qualify: false,
-                        qualify: None,
-                        value_table_mode: None,
-                        connect_by: vec![],
-                        flavor: SelectFlavor::Standard,
-                    })))
-                }))],
-                end_token: AttachedToken::empty(),
-            }),
+            or_replace: false,
+            body: CreateProcedureBody::AsStatements(ConditionalStatements::BeginEnd(
+                BeginEndStatements {
+                    begin_token: AttachedToken::empty(),
+                    statements: vec![Statement::Query(Box::new(Query {
+                        with: None,
+                        limit_clause: None,
+                        fetch: None,
+                        locks: vec![],
+                        for_clause: None,
+                        order_by: None,
+                        settings: None,
+                        format_clause: None,
+                        pipe_operators: vec![],
+                        options: vec![],
+                        body: Box::new(SetExpr::Select(Box::new(Select {
+                            select_token: AttachedToken::empty(),
+                            optimizer_hints: vec![],
+                            distinct: None,
+                            select_modifiers: None,
+                            top: None,
+                            top_before_distinct: false,
+                            projection: vec![SelectItem::UnnamedExpr(Expr::Value(
+                                (number("1")).with_empty_span()
+                            ))],
+                            exclude: None,
+                            into: None,
+                            from: vec![],
+                            lateral_views: vec![],
+                            prewhere: None,
+                            selection: None,
+                            group_by: GroupByExpr::Expressions(vec![], vec![]),
+                            cluster_by: vec![],
+                            distribute_by: vec![],
+                            sort_by: vec![],
+                            having: None,
+                            named_window: vec![],
+                            window_before_qualify: false,
+                            qualify: None,
+                            value_table_mode: None,
+                            connect_by: vec![],
+                            flavor: SelectFlavor::Standard,
+                        })))
+                    }))],
+                    end_token: AttachedToken::empty(),
+                }
+            )),
             params: Some(vec![
                 ProcedureParam {
                     name: Ident {
@@ -201,7 +206,10 @@ fn parse_create_procedure() {
                 quote_style: None,
                 span: Span::empty(),
             }]),
+            return_type: None,
             language: None,
+            execute_as: None,
+            options: None,
         }
     )
 }
@@ -490,7 +498,8 @@ fn parse_mssql_openjson() {
                 partitions: vec![],
                 json_path: None,
                 sample: None,
-                index_hints: vec![]
+                index_hints: vec![],
+                final_modifier: false,
             },
             joins: vec![Join {
                 relation: TableFactor::OpenJsonTable {
@@ -522,6 +531,7 @@ fn parse_mssql_openjson() {
                     alias: table_alias(true, "B")
                 },
                 global: false,
+                strictness: None,
                 join_operator: JoinOperator::CrossApply
             }]
         }],
@@ -544,7 +554,8 @@ fn parse_mssql_openjson() {
                 partitions: vec![],
                 json_path: None,
                 sample: None,
-                index_hints: vec![]
+                index_hints: vec![],
+                final_modifier: false,
             },
             joins: vec![Join {
                 relation: TableFactor::OpenJsonTable {
@@ -576,6 +587,7 @@ fn parse_mssql_openjson() {
                     alias: table_alias(true, "B")
                 },
                 global: false,
+                strictness: None,
                 join_operator: JoinOperator::CrossApply
             }]
         }],
@@ -598,7 +610,8 @@ fn parse_mssql_openjson() {
                 partitions: vec![],
                 json_path: None,
                 sample: None,
-                index_hints: vec![]
+                index_hints: vec![],
+                final_modifier: false,
             },
             joins: vec![Join {
                 relation: TableFactor::OpenJsonTable {
@@ -630,6 +643,7 @@ fn parse_mssql_openjson() {
                     alias: table_alias(true, "B")
                 },
                 global: false,
+                strictness: None,
                 join_operator: JoinOperator::CrossApply
             }]
         }],
@@ -653,6 +667,7 @@ fn parse_mssql_openjson() {
                 json_path: None,
                 sample: None,
                 index_hints: vec![],
+                final_modifier: false,
             },
             joins: vec![Join {
                 relation: TableFactor::OpenJsonTable {
@@ -664,6 +679,7 @@ fn parse_mssql_openjson() {
                     alias: table_alias(true, "B")
                 },
                 global: false,
+                strictness: None,
                 join_operator: JoinOperator::CrossApply
             }]
         }],
@@ -687,6 +703,7 @@ fn parse_mssql_openjson() {
                 json_path: None,
                 sample: None,
                 index_hints: vec![],
+                final_modifier: false,
             },
             joins: vec![Join {
                 relation: TableFactor::OpenJsonTable {
@@ -698,6 +715,7 @@ fn parse_mssql_openjson() {
                     alias: table_alias(true, "B")
                 },
                 global: false,
+                strictness: None,
                 join_operator: JoinOperator::CrossApply
             }]
         }],
@@ -1404,6 +1422,7 @@ fn parse_substring_in_select() {
                     settings: None,
                     format_clause: None,
                     pipe_operators: vec![],
+                    options: vec![],
                 }),
                 query
             );
@@ -1493,6 +1512,7 @@ fn parse_mssql_declare() {
                 scope: None,
                 hivevar: false,
                 variable: ObjectName::from(vec![Ident::new("@bar")]),
+                uses_to: false,
                 values: vec![Expr::Value(
                     (Value::Number("2".parse().unwrap(), false)).with_empty_span()
                 )],
@@ -1507,6 +1527,7 @@ fn parse_mssql_declare() {
                 settings: None,
                 format_clause: None,
                 pipe_operators: vec![],
+                options: vec![],
 
                 body: Box::new(SetExpr::Select(Box::new(Select {
                     select_token: AttachedToken::empty(),
@@ -1965,6 +1986,7 @@ fn parse_create_table_with_valid_options() {
                     },
                 ],
                 constraints: vec![],
+                periods: vec![],
                 hive_distribution: HiveDistributionStyle::NONE,
                 hive_formats: None,
                 file_format: None,
@@ -1973,6 +1995,8 @@ fn parse_create_table_with_valid_options() {
                 without_rowid: false,
                 like: None,
                 clone: None,
+                copy: None,
+                with_connection: None,
                 comment: None,
                 on_commit: None,
                 on_cluster: None,
@@ -2013,6 +2037,8 @@ fn parse_create_table_with_valid_options() {
                 distkey: None,
                 sortkey: None,
                 backup: None,
+                with_system_versioning: false,
+                skewed_by: None,
             })
         );
     }
@@ -2077,6 +2103,7 @@ fn parse_create_table_with_identity_column() {
                         IdentityProperty {
                             parameters: None,
                             order: None,
+                            not_for_replication: false,
                         },
                     )),
                 },
@@ -2100,6 +2127,7 @@ fn parse_create_table_with_identity_column() {
                                 },
                             )),
                             order: None,
+                            not_for_replication: false,
                         },
                     )),
                 },
@@ -2141,6 +2169,7 @@ fn parse_create_table_with_identity_column() {
                     options: column_options,
                 },],
                 constraints: vec![],
+                periods: vec![],
                 hive_distribution: HiveDistributionStyle::NONE,
                 hive_formats: None,
                 file_format: None,
@@ -2149,6 +2178,8 @@ fn parse_create_table_with_identity_column() {
                 without_rowid: false,
                 like: None,
                 clone: None,
+                copy: None,
+                with_connection: None,
                 comment: None,
                 on_commit: None,
                 on_cluster: None,
@@ -2187,11 +2218,245 @@ fn parse_create_table_with_identity_column() {
                 distkey: None,
                 sortkey: None,
                 backup: None,
+                with_system_versioning: false,
+                skewed_by: None,
             }),
         );
     }
 }
 
+#[test]
+fn parse_create_table_not_for_replication() {
+    let sql = "CREATE TABLE t (\
+        id INT IDENTITY(1, 1) NOT FOR REPLICATION NOT NULL, \
+        parent_id INT REFERENCES parents (id) ON DELETE CASCADE NOT FOR REPLICATION, \
+        CONSTRAINT fk_other FOREIGN KEY (id) REFERENCES other(id) NOT FOR REPLICATION, \
+        CONSTRAINT ck_id CHECK NOT FOR REPLICATION (id > 0))";
+    let (columns, constraints) = match ms_and_generic().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable {
+            columns,
+            constraints,
+            ..
+        }) => (columns, constraints),
+        _ => unreachable!(),
+    };
+
+    assert_eq!(
+        columns[0].options[0].option,
+        ColumnOption::Identity(IdentityPropertyKind::Identity(IdentityProperty {
+            parameters: Some(IdentityPropertyFormatKind::FunctionCall(
+                IdentityParameters {
+                    seed: Expr::value(number("1")),
+                    increment: Expr::value(number("1")),
+                }
+            )),
+            order: None,
+            not_for_replication: true,
+        }))
+    );
+    assert_eq!(columns[0].options[1].option, ColumnOption::NotNull);
+
+    let not_for_replication = Some(ConstraintCharacteristics {
+        not_for_replication: true,
+        ..Default::default()
+    });
+    match &columns[1].options[0].option {
+        ColumnOption::ForeignKey(fk) => assert_eq!(fk.characteristics, not_for_replication),
+        _ => unreachable!(),
+    }
+    match &constraints[0] {
+        TableConstraint::ForeignKey(fk) => assert_eq!(fk.characteristics, not_for_replication),
+        _ => unreachable!(),
+    }
+    match &constraints[1] {
+        TableConstraint::Check(check) => assert!(check.not_for_replication),
+        _ => unreachable!(),
+    }
+
+    ms().verified_stmt("CREATE TABLE t (id INT IDENTITY NOT FOR REPLICATION)");
+    ms().verified_stmt("CREATE TABLE t (id INT CHECK NOT FOR REPLICATION (id > 0))");
+
+    assert!(all_dialects_except(|d| d.supports_not_for_replication())
+        .parse_sql_statements("CREATE TABLE t (id INT REFERENCES p (id) NOT FOR REPLICATION)")
+        .is_err());
+}
+
+#[test]
+fn parse_create_temporal_table() {
+    let sql = "CREATE TABLE dbo.Department (\
+        DeptID INT NOT NULL PRIMARY KEY, \
+        DeptName VARCHAR(50) NOT NULL, \
+        ValidFrom DATETIME2 GENERATED ALWAYS AS ROW START HIDDEN NOT NULL, \
+        ValidTo DATETIME2 GENERATED ALWAYS AS ROW END HIDDEN NOT NULL, \
+        PERIOD FOR SYSTEM_TIME (ValidFrom, ValidTo)\
+        ) \
+        WITH (SYSTEM_VERSIONING = ON (HISTORY_TABLE = dbo.DepartmentHistory, DATA_CONSISTENCY_CHECK = ON))";
+    let create_table = match ms_and_generic().verified_stmt(sql) {
+        Statement::CreateTable(create_table) => create_table,
+        _ => unreachable!(),
+    };
+
+    assert_eq!(
+        create_table.columns[2].options[0].option,
+        ColumnOption::GeneratedAsRow {
+            boundary: GeneratedRowBoundary::Start,
+            hidden: true,
+        }
+    );
+    assert_eq!(
+        create_table.columns[3].options[0].option,
+        ColumnOption::GeneratedAsRow {
+            boundary: GeneratedRowBoundary::End,
+            hidden: true,
+        }
+    );
+    assert_eq!(
+        create_table.periods,
+        vec![PeriodDefinition {
+            name: Ident::new("SYSTEM_TIME"),
+            start_column: Ident::new("ValidFrom"),
+            end_column: Ident::new("ValidTo"),
+        }]
+    );
+    assert_eq!(
+        create_table.table_options,
+        CreateTableOptions::With(vec![SqlOption::KeyValueWithOptions {
+            key: Ident::new("SYSTEM_VERSIONING"),
+            value: Expr::Identifier(Ident::new("ON")),
+            options: vec![
+                SqlOption::KeyValue {
+                    key: Ident::new("HISTORY_TABLE"),
+                    value: Expr::CompoundIdentifier(vec![
+                        Ident::new("dbo"),
+                        Ident::new("DepartmentHistory"),
+                    ]),
+                },
+                SqlOption::KeyValue {
+                    key: Ident::new("DATA_CONSISTENCY_CHECK"),
+                    value: Expr::Identifier(Ident::new("ON")),
+                },
+            ],
+        }])
+    );
+
+    ms().verified_stmt(
+        "CREATE TABLE t (a INT, s DATETIME2 GENERATED ALWAYS AS ROW START, e DATETIME2 GENERATED ALWAYS AS ROW END, PERIOD FOR SYSTEM_TIME (s, e)) WITH (SYSTEM_VERSIONING = ON)",
+    );
+}
+
+#[test]
+fn parse_create_synonym() {
+    assert_eq!(
+        ms_and_generic()
+            .verified_stmt("CREATE SYNONYM dbo.Orders FOR remote_srv.sales_db.dbo.Orders"),
+        Statement::CreateSynonym(CreateSynonym {
+            or_replace: false,
+            alias_keyword: false,
+            name: ObjectName::from(vec![Ident::new("dbo"), Ident::new("Orders")]),
+            for_object: ObjectName::from(vec![
+                Ident::new("remote_srv"),
+                Ident::new("sales_db"),
+                Ident::new("dbo"),
+                Ident::new("Orders"),
+            ]),
+        })
+    );
+
+    match ms().verified_stmt("CREATE OR REPLACE ALIAS app.customers FOR [sales].[customers]") {
+        Statement::CreateSynonym(CreateSynonym {
+            or_replace,
+            alias_keyword,
+            for_object,
+            ..
+        }) => {
+            assert!(or_replace);
+            assert!(alias_keyword);
+            assert_eq!(
+                for_object,
+                ObjectName::from(vec![
+                    Ident::with_quote('[', "sales"),
+                    Ident::with_quote('[', "customers"),
+                ])
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    match ms_and_generic().verified_stmt("DROP SYNONYM IF EXISTS dbo.Orders") {
+        Statement::Drop {
+            object_type,
+            if_exists,
+            names,
+            ..
+        } => {
+            assert_eq!(object_type, ObjectType::Synonym);
+            assert!(if_exists);
+            assert_eq!(
+                names,
+                vec![ObjectName::from(vec![
+                    Ident::new("dbo"),
+                    Ident::new("Orders")
+                ])]
+            );
+        }
+        _ => unreachable!(),
+    }
+    ms().verified_stmt("DROP SYNONYM Orders");
+}
+
+#[test]
+fn parse_create_table_with_computed_columns() {
+    let sql = "CREATE TABLE orders (price DECIMAL(10,2), qty INT, \
+        total AS (price * qty) PERSISTED NOT NULL, \
+        discounted AS (price * 0.9))";
+    let columns = match ms_and_generic().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable { columns, .. }) => columns,
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        columns[2],
+        ColumnDef {
+            name: "total".into(),
+            data_type: DataType::Unspecified,
+            options: vec![
+                ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::Generated {
+                        generated_as: GeneratedAs::ExpStored,
+                        sequence_options: None,
+                        generation_expr: Some(ms().verified_expr("price * qty")),
+                        generation_expr_mode: Some(GeneratedExpressionMode::Persisted),
+                        generated_keyword: false,
+                    },
+                },
+                ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::NotNull,
+                },
+            ],
+        }
+    );
+    assert_eq!(
+        columns[3],
+        ColumnDef {
+            name: "discounted".into(),
+            data_type: DataType::Unspecified,
+            options: vec![ColumnOptionDef {
+                name: None,
+                option: ColumnOption::Generated {
+                    generated_as: GeneratedAs::Always,
+                    sequence_options: None,
+                    generation_expr: Some(ms().verified_expr("price * 0.9")),
+                    generation_expr_mode: None,
+                    generated_keyword: false,
+                },
+            }],
+        }
+    );
+
+    ms().verified_stmt("CREATE TABLE t (a INT, b AS (a + 1) PERSISTED, c AS (UPPER(a)))");
+}
+
 #[test]
 fn parse_true_false_as_identifiers() {
     assert_eq!(
@@ -2433,6 +2698,7 @@ fn tsql() -> TestedDialects {
             trailing_commas: false,
             unescape: true,
             require_semicolon_stmt_delimiter: false,
+            unparsed_statements: false,
         },
     )
 }
@@ -2472,6 +2738,7 @@ fn parse_create_trigger() {
             or_alter: true,
             temporary: false,
             or_replace: false,
+            if_not_exists: false,
             is_constraint: false,
             name: ObjectName::from(vec![Ident::new("reminder1")]),
             period: Some(TriggerPeriod::After),
@@ -2834,6 +3101,27 @@ fn test_exec_dynamic_sql_string_concat() {
     );
 }
 
+#[test]
+fn parse_exec_default_argument() {
+    match ms().one_statement_parses_to(
+        "EXEC p @a = DEFAULT, @b = 1",
+        "EXECUTE p @a = DEFAULT, @b = 1",
+    ) {
+        Statement::Execute { parameters, .. } => {
+            assert_eq!(
+                parameters[0],
+                Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Ident::new("@a"))),
+                    op: BinaryOperator::Eq,
+                    right: Box::new(Expr::Default),
+                }
+            );
+        }
+        _ => unreachable!(),
+    }
+    ms().one_statement_parses_to("EXEC p 1, DEFAULT", "EXECUTE p 1, DEFAULT");
+}
+
 // MSSQL OUTPUT clause on INSERT/UPDATE/DELETE
 // https://learn.microsoft.com/en-us/sql/t-sql/queries/output-clause-transact-sql
 #[test]
@@ -2915,3 +3203,332 @@ fn parse_mssql_money_constants() {
         expr_from_projection(only(&select.projection)),
     );
 }
+
+#[test]
+fn parse_table_time_travel_ranges() {
+    let select = ms().verified_only_select(
+        "SELECT * FROM t1 FOR SYSTEM_TIME BETWEEN '2024-01-01' AND '2024-02-01' AS h",
+    );
+    match &select.from[0].relation {
+        TableFactor::Table { version, alias, .. } => {
+            assert_eq!(
+                *version,
+                Some(TableVersion::ForSystemTimeBetween {
+                    low: Expr::value(Value::SingleQuotedString("2024-01-01".to_string())),
+                    high: Expr::value(Value::SingleQuotedString("2024-02-01".to_string())),
+                })
+            );
+            assert_eq!(alias.as_ref().unwrap().name, Ident::new("h"));
+        }
+        _ => unreachable!(),
+    }
+
+    ms().verified_stmt("SELECT * FROM t1 FOR SYSTEM_TIME FROM '2024-01-01' TO '2024-02-01'");
+    ms().verified_stmt(
+        "SELECT * FROM t1 FOR SYSTEM_TIME CONTAINED IN ('2024-01-01', '2024-02-01') WHERE id = 1",
+    );
+    ms().verified_stmt("SELECT * FROM t1 FOR SYSTEM_TIME ALL ORDER BY valid_from");
+    ms().verified_stmt("SELECT * FROM t1 FOR SYSTEM_TIME AS OF @as_of");
+
+    assert!(ms()
+        .parse_sql_statements("SELECT * FROM t1 FOR SYSTEM_TIME SOME '2024-01-01'")
+        .is_err());
+}
+
+#[test]
+fn parse_mssql_set_session_options() {
+    match ms().verified_stmt("SET IDENTITY_INSERT dbo.t ON") {
+        Statement::SetSessionOption {
+            names,
+            target_table,
+            value,
+        } => {
+            assert_eq!(names, vec![Ident::new("IDENTITY_INSERT")]);
+            assert_eq!(
+                target_table,
+                Some(ObjectName::from(vec![Ident::new("dbo"), Ident::new("t")]))
+            );
+            assert_eq!(value, SessionParamValue::On);
+        }
+        _ => unreachable!(),
+    }
+
+    for (sql, expected_names, expected_value) in [
+        ("SET NOCOUNT ON", vec!["NOCOUNT"], SessionParamValue::On),
+        (
+            "SET XACT_ABORT ON",
+            vec!["XACT_ABORT"],
+            SessionParamValue::On,
+        ),
+        (
+            "SET ANSI_NULLS OFF",
+            vec!["ANSI_NULLS"],
+            SessionParamValue::Off,
+        ),
+        (
+            "SET ANSI_NULLS, QUOTED_IDENTIFIER ON",
+            vec!["ANSI_NULLS", "QUOTED_IDENTIFIER"],
+            SessionParamValue::On,
+        ),
+    ] {
+        match ms().verified_stmt(sql) {
+            Statement::SetSessionOption {
+                names,
+                target_table,
+                value,
+            } => {
+                assert_eq!(
+                    names,
+                    expected_names
+                        .into_iter()
+                        .map(Ident::new)
+                        .collect::<Vec<_>>()
+                );
+                assert_eq!(target_table, None);
+                assert_eq!(value, expected_value);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    ms().verified_stmt("SET IDENTITY_INSERT [dbo].[t] OFF");
+    ms().one_statement_parses_to("set identity_insert t on", "SET identity_insert t ON");
+
+    // Options that do not take ON or OFF keep the generic session parameter form
+    match ms().verified_stmt("SET LOCK_TIMEOUT 1800") {
+        Statement::Set(Set::SetSessionParam(SetSessionParamKind::Generic(
+            SetSessionParamGeneric { names, value },
+        ))) => {
+            assert_eq!(names, vec!["LOCK_TIMEOUT"]);
+            assert_eq!(value, "1800");
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_convert_type_before_value() {
+    let Expr::Convert {
+        expr,
+        data_type,
+        target_before_value,
+        styles,
+        ..
+    } = ms().verified_expr("CONVERT(VARCHAR(10), x, 120)")
+    else {
+        unreachable!()
+    };
+    assert_eq!(Expr::Identifier(Ident::new("x")), *expr);
+    assert_eq!(
+        Some(DataType::Varchar(Some(CharacterLength::IntegerLength {
+            length: 10,
+            unit: None
+        }))),
+        data_type
+    );
+    assert!(target_before_value);
+    assert_eq!(vec![Expr::value(number("120"))], styles);
+}
+
+#[test]
+fn parse_apply_table_valued_functions() {
+    let select = ms().verified_only_select(
+        "SELECT * FROM orders AS o CROSS APPLY get_items(o.id) AS i OUTER APPLY get_tax(i.id) AS x (rate, amount)",
+    );
+    let joins = &select.from[0].joins;
+    assert_eq!(2, joins.len());
+    assert_eq!(JoinOperator::CrossApply, joins[0].join_operator);
+    assert_eq!(JoinOperator::OuterApply, joins[1].join_operator);
+    match &joins[1].relation {
+        TableFactor::Table {
+            name,
+            args: Some(args),
+            alias: Some(alias),
+            ..
+        } => {
+            assert_eq!("get_tax", name.to_string());
+            assert_eq!(1, args.args.len());
+            assert_eq!(Ident::new("x"), alias.name);
+            assert_eq!(2, alias.columns.len());
+        }
+        r => unreachable!("{r:?}"),
+    }
+
+    ms().verified_stmt("SELECT * FROM orders o CROSS APPLY get_items(o.id) i");
+    ms().verified_stmt(
+        "SELECT * FROM orders AS o CROSS APPLY (SELECT TOP 1 * FROM items WHERE items.oid = o.id ORDER BY items.ts DESC) AS i",
+    );
+}
+
+#[test]
+fn parse_top_percent_with_ties() {
+    let select = ms().verified_only_select("SELECT TOP (10) PERCENT WITH TIES * FROM t ORDER BY x");
+    assert_eq!(
+        Some(Top {
+            with_ties: true,
+            percent: true,
+            quantity: Some(TopQuantity::Expr(Expr::value(number("10")))),
+        }),
+        select.top
+    );
+
+    let select = ms().verified_only_select("SELECT TOP (@n) * FROM t");
+    assert_eq!(
+        Some(Top {
+            with_ties: false,
+            percent: false,
+            quantity: Some(TopQuantity::Expr(Expr::Identifier(Ident::new("@n")))),
+        }),
+        select.top
+    );
+
+    let select = ms().verified_only_select("SELECT TOP 10 * FROM t");
+    assert_eq!(
+        Some(Top {
+            with_ties: false,
+            percent: false,
+            quantity: Some(TopQuantity::Constant(10)),
+        }),
+        select.top
+    );
+
+    ms().verified_stmt("SELECT TOP 10 PERCENT WITH TIES a FROM t ORDER BY a");
+    ms().verified_stmt("SELECT TOP (@n + 1) PERCENT a FROM t");
+}
+
+#[test]
+fn parse_delete_and_update_top() {
+    match ms().verified_stmt("DELETE TOP (1000) FROM t") {
+        Statement::Delete(Delete { top, .. }) => assert_eq!(
+            Some(Top {
+                with_ties: false,
+                percent: false,
+                quantity: Some(TopQuantity::Expr(Expr::value(number("1000")))),
+            }),
+            top
+        ),
+        _ => unreachable!(),
+    }
+    ms().verified_stmt("DELETE TOP (10) PERCENT FROM t WHERE a = 1");
+
+    match ms().verified_stmt("UPDATE TOP (@n) PERCENT t SET a = 1 WHERE b = 2") {
+        Statement::Update(Update { top, table, .. }) => {
+            assert_eq!(
+                Some(Top {
+                    with_ties: false,
+                    percent: true,
+                    quantity: Some(TopQuantity::Expr(Expr::Identifier(Ident::new("@n")))),
+                }),
+                top
+            );
+            assert_eq!("t", table.to_string());
+        }
+        _ => unreachable!(),
+    }
+    ms().verified_stmt("UPDATE TOP (10) t SET a = 1");
+}
+
+#[test]
+fn parse_option_query_hints() {
+    let sql = "SELECT * FROM t OPTION (MAXDOP 1, RECOMPILE, OPTIMIZE FOR (@p = 5, @q UNKNOWN))";
+    let query = ms().verified_query(sql);
+    assert_eq!(
+        vec![
+            QueryHint::Keyword {
+                keywords: vec![Ident::new("MAXDOP")],
+                has_eq: false,
+                value: Some(Expr::value(number("1"))),
+            },
+            QueryHint::Keyword {
+                keywords: vec![Ident::new("RECOMPILE")],
+                has_eq: false,
+                value: None,
+            },
+            QueryHint::OptimizeFor(vec![
+                OptimizeForVariable {
+                    name: Ident::new("@p"),
+                    value: Some(Expr::value(number("5"))),
+                },
+                OptimizeForVariable {
+                    name: Ident::new("@q"),
+                    value: None,
+                },
+            ]),
+        ],
+        query.options
+    );
+
+    let query = ms().verified_query(
+        "SELECT a FROM t ORDER BY a OPTION (HASH JOIN, MAX_GRANT_PERCENT = 25, OPTIMIZE FOR UNKNOWN)",
+    );
+    assert_eq!(
+        QueryHint::Keyword {
+            keywords: vec![Ident::new("HASH"), Ident::new("JOIN")],
+            has_eq: false,
+            value: None,
+        },
+        query.options[0]
+    );
+    assert_eq!(
+        QueryHint::Keyword {
+            keywords: vec![Ident::new("MAX_GRANT_PERCENT")],
+            has_eq: true,
+            value: Some(Expr::value(number("25"))),
+        },
+        query.options[1]
+    );
+    assert_eq!(QueryHint::OptimizeForUnknown, query.options[2]);
+
+    ms().verified_stmt(
+        "SELECT 1 OPTION (USE HINT ('DISABLE_OPTIMIZER_ROWGOAL', 'FORCE_LEGACY_CARDINALITY_ESTIMATION'))",
+    );
+    ms().verified_stmt("SELECT * FROM t WITH (NOLOCK) OPTION (FORCE ORDER)");
+    ms().verified_stmt("SELECT a FROM t FOR JSON PATH OPTION (KEEPFIXED PLAN)");
+    // `option` remains usable as an explicit alias
+    ms().verified_stmt("SELECT a AS option FROM t AS option");
+
+    match ms().verified_stmt("UPDATE t WITH (ROWLOCK) SET a = 1 WHERE b = 2 OPTION (RECOMPILE)") {
+        Statement::Update(Update { options, .. }) => assert_eq!(1, options.len()),
+        _ => unreachable!(),
+    }
+    match ms().verified_stmt("DELETE FROM t WHERE a = 1 OPTION (MAXDOP 2, LOOP JOIN)") {
+        Statement::Delete(Delete { options, .. }) => assert_eq!(2, options.len()),
+        _ => unreachable!(),
+    }
+    match ms().verified_stmt(
+        "MERGE INTO t USING s ON t.id = s.id WHEN MATCHED THEN DELETE OPTION (LOOP JOIN)",
+    ) {
+        Statement::Merge(Merge { options, .. }) => assert_eq!(1, options.len()),
+        _ => unreachable!(),
+    }
+    ms().verified_stmt("INSERT INTO t SELECT * FROM s OPTION (MAXDOP 1)");
+}
+
+#[test]
+fn parse_dml_target_table_hints() {
+    match ms().verified_stmt("INSERT INTO t WITH (TABLOCK) (a, b) VALUES (1, 2)") {
+        Statement::Insert(Insert { with_hints, .. }) => {
+            assert_eq!(vec![Expr::Identifier(Ident::new("TABLOCK"))], with_hints)
+        }
+        _ => unreachable!(),
+    }
+    ms().verified_stmt("INSERT INTO t WITH (TABLOCK, KEEPIDENTITY) SELECT * FROM s");
+
+    let sql =
+        "MERGE INTO t WITH (HOLDLOCK) AS tgt USING s ON tgt.id = s.id WHEN MATCHED THEN DELETE";
+    match ms().verified_stmt(sql) {
+        Statement::Merge(Merge {
+            table: TableFactor::Table {
+                alias, with_hints, ..
+            },
+            ..
+        }) => {
+            assert_eq!(Some(Ident::new("tgt")), alias.map(|a| a.name));
+            assert_eq!(vec![Expr::Identifier(Ident::new("HOLDLOCK"))], with_hints);
+        }
+        _ => unreachable!(),
+    }
+    ms().verified_stmt("MERGE t WITH (HOLDLOCK) USING s ON t.id = s.id WHEN MATCHED THEN DELETE");
+    ms().verified_stmt("UPDATE t WITH (ROWLOCK) SET a = 1");
+    ms().verified_stmt("DELETE FROM t WITH (TABLOCK) WHERE a = 1");
+}
@@ -24,7 +24,7 @@ use matches::assert_matches;
 
 use sqlparser::ast::MysqlInsertPriority::{Delayed, HighPriority, LowPriority};
 use sqlparser::ast::*;
-use sqlparser::dialect::{GenericDialect, MySqlDialect};
+use sqlparser::dialect::{GenericDialect, MySqlDialect, PostgreSqlDialect};
 use sqlparser::parser::{ParserError, ParserOptions};
 use sqlparser::tokenizer::Span;
 use sqlparser::tokenizer::Token;
@@ -558,12 +558,16 @@ fn parse_show_create() {
         ShowCreateObject::Function,
         ShowCreateObject::Procedure,
         ShowCreateObject::View,
+        ShowCreateObject::Database,
+        ShowCreateObject::Schema,
+        ShowCreateObject::User,
     ] {
         assert_eq!(
             mysql_and_generic().verified_stmt(format!("SHOW CREATE {obj_type} myident").as_str()),
             Statement::ShowCreate {
                 obj_type: *obj_type,
                 obj_name: obj_name.clone(),
+                format: None,
             }
         );
     }
@@ -632,6 +636,7 @@ fn parse_set_variables() {
             scope: Some(ContextModifier::Local),
             hivevar: false,
             variable: ObjectName::from(vec!["autocommit".into()]),
+            uses_to: false,
             values: vec![Expr::value(number("1"))],
         })
     );
@@ -888,6 +893,7 @@ fn test_functional_key_part() {
             data_type: DataType::Unsigned,
             array: false,
             format: None,
+            charset: None,
         })),
     );
     assert_eq!(
@@ -906,6 +912,7 @@ fn test_functional_key_part() {
             data_type: DataType::Unsigned,
             array: true,
             format: None,
+            charset: None,
         })),
     );
 }
@@ -1492,6 +1499,7 @@ fn parse_escaped_quote_identifiers_with_escape() {
             settings: None,
             format_clause: None,
             pipe_operators: vec![],
+            options: vec![],
         }))
     );
 }
@@ -1506,6 +1514,7 @@ fn parse_escaped_quote_identifiers_with_no_escape() {
                 trailing_commas: false,
                 unescape: false,
                 require_semicolon_stmt_delimiter: true,
+                unparsed_statements: false,
             }
         )
         .verified_stmt(sql),
@@ -1549,6 +1558,7 @@ fn parse_escaped_quote_identifiers_with_no_escape() {
             settings: None,
             format_clause: None,
             pipe_operators: vec![],
+            options: vec![],
         }))
     );
 }
@@ -1598,6 +1608,7 @@ fn parse_escaped_backticks_with_escape() {
             settings: None,
             format_clause: None,
             pipe_operators: vec![],
+            options: vec![],
         }))
     );
 }
@@ -1651,6 +1662,7 @@ fn parse_escaped_backticks_with_no_escape() {
             settings: None,
             format_clause: None,
             pipe_operators: vec![],
+            options: vec![],
         }))
     );
 }
@@ -1971,6 +1983,7 @@ fn parse_simple_insert() {
                     settings: None,
                     format_clause: None,
                     pipe_operators: vec![],
+                    options: vec![],
                 })),
                 source
             );
@@ -2027,6 +2040,7 @@ fn parse_ignore_insert() {
                     settings: None,
                     format_clause: None,
                     pipe_operators: vec![],
+                    options: vec![],
                 })),
                 source
             );
@@ -2083,6 +2097,7 @@ fn parse_priority_insert() {
                     settings: None,
                     format_clause: None,
                     pipe_operators: vec![],
+                    options: vec![],
                 })),
                 source
             );
@@ -2136,6 +2151,7 @@ fn parse_priority_insert() {
                     settings: None,
                     format_clause: None,
                     pipe_operators: vec![],
+                    options: vec![],
                 })),
                 source
             );
@@ -2188,6 +2204,7 @@ fn parse_insert_as() {
                     settings: None,
                     format_clause: None,
                     pipe_operators: vec![],
+                    options: vec![],
                 })),
                 source
             );
@@ -2255,6 +2272,7 @@ fn parse_insert_as() {
                     settings: None,
                     format_clause: None,
                     pipe_operators: vec![],
+                    options: vec![],
                 })),
                 source
             );
@@ -2312,6 +2330,7 @@ fn parse_replace_insert() {
                     settings: None,
                     format_clause: None,
                     pipe_operators: vec![],
+                    options: vec![],
                 })),
                 source
             );
@@ -2357,6 +2376,7 @@ fn parse_empty_row_insert() {
                     settings: None,
                     format_clause: None,
                     pipe_operators: vec![],
+                    options: vec![],
                 })),
                 source
             );
@@ -2423,6 +2443,7 @@ fn parse_insert_with_on_duplicate_update() {
                     settings: None,
                     format_clause: None,
                     pipe_operators: vec![],
+                    options: vec![],
                 })),
                 source
             );
@@ -2725,8 +2746,10 @@ fn parse_update_with_joins() {
             order_by: _,
             limit: None,
             optimizer_hints,
+            top: None,
             update_token: _,
             output: _,
+            options: _,
         }) if optimizer_hints.is_empty() => {
             assert_eq!(
                 TableWithJoins {
@@ -2741,6 +2764,7 @@ fn parse_update_with_joins() {
                         json_path: None,
                         sample: None,
                         index_hints: vec![],
+                        final_modifier: false,
                     },
                     joins: vec![Join {
                         relation: TableFactor::Table {
@@ -2754,8 +2778,10 @@ fn parse_update_with_joins() {
                             json_path: None,
                             sample: None,
                             index_hints: vec![],
+                            final_modifier: false,
                         },
                         global: false,
+                        strictness: None,
                         join_operator: JoinOperator::Join(JoinConstraint::On(Expr::BinaryOp {
                             left: Box::new(Expr::CompoundIdentifier(vec![
                                 Ident::new("o"),
@@ -3397,6 +3423,7 @@ fn parse_substring_in_select() {
                     settings: None,
                     format_clause: None,
                     pipe_operators: vec![],
+                    options: vec![],
                 }),
                 query
             );
@@ -3493,6 +3520,41 @@ fn parse_table_column_option_on_update() {
     }
 }
 
+#[test]
+fn parse_column_on_update_precision_and_parenthesized_default() {
+    let sql = "CREATE TABLE t (updated_at TIMESTAMP(6) DEFAULT (CURRENT_TIMESTAMP(6)) ON UPDATE CURRENT_TIMESTAMP(6), uid VARCHAR(36) DEFAULT (uuid()))";
+    match mysql_and_generic().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable { columns, .. }) => {
+            let options: Vec<_> = columns[0].options.iter().map(|o| &o.option).collect();
+            assert_eq!(
+                vec![
+                    &ColumnOption::Default(Expr::Nested(Box::new(call(
+                        "CURRENT_TIMESTAMP",
+                        [Expr::value(number("6"))]
+                    )))),
+                    &ColumnOption::OnUpdate(call("CURRENT_TIMESTAMP", [Expr::value(number("6"))])),
+                ],
+                options
+            );
+            assert_eq!(
+                vec![ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::Default(Expr::Nested(Box::new(call("uuid", [])))),
+                }],
+                columns[1].options
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    mysql_and_generic().verified_stmt(
+        "CREATE TABLE t (updated_at TIMESTAMP(6) DEFAULT CURRENT_TIMESTAMP(6) ON UPDATE CURRENT_TIMESTAMP(6))",
+    );
+    mysql_and_generic().verified_stmt(
+        "ALTER TABLE t ADD COLUMN u TIMESTAMP(3) DEFAULT (NOW(3)) ON UPDATE CURRENT_TIMESTAMP(3)",
+    );
+}
+
 #[test]
 fn parse_set_names() {
     let stmt = mysql_and_generic().verified_stmt("SET NAMES utf8mb4");
@@ -3728,6 +3790,7 @@ fn parse_hex_string_introducer() {
             settings: None,
             format_clause: None,
             pipe_operators: vec![],
+            options: vec![],
         }))
     )
 }
@@ -4213,6 +4276,7 @@ fn parse_create_trigger() {
             or_alter: false,
             temporary: false,
             or_replace: false,
+            if_not_exists: false,
             is_constraint: false,
             name: ObjectName::from(vec![Ident::new("emp_stamp")]),
             period: Some(TriggerPeriod::Before),
@@ -4894,3 +4958,587 @@ fn parse_create_database_with_charset_option_ordering() {
         "CREATE DATABASE mydb DEFAULT CHARACTER SET utf8mb4 DEFAULT COLLATE utf8mb4_unicode_ci",
     );
 }
+
+#[test]
+fn parse_default_function() {
+    let select = mysql().verified_only_select("SELECT DEFAULT(a) FROM t WHERE b = DEFAULT(b)");
+    assert_eq!(
+        expr_from_projection(only(&select.projection)),
+        &call("DEFAULT", [Expr::Identifier(Ident::new("a"))])
+    );
+    mysql().verified_stmt("INSERT INTO t (a, b) VALUES (DEFAULT(a) + 1, DEFAULT)");
+    mysql().verified_stmt("UPDATE t SET a = DEFAULT(a) * 2, b = DEFAULT");
+    mysql().verified_stmt("INSERT INTO t (a) VALUES (1) ON DUPLICATE KEY UPDATE a = DEFAULT");
+}
+
+#[test]
+fn parse_group_concat_clauses() {
+    let select = mysql_and_generic().verified_only_select(
+        "SELECT GROUP_CONCAT(DISTINCT first_name, last_name ORDER BY last_name DESC SEPARATOR ', ') FROM t",
+    );
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function {
+            args:
+                FunctionArguments::List(FunctionArgumentList {
+                    duplicate_treatment,
+                    args,
+                    clauses,
+                }),
+            ..
+        }) => {
+            assert_eq!(duplicate_treatment, &Some(DuplicateTreatment::Distinct));
+            assert_eq!(args.len(), 2);
+            assert_eq!(
+                clauses,
+                &vec![
+                    FunctionArgumentClause::OrderBy(vec![OrderByExpr {
+                        expr: Expr::Identifier(Ident::new("last_name")),
+                        options: OrderByOptions {
+                            asc: Some(false),
+                            nulls_first: None,
+                        },
+                        with_fill: None,
+                    }]),
+                    FunctionArgumentClause::Separator(
+                        Value::SingleQuotedString(", ".to_string()).with_empty_span()
+                    ),
+                ]
+            );
+        }
+        e => panic!("unexpected expression: {e:?}"),
+    }
+
+    let select =
+        mysql_and_generic().verified_only_select("SELECT GROUP_CONCAT(x SEPARATOR '') FROM t");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function {
+            args: FunctionArguments::List(FunctionArgumentList { clauses, .. }),
+            ..
+        }) => assert_eq!(
+            clauses,
+            &vec![FunctionArgumentClause::Separator(
+                Value::SingleQuotedString(String::new()).with_empty_span()
+            )]
+        ),
+        e => panic!("unexpected expression: {e:?}"),
+    }
+}
+
+#[test]
+fn parse_charset_aware_conversions() {
+    // MySQL puts the value before the target type, unlike MSSQL
+    let Expr::Convert {
+        expr,
+        data_type,
+        charset,
+        target_before_value,
+        ..
+    } = mysql().verified_expr("CONVERT(x, CHAR(10))")
+    else {
+        unreachable!()
+    };
+    assert_eq!(Expr::Identifier(Ident::new("x")), *expr);
+    assert_eq!(
+        Some(DataType::Char(Some(CharacterLength::IntegerLength {
+            length: 10,
+            unit: None
+        }))),
+        data_type
+    );
+    assert!(charset.is_none());
+    assert!(!target_before_value);
+
+    let Expr::Convert {
+        data_type, charset, ..
+    } = mysql().verified_expr("CONVERT('abc' USING utf8mb4)")
+    else {
+        unreachable!()
+    };
+    assert!(data_type.is_none());
+    assert_eq!(Some(ObjectName::from(vec![Ident::new("utf8mb4")])), charset);
+
+    let Expr::Cast {
+        data_type, charset, ..
+    } = mysql().verified_expr("CAST(x AS CHAR CHARACTER SET utf8mb4)")
+    else {
+        unreachable!()
+    };
+    assert_eq!(DataType::Char(None), data_type);
+    assert_eq!(Some(ObjectName::from(vec![Ident::new("utf8mb4")])), charset);
+    mysql().verified_expr("CAST(x AS CHAR(10) CHARACTER SET latin1)");
+
+    mysql().verified_stmt("SELECT WEIGHT_STRING('ab'), HEX(WEIGHT_STRING(x)) FROM t");
+}
+
+#[test]
+fn parse_set_character_set() {
+    assert_eq!(
+        mysql_and_generic().verified_stmt("SET CHARACTER SET utf8mb4"),
+        Statement::Set(Set::SetCharacterSet {
+            charset_name: Ident::new("utf8mb4"),
+        })
+    );
+    mysql_and_generic().verified_stmt("SET CHARACTER SET DEFAULT");
+    mysql_and_generic().verified_stmt("SET NAMES utf8mb4 COLLATE utf8mb4_bin");
+    mysql_and_generic().verified_stmt("SET @a = 1, @@session.sql_mode = 'ANSI'");
+}
+
+#[test]
+fn parse_alter_table_convert_to_character_set() {
+    assert_eq!(
+        alter_table_op(mysql_and_generic().verified_stmt(
+            "ALTER TABLE tab CONVERT TO CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci"
+        )),
+        AlterTableOperation::ConvertToCharacterSet {
+            charset: Ident::new("utf8mb4"),
+            collation: Some(Ident::new("utf8mb4_unicode_ci")),
+        }
+    );
+    assert_eq!(
+        alter_table_op(
+            mysql_and_generic().verified_stmt("ALTER TABLE tab CONVERT TO CHARACTER SET latin1")
+        ),
+        AlterTableOperation::ConvertToCharacterSet {
+            charset: Ident::new("latin1"),
+            collation: None,
+        }
+    );
+    mysql_and_generic()
+        .verified_stmt("ALTER TABLE tab CONVERT TO CHARACTER SET utf8mb4, ALGORITHM = INPLACE");
+
+    // `DEFAULT CHARACTER SET` is a table option, not a conversion
+    assert!(mysql()
+        .parse_sql_statements("ALTER TABLE tab CONVERT TO DEFAULT CHARACTER SET utf8mb4")
+        .is_err());
+}
+
+#[test]
+fn parse_alter_table_force() {
+    assert_eq!(
+        alter_table_op(mysql_and_generic().verified_stmt("ALTER TABLE tab FORCE")),
+        AlterTableOperation::Force
+    );
+    match mysql_and_generic()
+        .verified_stmt("ALTER TABLE tab FORCE, ALGORITHM = INPLACE, LOCK = NONE")
+    {
+        Statement::AlterTable(AlterTable { operations, .. }) => {
+            assert_eq!(
+                operations,
+                vec![
+                    AlterTableOperation::Force,
+                    AlterTableOperation::Algorithm {
+                        equals: true,
+                        algorithm: AlterTableAlgorithm::Inplace,
+                    },
+                    AlterTableOperation::Lock {
+                        equals: true,
+                        lock: AlterTableLock::None,
+                    },
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    assert!(TestedDialects::new(vec![Box::new(PostgreSqlDialect {})])
+        .parse_sql_statements("ALTER TABLE tab FORCE")
+        .is_err());
+}
+
+#[test]
+fn parse_alter_table_inherit_unsupported() {
+    assert!(mysql()
+        .parse_sql_statements("ALTER TABLE tab INHERIT parent")
+        .is_err());
+    assert!(mysql()
+        .parse_sql_statements("ALTER TABLE tab NO INHERIT parent")
+        .is_err());
+}
+
+#[test]
+fn parse_create_view_params_and_check_option() {
+    let sql = "CREATE ALGORITHM = MERGE DEFINER = admin@'%' SQL SECURITY DEFINER VIEW v AS SELECT a FROM t WITH LOCAL CHECK OPTION";
+    match mysql().verified_stmt(sql) {
+        Statement::CreateView(CreateView {
+            params: Some(params),
+            with_check_option,
+            ..
+        }) => {
+            assert_eq!(
+                params,
+                CreateViewParams {
+                    algorithm: Some(CreateViewAlgorithm::Merge),
+                    definer: Some(GranteeName::UserHost {
+                        user: Ident::new("admin"),
+                        host: Ident::with_quote('\'', "%"),
+                    }),
+                    security: Some(CreateViewSecurity::Definer),
+                }
+            );
+            assert_eq!(Some(ViewCheckOption::Local), with_check_option);
+        }
+        _ => unreachable!(),
+    }
+
+    mysql().verified_stmt("CREATE VIEW v AS SELECT a FROM t WITH CHECK OPTION");
+    mysql().verified_stmt(
+        "CREATE OR REPLACE SQL SECURITY INVOKER VIEW v (a) AS SELECT a FROM t WITH CASCADED CHECK OPTION",
+    );
+    mysql().verified_stmt("CREATE DEFINER = 'admin'@'localhost' VIEW v AS SELECT 1");
+}
+
+#[test]
+fn parse_create_event() {
+    let sql = "CREATE DEFINER = 'admin'@'localhost' EVENT IF NOT EXISTS db.e1 ON SCHEDULE AT CURRENT_TIMESTAMP + INTERVAL 1 HOUR ON COMPLETION NOT PRESERVE DISABLE ON REPLICA COMMENT 'one shot' DO UPDATE t SET a = a + 1";
+    match mysql().verified_stmt(sql) {
+        Statement::CreateEvent(CreateEvent {
+            definer,
+            if_not_exists,
+            name,
+            schedule,
+            on_completion,
+            status,
+            comment,
+            body,
+        }) => {
+            assert_eq!(
+                Some(GranteeName::UserHost {
+                    user: Ident::with_quote('\'', "admin"),
+                    host: Ident::with_quote('\'', "localhost"),
+                }),
+                definer
+            );
+            assert!(if_not_exists);
+            assert_eq!("db.e1", name.to_string());
+            assert_matches!(schedule, EventSchedule::At(_));
+            assert_eq!(Some(EventOnCompletion::NotPreserve), on_completion);
+            assert_eq!(Some(EventStatus::DisableOnReplica), status);
+            assert_eq!(Some("one shot".to_string()), comment);
+            assert_matches!(*body, Statement::Update(_));
+        }
+        _ => unreachable!(),
+    }
+
+    let sql = "CREATE EVENT e2 ON SCHEDULE EVERY 1 DAY STARTS '2024-01-01 00:00:00' ENDS '2024-12-31 00:00:00' ON COMPLETION PRESERVE ENABLE DO DELETE FROM logs WHERE ts < NOW()";
+    match mysql().verified_stmt(sql) {
+        Statement::CreateEvent(CreateEvent {
+            definer: None,
+            schedule:
+                EventSchedule::Every {
+                    quantity,
+                    unit,
+                    starts,
+                    ends,
+                },
+            on_completion,
+            status,
+            ..
+        }) => {
+            assert_eq!(Expr::value(number("1")), quantity);
+            assert_eq!(DateTimeField::Day, unit);
+            assert_eq!(
+                Some(Expr::value(Value::SingleQuotedString(
+                    "2024-01-01 00:00:00".to_string()
+                ))),
+                starts
+            );
+            assert_eq!(
+                Some(Expr::value(Value::SingleQuotedString(
+                    "2024-12-31 00:00:00".to_string()
+                ))),
+                ends
+            );
+            assert_eq!(Some(EventOnCompletion::Preserve), on_completion);
+            assert_eq!(Some(EventStatus::Enable), status);
+        }
+        _ => unreachable!(),
+    }
+
+    mysql()
+        .verified_stmt("CREATE EVENT e3 ON SCHEDULE EVERY 30 MINUTE DO INSERT INTO t VALUES (1)");
+    assert!(TestedDialects::new(vec![Box::new(PostgreSqlDialect {})])
+        .parse_sql_statements("CREATE EVENT e ON SCHEDULE EVERY 1 DAY DO SELECT 1")
+        .is_err());
+}
+
+#[test]
+fn parse_alter_and_drop_event() {
+    match mysql().verified_stmt(
+        "ALTER DEFINER = admin EVENT e1 ON SCHEDULE EVERY 2 HOUR RENAME TO e2 DISABLE COMMENT 'paused' DO DELETE FROM t",
+    ) {
+        Statement::AlterEvent(AlterEvent {
+            definer,
+            name,
+            schedule,
+            rename_to,
+            status,
+            body,
+            ..
+        }) => {
+            assert_eq!(
+                Some(GranteeName::ObjectName(ObjectName::from(vec![
+                    Ident::new("admin")
+                ]))),
+                definer
+            );
+            assert_eq!("e1", name.to_string());
+            assert_matches!(schedule, Some(EventSchedule::Every { .. }));
+            assert_eq!(Some("e2".to_string()), rename_to.map(|n| n.to_string()));
+            assert_eq!(Some(EventStatus::Disable), status);
+            assert!(body.is_some());
+        }
+        _ => unreachable!(),
+    }
+    mysql().verified_stmt("ALTER EVENT e1 ON COMPLETION PRESERVE ENABLE");
+
+    match mysql().verified_stmt("DROP EVENT IF EXISTS e1") {
+        Statement::Drop {
+            object_type,
+            if_exists,
+            ..
+        } => {
+            assert_eq!(ObjectType::Event, object_type);
+            assert!(if_exists);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_alter_table_add_multiple_columns() {
+    match alter_table_op(
+        mysql().verified_stmt(
+            "ALTER TABLE tab ADD COLUMN (a INT NOT NULL, b VARCHAR(10) DEFAULT 'x')",
+        ),
+    ) {
+        AlterTableOperation::AddColumns {
+            column_defs,
+            parenthesized,
+            ..
+        } => {
+            assert!(parenthesized);
+            assert_eq!(2, column_defs.len());
+        }
+        op => panic!("unexpected operation: {op:?}"),
+    }
+    mysql().verified_stmt("ALTER TABLE tab ADD (a INT, b INT), ALGORITHM = INPLACE");
+    mysql().verified_stmt("ALTER TABLE tab ADD a INT, b INT");
+    mysql().verified_stmt("ALTER TABLE tab ADD COLUMN a INT FIRST, ADD COLUMN b INT AFTER a");
+    mysql().verified_stmt("ALTER TABLE tab ADD COLUMN a INT, ALGORITHM = INPLACE");
+}
+
+#[test]
+fn parse_column_check_enforced() {
+    let sql = "CREATE TABLE t (c INT CONSTRAINT chk CHECK (c > 0) NOT ENFORCED, d INT CHECK (d < 10) ENFORCED)";
+    match mysql_and_generic().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable { columns, .. }) => {
+            assert_eq!(
+                vec![ColumnOptionDef {
+                    name: Some(Ident::new("chk")),
+                    option: ColumnOption::Check(CheckConstraint {
+                        name: None,
+                        expr: Box::new(Expr::BinaryOp {
+                            left: Box::new(Expr::Identifier(Ident::new("c"))),
+                            op: BinaryOperator::Gt,
+                            right: Box::new(Expr::value(number("0"))),
+                        }),
+                        no_inherit: false,
+                        enforced: Some(false),
+                        not_for_replication: false,
+                    }),
+                }],
+                columns[0].options
+            );
+            match &columns[1].options[0].option {
+                ColumnOption::Check(check) => assert_eq!(Some(true), check.enforced),
+                option => panic!("unexpected option: {option:?}"),
+            }
+        }
+        _ => unreachable!(),
+    }
+
+    match alter_table_op(
+        mysql_and_generic()
+            .verified_stmt("ALTER TABLE tab ADD COLUMN c INT CHECK (c > 0) NOT ENFORCED"),
+    ) {
+        AlterTableOperation::AddColumn { column_def, .. } => match &column_def.options[0].option {
+            ColumnOption::Check(check) => assert_eq!(Some(false), check.enforced),
+            option => panic!("unexpected option: {option:?}"),
+        },
+        op => panic!("unexpected operation: {op:?}"),
+    }
+
+    // Without a flag the check is printed unchanged.
+    mysql_and_generic().verified_stmt("CREATE TABLE t (c INT CHECK (c > 0))");
+}
+
+#[test]
+fn parse_alter_table_comment() {
+    assert_eq!(
+        alter_table_op(
+            mysql_and_generic().verified_stmt("ALTER TABLE tab COMMENT = 'new comment'")
+        ),
+        AlterTableOperation::SetComment {
+            comment: "new comment".to_string(),
+            has_eq: true,
+        }
+    );
+    assert_eq!(
+        alter_table_op(mysql_and_generic().verified_stmt("ALTER TABLE tab COMMENT 'it''s new'")),
+        AlterTableOperation::SetComment {
+            comment: "it's new".to_string(),
+            has_eq: false,
+        }
+    );
+}
+
+#[test]
+fn parse_values_row_constructor() {
+    match mysql().verified_stmt("INSERT INTO t1 VALUES ROW(1, 2), ROW(3, 4)") {
+        Statement::Insert(Insert {
+            source: Some(source),
+            ..
+        }) => match *source.body {
+            SetExpr::Values(Values {
+                explicit_row, rows, ..
+            }) => {
+                assert!(explicit_row);
+                assert_eq!(rows.len(), 2);
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+
+    let query = mysql().verified_query("VALUES ROW(1, -2), ROW(5, 7) ORDER BY column_0");
+    assert!(query.order_by.is_some());
+    mysql().verified_stmt("VALUES ROW(1), ROW(2) UNION VALUES ROW(3)");
+}
+
+#[test]
+fn parse_insert_table_source() {
+    match mysql().verified_stmt("INSERT INTO t1 TABLE t2") {
+        Statement::Insert(Insert {
+            source: Some(source),
+            ..
+        }) => assert_eq!(
+            *source.body,
+            SetExpr::Table(Box::new(Table {
+                table_name: Some("t2".to_string()),
+                schema_name: None,
+            }))
+        ),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_alter_view_as_query() {
+    match mysql().verified_stmt("ALTER VIEW v (a, b) AS SELECT x, y FROM t") {
+        Statement::AlterView {
+            if_exists: false,
+            name,
+            operation:
+                AlterViewOperation::As {
+                    columns,
+                    query,
+                    with_options,
+                },
+        } => {
+            assert_eq!(name.to_string(), "v");
+            assert_eq!(columns, vec![Ident::new("a"), Ident::new("b")]);
+            assert_eq!(query.to_string(), "SELECT x, y FROM t");
+            assert!(with_options.is_empty());
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_create_table_with_system_versioning() {
+    let sql = concat!(
+        "CREATE TABLE t (",
+        "x INT, ",
+        "row_start TIMESTAMP(6) GENERATED ALWAYS AS ROW START INVISIBLE, ",
+        "row_end TIMESTAMP(6) GENERATED ALWAYS AS ROW END INVISIBLE, ",
+        "PERIOD FOR SYSTEM_TIME (row_start, row_end)",
+        ") WITH SYSTEM VERSIONING"
+    );
+    match mysql_and_generic().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable {
+            columns,
+            periods,
+            with_system_versioning,
+            ..
+        }) => {
+            assert!(with_system_versioning);
+            assert_eq!(
+                periods,
+                vec![PeriodDefinition {
+                    name: Ident::new("SYSTEM_TIME"),
+                    start_column: Ident::new("row_start"),
+                    end_column: Ident::new("row_end"),
+                }]
+            );
+            assert_eq!(
+                columns[1].options[0].option,
+                ColumnOption::GeneratedAsRow {
+                    boundary: GeneratedRowBoundary::Start,
+                    hidden: false,
+                }
+            );
+            assert_eq!(
+                columns[2].options[0].option,
+                ColumnOption::GeneratedAsRow {
+                    boundary: GeneratedRowBoundary::End,
+                    hidden: false,
+                }
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    mysql_and_generic().verified_stmt(
+        "CREATE TABLE t (x INT, PRIMARY KEY (x)) ENGINE = InnoDB WITH SYSTEM VERSIONING",
+    );
+    mysql_and_generic().one_statement_parses_to(
+        "CREATE TABLE t (x INT) WITH SYSTEM VERSIONING ENGINE = InnoDB",
+        "CREATE TABLE t (x INT) ENGINE = InnoDB WITH SYSTEM VERSIONING",
+    );
+    // `period` is still a valid column name
+    mysql().verified_stmt("CREATE TABLE t (period INT, x INT)");
+}
+
+#[test]
+fn parse_alter_table_system_versioning() {
+    match mysql_and_generic().verified_stmt("ALTER TABLE t ADD SYSTEM VERSIONING") {
+        Statement::AlterTable(AlterTable { operations, .. }) => {
+            assert_eq!(operations, vec![AlterTableOperation::AddSystemVersioning]);
+        }
+        _ => unreachable!(),
+    }
+    match mysql_and_generic().verified_stmt("ALTER TABLE t DROP SYSTEM VERSIONING") {
+        Statement::AlterTable(AlterTable { operations, .. }) => {
+            assert_eq!(operations, vec![AlterTableOperation::DropSystemVersioning]);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_select_for_system_time() {
+    let select = mysql().verified_only_select("SELECT * FROM t FOR SYSTEM_TIME ALL");
+    match &select.from[0].relation {
+        TableFactor::Table { version, .. } => {
+            assert_eq!(version, &Some(TableVersion::ForSystemTimeAll));
+        }
+        _ => unreachable!(),
+    }
+    mysql().verified_stmt("SELECT * FROM t FOR SYSTEM_TIME AS OF TIMESTAMP '2016-10-09 08:07:06'");
+    mysql().verified_stmt(
+        "SELECT * FROM t FOR SYSTEM_TIME BETWEEN (NOW() - INTERVAL 1 YEAR) AND NOW() AS h",
+    );
+    mysql().verified_stmt(
+        "SELECT * FROM t FOR SYSTEM_TIME FROM '2016-01-01 00:00:00' TO '2017-01-01 00:00:00'",
+    );
+}
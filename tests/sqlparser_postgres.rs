@@ -78,7 +78,7 @@ fn parse_create_table_generated_always_as_identity() {
     pg().one_statement_parses_to(
         sql,
         "CREATE TABLE table2 (\
-        column23 BIGINT GENERATED BY DEFAULT AS IDENTITY ( INCREMENT 1 MINVALUE 1 MAXVALUE 20 START WITH 10 CACHE 2 NO CYCLE ), \
+        column23 BIGINT GENERATED BY DEFAULT AS IDENTITY (INCREMENT 1 MINVALUE 1 MAXVALUE 20 START WITH 10 CACHE 2 NO CYCLE), \
         column30 TEXT)",
     );
 
@@ -88,7 +88,7 @@ fn parse_create_table_generated_always_as_identity() {
     pg().one_statement_parses_to(
         sql,
         "CREATE TABLE table2 (\
-        column24 BIGINT GENERATED BY DEFAULT AS IDENTITY ( INCREMENT 1 MINVALUE 1 MAXVALUE 20 START WITH 10 CACHE 2 CYCLE ), \
+        column24 BIGINT GENERATED BY DEFAULT AS IDENTITY (INCREMENT 1 MINVALUE 1 MAXVALUE 20 START WITH 10 CACHE 2 CYCLE), \
         column30 TEXT)",
     );
 
@@ -98,7 +98,7 @@ fn parse_create_table_generated_always_as_identity() {
     pg().one_statement_parses_to(
         sql,
         "CREATE TABLE table2 (\
-        column25 BIGINT GENERATED BY DEFAULT AS IDENTITY ( INCREMENT 1 MINVALUE 1 MAXVALUE 20 START WITH 10 CACHE 2 ), \
+        column25 BIGINT GENERATED BY DEFAULT AS IDENTITY (INCREMENT 1 MINVALUE 1 MAXVALUE 20 START WITH 10 CACHE 2), \
         column30 TEXT)",
     );
     let sql = "CREATE TABLE table2 (
@@ -107,7 +107,7 @@ fn parse_create_table_generated_always_as_identity() {
     pg().one_statement_parses_to(
         sql,
         "CREATE TABLE table2 (\
-        column26 BIGINT GENERATED BY DEFAULT AS IDENTITY ( INCREMENT 1 MINVALUE 1 MAXVALUE 20 START WITH 10 ), \
+        column26 BIGINT GENERATED BY DEFAULT AS IDENTITY (INCREMENT 1 MINVALUE 1 MAXVALUE 20 START WITH 10), \
         column30 TEXT)",
     );
     let sql = "CREATE TABLE table2 (
@@ -116,7 +116,7 @@ fn parse_create_table_generated_always_as_identity() {
     pg().one_statement_parses_to(
         sql,
         "CREATE TABLE table2 (\
-        column27 BIGINT GENERATED BY DEFAULT AS IDENTITY ( INCREMENT 1 MINVALUE 1 MAXVALUE 20 ), \
+        column27 BIGINT GENERATED BY DEFAULT AS IDENTITY (INCREMENT 1 MINVALUE 1 MAXVALUE 20), \
         column30 TEXT)",
     );
     let sql = "CREATE TABLE table2 (
@@ -125,7 +125,7 @@ fn parse_create_table_generated_always_as_identity() {
     pg().one_statement_parses_to(
         sql,
         "CREATE TABLE table2 (\
-        column28 BIGINT GENERATED BY DEFAULT AS IDENTITY ( INCREMENT 1 MINVALUE 1 ), \
+        column28 BIGINT GENERATED BY DEFAULT AS IDENTITY (INCREMENT 1 MINVALUE 1), \
         column30 TEXT)",
     );
     let sql = "CREATE TABLE table2 (
@@ -134,7 +134,7 @@ fn parse_create_table_generated_always_as_identity() {
     pg().one_statement_parses_to(
         sql,
         "CREATE TABLE table2 (\
-        column29 BIGINT GENERATED BY DEFAULT AS IDENTITY ( INCREMENT 1 ), \
+        column29 BIGINT GENERATED BY DEFAULT AS IDENTITY (INCREMENT 1), \
         column30 TEXT)",
     );
 
@@ -153,7 +153,7 @@ fn parse_create_table_generated_always_as_identity() {
     pg().one_statement_parses_to(
         sql,
         "CREATE TABLE table2 (\
-        column23 BIGINT GENERATED ALWAYS AS IDENTITY ( INCREMENT 1 MINVALUE 1 MAXVALUE 20 START WITH 10 CACHE 2 NO CYCLE ), \
+        column23 BIGINT GENERATED ALWAYS AS IDENTITY (INCREMENT 1 MINVALUE 1 MAXVALUE 20 START WITH 10 CACHE 2 NO CYCLE), \
         column30 TEXT)",
     );
 
@@ -163,7 +163,7 @@ fn parse_create_table_generated_always_as_identity() {
     pg().one_statement_parses_to(
         sql,
         "CREATE TABLE table2 (\
-        column24 BIGINT GENERATED ALWAYS AS IDENTITY ( INCREMENT 1 MINVALUE 1 MAXVALUE 20 START WITH 10 CACHE 2 CYCLE ), \
+        column24 BIGINT GENERATED ALWAYS AS IDENTITY (INCREMENT 1 MINVALUE 1 MAXVALUE 20 START WITH 10 CACHE 2 CYCLE), \
         column30 TEXT)",
     );
 
@@ -173,7 +173,7 @@ fn parse_create_table_generated_always_as_identity() {
     pg().one_statement_parses_to(
         sql,
         "CREATE TABLE table2 (\
-        column25 BIGINT GENERATED ALWAYS AS IDENTITY ( INCREMENT 1 MINVALUE 1 MAXVALUE 20 START WITH 10 CACHE 2 ), \
+        column25 BIGINT GENERATED ALWAYS AS IDENTITY (INCREMENT 1 MINVALUE 1 MAXVALUE 20 START WITH 10 CACHE 2), \
         column30 TEXT)",
     );
     let sql = "CREATE TABLE table2 (
@@ -182,7 +182,7 @@ fn parse_create_table_generated_always_as_identity() {
     pg().one_statement_parses_to(
         sql,
         "CREATE TABLE table2 (\
-        column26 BIGINT GENERATED ALWAYS AS IDENTITY ( INCREMENT 1 MINVALUE 1 MAXVALUE 20 START WITH 10 ), \
+        column26 BIGINT GENERATED ALWAYS AS IDENTITY (INCREMENT 1 MINVALUE 1 MAXVALUE 20 START WITH 10), \
         column30 TEXT)",
     );
     let sql = "CREATE TABLE table2 (
@@ -191,7 +191,7 @@ fn parse_create_table_generated_always_as_identity() {
     pg().one_statement_parses_to(
         sql,
         "CREATE TABLE table2 (\
-        column27 BIGINT GENERATED ALWAYS AS IDENTITY ( INCREMENT 1 MINVALUE 1 MAXVALUE 20 ), \
+        column27 BIGINT GENERATED ALWAYS AS IDENTITY (INCREMENT 1 MINVALUE 1 MAXVALUE 20), \
         column30 TEXT)",
     );
     let sql = "CREATE TABLE table2 (
@@ -200,7 +200,7 @@ fn parse_create_table_generated_always_as_identity() {
     pg().one_statement_parses_to(
         sql,
         "CREATE TABLE table2 (\
-        column28 BIGINT GENERATED ALWAYS AS IDENTITY ( INCREMENT 1 MINVALUE 1 ), \
+        column28 BIGINT GENERATED ALWAYS AS IDENTITY (INCREMENT 1 MINVALUE 1), \
         column30 TEXT)",
     );
     let sql = "CREATE TABLE table2 (
@@ -209,7 +209,7 @@ fn parse_create_table_generated_always_as_identity() {
     pg().one_statement_parses_to(
         sql,
         "CREATE TABLE table2 (\
-        column29 BIGINT GENERATED ALWAYS AS IDENTITY ( INCREMENT 1 ), \
+        column29 BIGINT GENERATED ALWAYS AS IDENTITY (INCREMENT 1), \
         column30 TEXT)",
     );
     let sql = "CREATE TABLE table2 (
@@ -1049,9 +1049,9 @@ fn parse_alter_table_alter_column_add_generated() {
         .verified_stmt("ALTER TABLE t ALTER COLUMN id ADD GENERATED BY DEFAULT AS IDENTITY");
     pg_and_generic().verified_stmt("ALTER TABLE t ALTER COLUMN id ADD GENERATED AS IDENTITY");
     pg_and_generic().verified_stmt(
-        "ALTER TABLE t ALTER COLUMN id ADD GENERATED AS IDENTITY ( INCREMENT 1 MINVALUE 1 )",
+        "ALTER TABLE t ALTER COLUMN id ADD GENERATED AS IDENTITY (INCREMENT 1 MINVALUE 1)",
     );
-    pg_and_generic().verified_stmt("ALTER TABLE t ALTER COLUMN id ADD GENERATED AS IDENTITY ( )");
+    pg_and_generic().verified_stmt("ALTER TABLE t ALTER COLUMN id ADD GENERATED AS IDENTITY ()");
 
     let res = pg().parse_sql_statements(
         "ALTER TABLE t ALTER COLUMN id ADD GENERATED ( INCREMENT 1 MINVALUE 1 )",
@@ -1062,7 +1062,7 @@ fn parse_alter_table_alter_column_add_generated() {
     );
 
     let res = pg().parse_sql_statements(
-        "ALTER TABLE t ALTER COLUMN id ADD GENERATED AS IDENTITY ( INCREMENT )",
+        "ALTER TABLE t ALTER COLUMN id ADD GENERATED AS IDENTITY (INCREMENT)",
     );
     assert_eq!(
         ParserError::ParserError("Expected: a value, found: )".to_string()),
@@ -1641,6 +1641,7 @@ fn parse_copy_to() {
                 settings: None,
                 format_clause: None,
                 pipe_operators: vec![],
+                options: vec![],
             })),
             to: true,
             target: CopyTarget::File {
@@ -1750,6 +1751,7 @@ fn parse_set() {
             scope: None,
             hivevar: false,
             variable: ObjectName::from(vec![Ident::new("a")]),
+            uses_to: false,
             values: vec![Expr::Identifier(Ident {
                 value: "b".into(),
                 quote_style: None,
@@ -1765,6 +1767,7 @@ fn parse_set() {
             scope: None,
             hivevar: false,
             variable: ObjectName::from(vec![Ident::new("a")]),
+            uses_to: false,
             values: vec![Expr::Value(
                 (Value::SingleQuotedString("b".into())).with_empty_span()
             )],
@@ -1778,6 +1781,7 @@ fn parse_set() {
             scope: None,
             hivevar: false,
             variable: ObjectName::from(vec![Ident::new("a")]),
+            uses_to: false,
             values: vec![Expr::value(number("0"))],
         })
     );
@@ -1789,7 +1793,8 @@ fn parse_set() {
             scope: None,
             hivevar: false,
             variable: ObjectName::from(vec![Ident::new("a")]),
-            values: vec![Expr::Identifier(Ident::new("DEFAULT"))],
+            uses_to: false,
+            values: vec![Expr::Default],
         })
     );
 
@@ -1800,6 +1805,7 @@ fn parse_set() {
             scope: Some(ContextModifier::Local),
             hivevar: false,
             variable: ObjectName::from(vec![Ident::new("a")]),
+            uses_to: false,
             values: vec![Expr::Identifier("b".into())],
         })
     );
@@ -1811,6 +1817,7 @@ fn parse_set() {
             scope: None,
             hivevar: false,
             variable: ObjectName::from(vec![Ident::new("a"), Ident::new("b"), Ident::new("c")]),
+            uses_to: false,
             values: vec![Expr::Identifier(Ident {
                 value: "b".into(),
                 quote_style: None,
@@ -1835,11 +1842,12 @@ fn parse_set() {
                 Ident::new("reducer"),
                 Ident::new("parallelism")
             ]),
+            uses_to: false,
             values: vec![Expr::Value((Value::Boolean(false)).with_empty_span())],
         })
     );
 
-    pg_and_generic().one_statement_parses_to("SET a TO b", "SET a = b");
+    pg_and_generic().verified_stmt("SET a TO b");
 
     assert_eq!(
         pg_and_generic().parse_sql_statements("SET"),
@@ -2017,7 +2025,8 @@ fn parse_execute() {
                         )),
                         data_type: DataType::SmallInt(None),
                         array: false,
-                        format: None
+                        format: None,
+                        charset: None,
                     },
                     alias: None
                 },
@@ -2029,7 +2038,8 @@ fn parse_execute() {
                         )),
                         data_type: DataType::SmallInt(None),
                         array: false,
-                        format: None
+                        format: None,
+                        charset: None,
                     },
                     alias: None
                 },
@@ -2657,6 +2667,7 @@ fn parse_array_index_expr() {
                 )),
                 array: false,
                 format: None,
+                charset: None,
             }))),
             access_chain: vec![
                 AccessExpr::Subscript(Subscript::Index {
@@ -3435,6 +3446,7 @@ fn parse_array_subquery_expr() {
                 settings: None,
                 format_clause: None,
                 pipe_operators: vec![],
+                options: vec![],
             })),
             filter: None,
             null_treatment: None,
@@ -5712,6 +5724,7 @@ fn parse_join_constraint_unnest_alias() {
                 with_ordinality: false,
             },
             global: false,
+            strictness: None,
             join_operator: JoinOperator::Join(JoinConstraint::On(Expr::BinaryOp {
                 left: Box::new(Expr::Identifier("c1".into())),
                 op: BinaryOperator::Eq,
@@ -5748,6 +5761,7 @@ fn test_simple_postgres_insert_with_alias() {
                 quote_style: None,
                 span: Span::empty(),
             }])),
+            with_hints: vec![],
             table_alias: Some(TableAliasWithoutColumns {
                 explicit: true,
                 alias: Ident {
@@ -5775,7 +5789,7 @@ fn test_simple_postgres_insert_with_alias() {
                     value_keyword: false,
                     explicit_row: false,
                     rows: vec![Parens::with_empty_span(vec![
-                        Expr::Identifier(Ident::new("DEFAULT")),
+                        Expr::Default,
                         Expr::Value((Value::Number("123".to_string(), false)).with_empty_span())
                     ])]
                 })),
@@ -5787,11 +5801,13 @@ fn test_simple_postgres_insert_with_alias() {
                 settings: None,
                 format_clause: None,
                 pipe_operators: vec![],
+                options: vec![],
             })),
             assignments: vec![],
             partitioned: None,
             after_columns: vec![],
             has_table_keyword: false,
+            by_name: false,
             on: None,
             returning: None,
             output: None,
@@ -5828,6 +5844,7 @@ fn test_simple_postgres_insert_with_alias() {
                 quote_style: None,
                 span: Span::empty(),
             }])),
+            with_hints: vec![],
             table_alias: Some(TableAliasWithoutColumns {
                 explicit: true,
                 alias: Ident {
@@ -5855,7 +5872,7 @@ fn test_simple_postgres_insert_with_alias() {
                     value_keyword: false,
                     explicit_row: false,
                     rows: vec![Parens::with_empty_span(vec![
-                        Expr::Identifier(Ident::new("DEFAULT")),
+                        Expr::Default,
                         Expr::Value(
                             (Value::Number(bigdecimal::BigDecimal::new(123.into(), 0), false))
                                 .with_empty_span()
@@ -5870,11 +5887,13 @@ fn test_simple_postgres_insert_with_alias() {
                 settings: None,
                 format_clause: None,
                 pipe_operators: vec![],
+                options: vec![],
             })),
             assignments: vec![],
             partitioned: None,
             after_columns: vec![],
             has_table_keyword: false,
+            by_name: false,
             on: None,
             returning: None,
             output: None,
@@ -5910,6 +5929,7 @@ fn test_simple_insert_with_quoted_alias() {
                 quote_style: None,
                 span: Span::empty(),
             }])),
+            with_hints: vec![],
             table_alias: Some(TableAliasWithoutColumns {
                 explicit: true,
                 alias: Ident {
@@ -5937,7 +5957,7 @@ fn test_simple_insert_with_quoted_alias() {
                     value_keyword: false,
                     explicit_row: false,
                     rows: vec![Parens::with_empty_span(vec![
-                        Expr::Identifier(Ident::new("DEFAULT")),
+                        Expr::Default,
                         Expr::Value(
                             (Value::SingleQuotedString("0123".to_string())).with_empty_span()
                         )
@@ -5951,11 +5971,13 @@ fn test_simple_insert_with_quoted_alias() {
                 settings: None,
                 format_clause: None,
                 pipe_operators: vec![],
+                options: vec![],
             })),
             assignments: vec![],
             partitioned: None,
             after_columns: vec![],
             has_table_keyword: false,
+            by_name: false,
             on: None,
             returning: None,
             output: None,
@@ -6024,6 +6046,7 @@ fn parse_at_time_zone() {
                 data_type: DataType::Text,
                 array: false,
                 format: None,
+                charset: None,
             }),
         }),
         op: BinaryOperator::Plus,
@@ -6207,7 +6230,9 @@ fn parse_create_domain() {
                 op: BinaryOperator::Gt,
                 right: Box::new(Expr::Value(test_utils::number("0").into())),
             }),
+            no_inherit: false,
             enforced: None,
+            not_for_replication: false,
         }
         .into()],
     });
@@ -6227,7 +6252,9 @@ fn parse_create_domain() {
                 op: BinaryOperator::Gt,
                 right: Box::new(Expr::Value(test_utils::number("0").into())),
             }),
+            no_inherit: false,
             enforced: None,
+            not_for_replication: false,
         }
         .into()],
     });
@@ -6247,7 +6274,9 @@ fn parse_create_domain() {
                 op: BinaryOperator::Gt,
                 right: Box::new(Expr::Value(test_utils::number("0").into())),
             }),
+            no_inherit: false,
             enforced: None,
+            not_for_replication: false,
         }
         .into()],
     });
@@ -6267,7 +6296,9 @@ fn parse_create_domain() {
                 op: BinaryOperator::Gt,
                 right: Box::new(Expr::Value(test_utils::number("0").into())),
             }),
+            no_inherit: false,
             enforced: None,
+            not_for_replication: false,
         }
         .into()],
     });
@@ -6287,7 +6318,9 @@ fn parse_create_domain() {
                 op: BinaryOperator::Gt,
                 right: Box::new(Expr::Value(test_utils::number("0").into())),
             }),
+            no_inherit: false,
             enforced: None,
+            not_for_replication: false,
         }
         .into()],
     });
@@ -6302,6 +6335,7 @@ fn parse_create_simple_before_insert_trigger() {
         or_alter: false,
         temporary: false,
         or_replace: false,
+        if_not_exists: false,
         is_constraint: false,
         name: ObjectName::from(vec![Ident::new("check_insert")]),
         period: Some(TriggerPeriod::Before),
@@ -6334,6 +6368,7 @@ fn parse_create_after_update_trigger_with_condition() {
         or_alter: false,
         temporary: false,
         or_replace: false,
+        if_not_exists: false,
         is_constraint: false,
         name: ObjectName::from(vec![Ident::new("check_update")]),
         period: Some(TriggerPeriod::After),
@@ -6373,6 +6408,7 @@ fn parse_create_instead_of_delete_trigger() {
         or_alter: false,
         temporary: false,
         or_replace: false,
+        if_not_exists: false,
         is_constraint: false,
         name: ObjectName::from(vec![Ident::new("check_delete")]),
         period: Some(TriggerPeriod::InsteadOf),
@@ -6405,6 +6441,7 @@ fn parse_create_trigger_with_multiple_events_and_deferrable() {
         or_alter: false,
         temporary: false,
         or_replace: false,
+        if_not_exists: false,
         is_constraint: true,
         name: ObjectName::from(vec![Ident::new("check_multiple_events")]),
         period: Some(TriggerPeriod::Before),
@@ -6432,6 +6469,7 @@ fn parse_create_trigger_with_multiple_events_and_deferrable() {
             deferrable: Some(true),
             initially: Some(DeferrableInitial::Deferred),
             enforced: None,
+            not_for_replication: false,
         }),
     });
 
@@ -6445,6 +6483,7 @@ fn parse_create_trigger_with_referencing() {
         or_alter: false,
         temporary: false,
         or_replace: false,
+        if_not_exists: false,
         is_constraint: false,
         name: ObjectName::from(vec![Ident::new("check_referencing")]),
         period: Some(TriggerPeriod::Before),
@@ -6523,6 +6562,7 @@ fn parse_drop_trigger() {
                 "DROP TRIGGER{} check_update ON table_name{}",
                 if if_exists { " IF EXISTS" } else { "" },
                 option
+                    .as_ref()
                     .map(|o| format!(" {o}"))
                     .unwrap_or_else(|| "".to_string())
             );
@@ -6670,6 +6710,7 @@ fn parse_trigger_related_functions() {
                 },
             ],
             constraints: vec![],
+            periods: vec![],
             hive_distribution: HiveDistributionStyle::NONE,
             hive_formats: None,
             file_format: None,
@@ -6678,6 +6719,8 @@ fn parse_trigger_related_functions() {
             without_rowid: false,
             like: None,
             clone: None,
+            copy: None,
+            with_connection: None,
             comment: None,
             on_commit: None,
             on_cluster: None,
@@ -6716,6 +6759,8 @@ fn parse_trigger_related_functions() {
             distkey: None,
             sortkey: None,
             backup: None,
+            with_system_versioning: false,
+            skewed_by: None,
         }
     );
 
@@ -6767,6 +6812,7 @@ fn parse_trigger_related_functions() {
             or_alter: false,
             temporary: false,
             or_replace: false,
+            if_not_exists: false,
             is_constraint: false,
             name: ObjectName::from(vec![Ident::new("emp_stamp")]),
             period: Some(TriggerPeriod::Before),
@@ -6881,6 +6927,7 @@ fn arrow_cast_precedence() {
                 data_type: DataType::Text,
                 array: false,
                 format: None,
+                charset: None,
             }),
         }
     )
@@ -7324,6 +7371,57 @@ fn parse_foreign_key_match_with_actions() {
     pg_and_generic().verified_stmt(sql);
 }
 
+#[test]
+fn parse_foreign_key_set_null_set_default_columns() {
+    let sql = "CREATE TABLE t (a INT, b INT, c INT REFERENCES other (z) ON DELETE SET DEFAULT (c), FOREIGN KEY (a, b) REFERENCES other(x, y) MATCH FULL ON DELETE SET NULL (a) ON UPDATE SET DEFAULT)";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable {
+            columns,
+            constraints,
+            ..
+        }) => {
+            match &columns[2].options[0].option {
+                ColumnOption::ForeignKey(constraint) => {
+                    assert_eq!(
+                        constraint.on_delete,
+                        Some(ReferentialAction::SetDefault(vec![Ident::new("c")]))
+                    );
+                }
+                _ => panic!("Expected ColumnOption::ForeignKey"),
+            }
+            match &constraints[0] {
+                TableConstraint::ForeignKey(constraint) => {
+                    assert_eq!(
+                        constraint.match_kind,
+                        Some(ConstraintReferenceMatchKind::Full)
+                    );
+                    assert_eq!(
+                        constraint.on_delete,
+                        Some(ReferentialAction::SetNull(vec![Ident::new("a")]))
+                    );
+                    assert_eq!(
+                        constraint.on_update,
+                        Some(ReferentialAction::SetDefault(vec![]))
+                    );
+                }
+                _ => panic!("Expected TableConstraint::ForeignKey"),
+            }
+        }
+        _ => unreachable!(),
+    }
+
+    // The column list is only allowed for ON DELETE
+    for action in ["SET NULL", "SET DEFAULT"] {
+        let sql = format!("CREATE TABLE t (a INT REFERENCES other (z) ON UPDATE {action} (a))");
+        assert_eq!(
+            pg_and_generic().parse_sql_statements(&sql).unwrap_err(),
+            ParserError::ParserError(
+                "a column list is only supported for ON DELETE SET NULL or SET DEFAULT".to_string()
+            )
+        );
+    }
+}
+
 #[test]
 fn parse_create_operator() {
     let sql = "CREATE OPERATOR myschema.@@ (PROCEDURE = myschema.my_proc, LEFTARG = TIMESTAMP WITH TIME ZONE, RIGHTARG = VARCHAR(255), COMMUTATOR = schema.>, NEGATOR = schema.<=, RESTRICT = myschema.sel_func, JOIN = myschema.join_func, HASHES, MERGES)";
@@ -9221,3 +9319,1364 @@ fn parse_lock_table() {
         }
     }
 }
+
+#[test]
+fn parse_listen_notify_job_queue() {
+    pg().verified_stmt("LISTEN jobs");
+    match pg().verified_stmt(r#"NOTIFY jobs, '{"id": 5}'"#) {
+        Statement::NOTIFY { channel, payload } => {
+            assert_eq!(channel, Ident::new("jobs"));
+            assert_eq!(payload.as_deref(), Some(r#"{"id": 5}"#));
+        }
+        _ => unreachable!(),
+    }
+    pg().verified_stmt("UNLISTEN jobs");
+    pg().verified_stmt("UNLISTEN *");
+}
+
+#[test]
+fn parse_check_constraint_no_inherit() {
+    match pg().verified_stmt(
+        "CREATE TABLE t (a INT CHECK (a > 0) NO INHERIT, CONSTRAINT b_positive CHECK (b > 0) NO INHERIT)",
+    ) {
+        Statement::CreateTable(CreateTable {
+            columns,
+            constraints,
+            ..
+        }) => {
+            match &columns[0].options[0].option {
+                ColumnOption::Check(check) => assert!(check.no_inherit),
+                _ => unreachable!(),
+            }
+            match &constraints[0] {
+                TableConstraint::Check(check) => {
+                    assert_eq!(check.name, Some(Ident::new("b_positive")));
+                    assert!(check.no_inherit);
+                }
+                _ => unreachable!(),
+            }
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt("ALTER TABLE t ADD CONSTRAINT c CHECK (a > 0) NO INHERIT");
+}
+
+#[test]
+fn parse_column_constraint_characteristics() {
+    match pg().verified_stmt(
+        "CREATE TABLE t (a INT CONSTRAINT fk REFERENCES p (id) ON DELETE CASCADE DEFERRABLE INITIALLY DEFERRED)",
+    ) {
+        Statement::CreateTable(CreateTable { columns, .. }) => {
+            match &columns[0].options[0].option {
+                ColumnOption::ForeignKey(fk) => {
+                    let characteristics = fk.characteristics.as_ref().unwrap();
+                    assert_eq!(characteristics.deferrable, Some(true));
+                    assert_eq!(
+                        characteristics.initially,
+                        Some(DeferrableInitial::Deferred)
+                    );
+                }
+                _ => unreachable!(),
+            }
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt("CREATE TABLE t (a INT UNIQUE NOT DEFERRABLE INITIALLY IMMEDIATE)");
+    pg().verified_stmt("CREATE TABLE t (a INT PRIMARY KEY DEFERRABLE)");
+}
+
+#[test]
+fn parse_normalize_and_is_normalized() {
+    let select = pg().verified_only_select("SELECT NORMALIZE(s, NFKC)");
+    assert_eq!(
+        expr_from_projection(only(&select.projection)),
+        &Expr::Normalize {
+            expr: Box::new(Expr::Identifier(Ident::new("s"))),
+            form: Some(NormalizationForm::NFKC),
+        }
+    );
+    let select = pg().verified_only_select("SELECT NORMALIZE(s)");
+    assert_eq!(
+        expr_from_projection(only(&select.projection)),
+        &Expr::Normalize {
+            expr: Box::new(Expr::Identifier(Ident::new("s"))),
+            form: None,
+        }
+    );
+    pg().one_statement_parses_to("SELECT normalize(s, nfd)", "SELECT NORMALIZE(s, NFD)");
+
+    let select = pg().verified_only_select("SELECT s IS NOT NFKD NORMALIZED");
+    assert_eq!(
+        expr_from_projection(only(&select.projection)),
+        &Expr::IsNormalized {
+            expr: Box::new(Expr::Identifier(Ident::new("s"))),
+            form: Some(NormalizationForm::NFKD),
+            negated: true,
+        }
+    );
+    pg().verified_stmt("SELECT s IS NORMALIZED");
+    pg().verified_stmt("SELECT NORMALIZE(s, NFC) IS NFC NORMALIZED");
+
+    for sql in [
+        "SELECT TRIM(BOTH 'x' FROM name)",
+        "SELECT TRIM(LEADING 'x' FROM name)",
+        "SELECT TRIM(TRAILING name)",
+        "SELECT TRIM('x' FROM name)",
+        "SELECT TRIM(name)",
+    ] {
+        pg().verified_stmt(sql);
+    }
+}
+
+#[test]
+fn parse_set_to_spelling() {
+    match pg().verified_stmt("SET search_path TO a, b") {
+        Statement::Set(Set::SingleAssignment {
+            variable,
+            uses_to,
+            values,
+            ..
+        }) => {
+            assert_eq!(variable, ObjectName::from(vec![Ident::new("search_path")]));
+            assert!(uses_to);
+            assert_eq!(
+                values,
+                vec![
+                    Expr::Identifier(Ident::new("a")),
+                    Expr::Identifier(Ident::new("b"))
+                ]
+            );
+        }
+        s => panic!("unexpected statement: {s:?}"),
+    }
+    pg().verified_stmt("SET search_path = a, b");
+    pg().verified_stmt("SET LOCAL statement_timeout TO 1000");
+    pg().verified_stmt("SET enable_seqscan TO OFF");
+    pg().verified_stmt("SET work_mem TO DEFAULT");
+}
+
+#[test]
+fn parse_create_procedure_with_definition() {
+    let sql = "CREATE OR REPLACE PROCEDURE insert_data (a INTEGER, INOUT b TEXT) LANGUAGE plpgsql AS $$ BEGIN INSERT INTO tbl VALUES (a); END $$";
+    match pg().verified_stmt(sql) {
+        Statement::CreateProcedure {
+            or_replace,
+            params,
+            language,
+            body,
+            ..
+        } => {
+            assert!(or_replace);
+            assert_eq!(params.map(|p| p.len()), Some(2));
+            assert_eq!(language, Some(Ident::new("plpgsql")));
+            assert_eq!(
+                body,
+                CreateProcedureBody::AsDefinition(Expr::Value(
+                    Value::DollarQuotedString(DollarQuotedString {
+                        value: " BEGIN INSERT INTO tbl VALUES (a); END ".to_string(),
+                        tag: None,
+                    })
+                    .with_empty_span()
+                ))
+            );
+        }
+        s => panic!("unexpected statement: {s:?}"),
+    }
+    pg().verified_stmt("CREATE PROCEDURE p (IN a INTEGER) LANGUAGE sql AS 'SELECT a'");
+}
+
+#[test]
+fn parse_drop_function_multiple_signatures() {
+    let sql = "DROP FUNCTION IF EXISTS f(INT), f(TEXT) CASCADE";
+    assert_eq!(
+        pg().verified_stmt(sql),
+        Statement::DropFunction(DropFunction {
+            if_exists: true,
+            func_desc: vec![
+                FunctionDesc {
+                    name: ObjectName::from(vec![Ident::new("f")]),
+                    args: Some(vec![OperateFunctionArg::unnamed(DataType::Int(None))]),
+                },
+                FunctionDesc {
+                    name: ObjectName::from(vec![Ident::new("f")]),
+                    args: Some(vec![OperateFunctionArg::unnamed(DataType::Text)]),
+                },
+            ],
+            drop_behavior: Some(DropBehavior::Cascade),
+        })
+    );
+    pg().verified_stmt("DROP PROCEDURE IF EXISTS p(INT), q RESTRICT");
+}
+
+#[test]
+fn parse_alter_function_and_procedure_operations() {
+    match pg().verified_stmt("ALTER FUNCTION f(INT) OWNER TO r") {
+        Statement::AlterFunction(AlterFunction {
+            kind, operation, ..
+        }) => {
+            assert_eq!(kind, AlterFunctionKind::Function);
+            assert_eq!(
+                operation,
+                AlterFunctionOperation::OwnerTo(Owner::Ident(Ident::new("r")))
+            );
+        }
+        s => panic!("unexpected statement: {s:?}"),
+    }
+
+    match pg().verified_stmt("ALTER PROCEDURE p(INT) SET SCHEMA s") {
+        Statement::AlterFunction(AlterFunction {
+            kind,
+            function,
+            operation,
+            ..
+        }) => {
+            assert_eq!(kind, AlterFunctionKind::Procedure);
+            assert_eq!(function.name, ObjectName::from(vec![Ident::new("p")]));
+            assert_eq!(
+                operation,
+                AlterFunctionOperation::SetSchema {
+                    schema_name: ObjectName::from(vec![Ident::new("s")]),
+                }
+            );
+        }
+        s => panic!("unexpected statement: {s:?}"),
+    }
+
+    pg().verified_stmt("ALTER FUNCTION f(INT) RENAME TO g");
+    pg().verified_stmt("ALTER FUNCTION f(INT) IMMUTABLE");
+    pg().verified_stmt("ALTER FUNCTION f(INT) SET search_path = x");
+    pg().verified_stmt("ALTER PROCEDURE p(INT) RENAME TO q");
+    pg().verified_stmt("ALTER PROCEDURE p SECURITY DEFINER RESET ALL");
+}
+
+#[test]
+fn parse_enum_labels_preserve_quotes() {
+    match pg().verified_stmt("CREATE TYPE mood AS ENUM ('it''s', 'ok')") {
+        Statement::CreateType {
+            representation: Some(UserDefinedTypeRepresentation::Enum { labels }),
+            ..
+        } => {
+            assert_eq!(
+                vec![
+                    Ident::with_quote('\'', "it's"),
+                    Ident::with_quote('\'', "ok")
+                ],
+                labels
+            );
+        }
+        _ => unreachable!(),
+    }
+    pg().verified_stmt("ALTER TYPE mood ADD VALUE 'it''s' BEFORE 'ok'");
+    pg().verified_stmt("ALTER TYPE mood RENAME VALUE 'it''s' TO 'fine'");
+    pg().verified_stmt("CREATE TABLE t (m mood DEFAULT 'ok')");
+}
+
+#[test]
+fn parse_lateral_table_factors() {
+    let select = pg()
+        .verified_only_select("SELECT * FROM t, LATERAL (SELECT * FROM u WHERE u.x = t.id) AS s");
+    assert_eq!(2, select.from.len());
+    assert!(matches!(
+        select.from[1].relation,
+        TableFactor::Derived { lateral: true, .. }
+    ));
+
+    let select = pg().verified_only_select(
+        "SELECT * FROM t LEFT JOIN LATERAL f(t.id) AS g ON true JOIN u ON u.id = g.id",
+    );
+    let joins = &select.from[0].joins;
+    assert_eq!(2, joins.len());
+    assert!(matches!(joins[0].join_operator, JoinOperator::Left(_)));
+    match &joins[0].relation {
+        TableFactor::Function {
+            lateral: true,
+            name,
+            args,
+            alias: Some(_),
+            ..
+        } => {
+            assert_eq!("f", name.to_string());
+            assert_eq!(1, args.len());
+        }
+        r => unreachable!("{r:?}"),
+    }
+
+    pg().verified_stmt("SELECT * FROM t, LATERAL f(t.id)");
+    pg().verified_stmt("SELECT * FROM t CROSS JOIN LATERAL generate_series(1, t.n) AS g (i)");
+    pg().verified_stmt("SELECT * FROM t JOIN LATERAL (SELECT 1) AS s ON true");
+}
+
+#[test]
+fn parse_unnest_multiple_arrays_with_ordinality() {
+    let select = pg()
+        .verified_only_select("SELECT * FROM t, UNNEST(t.a, t.b) WITH ORDINALITY AS u (x, y, ord)");
+    match &select.from[1].relation {
+        TableFactor::UNNEST {
+            alias: Some(alias),
+            array_exprs,
+            with_offset: false,
+            with_ordinality: true,
+            ..
+        } => {
+            assert_eq!(2, array_exprs.len());
+            assert_eq!(
+                vec![
+                    TableAliasColumnDef::from_name("x"),
+                    TableAliasColumnDef::from_name("y"),
+                    TableAliasColumnDef::from_name("ord"),
+                ],
+                alias.columns
+            );
+        }
+        r => unreachable!("{r:?}"),
+    }
+
+    pg().verified_stmt("SELECT * FROM UNNEST(ARRAY[1, 2], ARRAY['a', 'b']) AS z (n, s)");
+    pg().one_statement_parses_to(
+        "SELECT * FROM UNNEST(a, b) WITH ORDINALITY AS t(x, y, ord)",
+        "SELECT * FROM UNNEST(a, b) WITH ORDINALITY AS t (x, y, ord)",
+    );
+}
+
+#[test]
+fn parse_alter_set_schema() {
+    match pg().verified_stmt("ALTER TABLE IF EXISTS foo SET SCHEMA \"New Schema\"") {
+        Statement::AlterTable(AlterTable {
+            name,
+            if_exists,
+            operations,
+            table_type,
+            ..
+        }) => {
+            assert_eq!("foo", name.to_string());
+            assert!(if_exists);
+            assert_eq!(None, table_type);
+            assert_eq!(
+                operations,
+                vec![AlterTableOperation::SetSchema {
+                    schema_name: ObjectName::from(vec![Ident::with_quote('"', "New Schema")]),
+                }]
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    let operation = alter_table_op_with_name(
+        pg().verified_stmt("ALTER TABLE public.foo SET SCHEMA archive"),
+        "public.foo",
+    );
+    assert_eq!(
+        operation,
+        AlterTableOperation::SetSchema {
+            schema_name: ObjectName::from(vec![Ident::new("archive")]),
+        }
+    );
+
+    match pg().verified_stmt("ALTER VIEW IF EXISTS v SET SCHEMA s") {
+        Statement::AlterTable(AlterTable {
+            if_exists,
+            table_type,
+            ..
+        }) => {
+            assert!(if_exists);
+            assert_eq!(Some(AlterTableType::View), table_type);
+        }
+        _ => unreachable!(),
+    }
+
+    match pg().verified_stmt("ALTER SEQUENCE seq SET SCHEMA \"S\"") {
+        Statement::AlterTable(AlterTable {
+            if_exists,
+            table_type,
+            ..
+        }) => {
+            assert!(!if_exists);
+            assert_eq!(Some(AlterTableType::Sequence), table_type);
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt("ALTER VIEW v SET SCHEMA s");
+    pg().verified_stmt("ALTER SEQUENCE IF EXISTS seq SET SCHEMA s");
+}
+
+#[test]
+fn parse_window_frame_expression_bounds() {
+    let select = pg().verified_only_select(
+        "SELECT SUM(x) OVER (ORDER BY d RANGE BETWEEN INTERVAL '1' DAY PRECEDING AND CURRENT ROW) FROM t",
+    );
+    match expr_from_projection(&select.projection[0]) {
+        Expr::Function(Function {
+            over:
+                Some(WindowType::WindowSpec(WindowSpec {
+                    window_frame: Some(window_frame),
+                    ..
+                })),
+            ..
+        }) => {
+            assert_eq!(WindowFrameUnits::Range, window_frame.units);
+            assert!(matches!(
+                &window_frame.start_bound,
+                WindowFrameBound::Preceding(Some(expr)) if matches!(**expr, Expr::Interval(_))
+            ));
+            assert_eq!(Some(WindowFrameBound::CurrentRow), window_frame.end_bound);
+        }
+        e => unreachable!("{e:?}"),
+    }
+
+    pg().verified_stmt(
+        "SELECT SUM(x) OVER (ORDER BY d ROWS BETWEEN t.lo PRECEDING AND t.hi + 1 FOLLOWING) FROM t",
+    );
+    pg().verified_stmt(
+        "SELECT COUNT(*) OVER w, SUM(x) OVER () FROM t WINDOW w AS (ORDER BY d RANGE BETWEEN INTERVAL '7 days' PRECEDING AND INTERVAL '1 day' FOLLOWING)",
+    );
+}
+
+#[test]
+fn parse_alter_table_reset_options() {
+    let operation = alter_table_op(
+        pg().verified_stmt("ALTER TABLE tab RESET (fillfactor, toast.autovacuum_enabled)"),
+    );
+    assert_eq!(
+        operation,
+        AlterTableOperation::ResetOptionsParens {
+            options: vec![
+                ObjectName::from(vec![Ident::new("fillfactor")]),
+                ObjectName::from(vec![Ident::new("toast"), Ident::new("autovacuum_enabled")]),
+            ],
+        }
+    );
+
+    pg().verified_stmt("ALTER TABLE tab SET (fillfactor = 70), RESET (autovacuum_enabled)");
+
+    // values are not allowed in RESET
+    assert!(pg()
+        .parse_sql_statements("ALTER TABLE tab RESET (fillfactor = 70)")
+        .is_err());
+}
+
+#[test]
+fn parse_alter_table_inherit() {
+    assert_eq!(
+        alter_table_op(pg_and_generic().verified_stmt("ALTER TABLE tab INHERIT parent")),
+        AlterTableOperation::Inherit {
+            parent: ObjectName::from(vec![Ident::new("parent")]),
+        }
+    );
+    assert_eq!(
+        alter_table_op(pg_and_generic().verified_stmt("ALTER TABLE tab NO INHERIT public.parent")),
+        AlterTableOperation::NoInherit {
+            parent: ObjectName::from(vec![Ident::new("public"), Ident::new("parent")]),
+        }
+    );
+
+    // `NO INHERIT` on a CHECK constraint stays attached to the constraint
+    match pg().verified_stmt(
+        "ALTER TABLE tab ADD CONSTRAINT c CHECK (a > 0) NO INHERIT, NO INHERIT parent",
+    ) {
+        Statement::AlterTable(AlterTable { operations, .. }) => {
+            assert_eq!(2, operations.len());
+            assert!(matches!(
+                operations[0],
+                AlterTableOperation::AddConstraint { .. }
+            ));
+            assert_eq!(
+                operations[1],
+                AlterTableOperation::NoInherit {
+                    parent: ObjectName::from(vec![Ident::new("parent")]),
+                }
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_alter_table_of_type() {
+    assert_eq!(
+        alter_table_op(pg().verified_stmt("ALTER TABLE tab OF public.my_type")),
+        AlterTableOperation::Of {
+            type_name: ObjectName::from(vec![Ident::new("public"), Ident::new("my_type")]),
+        }
+    );
+    assert_eq!(
+        alter_table_op(pg().verified_stmt("ALTER TABLE tab NOT OF")),
+        AlterTableOperation::NotOf
+    );
+    pg().verified_stmt("ALTER TABLE IF EXISTS tab NOT OF, OWNER TO admin");
+}
+
+#[test]
+fn parse_alter_column_set_statistics_and_storage() {
+    assert_eq!(
+        alter_table_op(pg().verified_stmt("ALTER TABLE tab ALTER COLUMN c SET STATISTICS 1000")),
+        AlterTableOperation::AlterColumn {
+            column_name: Ident::new("c"),
+            op: AlterColumnOperation::SetStatistics {
+                value: number("1000"),
+            },
+        }
+    );
+    assert_eq!(
+        alter_table_op(pg().verified_stmt("ALTER TABLE tab ALTER COLUMN c SET STATISTICS -1")),
+        AlterTableOperation::AlterColumn {
+            column_name: Ident::new("c"),
+            op: AlterColumnOperation::SetStatistics {
+                value: number("-1"),
+            },
+        }
+    );
+
+    for (storage, expected) in [
+        ("PLAIN", ColumnStorage::Plain),
+        ("EXTERNAL", ColumnStorage::External),
+        ("EXTENDED", ColumnStorage::Extended),
+        ("MAIN", ColumnStorage::Main),
+    ] {
+        let sql = format!("ALTER TABLE tab ALTER COLUMN c SET STORAGE {storage}");
+        assert_eq!(
+            alter_table_op(pg().verified_stmt(&sql)),
+            AlterTableOperation::AlterColumn {
+                column_name: Ident::new("c"),
+                op: AlterColumnOperation::SetStorage { storage: expected },
+            }
+        );
+    }
+
+    assert!(pg()
+        .parse_sql_statements("ALTER TABLE tab ALTER COLUMN c SET STORAGE COMPRESSED")
+        .is_err());
+}
+
+#[test]
+fn parse_alter_column_set_generated_and_drop_identity() {
+    assert_eq!(
+        alter_table_op(pg().verified_stmt("ALTER TABLE tab ALTER COLUMN id DROP IDENTITY")),
+        AlterTableOperation::AlterColumn {
+            column_name: Ident::new("id"),
+            op: AlterColumnOperation::DropIdentity { if_exists: false },
+        }
+    );
+    assert_eq!(
+        alter_table_op(
+            pg().verified_stmt("ALTER TABLE tab ALTER COLUMN id DROP IDENTITY IF EXISTS")
+        ),
+        AlterTableOperation::AlterColumn {
+            column_name: Ident::new("id"),
+            op: AlterColumnOperation::DropIdentity { if_exists: true },
+        }
+    );
+
+    assert_eq!(
+        alter_table_op(pg().verified_stmt("ALTER TABLE tab ALTER COLUMN id SET GENERATED ALWAYS")),
+        AlterTableOperation::AlterColumn {
+            column_name: Ident::new("id"),
+            op: AlterColumnOperation::SetGenerated {
+                generated_as: GeneratedAs::Always,
+                sequence_options: None,
+            },
+        }
+    );
+    assert_eq!(
+        alter_table_op(pg().verified_stmt(
+            "ALTER TABLE tab ALTER COLUMN id SET GENERATED BY DEFAULT SET INCREMENT BY 2 RESTART WITH 100"
+        )),
+        AlterTableOperation::AlterColumn {
+            column_name: Ident::new("id"),
+            op: AlterColumnOperation::SetGenerated {
+                generated_as: GeneratedAs::ByDefault,
+                sequence_options: Some(vec![
+                    SequenceOptions::IncrementBy(Expr::value(number("2")), true),
+                    SequenceOptions::Restart(Some(Expr::value(number("100"))), true),
+                ]),
+            },
+        }
+    );
+    pg().verified_stmt("ALTER TABLE tab ALTER COLUMN id SET GENERATED ALWAYS RESTART");
+    pg().verified_stmt("ALTER TABLE tab ALTER COLUMN id SET GENERATED ALWAYS RESTART 5");
+    pg().verified_stmt(
+        "ALTER TABLE tab ALTER COLUMN id SET GENERATED BY DEFAULT RESTART, ALTER COLUMN id SET NOT NULL",
+    );
+}
+
+#[test]
+fn parse_alter_table_add_multiple_columns_unsupported() {
+    assert!(pg()
+        .parse_sql_statements("ALTER TABLE tab ADD COLUMN (a INT, b TEXT)")
+        .is_err());
+    assert!(pg()
+        .parse_sql_statements("ALTER TABLE tab ADD COLUMN a INT, b TEXT")
+        .is_err());
+}
+
+#[test]
+fn parse_create_and_drop_language() {
+    assert_eq!(
+        pg().verified_stmt("CREATE OR REPLACE PROCEDURAL LANGUAGE plv8 HANDLER plv8_call_handler"),
+        Statement::CreateLanguage(CreateLanguage {
+            or_replace: true,
+            trusted: false,
+            procedural: true,
+            name: Ident::new("plv8"),
+            handler: Some(ObjectName::from(vec![Ident::new("plv8_call_handler")])),
+            inline_handler: None,
+            validator: None,
+        })
+    );
+    pg().verified_stmt("CREATE TRUSTED LANGUAGE plperl HANDLER plperl_call_handler INLINE plperl_inline_handler VALIDATOR plperl_validator");
+    pg().verified_stmt("CREATE LANGUAGE plpgsql");
+
+    assert_eq!(
+        pg().verified_stmt("DROP PROCEDURAL LANGUAGE IF EXISTS plv8 CASCADE"),
+        Statement::DropLanguage(DropLanguage {
+            procedural: true,
+            if_exists: true,
+            name: Ident::new("plv8"),
+            drop_behavior: Some(DropBehavior::Cascade),
+        })
+    );
+    pg().verified_stmt("DROP LANGUAGE plv8");
+}
+
+#[test]
+fn parse_create_and_drop_transform() {
+    let sql = "CREATE TRANSFORM FOR hstore LANGUAGE plpython3u (FROM SQL WITH FUNCTION hstore_to_plpython(internal), TO SQL WITH FUNCTION plpython_to_hstore(internal))";
+    match pg().verified_stmt(sql) {
+        Statement::CreateTransform(CreateTransform {
+            or_replace,
+            type_name,
+            language,
+            from_sql,
+            to_sql,
+        }) => {
+            assert!(!or_replace);
+            assert_eq!("hstore", type_name.to_string());
+            assert_eq!(Ident::new("plpython3u"), language);
+            assert_eq!(
+                "hstore_to_plpython(internal)",
+                from_sql.unwrap().to_string()
+            );
+            assert_eq!("plpython_to_hstore(internal)", to_sql.unwrap().to_string());
+        }
+        _ => unreachable!(),
+    }
+    pg().verified_stmt(
+        "CREATE OR REPLACE TRANSFORM FOR INT LANGUAGE plpgsql (TO SQL WITH FUNCTION f(internal))",
+    );
+
+    assert_eq!(
+        pg().verified_stmt("DROP TRANSFORM IF EXISTS FOR hstore LANGUAGE plpython3u RESTRICT"),
+        Statement::DropTransform(DropTransform {
+            if_exists: true,
+            type_name: DataType::Custom(ObjectName::from(vec![Ident::new("hstore")]), vec![]),
+            language: Ident::new("plpython3u"),
+            drop_behavior: Some(DropBehavior::Restrict),
+        })
+    );
+}
+
+#[test]
+fn parse_create_and_drop_conversion() {
+    assert_eq!(
+        pg().verified_stmt("CREATE DEFAULT CONVERSION myconv FOR 'UTF8' TO 'LATIN1' FROM myfunc"),
+        Statement::CreateConversion(CreateConversion {
+            default: true,
+            name: ObjectName::from(vec![Ident::new("myconv")]),
+            source_encoding: "UTF8".to_string(),
+            destination_encoding: "LATIN1".to_string(),
+            function: ObjectName::from(vec![Ident::new("myfunc")]),
+        })
+    );
+    pg().verified_stmt("CREATE CONVERSION public.myconv FOR 'UTF8' TO 'LATIN1' FROM myfunc");
+
+    assert_eq!(
+        pg().verified_stmt("DROP CONVERSION IF EXISTS public.myconv CASCADE"),
+        Statement::DropConversion(DropConversion {
+            if_exists: true,
+            name: ObjectName::from(vec![Ident::new("public"), Ident::new("myconv")]),
+            drop_behavior: Some(DropBehavior::Cascade),
+        })
+    );
+    pg().verified_stmt("DROP CONVERSION myconv");
+}
+
+#[test]
+fn parse_exclusion_constraint() {
+    let sql = "CREATE TABLE reservations (room INT, during TSRANGE, EXCLUDE USING GIST (room WITH =, during WITH &&))";
+    match pg().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable { constraints, .. }) => {
+            assert_eq!(
+                vec![TableConstraint::Exclude(ExclusionConstraint {
+                    name: None,
+                    index_type: Some(IndexType::GiST),
+                    elements: vec![
+                        ExcludeElement {
+                            expr: IndexColumn::from("room"),
+                            operator: "=".to_string(),
+                        },
+                        ExcludeElement {
+                            expr: IndexColumn::from("during"),
+                            operator: "&&".to_string(),
+                        },
+                    ],
+                    where_clause: None,
+                    characteristics: None,
+                })],
+                constraints
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    let sql = "CREATE TABLE t (a INT, CONSTRAINT no_overlap EXCLUDE USING GIST ((lower(b)) WITH =, c gist_int4_ops WITH <>) WHERE (active) DEFERRABLE INITIALLY DEFERRED)";
+    match pg().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable { constraints, .. }) => match &constraints[0] {
+            TableConstraint::Exclude(ExclusionConstraint {
+                name,
+                elements,
+                where_clause,
+                characteristics,
+                ..
+            }) => {
+                assert_eq!(Some(Ident::new("no_overlap")), *name);
+                assert_eq!(
+                    Some(ObjectName::from(vec![Ident::new("gist_int4_ops")])),
+                    elements[1].expr.operator_class
+                );
+                assert_eq!("<>", elements[1].operator);
+                assert_eq!(
+                    Some(Box::new(Expr::Identifier(Ident::new("active")))),
+                    *where_clause
+                );
+                assert!(characteristics.is_some());
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt("ALTER TABLE t ADD CONSTRAINT x EXCLUDE (a WITH =)");
+    // `exclude` remains usable as a column name.
+    pg().verified_stmt("CREATE TABLE t (exclude INT)");
+}
+
+#[test]
+fn parse_create_text_search() {
+    let sql = "CREATE TEXT SEARCH CONFIGURATION fr (COPY = french)";
+    assert_eq!(
+        pg().verified_stmt(sql),
+        Statement::CreateTextSearch(CreateTextSearch {
+            object_type: TextSearchObjectType::Configuration,
+            name: ObjectName::from(vec![Ident::new("fr")]),
+            options: vec![SqlOption::KeyValue {
+                key: Ident::new("COPY"),
+                value: Expr::Identifier(Ident::new("french")),
+            }],
+        })
+    );
+
+    pg().verified_stmt("CREATE TEXT SEARCH CONFIGURATION public.my_config (PARSER = default)");
+    pg().verified_stmt(
+        "CREATE TEXT SEARCH DICTIONARY my_russian (TEMPLATE = snowball, Language = russian, StopWords = myrussian)",
+    );
+    pg().verified_stmt(
+        "CREATE TEXT SEARCH PARSER my_parser (START = prsd_start, GETTOKEN = prsd_nexttoken, END = prsd_end, LEXTYPES = prsd_lextype)",
+    );
+    pg().verified_stmt(
+        "CREATE TEXT SEARCH TEMPLATE my_template (INIT = dsimple_init, LEXIZE = dsimple_lexize)",
+    );
+    assert!(pg()
+        .parse_sql_statements("CREATE TEXT SEARCH INDEX x (a = b)")
+        .is_err());
+}
+
+#[test]
+fn parse_alter_text_search_configuration() {
+    let sql =
+        "ALTER TEXT SEARCH CONFIGURATION fr ALTER MAPPING FOR hword, word WITH unaccent, french_stem";
+    assert_eq!(
+        pg().verified_stmt(sql),
+        Statement::AlterTextSearchConfiguration(AlterTextSearchConfiguration {
+            name: ObjectName::from(vec![Ident::new("fr")]),
+            operation: AlterTextSearchConfigurationOperation::AlterMapping {
+                token_types: vec![Ident::new("hword"), Ident::new("word")],
+                dictionaries: vec![
+                    ObjectName::from(vec![Ident::new("unaccent")]),
+                    ObjectName::from(vec![Ident::new("french_stem")]),
+                ],
+            },
+        })
+    );
+
+    pg().verified_stmt(
+        "ALTER TEXT SEARCH CONFIGURATION fr ADD MAPPING FOR asciiword, asciihword WITH simple",
+    );
+    pg().verified_stmt(
+        "ALTER TEXT SEARCH CONFIGURATION fr ALTER MAPPING REPLACE english_stem WITH swedish_stem",
+    );
+    pg().verified_stmt(
+        "ALTER TEXT SEARCH CONFIGURATION fr ALTER MAPPING FOR word REPLACE english_stem WITH swedish_stem",
+    );
+    pg().verified_stmt("ALTER TEXT SEARCH CONFIGURATION fr DROP MAPPING FOR email, url");
+    pg().verified_stmt("ALTER TEXT SEARCH CONFIGURATION fr DROP MAPPING IF EXISTS FOR hword");
+    assert!(pg()
+        .parse_sql_statements("ALTER TEXT SEARCH CONFIGURATION fr ALTER MAPPING WITH simple")
+        .is_err());
+}
+
+#[test]
+fn parse_create_statistics() {
+    let sql = "CREATE STATISTICS s1 (dependencies, ndistinct) ON a, b FROM t";
+    assert_eq!(
+        pg_and_generic().verified_stmt(sql),
+        Statement::CreateStatistics(CreateStatistics {
+            if_not_exists: false,
+            name: ObjectName::from(vec![Ident::new("s1")]),
+            kinds: vec![Ident::new("dependencies"), Ident::new("ndistinct")],
+            expressions: vec![
+                Expr::Identifier(Ident::new("a")),
+                Expr::Identifier(Ident::new("b")),
+            ],
+            table_name: ObjectName::from(vec![Ident::new("t")]),
+        })
+    );
+
+    let sql = "CREATE STATISTICS IF NOT EXISTS public.s2 ON (a + b), (a * b) FROM s.t";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateStatistics(CreateStatistics {
+            if_not_exists,
+            kinds,
+            expressions,
+            ..
+        }) => {
+            assert!(if_not_exists);
+            assert!(kinds.is_empty());
+            assert_eq!(2, expressions.len());
+            assert!(matches!(expressions[0], Expr::Nested(_)));
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt("CREATE STATISTICS s3 (mcv) ON a, (lower(b)) FROM t");
+    assert!(pg()
+        .parse_sql_statements("CREATE STATISTICS s4 (mcv) ON a, b")
+        .is_err());
+}
+
+#[test]
+fn parse_drop_and_alter_statistics() {
+    match pg_and_generic().verified_stmt("DROP STATISTICS IF EXISTS s1, s2") {
+        Statement::Drop {
+            object_type,
+            if_exists,
+            names,
+            ..
+        } => {
+            assert_eq!(ObjectType::Statistics, object_type);
+            assert!(if_exists);
+            assert_eq!(
+                vec![
+                    ObjectName::from(vec![Ident::new("s1")]),
+                    ObjectName::from(vec![Ident::new("s2")]),
+                ],
+                names
+            );
+        }
+        _ => unreachable!(),
+    }
+    pg_and_generic().verified_stmt("DROP STATISTICS public.s1 CASCADE");
+
+    assert_eq!(
+        pg_and_generic().verified_stmt("ALTER STATISTICS s1 RENAME TO s2"),
+        Statement::AlterStatistics(AlterStatistics {
+            name: ObjectName::from(vec![Ident::new("s1")]),
+            operation: AlterStatisticsOperation::RenameTo {
+                new_name: Ident::new("s2"),
+            },
+        })
+    );
+    assert_eq!(
+        pg_and_generic().verified_stmt("ALTER STATISTICS s1 SET STATISTICS 500"),
+        Statement::AlterStatistics(AlterStatistics {
+            name: ObjectName::from(vec![Ident::new("s1")]),
+            operation: AlterStatisticsOperation::SetStatistics {
+                value: number("500"),
+            },
+        })
+    );
+    assert_eq!(
+        pg_and_generic().verified_stmt("ALTER STATISTICS s1 OWNER TO bob"),
+        Statement::AlterStatistics(AlterStatistics {
+            name: ObjectName::from(vec![Ident::new("s1")]),
+            operation: AlterStatisticsOperation::OwnerTo(Owner::Ident(Ident::new("bob"))),
+        })
+    );
+    pg_and_generic().verified_stmt("ALTER STATISTICS s1 OWNER TO CURRENT_ROLE");
+    pg_and_generic().verified_stmt("ALTER STATISTICS s1 SET STATISTICS -1");
+    pg_and_generic().verified_stmt("ALTER STATISTICS public.s1 SET SCHEMA archive");
+    assert!(pg()
+        .parse_sql_statements("ALTER STATISTICS s1 SET TABLESPACE x")
+        .is_err());
+}
+
+#[test]
+fn parse_create_policy_restrictive_with_multiple_roles() {
+    let sql = "CREATE POLICY p ON t AS RESTRICTIVE FOR ALL TO role1, role2, PUBLIC USING (owner = current_user) WITH CHECK (owner = current_user)";
+    match pg().verified_stmt(sql) {
+        Statement::CreatePolicy(CreatePolicy {
+            name,
+            table_name,
+            policy_type,
+            command,
+            to,
+            using,
+            with_check,
+        }) => {
+            assert_eq!(name, Ident::new("p"));
+            assert_eq!(table_name.to_string(), "t");
+            assert_eq!(policy_type, Some(CreatePolicyType::Restrictive));
+            assert_eq!(command, Some(CreatePolicyCommand::All));
+            assert_eq!(
+                to,
+                Some(vec![
+                    Owner::Ident(Ident::new("role1")),
+                    Owner::Ident(Ident::new("role2")),
+                    Owner::Ident(Ident::new("PUBLIC")),
+                ])
+            );
+            assert_eq!(using.unwrap().to_string(), "owner = current_user");
+            assert_eq!(with_check.unwrap().to_string(), "owner = current_user");
+        }
+        _ => unreachable!(),
+    }
+
+    for command in ["SELECT", "INSERT", "UPDATE", "DELETE"] {
+        pg().verified_stmt(&format!(
+            "CREATE POLICY p ON t AS PERMISSIVE FOR {command} TO CURRENT_USER, SESSION_USER USING (true)"
+        ));
+    }
+    pg().verified_stmt("CREATE POLICY p ON t WITH CHECK (a > 0)");
+    pg().verified_stmt("CREATE POLICY p ON t");
+
+    pg().verified_stmt("DROP POLICY IF EXISTS p ON t CASCADE");
+    pg().verified_stmt("DROP POLICY p ON t");
+}
+
+#[test]
+fn parse_alter_server() {
+    let sql = "ALTER SERVER film_server VERSION '2.0' OPTIONS (ADD host 'db', SET dbname 'films', DROP port, user 'u')";
+    assert_eq!(
+        pg_and_generic().verified_stmt(sql),
+        Statement::AlterServer(AlterServer {
+            name: ObjectName::from(vec![Ident::new("film_server")]),
+            operation: AlterServerOperation::Update {
+                version: Some(Ident::with_quote('\'', "2.0")),
+                options: Some(vec![
+                    AlterServerOption {
+                        action: Some(AlterServerOptionAction::Add),
+                        key: Ident::new("host"),
+                        value: Some(Ident::with_quote('\'', "db")),
+                    },
+                    AlterServerOption {
+                        action: Some(AlterServerOptionAction::Set),
+                        key: Ident::new("dbname"),
+                        value: Some(Ident::with_quote('\'', "films")),
+                    },
+                    AlterServerOption {
+                        action: Some(AlterServerOptionAction::Drop),
+                        key: Ident::new("port"),
+                        value: None,
+                    },
+                    AlterServerOption {
+                        action: None,
+                        key: Ident::new("user"),
+                        value: Some(Ident::with_quote('\'', "u")),
+                    },
+                ]),
+            },
+        })
+    );
+
+    pg_and_generic().verified_stmt("ALTER SERVER s VERSION '1.1'");
+    pg_and_generic().verified_stmt("ALTER SERVER s OPTIONS (SET host 'foo')");
+    pg_and_generic().verified_stmt("ALTER SERVER s RENAME TO t");
+
+    match pg_and_generic().verified_stmt("ALTER SERVER s OWNER TO CURRENT_USER") {
+        Statement::AlterServer(AlterServer {
+            operation: AlterServerOperation::OwnerTo(owner),
+            ..
+        }) => assert_eq!(owner, Owner::CurrentUser),
+        _ => unreachable!(),
+    }
+
+    assert_eq!(
+        pg().parse_sql_statements("ALTER SERVER s").unwrap_err(),
+        ParserError::ParserError(
+            "Expected: VERSION, OPTIONS, OWNER TO or RENAME TO after ALTER SERVER, found: EOF"
+                .to_string()
+        )
+    );
+}
+
+#[test]
+fn parse_drop_server() {
+    match pg_and_generic().verified_stmt("DROP SERVER IF EXISTS s1, s2 CASCADE") {
+        Statement::Drop {
+            object_type,
+            if_exists,
+            names,
+            cascade,
+            ..
+        } => {
+            assert_eq!(object_type, ObjectType::Server);
+            assert!(if_exists);
+            assert_eq!(
+                names,
+                vec![
+                    ObjectName::from(vec![Ident::new("s1")]),
+                    ObjectName::from(vec![Ident::new("s2")])
+                ]
+            );
+            assert!(cascade);
+        }
+        _ => unreachable!(),
+    }
+    pg_and_generic().verified_stmt("DROP SERVER s");
+}
+
+#[test]
+fn parse_create_user_mapping() {
+    let sql = "CREATE USER MAPPING IF NOT EXISTS FOR CURRENT_USER SERVER film_server OPTIONS (user 'x', password 'y')";
+    assert_eq!(
+        pg_and_generic().verified_stmt(sql),
+        Statement::CreateUserMapping(CreateUserMapping {
+            if_not_exists: true,
+            user: Owner::CurrentUser,
+            server_name: ObjectName::from(vec![Ident::new("film_server")]),
+            options: Some(vec![
+                CreateServerOption {
+                    key: Ident::new("user"),
+                    value: Ident::with_quote('\'', "x"),
+                },
+                CreateServerOption {
+                    key: Ident::new("password"),
+                    value: Ident::with_quote('\'', "y"),
+                },
+            ]),
+        })
+    );
+
+    match pg_and_generic().verified_stmt("CREATE USER MAPPING FOR PUBLIC SERVER s") {
+        Statement::CreateUserMapping(CreateUserMapping { user, options, .. }) => {
+            assert_eq!(user, Owner::Public);
+            assert_eq!(options, None);
+        }
+        _ => unreachable!(),
+    }
+    match pg_and_generic().verified_stmt("CREATE USER MAPPING FOR USER SERVER s") {
+        Statement::CreateUserMapping(CreateUserMapping { user, .. }) => {
+            assert_eq!(user, Owner::User);
+        }
+        _ => unreachable!(),
+    }
+    pg_and_generic().verified_stmt("CREATE USER MAPPING FOR bob SERVER s OPTIONS (user 'bob')");
+
+    // a user named `mapping` is still a plain CREATE USER
+    pg_and_generic().verified_stmt("CREATE USER mapping");
+}
+
+#[test]
+fn parse_alter_user_mapping() {
+    let sql =
+        "ALTER USER MAPPING FOR PUBLIC SERVER s OPTIONS (ADD user 'x', SET password 'y', DROP port)";
+    assert_eq!(
+        pg_and_generic().verified_stmt(sql),
+        Statement::AlterUserMapping(AlterUserMapping {
+            user: Owner::Public,
+            server_name: ObjectName::from(vec![Ident::new("s")]),
+            options: vec![
+                AlterServerOption {
+                    action: Some(AlterServerOptionAction::Add),
+                    key: Ident::new("user"),
+                    value: Some(Ident::with_quote('\'', "x")),
+                },
+                AlterServerOption {
+                    action: Some(AlterServerOptionAction::Set),
+                    key: Ident::new("password"),
+                    value: Some(Ident::with_quote('\'', "y")),
+                },
+                AlterServerOption {
+                    action: Some(AlterServerOptionAction::Drop),
+                    key: Ident::new("port"),
+                    value: None,
+                },
+            ],
+        })
+    );
+    pg_and_generic()
+        .verified_stmt("ALTER USER MAPPING FOR CURRENT_USER SERVER s OPTIONS (user 'x')");
+    pg_and_generic().verified_stmt("ALTER USER mapping RENAME TO m");
+}
+
+#[test]
+fn parse_drop_user_mapping() {
+    assert_eq!(
+        pg_and_generic().verified_stmt("DROP USER MAPPING IF EXISTS FOR CURRENT_USER SERVER s"),
+        Statement::DropUserMapping(DropUserMapping {
+            if_exists: true,
+            user: Owner::CurrentUser,
+            server_name: ObjectName::from(vec![Ident::new("s")]),
+        })
+    );
+    pg_and_generic().verified_stmt("DROP USER MAPPING FOR PUBLIC SERVER s");
+    pg_and_generic().verified_stmt("DROP USER MAPPING FOR bob SERVER s");
+}
+
+#[test]
+fn parse_create_sequence_all_options() {
+    let sql = "CREATE SEQUENCE s AS SMALLINT INCREMENT BY 2 MINVALUE 1 MAXVALUE 100 START WITH 1 CACHE 10 NO CYCLE OWNED BY t.id";
+    match pg().verified_stmt(sql) {
+        Statement::CreateSequence {
+            data_type,
+            sequence_options,
+            owned_by,
+            ..
+        } => {
+            assert_eq!(data_type, Some(DataType::SmallInt(None)));
+            assert_eq!(
+                sequence_options,
+                vec![
+                    SequenceOptions::IncrementBy(Expr::value(number("2")), true),
+                    SequenceOptions::MinValue(Some(Expr::value(number("1")))),
+                    SequenceOptions::MaxValue(Some(Expr::value(number("100")))),
+                    SequenceOptions::StartWith(Expr::value(number("1")), true),
+                    SequenceOptions::Cache(Expr::value(number("10"))),
+                    SequenceOptions::Cycle(true),
+                ]
+            );
+            assert_eq!(
+                owned_by,
+                Some(ObjectName::from(vec!["t".into(), "id".into()]))
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt("CREATE SEQUENCE s NO MINVALUE NO MAXVALUE CYCLE OWNED BY NONE");
+
+    // Options may be given in any order
+    pg().verified_stmt("CREATE SEQUENCE s CACHE 10 START 1 NO CYCLE INCREMENT 2");
+    pg().one_statement_parses_to(
+        "CREATE SEQUENCE s OWNED BY t.id INCREMENT 2",
+        "CREATE SEQUENCE s INCREMENT 2 OWNED BY t.id",
+    );
+
+    assert_eq!(SequenceOptions::MinValue(None).to_string(), "NO MINVALUE");
+}
+
+#[test]
+fn parse_alter_view_operations() {
+    match pg().verified_stmt("ALTER VIEW IF EXISTS v RENAME TO w") {
+        Statement::AlterView {
+            if_exists,
+            name,
+            operation,
+        } => {
+            assert!(if_exists);
+            assert_eq!(name.to_string(), "v");
+            assert_eq!(
+                operation,
+                AlterViewOperation::RenameTo {
+                    new_name: ObjectName::from(vec![Ident::new("w")]),
+                }
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    match pg().verified_stmt("ALTER VIEW v OWNER TO CURRENT_USER") {
+        Statement::AlterView { operation, .. } => assert_eq!(
+            operation,
+            AlterViewOperation::OwnerTo {
+                new_owner: Owner::CurrentUser,
+            }
+        ),
+        _ => unreachable!(),
+    }
+
+    match pg().verified_stmt("ALTER VIEW v SET (security_barrier = true, check_option = 'local')") {
+        Statement::AlterView {
+            operation: AlterViewOperation::SetOptions { options },
+            ..
+        } => assert_eq!(options.len(), 2),
+        _ => unreachable!(),
+    }
+
+    match pg().verified_stmt("ALTER VIEW v RESET (security_barrier, check_option)") {
+        Statement::AlterView { operation, .. } => assert_eq!(
+            operation,
+            AlterViewOperation::ResetOptions {
+                names: vec![Ident::new("security_barrier"), Ident::new("check_option")],
+            }
+        ),
+        _ => unreachable!(),
+    }
+
+    match pg().verified_stmt("ALTER VIEW v ALTER COLUMN c SET DEFAULT 0") {
+        Statement::AlterView { operation, .. } => assert_eq!(
+            operation,
+            AlterViewOperation::AlterColumnSetDefault {
+                column: Ident::new("c"),
+                default: Expr::value(number("0")),
+            }
+        ),
+        _ => unreachable!(),
+    }
+    pg().one_statement_parses_to(
+        "ALTER VIEW v ALTER c SET DEFAULT 0",
+        "ALTER VIEW v ALTER COLUMN c SET DEFAULT 0",
+    );
+}
+
+#[test]
+fn parse_create_materialized_view_with_data() {
+    match pg().verified_stmt("CREATE MATERIALIZED VIEW mv AS SELECT a FROM t WITH NO DATA") {
+        Statement::CreateView(CreateView {
+            materialized,
+            with_data,
+            ..
+        }) => {
+            assert!(materialized);
+            assert_eq!(with_data, Some(false));
+        }
+        _ => unreachable!(),
+    }
+    match pg().verified_stmt("CREATE MATERIALIZED VIEW mv AS SELECT a FROM t WITH DATA") {
+        Statement::CreateView(CreateView { with_data, .. }) => {
+            assert_eq!(with_data, Some(true));
+        }
+        _ => unreachable!(),
+    }
+    match pg().verified_stmt("CREATE MATERIALIZED VIEW mv AS SELECT a FROM t") {
+        Statement::CreateView(CreateView { with_data, .. }) => assert_eq!(with_data, None),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_refresh_materialized_view() {
+    assert_eq!(
+        pg().verified_stmt("REFRESH MATERIALIZED VIEW CONCURRENTLY s.mv WITH NO DATA"),
+        Statement::RefreshMaterializedView {
+            concurrently: true,
+            name: ObjectName::from(vec![Ident::new("s"), Ident::new("mv")]),
+            with_data: Some(false),
+        }
+    );
+    assert_eq!(
+        pg().verified_stmt("REFRESH MATERIALIZED VIEW mv"),
+        Statement::RefreshMaterializedView {
+            concurrently: false,
+            name: ObjectName::from(vec![Ident::new("mv")]),
+            with_data: None,
+        }
+    );
+    pg().verified_stmt("REFRESH MATERIALIZED VIEW mv WITH DATA");
+}
+
+#[test]
+fn parse_alter_materialized_view() {
+    assert_eq!(
+        pg().verified_stmt("ALTER MATERIALIZED VIEW IF EXISTS mv RENAME TO mv2"),
+        Statement::AlterMaterializedView {
+            if_exists: true,
+            name: ObjectName::from(vec![Ident::new("mv")]),
+            operations: vec![AlterViewOperation::RenameTo {
+                new_name: ObjectName::from(vec![Ident::new("mv2")]),
+            }],
+        }
+    );
+
+    match pg().verified_stmt("ALTER MATERIALIZED VIEW s.mv SET SCHEMA s2") {
+        Statement::AlterMaterializedView {
+            if_exists,
+            name,
+            operations,
+        } => {
+            assert!(!if_exists);
+            assert_eq!(name.to_string(), "s.mv");
+            assert_eq!(
+                operations,
+                vec![AlterViewOperation::SetSchema {
+                    schema_name: ObjectName::from(vec![Ident::new("s2")]),
+                }]
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    match pg().verified_stmt(
+        "ALTER MATERIALIZED VIEW mv OWNER TO admin, SET (fillfactor = 70), RESET (autovacuum_enabled)",
+    ) {
+        Statement::AlterMaterializedView { operations, .. } => {
+            assert_eq!(operations.len(), 3);
+            assert_eq!(
+                operations[0],
+                AlterViewOperation::OwnerTo {
+                    new_owner: Owner::Ident(Ident::new("admin")),
+                }
+            );
+            assert!(
+                matches!(&operations[1], AlterViewOperation::SetOptions { options } if options.len() == 1)
+            );
+            assert_eq!(
+                operations[2],
+                AlterViewOperation::ResetOptions {
+                    names: vec![Ident::new("autovacuum_enabled")],
+                }
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    assert!(pg()
+        .parse_sql_statements("ALTER MATERIALIZED VIEW mv ADD COLUMN c INT")
+        .is_err());
+}
+
+#[test]
+fn parse_cte_prefixed_dml() {
+    match pg().verified_stmt(
+        "WITH doomed AS (SELECT id FROM t WHERE old) DELETE FROM t WHERE id IN (SELECT id FROM doomed) RETURNING id",
+    ) {
+        Statement::Query(query) => {
+            let with = query.with.unwrap();
+            assert!(!with.recursive);
+            assert_eq!(with.cte_tables[0].alias.name, Ident::new("doomed"));
+            assert!(matches!(*query.body, SetExpr::Delete(_)));
+        }
+        _ => unreachable!(),
+    }
+
+    match pg().verified_stmt(
+        "WITH RECURSIVE r (n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM r WHERE n < 10) UPDATE t SET x = 0 WHERE id IN (SELECT n FROM r)",
+    ) {
+        Statement::Query(query) => {
+            assert!(query.with.unwrap().recursive);
+            assert!(matches!(*query.body, SetExpr::Update(_)));
+        }
+        _ => unreachable!(),
+    }
+
+    match pg().verified_stmt(
+        "WITH RECURSIVE r (n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM r WHERE n < 10) INSERT INTO t (id) SELECT n FROM r",
+    ) {
+        Statement::Query(query) => {
+            assert!(query.with.unwrap().recursive);
+            assert!(matches!(*query.body, SetExpr::Insert(_)));
+        }
+        _ => unreachable!(),
+    }
+}
@@ -643,6 +643,7 @@ fn test_snowflake_create_table_with_autoincrement_columns() {
                                 IdentityProperty {
                                     parameters: None,
                                     order: Some(IdentityPropertyOrder::Order),
+                                    not_for_replication: false,
                                 }
                             ))
                         }]
@@ -661,6 +662,7 @@ fn test_snowflake_create_table_with_autoincrement_columns() {
                                         }
                                     )),
                                     order: Some(IdentityPropertyOrder::NoOrder),
+                                    not_for_replication: false,
                                 }
                             ))
                         }]
@@ -674,6 +676,7 @@ fn test_snowflake_create_table_with_autoincrement_columns() {
                                 IdentityProperty {
                                     parameters: None,
                                     order: None,
+                                    not_for_replication: false,
                                 }
                             ))
                         }]
@@ -698,6 +701,7 @@ fn test_snowflake_create_table_with_autoincrement_columns() {
                                         )
                                     ),
                                     order: Some(IdentityPropertyOrder::Order),
+                                    not_for_replication: false,
                                 }
                             ))
                         }]
@@ -879,7 +883,8 @@ fn test_snowflake_create_table_with_several_column_options() {
                                 option: ColumnOption::Identity(IdentityPropertyKind::Identity(
                                     IdentityProperty {
                                         parameters: None,
-                                        order: None
+                                        order: None,
+                                        not_for_replication: false,
                                     }
                                 )),
                             },
@@ -1083,6 +1088,7 @@ fn parse_sf_create_or_replace_with_comment_for_snowflake() {
             cluster_by,
             comment,
             with_no_schema_binding: late_binding,
+            with_check_option: None,
             if_not_exists,
             temporary,
             ..
@@ -1278,6 +1284,7 @@ fn parse_array() {
             data_type: DataType::Array(ArrayElemTypeDef::None),
             array: false,
             format: None,
+            charset: None,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -1388,6 +1395,7 @@ fn parse_semi_structured_data_traversal() {
                 data_type: DataType::Array(ArrayElemTypeDef::None),
                 array: false,
                 format: None,
+                charset: None,
             }),
             path: JsonPath {
                 path: vec![JsonPathElem::Bracket {
@@ -3009,6 +3017,7 @@ fn asof_joins() {
             joins: vec![Join {
                 relation: table_with_alias("quotes_unixtime", true, "qu"),
                 global: false,
+                strictness: None,
                 join_operator: JoinOperator::AsOf {
                     match_condition: Expr::BinaryOp {
                         left: Box::new(Expr::CompoundIdentifier(vec![
@@ -3098,13 +3107,15 @@ fn parse_explain_table() {
         Statement::ExplainTable {
             describe_alias,
             hive_format,
-            has_table_keyword,
+            object_type,
             table_name,
+            partition,
         } => {
             assert_eq!(describe_alias, DescribeAlias::Explain);
             assert_eq!(hive_format, None);
-            assert_eq!(has_table_keyword, true);
+            assert_eq!(object_type, Some(ObjectType::Table));
             assert_eq!("test_identifier", table_name.to_string());
+            assert_eq!(partition, None);
         }
         _ => panic!("Unexpected Statement, must be ExplainTable"),
     }
@@ -4270,6 +4281,7 @@ fn test_nested_join_without_parentheses() {
                         json_path: None,
                         sample: None,
                         index_hints: vec![],
+                        final_modifier: false,
                     },
                     joins: vec![Join {
                         relation: TableFactor::Table {
@@ -4283,8 +4295,10 @@ fn test_nested_join_without_parentheses() {
                             json_path: None,
                             sample: None,
                             index_hints: vec![],
+                            final_modifier: false,
                         },
                         global: false,
+                        strictness: None,
                         join_operator: JoinOperator::Inner(JoinConstraint::On(Expr::BinaryOp {
                             left: Box::new(Expr::CompoundIdentifier(vec![
                                 Ident::new("p".to_string()),
@@ -4301,6 +4315,7 @@ fn test_nested_join_without_parentheses() {
                 alias: None
             },
             global: false,
+            strictness: None,
             join_operator: JoinOperator::Inner(JoinConstraint::On(Expr::BinaryOp {
                 left: Box::new(Expr::CompoundIdentifier(vec![
                     Ident::new("c".to_string()),
@@ -4337,6 +4352,7 @@ fn test_nested_join_without_parentheses() {
                         json_path: None,
                         sample: None,
                         index_hints: vec![],
+                        final_modifier: false,
                     },
                     joins: vec![Join {
                         relation: TableFactor::Table {
@@ -4350,8 +4366,10 @@ fn test_nested_join_without_parentheses() {
                             json_path: None,
                             sample: None,
                             index_hints: vec![],
+                            final_modifier: false,
                         },
                         global: false,
+                        strictness: None,
                         join_operator: JoinOperator::Join(JoinConstraint::On(Expr::BinaryOp {
                             left: Box::new(Expr::CompoundIdentifier(vec![
                                 Ident::new("p".to_string()),
@@ -4368,6 +4386,7 @@ fn test_nested_join_without_parentheses() {
                 alias: None
             },
             global: false,
+            strictness: None,
             join_operator: JoinOperator::Join(JoinConstraint::On(Expr::BinaryOp {
                 left: Box::new(Expr::CompoundIdentifier(vec![
                     Ident::new("c".to_string()),
@@ -4404,6 +4423,7 @@ fn test_nested_join_without_parentheses() {
                         json_path: None,
                         sample: None,
                         index_hints: vec![],
+                        final_modifier: false,
                     },
                     joins: vec![Join {
                         relation: TableFactor::Table {
@@ -4417,8 +4437,10 @@ fn test_nested_join_without_parentheses() {
                             json_path: None,
                             sample: None,
                             index_hints: vec![],
+                            final_modifier: false,
                         },
                         global: false,
+                        strictness: None,
                         join_operator: JoinOperator::Left(JoinConstraint::On(Expr::BinaryOp {
                             left: Box::new(Expr::CompoundIdentifier(vec![
                                 Ident::new("p".to_string()),
@@ -4435,6 +4457,7 @@ fn test_nested_join_without_parentheses() {
                 alias: None
             },
             global: false,
+            strictness: None,
             join_operator: JoinOperator::Left(JoinConstraint::On(Expr::BinaryOp {
                 left: Box::new(Expr::CompoundIdentifier(vec![
                     Ident::new("c".to_string()),
@@ -4471,6 +4494,7 @@ fn test_nested_join_without_parentheses() {
                         json_path: None,
                         sample: None,
                         index_hints: vec![],
+                        final_modifier: false,
                     },
                     joins: vec![Join {
                         relation: TableFactor::Table {
@@ -4484,8 +4508,10 @@ fn test_nested_join_without_parentheses() {
                             json_path: None,
                             sample: None,
                             index_hints: vec![],
+                            final_modifier: false,
                         },
                         global: false,
+                        strictness: None,
                         join_operator: JoinOperator::Right(JoinConstraint::On(Expr::BinaryOp {
                             left: Box::new(Expr::CompoundIdentifier(vec![
                                 Ident::new("p".to_string()),
@@ -4502,6 +4528,7 @@ fn test_nested_join_without_parentheses() {
                 alias: None
             },
             global: false,
+            strictness: None,
             join_operator: JoinOperator::Right(JoinConstraint::On(Expr::BinaryOp {
                 left: Box::new(Expr::CompoundIdentifier(vec![
                     Ident::new("c".to_string()),
@@ -4538,6 +4565,7 @@ fn test_nested_join_without_parentheses() {
                         json_path: None,
                         sample: None,
                         index_hints: vec![],
+                        final_modifier: false,
                     },
                     joins: vec![Join {
                         relation: TableFactor::Table {
@@ -4551,8 +4579,10 @@ fn test_nested_join_without_parentheses() {
                             json_path: None,
                             sample: None,
                             index_hints: vec![],
+                            final_modifier: false,
                         },
                         global: false,
+                        strictness: None,
                         join_operator: JoinOperator::FullOuter(JoinConstraint::On(
                             Expr::BinaryOp {
                                 left: Box::new(Expr::CompoundIdentifier(vec![
@@ -4571,6 +4601,7 @@ fn test_nested_join_without_parentheses() {
                 alias: None
             },
             global: false,
+            strictness: None,
             join_operator: JoinOperator::FullOuter(JoinConstraint::On(Expr::BinaryOp {
                 left: Box::new(Expr::CompoundIdentifier(vec![
                     Ident::new("c".to_string()),
@@ -5010,3 +5041,672 @@ fn test_select_dollar_column_from_stage() {
     // With table function args, without alias
     snowflake().verified_stmt("SELECT $1, $2 FROM @mystage1(file_format => 'myformat')");
 }
+
+#[test]
+fn test_match_recognize_snowflake_examples() {
+    // Examples from <https://docs.snowflake.com/en/sql-reference/constructs/match_recognize>
+    snowflake().verified_stmt(concat!(
+        "SELECT * FROM stock_price_history MATCH_RECOGNIZE(",
+        "PARTITION BY company ORDER BY price_date ",
+        "MEASURES MATCH_NUMBER() AS match_number, FIRST(price_date) AS start_date, LAST(price_date) AS end_date, ",
+        "COUNT(*) AS rows_in_sequence, COUNT(row_with_price_decrease.*) AS num_decreases ",
+        "ONE ROW PER MATCH ",
+        "AFTER MATCH SKIP TO LAST row_with_price_increase ",
+        "PATTERN (row_before_decrease row_with_price_decrease+ row_with_price_increase+) ",
+        "DEFINE row_with_price_decrease AS price < LAG(price), row_with_price_increase AS price > LAG(price)",
+        ") ORDER BY company, match_number"
+    ));
+    snowflake().verified_stmt(concat!(
+        "SELECT * FROM stock_price_history MATCH_RECOGNIZE(",
+        "PARTITION BY company ORDER BY price_date ",
+        "MEASURES FINAL LAST(price) AS final_price, RUNNING COUNT(*) AS running_count, CLASSIFIER() AS symbol ",
+        "ALL ROWS PER MATCH WITH UNMATCHED ROWS ",
+        "AFTER MATCH SKIP TO NEXT ROW ",
+        "PATTERN (^ start_row {- filler -} up{2,4} down{,3} flat{3,}? ( up | down )* $) ",
+        "DEFINE up AS price > PREV(price, 2), down AS price < NEXT(price), flat AS price = FIRST(price)",
+        ")"
+    ));
+
+    let select = snowflake().verified_only_select(
+        "SELECT * FROM t MATCH_RECOGNIZE(ORDER BY d AFTER MATCH SKIP TO b PATTERN (a {- b -} c) DEFINE a AS true)",
+    );
+    match &select.from[0].relation {
+        TableFactor::MatchRecognize {
+            after_match_skip,
+            pattern,
+            ..
+        } => {
+            assert_eq!(*after_match_skip, Some(AfterMatchSkip::To(Ident::new("b"))));
+            assert_eq!(
+                *pattern,
+                MatchRecognizePattern::Concat(vec![
+                    MatchRecognizePattern::Symbol(MatchRecognizeSymbol::Named(Ident::new("a"))),
+                    MatchRecognizePattern::Exclude(MatchRecognizeSymbol::Named(Ident::new("b"))),
+                    MatchRecognizePattern::Symbol(MatchRecognizeSymbol::Named(Ident::new("c"))),
+                ])
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // Columns named `running` and `final` are not measure semantics
+    let select = snowflake().verified_only_select(
+        "SELECT * FROM t MATCH_RECOGNIZE(ORDER BY d MEASURES running + 1 AS r, final AS f, FINAL running AS fr PATTERN (a) DEFINE a AS true)",
+    );
+    match &select.from[0].relation {
+        TableFactor::MatchRecognize { measures, .. } => {
+            assert_eq!(
+                measures
+                    .iter()
+                    .map(|m| (m.semantics, m.expr.to_string()))
+                    .collect::<Vec<_>>(),
+                vec![
+                    (None, "running + 1".to_string()),
+                    (None, "final".to_string()),
+                    (Some(MeasureSemantics::Final), "running".to_string()),
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_describe_object_types() {
+    for (sql, expected) in [
+        ("DESCRIBE STAGE mystage", ObjectType::Stage),
+        ("DESC VIEW db.sch.v", ObjectType::View),
+        ("DESCRIBE TABLE t", ObjectType::Table),
+        (
+            "DESCRIBE MATERIALIZED VIEW mv",
+            ObjectType::MaterializedView,
+        ),
+        ("DESC SCHEMA db.sch", ObjectType::Schema),
+        ("DESCRIBE DATABASE db", ObjectType::Database),
+        ("DESCRIBE SEQUENCE seq", ObjectType::Sequence),
+        ("DESCRIBE STREAM s", ObjectType::Stream),
+        ("DESCRIBE USER u", ObjectType::User),
+    ] {
+        match snowflake().verified_stmt(sql) {
+            Statement::ExplainTable { object_type, .. } => {
+                assert_eq!(object_type, Some(expected))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[test]
+fn parse_alter_table_set_unset_tag() {
+    match alter_table_op(
+        snowflake().verified_stmt("ALTER TABLE tab SET TAG cost_center='x', db.sch.owner='y'"),
+    ) {
+        AlterTableOperation::SetTag { tag_list } => assert_eq!(
+            tag_list,
+            vec![
+                Tag::new(
+                    ObjectName::from(vec![Ident::new("cost_center")]),
+                    "x".into()
+                ),
+                Tag::new(
+                    ObjectName::from(vec![
+                        Ident::new("db"),
+                        Ident::new("sch"),
+                        Ident::new("owner")
+                    ]),
+                    "y".into()
+                ),
+            ]
+        ),
+        _ => unreachable!(),
+    }
+
+    match alter_table_op(
+        snowflake().verified_stmt("ALTER TABLE tab UNSET TAG cost_center, db.sch.owner"),
+    ) {
+        AlterTableOperation::UnsetTag { tag_names } => assert_eq!(
+            tag_names,
+            vec![
+                ObjectName::from(vec![Ident::new("cost_center")]),
+                ObjectName::from(vec![
+                    Ident::new("db"),
+                    Ident::new("sch"),
+                    Ident::new("owner")
+                ]),
+            ]
+        ),
+        _ => unreachable!(),
+    }
+
+    snowflake().one_statement_parses_to(
+        "ALTER TABLE tab SET TAG cost_center = 'x'",
+        "ALTER TABLE tab SET TAG cost_center='x'",
+    );
+    assert!(snowflake()
+        .parse_sql_statements("ALTER TABLE tab SET TAG")
+        .is_err());
+}
+
+#[test]
+fn parse_alter_table_column_set_unset_tag() {
+    match alter_table_op(
+        snowflake()
+            .verified_stmt("ALTER TABLE tab ALTER COLUMN c SET TAG pii='email', tier='gold'"),
+    ) {
+        AlterTableOperation::AlterColumn { column_name, op } => {
+            assert_eq!(column_name, Ident::new("c"));
+            assert_eq!(
+                op,
+                AlterColumnOperation::SetTag {
+                    tag_list: vec![
+                        Tag::new(ObjectName::from(vec![Ident::new("pii")]), "email".into()),
+                        Tag::new(ObjectName::from(vec![Ident::new("tier")]), "gold".into()),
+                    ]
+                }
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    match alter_table_op(
+        snowflake().verified_stmt("ALTER TABLE tab ALTER COLUMN c UNSET TAG pii, tier"),
+    ) {
+        AlterTableOperation::AlterColumn { op, .. } => assert_eq!(
+            op,
+            AlterColumnOperation::UnsetTag {
+                tag_names: vec![
+                    ObjectName::from(vec![Ident::new("pii")]),
+                    ObjectName::from(vec![Ident::new("tier")]),
+                ]
+            }
+        ),
+        _ => unreachable!(),
+    }
+
+    // `MODIFY [COLUMN]` is a synonym of `ALTER [COLUMN]`
+    snowflake().verified_stmt("ALTER TABLE tab MODIFY COLUMN c SET TAG pii='email', tier='gold'");
+    snowflake().verified_stmt("ALTER TABLE tab MODIFY c UNSET TAG pii");
+
+    // Other dialects do not support tag operations
+    assert!(all_dialects_except(|d| d.supports_alter_tag_operations())
+        .parse_sql_statements("ALTER TABLE tab SET TAG cost_center='x'")
+        .is_err());
+}
+
+#[test]
+fn parse_create_storage_integration() {
+    let sql = concat!(
+        "CREATE STORAGE INTEGRATION s3_int ",
+        "TYPE = EXTERNAL_STAGE ",
+        "STORAGE_PROVIDER = 'S3' ",
+        "ENABLED = true ",
+        "STORAGE_AWS_ROLE_ARN = 'arn:aws:iam::001234567890:role/myrole' ",
+        "STORAGE_ALLOWED_LOCATIONS = ('s3://a/', 's3://b/')"
+    );
+    match snowflake().verified_stmt(sql) {
+        Statement::CreateIntegration {
+            or_replace,
+            kind,
+            if_not_exists,
+            name,
+            options,
+        } => {
+            assert!(!or_replace);
+            assert!(!if_not_exists);
+            assert_eq!(kind, Ident::new("STORAGE"));
+            assert_eq!(name, ObjectName::from(vec![Ident::new("s3_int")]));
+            assert_eq!(options.len(), 5);
+            assert_eq!(
+                options[0],
+                SqlOption::KeyValue {
+                    key: Ident::new("TYPE"),
+                    value: Expr::Identifier(Ident::new("EXTERNAL_STAGE")),
+                }
+            );
+            assert_eq!(
+                options[4],
+                SqlOption::KeyValue {
+                    key: Ident::new("STORAGE_ALLOWED_LOCATIONS"),
+                    value: Expr::Tuple(vec![
+                        Expr::Value(
+                            (Value::SingleQuotedString("s3://a/".to_string())).with_empty_span()
+                        ),
+                        Expr::Value(
+                            (Value::SingleQuotedString("s3://b/".to_string())).with_empty_span()
+                        ),
+                    ]),
+                }
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    snowflake().one_statement_parses_to(
+        "CREATE STORAGE INTEGRATION s3_int TYPE = EXTERNAL_STAGE ENABLED = TRUE",
+        "CREATE STORAGE INTEGRATION s3_int TYPE = EXTERNAL_STAGE ENABLED = true",
+    );
+}
+
+#[test]
+fn parse_create_notification_integration() {
+    snowflake().verified_stmt(concat!(
+        "CREATE OR REPLACE NOTIFICATION INTEGRATION IF NOT EXISTS my_notification_int ",
+        "TYPE = QUEUE ",
+        "NOTIFICATION_PROVIDER = AZURE_STORAGE_QUEUE ",
+        "ENABLED = true ",
+        "AZURE_STORAGE_QUEUE_PRIMARY_URI = 'https://myqueue.queue.core.windows.net/myqueue' ",
+        "AZURE_TENANT_ID = 'a123b4c5-1234-123a-a12b-1a23b45678c9' ",
+        "COMMENT = 'notifications'"
+    ));
+    snowflake().verified_stmt(concat!(
+        "CREATE NOTIFICATION INTEGRATION my_email_int ",
+        "TYPE = EMAIL ",
+        "ENABLED = true ",
+        "ALLOWED_RECIPIENTS = ('first.last@example.com', 'first2.last2@example.com')"
+    ));
+    snowflake().verified_stmt(
+        "CREATE SECURITY INTEGRATION oauth_int TYPE = OAUTH ENABLED = true OAUTH_CLIENT = TABLEAU_DESKTOP",
+    );
+}
+
+#[test]
+fn parse_alter_drop_describe_integration() {
+    match snowflake().verified_stmt(
+        "ALTER STORAGE INTEGRATION IF EXISTS s3_int SET ENABLED = false STORAGE_BLOCKED_LOCATIONS = ('s3://c/')",
+    ) {
+        Statement::AlterIntegration {
+            kind,
+            if_exists,
+            name,
+            operation: AlterIntegrationOperation::Set(options),
+        } => {
+            assert_eq!(kind, Some(Ident::new("STORAGE")));
+            assert!(if_exists);
+            assert_eq!(name, ObjectName::from(vec![Ident::new("s3_int")]));
+            assert_eq!(options.len(), 2);
+        }
+        _ => unreachable!(),
+    }
+
+    match snowflake().verified_stmt("ALTER INTEGRATION s3_int UNSET COMMENT, ENABLED") {
+        Statement::AlterIntegration {
+            kind: None,
+            if_exists: false,
+            operation: AlterIntegrationOperation::Unset(properties),
+            ..
+        } => assert_eq!(
+            properties,
+            vec![Ident::new("COMMENT"), Ident::new("ENABLED")]
+        ),
+        _ => unreachable!(),
+    }
+
+    match snowflake().verified_stmt("DROP NOTIFICATION INTEGRATION IF EXISTS my_int") {
+        Statement::DropIntegration {
+            kind,
+            if_exists,
+            name,
+        } => {
+            assert_eq!(kind, Some(Ident::new("NOTIFICATION")));
+            assert!(if_exists);
+            assert_eq!(name, ObjectName::from(vec![Ident::new("my_int")]));
+        }
+        _ => unreachable!(),
+    }
+    snowflake().verified_stmt("DROP INTEGRATION my_int");
+
+    match snowflake().verified_stmt("DESC INTEGRATION my_int") {
+        Statement::DescribeIntegration {
+            describe_alias,
+            kind,
+            name,
+        } => {
+            assert_eq!(describe_alias, DescribeAlias::Desc);
+            assert_eq!(kind, None);
+            assert_eq!(name, ObjectName::from(vec![Ident::new("my_int")]));
+        }
+        _ => unreachable!(),
+    }
+    snowflake().verified_stmt("DESCRIBE STORAGE INTEGRATION s3_int");
+
+    // tables named `integration` are unaffected
+    snowflake().verified_stmt("DESC integration");
+    snowflake().verified_stmt("DROP TABLE integration");
+    snowflake().verified_stmt("ALTER TABLE integration ADD COLUMN a INT");
+
+    assert!(snowflake()
+        .parse_sql_statements("ALTER INTEGRATION my_int SET")
+        .is_err());
+}
+
+#[test]
+fn parse_trim_spellings() {
+    let select = snowflake().verified_only_select("SELECT TRIM(BOTH 'x' FROM name)");
+    assert_eq!(
+        expr_from_projection(only(&select.projection)),
+        &Expr::Trim {
+            expr: Box::new(Expr::Identifier(Ident::new("name"))),
+            trim_where: Some(TrimWhereField::Both),
+            trim_what: Some(Box::new(Expr::Value(
+                (Value::SingleQuotedString("x".to_string())).with_empty_span()
+            ))),
+            trim_characters: None,
+        }
+    );
+    let select = snowflake().verified_only_select("SELECT TRIM(name, 'x')");
+    assert_eq!(
+        expr_from_projection(only(&select.projection)),
+        &Expr::Trim {
+            expr: Box::new(Expr::Identifier(Ident::new("name"))),
+            trim_where: None,
+            trim_what: None,
+            trim_characters: Some(vec![Expr::Value(
+                (Value::SingleQuotedString("x".to_string())).with_empty_span()
+            )]),
+        }
+    );
+    snowflake().verified_stmt("SELECT TRIM(LEADING 'x' FROM name), TRIM(name, 'x'), TRIM(name)");
+}
+
+#[test]
+fn parse_null_treatment_after_function_args() {
+    let select =
+        snowflake().verified_only_select("SELECT LAG(x) IGNORE NULLS OVER (ORDER BY y) FROM t");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function {
+            null_treatment,
+            over,
+            ..
+        }) => {
+            assert_eq!(null_treatment, &Some(NullTreatment::IgnoreNulls));
+            assert!(over.is_some());
+        }
+        e => panic!("unexpected expression: {e:?}"),
+    }
+    snowflake().verified_stmt(
+        "SELECT FIRST_VALUE(x) RESPECT NULLS OVER (PARTITION BY z ORDER BY y) FROM t",
+    );
+    snowflake().verified_stmt("SELECT LAST_VALUE(x IGNORE NULLS) OVER (ORDER BY y) FROM t");
+}
+
+#[test]
+fn parse_grant_role_and_ownership() {
+    match snowflake().verified_stmt("GRANT ROLE analyst TO USER alice") {
+        Statement::Grant(Grant {
+            privileges,
+            objects,
+            grantees,
+            ..
+        }) => {
+            assert_eq!(
+                privileges,
+                Privileges::Actions(vec![Action::Role {
+                    role: ObjectName::from(vec![Ident::new("analyst")])
+                }])
+            );
+            assert_eq!(objects, None);
+            assert_eq!(
+                grantees,
+                vec![Grantee {
+                    grantee_type: GranteesType::User,
+                    name: Some(GranteeName::ObjectName(ObjectName::from(vec![Ident::new(
+                        "alice"
+                    )]))),
+                }]
+            );
+        }
+        s => panic!("unexpected statement: {s:?}"),
+    }
+    snowflake().verified_stmt("GRANT ROLE r1 TO ROLE r2");
+    snowflake().verified_stmt("REVOKE ROLE r1 FROM ROLE r2");
+
+    match snowflake().one_statement_parses_to(
+        "GRANT OWNERSHIP ON TABLE t TO ROLE r COPY CURRENT GRANTS",
+        "GRANT OWNERSHIP ON t TO ROLE r COPY CURRENT GRANTS",
+    ) {
+        Statement::Grant(Grant {
+            privileges,
+            objects,
+            current_grants,
+            ..
+        }) => {
+            assert_eq!(privileges, Privileges::Actions(vec![Action::Ownership]));
+            assert_eq!(
+                objects,
+                Some(GrantObjects::Tables(vec![ObjectName::from(vec![
+                    Ident::new("t")
+                ])]))
+            );
+            assert_eq!(current_grants, Some(CurrentGrantsKind::CopyCurrentGrants));
+        }
+        s => panic!("unexpected statement: {s:?}"),
+    }
+    snowflake().verified_stmt("GRANT OWNERSHIP ON SCHEMA s TO ROLE r REVOKE CURRENT GRANTS");
+    snowflake().verified_stmt("GRANT CREATE TABLE, CREATE VIEW ON SCHEMA s TO ROLE r");
+}
+
+#[test]
+fn parse_create_procedure_with_returns_and_execute_as() {
+    let sql = "CREATE OR REPLACE PROCEDURE sp_pi (x FLOAT) RETURNS VARCHAR LANGUAGE JAVASCRIPT EXECUTE AS CALLER AS 'return 3.14;'";
+    match snowflake().verified_stmt(sql) {
+        Statement::CreateProcedure {
+            or_replace,
+            return_type,
+            language,
+            execute_as,
+            body,
+            ..
+        } => {
+            assert!(or_replace);
+            assert_eq!(return_type, Some(DataType::Varchar(None)));
+            assert_eq!(language, Some(Ident::new("JAVASCRIPT")));
+            assert_eq!(execute_as, Some(ProcedureExecuteAs::Caller));
+            assert_eq!(
+                body,
+                CreateProcedureBody::AsDefinition(Expr::Value(
+                    Value::SingleQuotedString("return 3.14;".to_string()).with_empty_span()
+                ))
+            );
+        }
+        s => panic!("unexpected statement: {s:?}"),
+    }
+    snowflake().verified_stmt(
+        "CREATE PROCEDURE p (a INT) RETURNS INT LANGUAGE SQL EXECUTE AS OWNER AS $$ BEGIN RETURN a; END $$",
+    );
+    snowflake().verified_stmt(
+        "CREATE PROCEDURE p (a INT) RETURNS INT LANGUAGE PYTHON EXECUTE AS RESTRICTED CALLER AS 'x'",
+    );
+}
+
+#[test]
+fn parse_table_function_with_named_args() {
+    let select = snowflake().verified_only_select(
+        "SELECT * FROM TABLE(my_tf(start_date => '2024-01-01', n => 3)) AS t",
+    );
+    match &select.from[0].relation {
+        TableFactor::TableFunction {
+            expr: Expr::Function(func),
+            alias: Some(alias),
+        } => {
+            assert_eq!("my_tf", func.name.to_string());
+            assert_eq!(Ident::new("t"), alias.name);
+            match &func.args {
+                FunctionArguments::List(list) => {
+                    assert_eq!(2, list.args.len());
+                    assert!(matches!(
+                        &list.args[0],
+                        FunctionArg::Named {
+                            operator: FunctionArgOperator::RightArrow,
+                            ..
+                        }
+                    ));
+                }
+                args => unreachable!("{args:?}"),
+            }
+        }
+        r => unreachable!("{r:?}"),
+    }
+
+    snowflake().verified_stmt("SELECT * FROM TABLE(my_tf(start_date => '2024-01-01'))");
+    snowflake().verified_stmt("SELECT * FROM t, TABLE(FLATTEN(input => t.v)) f");
+}
+
+#[test]
+fn parse_time_travel_clauses() {
+    let select = snowflake()
+        .verified_only_select("SELECT * FROM t AT(TIMESTAMP => '2024-01-01'::TIMESTAMP)");
+    match &select.from[0].relation {
+        TableFactor::Table {
+            version: Some(TableVersion::Function(Expr::Function(func))),
+            ..
+        } => assert_eq!("AT", func.name.to_string()),
+        r => unreachable!("{r:?}"),
+    }
+
+    snowflake().verified_stmt("SELECT * FROM t AT(OFFSET => -3600) AS x");
+    snowflake().verified_stmt(
+        "SELECT * FROM t BEFORE(STATEMENT => '8e5d0ca9-005e-44e6-b858-a8f5b37c5726')",
+    );
+    snowflake().verified_stmt("SELECT * FROM t AT(STREAM => 's1')");
+
+    match snowflake().verified_stmt("CREATE TABLE t2 CLONE t AT(OFFSET => -3600)") {
+        Statement::CreateTable(CreateTable {
+            clone: Some(clone),
+            version: Some(TableVersion::Function(_)),
+            ..
+        }) => assert_eq!("t", clone.to_string()),
+        s => unreachable!("{s:?}"),
+    }
+    snowflake().verified_stmt("CREATE TABLE t2 CLONE t BEFORE(STATEMENT => 'abc')");
+}
+
+#[test]
+fn parse_repeat_loop() {
+    match snowflake()
+        .verified_stmt("REPEAT SET counter = counter + 1; UNTIL (counter > 5) END REPEAT")
+    {
+        Statement::Repeat(RepeatStatement { body, until }) => {
+            assert_eq!(1, body.len());
+            assert_eq!("(counter > 5)", until.to_string());
+        }
+        _ => unreachable!(),
+    }
+    snowflake().verified_stmt("REPEAT IF (x > 1) THEN BREAK; END IF; UNTIL (x > 5) END REPEAT");
+    snowflake().verified_stmt("CONTINUE outer_loop");
+}
+
+#[test]
+fn parse_alter_table_add_multiple_columns() {
+    match alter_table_op(
+        snowflake().verified_stmt("ALTER TABLE tab ADD COLUMN (a INT, b STRING DEFAULT 'x')"),
+    ) {
+        AlterTableOperation::AddColumns {
+            column_keyword,
+            if_not_exists,
+            column_defs,
+            parenthesized,
+        } => {
+            assert!(column_keyword);
+            assert!(!if_not_exists);
+            assert!(parenthesized);
+            assert_eq!(
+                vec!["a", "b"],
+                column_defs
+                    .iter()
+                    .map(|c| c.name.value.as_str())
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(DataType::String(None), column_defs[1].data_type);
+        }
+        op => panic!("unexpected operation: {op:?}"),
+    }
+
+    match alter_table_op(snowflake().verified_stmt("ALTER TABLE tab ADD a INT, b STRING")) {
+        AlterTableOperation::AddColumns {
+            column_keyword,
+            column_defs,
+            parenthesized,
+            ..
+        } => {
+            assert!(!column_keyword);
+            assert!(!parenthesized);
+            assert_eq!(2, column_defs.len());
+        }
+        op => panic!("unexpected operation: {op:?}"),
+    }
+
+    // A single column keeps the existing representation.
+    assert!(matches!(
+        alter_table_op(snowflake().verified_stmt("ALTER TABLE tab ADD COLUMN a INT")),
+        AlterTableOperation::AddColumn { .. }
+    ));
+    // A comma followed by another operation still separates operations.
+    snowflake().verified_stmt("ALTER TABLE tab ADD a INT, b STRING, DROP COLUMN c");
+    snowflake().verified_stmt("ALTER TABLE tab ADD COLUMN a INT, ADD COLUMN b INT");
+}
+
+#[test]
+fn parse_alter_table_search_optimization() {
+    assert_eq!(
+        alter_table_op(snowflake().verified_stmt("ALTER TABLE tab ADD SEARCH OPTIMIZATION")),
+        AlterTableOperation::AddSearchOptimization { on: None }
+    );
+    assert_eq!(
+        alter_table_op(snowflake().verified_stmt("ALTER TABLE tab DROP SEARCH OPTIMIZATION")),
+        AlterTableOperation::DropSearchOptimization { on: None }
+    );
+
+    match alter_table_op(snowflake().verified_stmt(
+        "ALTER TABLE tab ADD SEARCH OPTIMIZATION ON EQUALITY(col1), SUBSTRING(col2)",
+    )) {
+        AlterTableOperation::AddSearchOptimization { on: Some(on) } => {
+            assert_eq!(on.len(), 2);
+            assert!(matches!(&on[0], Expr::Function(f) if f.name.to_string() == "EQUALITY"));
+            assert!(matches!(&on[1], Expr::Function(f) if f.name.to_string() == "SUBSTRING"));
+        }
+        op => panic!("unexpected operation: {op:?}"),
+    }
+    snowflake().verified_stmt("ALTER TABLE tab DROP SEARCH OPTIMIZATION ON EQUALITY(*), GEO(c)");
+
+    assert!(snowflake()
+        .parse_sql_statements("ALTER TABLE tab ADD SEARCH OPTIMIZATION ON col1")
+        .is_err());
+}
+
+#[test]
+fn parse_alter_view_secure() {
+    match snowflake().verified_stmt("ALTER VIEW v SET SECURE") {
+        Statement::AlterView { operation, .. } => {
+            assert_eq!(operation, AlterViewOperation::SetSecure)
+        }
+        _ => unreachable!(),
+    }
+    match snowflake().verified_stmt("ALTER VIEW IF EXISTS db.s.v UNSET SECURE") {
+        Statement::AlterView {
+            if_exists,
+            operation,
+            ..
+        } => {
+            assert!(if_exists);
+            assert_eq!(operation, AlterViewOperation::UnsetSecure);
+        }
+        _ => unreachable!(),
+    }
+    snowflake().verified_stmt("ALTER VIEW v RENAME TO db.s.w");
+}
+
+#[test]
+fn parse_copy_into_stage_path() {
+    match snowflake().verified_stmt("COPY INTO t FROM @db.schema.stage/path/2024-01-01") {
+        Statement::CopyIntoSnowflake { from_obj, .. } => assert_eq!(
+            from_obj,
+            Some(ObjectName::from(vec![
+                Ident::new("@db"),
+                Ident::new("schema"),
+                Ident::new("stage/path/2024-01-01"),
+            ]))
+        ),
+        _ => unreachable!(),
+    }
+    snowflake().verified_stmt("COPY INTO @db.schema.stage/out FROM t");
+}
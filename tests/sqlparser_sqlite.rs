@@ -29,8 +29,8 @@ use test_utils::*;
 use sqlparser::ast::SelectItem::UnnamedExpr;
 use sqlparser::ast::Value::Placeholder;
 use sqlparser::ast::*;
-use sqlparser::dialect::{GenericDialect, SQLiteDialect};
-use sqlparser::parser::{ParserError, ParserOptions};
+use sqlparser::dialect::{Dialect, GenericDialect, SQLiteDialect};
+use sqlparser::parser::{Parser, ParserError, ParserOptions};
 use sqlparser::tokenizer::Token;
 
 #[test]
@@ -151,16 +151,99 @@ fn parse_create_virtual_table() {
             module_name,
             module_args,
         } => {
-            let args = vec![Ident::new("arg1"), Ident::new("arg2")];
+            let args = vec!["arg1".to_string(), "arg2".to_string()];
             assert_eq!("t", name.to_string());
             assert_eq!("module_name", module_name.to_string());
-            assert_eq!(args, module_args);
+            assert_eq!(Some(args), module_args);
         }
         _ => unreachable!(),
     }
 
     let sql = "CREATE VIRTUAL TABLE t USING module_name";
-    sqlite_and_generic().verified_stmt(sql);
+    match sqlite_and_generic().verified_stmt(sql) {
+        Statement::CreateVirtualTable { module_args, .. } => assert_eq!(None, module_args),
+        _ => unreachable!(),
+    }
+
+    // An empty argument list keeps its parentheses
+    let sql = "CREATE VIRTUAL TABLE t USING fts5 ()";
+    match sqlite_and_generic().verified_stmt(sql) {
+        Statement::CreateVirtualTable { module_args, .. } => {
+            assert_eq!(Some(vec![]), module_args)
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_create_virtual_table_module_args() {
+    let sql = "CREATE VIRTUAL TABLE IF NOT EXISTS docs USING fts5 (title, body, tokenize='porter unicode61', prefix = '2 3')";
+    match sqlite_and_generic().verified_stmt(sql) {
+        Statement::CreateVirtualTable {
+            if_not_exists: true,
+            module_name,
+            module_args,
+            ..
+        } => {
+            assert_eq!("fts5", module_name.to_string());
+            assert_eq!(
+                Some(vec![
+                    "title".to_string(),
+                    "body".to_string(),
+                    "tokenize='porter unicode61'".to_string(),
+                    "prefix = '2 3'".to_string(),
+                ]),
+                module_args
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // Nested parentheses don't split arguments
+    let sql = "CREATE VIRTUAL TABLE geo USING rtree (id, minX, maxX, +note TEXT CHECK (length(note, 2) > 0), \"quoted, arg\")";
+    match sqlite_and_generic().verified_stmt(sql) {
+        Statement::CreateVirtualTable { module_args, .. } => {
+            assert_eq!(
+                Some(vec![
+                    "id".to_string(),
+                    "minX".to_string(),
+                    "maxX".to_string(),
+                    "+note TEXT CHECK (length(note, 2) > 0)".to_string(),
+                    "\"quoted, arg\"".to_string(),
+                ]),
+                module_args
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // Spacing and comments inside an argument are kept as written
+    sqlite_and_generic().verified_stmt(
+        "CREATE VIRTUAL TABLE docs USING fts5 (title  UNINDEXED, tokenize = /* stemmer */ 'porter')",
+    );
+
+    // Arguments are sliced from the original input when it is available
+    let sql = "CREATE VIRTUAL TABLE t USING m (x'0a', 'It''s'  ,  1.50)";
+    for dialect in [&SQLiteDialect {} as &dyn Dialect, &GenericDialect {}] {
+        match Parser::parse_sql(dialect, sql).unwrap().pop().unwrap() {
+            Statement::CreateVirtualTable { module_args, .. } => assert_eq!(
+                Some(vec![
+                    "x'0a'".to_string(),
+                    "'It''s'".to_string(),
+                    "1.50".to_string(),
+                ]),
+                module_args
+            ),
+            _ => unreachable!(),
+        }
+    }
+
+    assert_eq!(
+        sqlite()
+            .parse_sql_statements("CREATE VIRTUAL TABLE t USING m (a, (b)")
+            .unwrap_err(),
+        ParserError::ParserError("Expected: ), found: EOF".to_string())
+    );
 }
 
 #[test]
@@ -177,6 +260,7 @@ fn parse_create_view_temporary_if_not_exists() {
             cluster_by,
             comment,
             with_no_schema_binding: late_binding,
+            with_check_option: None,
             if_not_exists,
             temporary,
             ..
@@ -478,6 +562,7 @@ fn parse_update_tuple_row_values() {
         sqlite().verified_stmt("UPDATE x SET (a, b) = (1, 2)"),
         Statement::Update(Update {
             optimizer_hints: vec![],
+            top: None,
             or: None,
             assignments: vec![Assignment {
                 target: AssignmentTarget::Tuple(vec![
@@ -499,6 +584,7 @@ fn parse_update_tuple_row_values() {
             output: None,
             order_by: vec![],
             limit: None,
+            options: vec![],
             update_token: AttachedToken::empty()
         })
     );
@@ -636,6 +722,7 @@ fn test_create_trigger() {
             or_alter,
             temporary,
             or_replace,
+            if_not_exists: false,
             is_constraint,
             name,
             period,
@@ -682,6 +769,7 @@ fn test_create_trigger() {
             or_alter,
             temporary,
             or_replace,
+            if_not_exists: false,
             is_constraint,
             name,
             period,
@@ -722,6 +810,7 @@ fn test_create_trigger() {
             or_alter,
             temporary,
             or_replace,
+            if_not_exists: false,
             is_constraint,
             name,
             period,
@@ -762,6 +851,7 @@ fn test_create_trigger() {
             or_alter,
             temporary,
             or_replace,
+            if_not_exists: false,
             is_constraint,
             name,
             period,
@@ -806,6 +896,7 @@ fn test_create_trigger() {
             or_alter,
             temporary,
             or_replace,
+            if_not_exists: false,
             is_constraint,
             name,
             period,
@@ -847,6 +938,7 @@ fn test_create_trigger() {
             or_alter,
             temporary,
             or_replace,
+            if_not_exists: false,
             is_constraint,
             name,
             period,
@@ -906,6 +998,56 @@ fn test_drop_trigger() {
     }
 }
 
+#[test]
+fn parse_returning() {
+    let expected = vec![
+        SelectItem::ExprWithAlias {
+            expr: Expr::Identifier(Ident::new("id")),
+            alias: Ident::new("new_id"),
+        },
+        SelectItem::UnnamedExpr(call("now", [])),
+    ];
+
+    match sqlite().verified_stmt("INSERT INTO t SELECT a FROM s RETURNING id AS new_id, now()") {
+        Statement::Insert(Insert { returning, .. }) => {
+            assert_eq!(returning, Some(expected.clone()))
+        }
+        _ => unreachable!(),
+    }
+    match sqlite().verified_stmt("UPDATE t SET a = 1 RETURNING id AS new_id, now()") {
+        Statement::Update(Update { returning, .. }) => {
+            assert_eq!(returning, Some(expected.clone()))
+        }
+        _ => unreachable!(),
+    }
+    match sqlite().verified_stmt("DELETE FROM t WHERE a = 1 RETURNING id AS new_id, now()") {
+        Statement::Delete(Delete { returning, .. }) => assert_eq!(returning, Some(expected)),
+        _ => unreachable!(),
+    }
+
+    match sqlite().verified_stmt("UPDATE t SET a = 1 RETURNING t.*") {
+        Statement::Update(Update { returning, .. }) => assert_eq!(
+            returning,
+            Some(vec![SelectItem::QualifiedWildcard(
+                SelectItemQualifiedWildcardKind::ObjectName(ObjectName::from(vec![Ident::new(
+                    "t"
+                )])),
+                WildcardAdditionalOptions::default(),
+            )])
+        ),
+        _ => unreachable!(),
+    }
+    for sql in [
+        "INSERT INTO t (a) VALUES (1) RETURNING *",
+        "INSERT INTO t SELECT a FROM s RETURNING t.*, a + 1 AS b",
+        "UPDATE t SET a = 1 WHERE b = 2 RETURNING *",
+        "DELETE FROM t RETURNING *",
+        "DELETE FROM t RETURNING t.*, a AS old_a",
+    ] {
+        sqlite().verified_stmt(sql);
+    }
+}
+
 fn sqlite() -> TestedDialects {
     TestedDialects::new(vec![Box::new(SQLiteDialect {})])
 }
@@ -920,3 +1062,110 @@ fn sqlite_and_generic() -> TestedDialects {
         Box::new(GenericDialect {}),
     ])
 }
+
+#[test]
+fn parse_group_concat() {
+    sqlite().verified_stmt("SELECT group_concat(x) FROM t");
+    sqlite().verified_stmt("SELECT group_concat(DISTINCT x) FROM t");
+    sqlite().verified_stmt("SELECT group_concat(x, '; ') FROM t");
+}
+
+#[test]
+fn parse_create_temporary_view() {
+    match sqlite().verified_stmt("CREATE TEMPORARY VIEW IF NOT EXISTS v (a, b) AS SELECT 1, 2") {
+        Statement::CreateView(CreateView {
+            temporary,
+            if_not_exists,
+            columns,
+            with_check_option,
+            ..
+        }) => {
+            assert!(temporary);
+            assert!(if_not_exists);
+            assert_eq!(2, columns.len());
+            assert_eq!(None, with_check_option);
+        }
+        _ => unreachable!(),
+    }
+    sqlite().one_statement_parses_to(
+        "CREATE TEMP VIEW v AS SELECT 1",
+        "CREATE TEMPORARY VIEW v AS SELECT 1",
+    );
+}
+
+#[test]
+fn parse_create_trigger_instead_of_update_of() {
+    let sql = "CREATE TRIGGER trg INSTEAD OF UPDATE OF col1, col2 ON v FOR EACH ROW WHEN NEW.x > 0 BEGIN UPDATE t SET a = NEW.a WHERE id = OLD.id; INSERT INTO log VALUES (OLD.id, NEW.x); END";
+    match sqlite().verified_stmt(sql) {
+        Statement::CreateTrigger(CreateTrigger {
+            if_not_exists,
+            name,
+            period,
+            events,
+            table_name,
+            condition,
+            statements,
+            ..
+        }) => {
+            assert!(!if_not_exists);
+            assert_eq!(name.to_string(), "trg");
+            assert_eq!(period, Some(TriggerPeriod::InsteadOf));
+            assert_eq!(
+                events,
+                vec![TriggerEvent::Update(vec![
+                    Ident::new("col1"),
+                    Ident::new("col2")
+                ])]
+            );
+            assert_eq!(table_name.to_string(), "v");
+            assert_eq!(condition.unwrap().to_string(), "NEW.x > 0");
+            assert_eq!(statements.unwrap().statements().len(), 2);
+        }
+        _ => unreachable!(),
+    }
+
+    let sql = "CREATE TRIGGER IF NOT EXISTS trg AFTER INSERT ON t BEGIN DELETE FROM u WHERE id = NEW.id; END";
+    match sqlite().verified_stmt(sql) {
+        Statement::CreateTrigger(CreateTrigger {
+            if_not_exists,
+            name,
+            ..
+        }) => {
+            assert!(if_not_exists);
+            assert_eq!(name.to_string(), "trg");
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_cte_prefixed_dml() {
+    match sqlite().verified_stmt(
+        "WITH doomed AS (SELECT id FROM t WHERE old) DELETE FROM t WHERE id IN (SELECT id FROM doomed)",
+    ) {
+        Statement::Query(query) => {
+            assert!(!query.with.unwrap().recursive);
+            assert!(matches!(*query.body, SetExpr::Delete(_)));
+        }
+        _ => unreachable!(),
+    }
+
+    match sqlite().verified_stmt(
+        "WITH src AS (SELECT 1 AS id) UPDATE t SET x = src.id FROM src WHERE t.id = src.id",
+    ) {
+        Statement::Query(query) => {
+            assert!(matches!(*query.body, SetExpr::Update(_)));
+        }
+        _ => unreachable!(),
+    }
+
+    match sqlite().verified_stmt(
+        "WITH RECURSIVE r (n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM r WHERE n < 10) INSERT INTO t (id) SELECT n FROM r",
+    ) {
+        Statement::Query(query) => {
+            assert!(query.with.unwrap().recursive);
+            assert!(matches!(*query.body, SetExpr::Insert(_)));
+        }
+        _ => unreachable!(),
+    }
+}